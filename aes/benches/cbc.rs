@@ -0,0 +1,51 @@
+#![feature(test)]
+extern crate test;
+
+use aes::cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use aes::{Aes128, Block, ParBlocks};
+
+const BLOCKS: usize = 1024;
+
+#[bench]
+pub fn aes128_cbc_encrypt(bh: &mut test::Bencher) {
+    let cipher = Aes128::new(&Default::default());
+    let mut data = vec![Block::default(); BLOCKS];
+
+    bh.iter(|| {
+        let mut prev = Block::default();
+        for block in data.iter_mut() {
+            for (a, b) in block.iter_mut().zip(prev.iter()) {
+                *a ^= *b;
+            }
+            cipher.encrypt_block(block);
+            prev = *block;
+        }
+        test::black_box(&data);
+    });
+    bh.bytes = (BLOCKS * 16) as u64;
+}
+
+#[bench]
+pub fn aes128_cbc_decrypt(bh: &mut test::Bencher) {
+    let cipher = Aes128::new(&Default::default());
+    let mut data = vec![Block::default(); BLOCKS];
+
+    bh.iter(|| {
+        let mut prev = Block::default();
+        for chunk in data.chunks_exact_mut(8) {
+            let ct = ParBlocks::clone_from_slice(chunk);
+            cipher.decrypt_blocks(chunk);
+            for (block, prev) in chunk
+                .iter_mut()
+                .zip(Some(&prev).into_iter().chain(&ct[..7]))
+            {
+                for (a, b) in block.iter_mut().zip(prev.iter()) {
+                    *a ^= *b;
+                }
+            }
+            prev = ct[7];
+        }
+        test::black_box(&data);
+    });
+    bh.bytes = (BLOCKS * 16) as u64;
+}
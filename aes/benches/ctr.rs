@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::stream_cipher_sync_bench!(aes::Aes128Ctr);
@@ -57,6 +57,18 @@ macro_rules! define_aes_impl {
             }
         }
 
+        impl $name {
+            /// Get the round keys of the encryption key schedule.
+            #[cfg(feature = "hazmat")]
+            pub(crate) fn round_keys(&self) -> [Block; $rounds] {
+                let mut round_keys = [Block::default(); $rounds];
+                for (dst, key) in round_keys.iter_mut().zip(self.encrypt.round_keys.iter()) {
+                    unsafe { vst1q_u8(dst.as_mut_ptr(), *key) };
+                }
+                round_keys
+            }
+        }
+
         impl BlockCipher for $name {
             type BlockSize = U16;
             type ParBlocks = U8;
@@ -98,7 +110,7 @@ macro_rules! define_aes_impl {
 
             fn new(key: &GenericArray<u8, $key_size>) -> Self {
                 Self {
-                    round_keys: expand_key(key.as_ref()),
+                    round_keys: unsafe { expand_key(key.as_ref()) },
                 }
             }
         }
@@ -142,7 +154,7 @@ macro_rules! define_aes_impl {
         impl From<&$name_enc> for $name_dec {
             fn from(enc: &$name_enc) -> $name_dec {
                 let mut round_keys = enc.round_keys;
-                inv_expanded_keys(&mut round_keys);
+                unsafe { inv_expanded_keys(&mut round_keys) };
                 Self { round_keys }
             }
         }
@@ -165,6 +177,31 @@ macro_rules! define_aes_impl {
         opaque_debug::implement!($name);
         opaque_debug::implement!($name_enc);
         opaque_debug::implement!($name_dec);
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name_enc {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.round_keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name_enc {}
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name_dec {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.round_keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name_dec {}
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
     };
 }
 
@@ -175,7 +212,7 @@ define_aes_impl!(Aes256, Aes256Enc, Aes256Dec, U32, 15, "AES-256");
 #[cfg(test)]
 mod tests {
     use super::{decrypt, decrypt8, encrypt, encrypt8, expand_key, inv_expanded_keys, ParBlocks};
-    use core::{arch::aarch64::*, convert::TryInto};
+    use core::arch::aarch64::*;
     use hex_literal::hex;
 
     /// FIPS 197, Appendix A.1: AES-128 Cipher Key
@@ -289,26 +326,26 @@ mod tests {
 
     #[test]
     fn aes128_key_expansion() {
-        let ek = expand_key(&AES128_KEY);
+        let ek = unsafe { expand_key(&AES128_KEY) };
         assert_eq!(store_expanded_keys(ek), AES128_EXP_KEYS);
     }
 
     #[test]
     fn aes128_key_expansion_inv() {
         let mut ek = load_expanded_keys(AES128_EXP_KEYS);
-        inv_expanded_keys(&mut ek);
+        unsafe { inv_expanded_keys(&mut ek) };
         assert_eq!(store_expanded_keys(ek), AES128_EXP_INVKEYS);
     }
 
     #[test]
     fn aes192_key_expansion() {
-        let ek = expand_key(&AES192_KEY);
+        let ek = unsafe { expand_key(&AES192_KEY) };
         assert_eq!(store_expanded_keys(ek), AES192_EXP_KEYS);
     }
 
     #[test]
     fn aes256_key_expansion() {
-        let ek = expand_key(&AES256_KEY);
+        let ek = unsafe { expand_key(&AES256_KEY) };
         assert_eq!(store_expanded_keys(ek), AES256_EXP_KEYS);
     }
 
@@ -321,7 +358,7 @@ mod tests {
         unsafe {
             encrypt(
                 &load_expanded_keys(AES128_EXP_KEYS),
-                (&mut block[3..]).try_into().unwrap(),
+                (&mut block[3..]).into(),
             )
         };
 
@@ -352,7 +389,7 @@ mod tests {
         unsafe {
             decrypt(
                 &load_expanded_keys(AES128_EXP_INVKEYS),
-                (&mut block[3..]).try_into().unwrap(),
+                (&mut block[3..]).into(),
             )
         };
 
@@ -1,43 +1,74 @@
 //! Autodetection support for hardware accelerated AES backends with fallback
 //! to the fixsliced "soft" implementation.
-
-use crate::{soft, Block, ParBlocks};
+//!
+//! Backends are listed in order of preference in the `define_aes_impls!`
+//! invocation below, each together with the runtime check which has to pass
+//! for it to be used. The checks are evaluated when a cipher is initialized
+//! and the first matching backend is selected, with the last entry serving
+//! as an unconditional fallback. Supporting a new backend only requires
+//! adding it to that list.
+
+use crate::{soft, Block};
 use cipher::{
-    consts::{U16, U24, U32, U8},
+    consts::{U16, U24, U32},
     generic_array::GenericArray,
     BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
 };
-use core::mem::ManuallyDrop;
 
-#[cfg(all(target_arch = "aarch64", feature = "armv8"))]
+#[cfg(target_arch = "aarch64")]
 use crate::armv8 as intrinsics;
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::ni as intrinsics;
 
+#[cfg(target_arch = "powerpc64")]
+use crate::power as intrinsics;
+
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::riscv as intrinsics;
+
+#[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+use crate::vaes;
+
+#[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
 cpufeatures::new!(aes_intrinsics, "aes");
+#[cfg(target_arch = "powerpc64")]
+use crate::power::detect as aes_intrinsics;
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+use crate::riscv::detect as aes_intrinsics;
+#[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+cpufeatures::new!(vaes_intrinsics, "aes", "avx512f", "vaes");
+
+/// Number of blocks processed in parallel, the batch size of the widest
+/// backend.
+#[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+type ParBlocksSize = U32;
+#[cfg(not(all(target_arch = "x86_64", feature = "vaes")))]
+type ParBlocksSize = cipher::consts::U8;
 
 macro_rules! define_aes_impl {
     (
-        $name:tt,
-        $module:tt,
+        $name:ident,
+        $inner:ident,
         $key_size:ty,
-        $doc:expr
+        $key_bytes:expr,
+        $rounds:tt,
+        $doc:expr,
+        [$($backend:ident => $module:ident if $detect:expr),*],
+        $fallback:ident => $fallback_module:ident
     ) => {
-        #[doc=$doc]
-        pub struct $name {
-            inner: $module::Inner,
-            token: aes_intrinsics::InitToken,
+        // the soft fallback is larger, but boxing is not an option in `no_std`
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Clone)]
+        enum $inner {
+            $($backend($module::$name),)*
+            $fallback($fallback_module::$name),
         }
 
-        mod $module {
-            use super::{intrinsics, soft};
-            use core::mem::ManuallyDrop;
-
-            pub(super) union Inner {
-                pub(super) intrinsics: ManuallyDrop<intrinsics::$name>,
-                pub(super) soft: ManuallyDrop<soft::$name>,
-            }
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $name {
+            inner: $inner,
         }
 
         impl NewBlockCipher for $name {
@@ -45,62 +76,89 @@ macro_rules! define_aes_impl {
 
             #[inline]
             fn new(key: &GenericArray<u8, $key_size>) -> Self {
-                let (token, aesni_present) = aes_intrinsics::init_get();
-
-                let inner = if aesni_present {
-                    $module::Inner {
-                        intrinsics: ManuallyDrop::new(intrinsics::$name::new(key)),
-                    }
-                } else {
-                    $module::Inner {
-                        soft: ManuallyDrop::new(soft::$name::new(key)),
+                $(
+                    if $detect {
+                        let inner = $inner::$backend($module::$name::new(key));
+                        return Self { inner };
                     }
-                };
-
-                Self { inner, token }
+                )*
+                Self::new_soft(key)
             }
         }
 
-        impl Clone for $name {
-            fn clone(&self) -> Self {
-                let inner = if self.token.get() {
-                    $module::Inner {
-                        intrinsics: unsafe { self.inner.intrinsics.clone() },
-                    }
-                } else {
-                    $module::Inner {
-                        soft: unsafe { self.inner.soft.clone() },
-                    }
-                };
+        impl $name {
+            /// Create a new cipher instance which always uses the
+            /// constant-time "soft" backend, even if hardware support for
+            /// AES is available.
+            ///
+            /// This allows testing and validating the software
+            /// implementation in builds without the `force-soft` feature.
+            #[inline]
+            pub fn new_soft(key: &GenericArray<u8, $key_size>) -> Self {
+                let inner = $inner::$fallback($fallback_module::$name::new(key));
+                Self { inner }
+            }
+
+            /// Create a new cipher instance in a `const` context.
+            ///
+            /// This allows initializing `static` cipher instances with the
+            /// round keys computed at compile time. Such instances always
+            /// use the "soft" backend, like the ones created with `new_soft`.
+            #[inline]
+            pub const fn new_const(key: &[u8; $key_bytes]) -> Self {
+                let inner = $inner::$fallback($fallback_module::$name::new_const(key));
+                Self { inner }
+            }
 
-                Self {
-                    inner,
-                    token: self.token,
+            /// ⚠️ Get the round keys of the key schedule.
+            ///
+            /// Returns the round keys as described in FIPS 197 Section 5.2,
+            /// like the key expansion functions of the [`hazmat`] module.
+            ///
+            /// # ☢️️ WARNING: HAZARDOUS API ☢️
+            ///
+            /// Use this function with great care! See the [module-level
+            /// documentation][crate::hazmat] for more information.
+            ///
+            /// [`hazmat`]: crate::hazmat
+            #[cfg(feature = "hazmat")]
+            pub fn round_keys(&self) -> [Block; $rounds] {
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.round_keys(),)*
+                    $inner::$fallback(cipher) => cipher.round_keys(),
                 }
             }
         }
 
         impl BlockCipher for $name {
             type BlockSize = U16;
-            type ParBlocks = U8;
+            type ParBlocks = ParBlocksSize;
         }
 
         impl BlockEncrypt for $name {
             #[inline]
             fn encrypt_block(&self, block: &mut Block) {
-                if self.token.get() {
-                    unsafe { self.inner.intrinsics.encrypt_block(block) }
-                } else {
-                    unsafe { self.inner.soft.encrypt_block(block) }
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.encrypt_block(block),)*
+                    $inner::$fallback(cipher) => cipher.encrypt_block(block),
+                }
+            }
+
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocksSize>) {
+                // backends narrower than `ParBlocksSize` process the blocks
+                // in several batches
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.encrypt_blocks(blocks),)*
+                    $inner::$fallback(cipher) => cipher.encrypt_blocks(blocks),
                 }
             }
 
             #[inline]
-            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
-                if self.token.get() {
-                    unsafe { self.inner.intrinsics.encrypt_par_blocks(blocks) }
-                } else {
-                    unsafe { self.inner.soft.encrypt_par_blocks(blocks) }
+            fn encrypt_blocks(&self, blocks: &mut [Block]) {
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.encrypt_blocks(blocks),)*
+                    $inner::$fallback(cipher) => cipher.encrypt_blocks(blocks),
                 }
             }
         }
@@ -108,32 +166,66 @@ macro_rules! define_aes_impl {
         impl BlockDecrypt for $name {
             #[inline]
             fn decrypt_block(&self, block: &mut Block) {
-                if self.token.get() {
-                    unsafe { self.inner.intrinsics.decrypt_block(block) }
-                } else {
-                    unsafe { self.inner.soft.decrypt_block(block) }
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.decrypt_block(block),)*
+                    $inner::$fallback(cipher) => cipher.decrypt_block(block),
                 }
             }
 
             #[inline]
-            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
-                if self.token.get() {
-                    unsafe { self.inner.intrinsics.decrypt_par_blocks(blocks) }
-                } else {
-                    unsafe { self.inner.soft.decrypt_par_blocks(blocks) }
+            fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocksSize>) {
+                // backends narrower than `ParBlocksSize` process the blocks
+                // in several batches
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.decrypt_blocks(blocks),)*
+                    $inner::$fallback(cipher) => cipher.decrypt_blocks(blocks),
+                }
+            }
+
+            #[inline]
+            fn decrypt_blocks(&self, blocks: &mut [Block]) {
+                match &self.inner {
+                    $($inner::$backend(cipher) => cipher.decrypt_blocks(blocks),)*
+                    $inner::$fallback(cipher) => cipher.decrypt_blocks(blocks),
                 }
             }
         }
 
         opaque_debug::implement!($name);
+
+        // the backend of the live variant wipes its round keys when dropped
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
     };
 }
 
-define_aes_impl!(Aes128, aes128, U16, "AES-128 block cipher instance");
-define_aes_impl!(Aes192, aes192, U24, "AES-192 block cipher instance");
-define_aes_impl!(Aes256, aes256, U32, "AES-256 block cipher instance");
+macro_rules! define_aes_impls {
+    ($($backends:tt)*) => {
+        define_aes_impl!(Aes128, Aes128Inner, U16, 16, 11, "AES-128 block cipher instance", $($backends)*);
+        define_aes_impl!(Aes192, Aes192Inner, U24, 24, 13, "AES-192 block cipher instance", $($backends)*);
+        define_aes_impl!(Aes256, Aes256Inner, U32, 32, 15, "AES-256 block cipher instance", $($backends)*);
+    };
+}
 
-#[cfg(all(feature = "ctr", target_arch = "aarch64"))]
+#[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+define_aes_impls!(
+    [
+        Vaes => vaes if vaes_intrinsics::get(),
+        Intrinsics => intrinsics if aes_intrinsics::get()
+    ],
+    Soft => soft
+);
+
+#[cfg(not(all(target_arch = "x86_64", feature = "vaes")))]
+define_aes_impls!(
+    [Intrinsics => intrinsics if aes_intrinsics::get()],
+    Soft => soft
+);
+
+#[cfg(all(
+    feature = "ctr",
+    not(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))
+))]
 pub(crate) mod ctr {
     use super::{Aes128, Aes192, Aes256};
 
@@ -147,41 +239,56 @@ pub(crate) mod ctr {
     pub type Aes256Ctr = ::ctr::Ctr64BE<Aes256>;
 }
 
-#[cfg(all(feature = "ctr", any(target_arch = "x86_64", target_arch = "x86")))]
+#[cfg(all(
+    feature = "ctr",
+    any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86")
+))]
 pub(crate) mod ctr {
-    use super::{Aes128, Aes192, Aes256};
-    use crate::{ni, soft};
+    use super::{Aes128, Aes128Inner, Aes192, Aes192Inner, Aes256, Aes256Inner};
     use cipher::{
         errors::{LoopError, OverflowError},
         generic_array::GenericArray,
         BlockCipher, FromBlockCipher, SeekNum, StreamCipher, StreamCipherSeek,
     };
-    use core::mem::ManuallyDrop;
 
-    cpufeatures::new!(aes_ssse3_cpuid, "aes", "ssse3");
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    use crate::ni as intrinsics;
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    cpufeatures::new!(ctr_intrinsics, "aes", "ssse3");
+
+    /// CTR mode directly on top of the ARMv8 backend, which avoids
+    /// dispatching to the selected backend for every batch of blocks.
+    #[cfg(target_arch = "aarch64")]
+    mod intrinsics {
+        use crate::armv8::{Aes128, Aes192, Aes256};
+
+        pub(super) type Aes128Ctr = ::ctr::Ctr64BE<Aes128>;
+        pub(super) type Aes192Ctr = ::ctr::Ctr64BE<Aes192>;
+        pub(super) type Aes256Ctr = ::ctr::Ctr64BE<Aes256>;
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    use super::aes_intrinsics as ctr_intrinsics;
 
     macro_rules! define_aes_ctr_impl {
         (
-            $name:tt,
+            $name:ident,
             $cipher:ident,
-            $module:tt,
+            $cipher_inner:ident,
+            $inner:ident,
             $doc:expr
         ) => {
+            #[allow(clippy::large_enum_variant)]
+            enum $inner {
+                Intrinsics(intrinsics::$name),
+                Generic(::ctr::Ctr64BE<$cipher>),
+            }
+
             #[doc=$doc]
             #[cfg_attr(docsrs, doc(cfg(feature = "ctr")))]
             pub struct $name {
-                inner: $module::Inner,
-                token: aes_ssse3_cpuid::InitToken,
-            }
-
-            mod $module {
-                use crate::{ni, soft};
-                use core::mem::ManuallyDrop;
-
-                pub(super) union Inner {
-                    pub(super) ni: ManuallyDrop<ni::$name>,
-                    pub(super) soft: ManuallyDrop<soft::$name>,
-                }
+                inner: $inner,
             }
 
             impl FromBlockCipher for $name {
@@ -192,39 +299,29 @@ pub(crate) mod ctr {
                     cipher: $cipher,
                     nonce: &GenericArray<u8, Self::NonceSize>,
                 ) -> Self {
-                    let (token, aesni_present) = aes_ssse3_cpuid::init_get();
-
-                    let inner = if aesni_present {
-                        let ni = ni::$name::from_block_cipher(
-                            unsafe { (*cipher.inner.intrinsics).clone() },
-                            nonce,
-                        );
-
-                        $module::Inner {
-                            ni: ManuallyDrop::new(ni),
+                    let inner = match cipher.inner {
+                        #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+                        $cipher_inner::Vaes(cipher) if ctr_intrinsics::get() => {
+                            $inner::Intrinsics(intrinsics::$name::from_vaes(cipher, nonce))
                         }
-                    } else {
-                        let soft = soft::$name::from_block_cipher(
-                            unsafe { (*cipher.inner.soft).clone() },
-                            nonce,
-                        );
-
-                        $module::Inner {
-                            soft: ManuallyDrop::new(soft),
+                        $cipher_inner::Intrinsics(cipher) if ctr_intrinsics::get() => {
+                            $inner::Intrinsics(intrinsics::$name::from_block_cipher(cipher, nonce))
+                        }
+                        inner => {
+                            let cipher = $cipher { inner };
+                            $inner::Generic(::ctr::Ctr64BE::from_block_cipher(cipher, nonce))
                         }
                     };
-
-                    Self { inner, token }
+                    Self { inner }
                 }
             }
 
             impl StreamCipher for $name {
                 #[inline]
                 fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
-                    if self.token.get() {
-                        unsafe { (*self.inner.ni).try_apply_keystream(data) }
-                    } else {
-                        unsafe { (*self.inner.soft).try_apply_keystream(data) }
+                    match &mut self.inner {
+                        $inner::Intrinsics(ctr) => ctr.try_apply_keystream(data),
+                        $inner::Generic(ctr) => ctr.try_apply_keystream(data),
                     }
                 }
             }
@@ -232,19 +329,17 @@ pub(crate) mod ctr {
             impl StreamCipherSeek for $name {
                 #[inline]
                 fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
-                    if self.token.get() {
-                        unsafe { (*self.inner.ni).try_current_pos() }
-                    } else {
-                        unsafe { (*self.inner.soft).try_current_pos() }
+                    match &self.inner {
+                        $inner::Intrinsics(ctr) => ctr.try_current_pos(),
+                        $inner::Generic(ctr) => ctr.try_current_pos(),
                     }
                 }
 
                 #[inline]
                 fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
-                    if self.token.get() {
-                        unsafe { (*self.inner.ni).try_seek(pos) }
-                    } else {
-                        unsafe { (*self.inner.soft).try_seek(pos) }
+                    match &mut self.inner {
+                        $inner::Intrinsics(ctr) => ctr.try_seek(pos),
+                        $inner::Generic(ctr) => ctr.try_seek(pos),
                     }
                 }
             }
@@ -253,7 +348,25 @@ pub(crate) mod ctr {
         };
     }
 
-    define_aes_ctr_impl!(Aes128Ctr, Aes128, aes128ctr, "AES-128 in CTR mode");
-    define_aes_ctr_impl!(Aes192Ctr, Aes192, aes192ctr, "AES-192 in CTR mode");
-    define_aes_ctr_impl!(Aes256Ctr, Aes256, aes256ctr, "AES-256 in CTR mode");
+    define_aes_ctr_impl!(
+        Aes128Ctr,
+        Aes128,
+        Aes128Inner,
+        Aes128CtrInner,
+        "AES-128 in CTR mode"
+    );
+    define_aes_ctr_impl!(
+        Aes192Ctr,
+        Aes192,
+        Aes192Inner,
+        Aes192CtrInner,
+        "AES-192 in CTR mode"
+    );
+    define_aes_ctr_impl!(
+        Aes256Ctr,
+        Aes256,
+        Aes256Inner,
+        Aes256CtrInner,
+        "AES-256 in CTR mode"
+    );
 }
@@ -11,40 +11,71 @@
 //! We do NOT recommending using it to implement any algorithm which has not
 //! received extensive peer review by cryptographers.
 
+pub mod fixslice;
+
 use crate::{soft::fixslice::hazmat as soft, Block, ParBlocks};
+use block_modes::gf128;
+use cipher::{
+    consts::{U16, U24, U32},
+    generic_array::GenericArray,
+    BlockEncrypt,
+};
+use core::convert::TryInto;
 
-#[cfg(all(
-    target_arch = "aarch64",
-    feature = "armv8",
-    not(feature = "force-soft")
-))]
+#[cfg(all(target_arch = "aarch64", not(feature = "force-soft")))]
 use crate::armv8::hazmat as intrinsics;
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2",
     not(feature = "force-soft")
 ))]
 use crate::ni::hazmat as intrinsics;
 
 #[cfg(all(
     any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        all(target_arch = "aarch64", feature = "armv8")
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        target_arch = "aarch64"
     ),
     not(feature = "force-soft")
 ))]
 cpufeatures::new!(aes_intrinsics, "aes");
 
+#[cfg(all(
+    target_arch = "powerpc64",
+    feature = "power8",
+    not(feature = "force-soft")
+))]
+use crate::power::{detect as aes_intrinsics, hazmat as intrinsics};
+
+#[cfg(all(
+    any(target_arch = "riscv32", target_arch = "riscv64"),
+    target_feature = "zkne",
+    target_feature = "zknd",
+    not(feature = "force-soft")
+))]
+use crate::riscv::{detect as aes_intrinsics, hazmat as intrinsics};
+
 /// Execute the provided body if CPU intrinsics are available.
 // TODO(tarcieri): more `cfg-if`-like macro with an else branch?
 macro_rules! if_intrinsics_available {
     ($body:expr) => {{
         #[cfg(all(
             any(
-                target_arch = "x86",
-                target_arch = "x86_64",
-                all(target_arch = "aarch64", feature = "armv8")
+                all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    target_feature = "sse2"
+                ),
+                target_arch = "aarch64",
+                all(target_arch = "powerpc64", feature = "power8"),
+                all(
+                    any(target_arch = "riscv32", target_arch = "riscv64"),
+                    target_feature = "zkne",
+                    target_feature = "zknd"
+                )
             ),
             not(feature = "force-soft")
         ))]
@@ -164,3 +195,138 @@ pub fn inv_mix_columns(block: &mut Block) {
 
     soft::inv_mix_columns(block);
 }
+
+/// Copy the first round keys of the shared key schedule of `key`.
+fn expand_key(key: &[u8], round_keys: &mut [Block]) {
+    let expanded = crate::soft::expand::expand_key_slice(key);
+    for (dst, src) in round_keys.iter_mut().zip(expanded.iter()) {
+        dst.copy_from_slice(src);
+    }
+}
+
+/// ⚠️ AES-128 key expansion.
+///
+/// Returns the 11 round keys of the AES-128 key schedule as described in
+/// FIPS 197 Section 5.2, i.e. `round_keys[r]` is the key schedule value for
+/// `round[r]` as listed in FIPS 197 Appendix C.1.
+///
+/// The round keys of the equivalent inverse cipher used with
+/// [`equiv_inv_cipher_round`] are obtained by reversing their order and
+/// applying [`inv_mix_columns`] to all but the first and the last one.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+pub fn aes128_round_keys(key: &GenericArray<u8, U16>) -> [Block; 11] {
+    let mut round_keys = [Block::default(); 11];
+    expand_key(key, &mut round_keys);
+    round_keys
+}
+
+/// ⚠️ AES-192 key expansion.
+///
+/// Returns the 13 round keys of the AES-192 key schedule, see
+/// [`aes128_round_keys`] for details.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+pub fn aes192_round_keys(key: &GenericArray<u8, U24>) -> [Block; 13] {
+    let mut round_keys = [Block::default(); 13];
+    expand_key(key, &mut round_keys);
+    round_keys
+}
+
+/// ⚠️ AES-256 key expansion.
+///
+/// Returns the 15 round keys of the AES-256 key schedule, see
+/// [`aes128_round_keys`] for details.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+pub fn aes256_round_keys(key: &GenericArray<u8, U32>) -> [Block; 15] {
+    let mut round_keys = [Block::default(); 15];
+    expand_key(key, &mut round_keys);
+    round_keys
+}
+
+/// ⚠️ Multiply a tweak by `α` in GF(2^128).
+///
+/// Blocks are interpreted with the little endian bit order of IEEE 1619
+/// (XTS), i.e. bit `i` of byte `j` is the coefficient of `x^(8 * j + i)`.
+/// This is [`block_modes::gf128::double`], which is shared by the modes of
+/// the `block-modes`, `eme2` and `cmc` crates.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+#[inline]
+pub fn gf128_double(block: &mut Block) {
+    gf128::double(block);
+}
+
+/// ⚠️ Multiply a block by `α` in GF(2^128) with the big endian bit order.
+///
+/// This is the doubling operation used by e.g. CMAC and OCB, in which the
+/// first byte holds the highest coefficients.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+#[inline]
+pub fn gf128_double_be(block: &mut Block) {
+    gf128::double_be(block);
+}
+
+/// ⚠️ Multiply two elements of GF(2^128).
+///
+/// Uses the same representation as [`gf128_double`], multiplying by the
+/// block `[2, 0, ..., 0]` is therefore equivalent to doubling. This runs in
+/// constant time, but is a lot slower than doubling, it is intended for
+/// computing `α^j` to seek to block `j` of a data unit.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+pub fn gf128_mul(a: &Block, b: &Block) -> Block {
+    let mut a = u128::from_le_bytes(a.as_slice().try_into().unwrap());
+    let b = u128::from_le_bytes(b.as_slice().try_into().unwrap());
+
+    let mut product = 0;
+    for i in 0..128 {
+        product ^= a & 0u128.wrapping_sub((b >> i) & 1);
+        a = gf128::mul_alpha(a);
+    }
+    product.to_le_bytes().into()
+}
+
+/// ⚠️ Compute the tweaks of consecutive blocks of an XTS data unit.
+///
+/// Encrypts `tweak` (e.g. the sector number) with `tweak_cipher` and stores
+/// it multiplied by `α^j` into `tweaks[j]`, as specified by IEEE 1619. The
+/// tweaks for the blocks following the ones written can be computed by
+/// applying [`gf128_double`] to the last of them.
+///
+/// # ☢️️ WARNING: HAZARDOUS API ☢️
+///
+/// Use this function with great care! See the [module-level documentation][crate::hazmat]
+/// for more information.
+pub fn xts_tweaks<C>(tweak_cipher: &C, tweak: &Block, tweaks: &mut [Block])
+where
+    C: BlockEncrypt<BlockSize = U16>,
+{
+    let mut t = *tweak;
+    tweak_cipher.encrypt_block(&mut t);
+    let mut t = u128::from_le_bytes(t.as_slice().try_into().unwrap());
+    for block in tweaks {
+        block.copy_from_slice(&t.to_le_bytes());
+        t = gf128::mul_alpha(t);
+    }
+}
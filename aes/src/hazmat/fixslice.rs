@@ -0,0 +1,127 @@
+//! ⚠️ Batch primitives of the fixsliced "soft" backend.
+//!
+//! These encrypt and decrypt [`BATCH_BLOCKS`] blocks at once using the
+//! constant-time fixsliced implementation, independently of any hardware
+//! acceleration which may be available. They allow reusing the bitsliced
+//! kernels for custom constructions processing several blocks in parallel.
+//!
+//! The batch size depends on the target: four blocks are processed at once
+//! where the 64-bit implementation is used, two with the 32-bit one.
+//!
+//! # ☢️️ WARNING: HAZARDOUS API ☢️
+//!
+//! Use these functions with great care! See the [module-level documentation][crate::hazmat]
+//! for more information.
+
+use crate::{soft::fixslice, Block};
+use cipher::{
+    consts::{U16, U24, U32},
+    generic_array::GenericArray,
+};
+
+/// Number of blocks processed by a single call of the batch functions.
+pub const BATCH_BLOCKS: usize = fixslice::FIXSLICE_BLOCKS;
+
+/// Blocks processed by a single call of the batch functions.
+pub type BatchBlocks = [Block; BATCH_BLOCKS];
+
+macro_rules! define_batch_fns {
+    (
+        $keys:ident,
+        $key_size:ty,
+        $fixslice_keys:ty,
+        $key_schedule:path,
+        $encrypt_fn:ident,
+        $decrypt_fn:ident,
+        $fixslice_encrypt:path,
+        $fixslice_decrypt:path,
+        $doc:expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $keys {
+            keys: $fixslice_keys,
+        }
+
+        impl $keys {
+            /// Compute the fixsliced round keys.
+            #[inline]
+            pub fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                Self {
+                    keys: $key_schedule(key),
+                }
+            }
+        }
+
+        opaque_debug::implement!($keys);
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $keys {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $keys {}
+
+        /// ⚠️ Encrypt a batch of blocks in place.
+        ///
+        /// # ☢️️ WARNING: HAZARDOUS API ☢️
+        ///
+        /// Use this function with great care! See the [module-level documentation][crate::hazmat]
+        /// for more information.
+        #[inline]
+        pub fn $encrypt_fn(keys: &$keys, blocks: &mut BatchBlocks) {
+            $fixslice_encrypt(&keys.keys, blocks);
+        }
+
+        /// ⚠️ Decrypt a batch of blocks in place.
+        ///
+        /// # ☢️️ WARNING: HAZARDOUS API ☢️
+        ///
+        /// Use this function with great care! See the [module-level documentation][crate::hazmat]
+        /// for more information.
+        #[inline]
+        pub fn $decrypt_fn(keys: &$keys, blocks: &mut BatchBlocks) {
+            $fixslice_decrypt(&keys.keys, blocks);
+        }
+    };
+}
+
+define_batch_fns!(
+    Aes128Keys,
+    U16,
+    fixslice::FixsliceKeys128,
+    fixslice::aes128_key_schedule,
+    aes128_encrypt,
+    aes128_decrypt,
+    fixslice::aes128_encrypt,
+    fixslice::aes128_decrypt,
+    "Fixsliced AES-128 round keys."
+);
+
+define_batch_fns!(
+    Aes192Keys,
+    U24,
+    fixslice::FixsliceKeys192,
+    fixslice::aes192_key_schedule,
+    aes192_encrypt,
+    aes192_decrypt,
+    fixslice::aes192_encrypt,
+    fixslice::aes192_decrypt,
+    "Fixsliced AES-192 round keys."
+);
+
+define_batch_fns!(
+    Aes256Keys,
+    U32,
+    fixslice::FixsliceKeys256,
+    fixslice::aes256_key_schedule,
+    aes256_encrypt,
+    aes256_decrypt,
+    fixslice::aes256_encrypt,
+    fixslice::aes256_decrypt,
+    "Fixsliced AES-256 round keys."
+);
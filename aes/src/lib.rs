@@ -14,20 +14,72 @@
 //! implemented entirely in terms of bitwise arithmetic with no use of any
 //! lookup tables or data-dependent branches.
 //!
+//! When a hardware-accelerated backend is available, this backend can still
+//! be selected at runtime by constructing the cipher with `new_soft` instead
+//! of [`NewBlockCipher::new`], e.g. [`Aes128::new_soft`].
+//!
+//! The round keys of this backend can also be computed in a `const` context
+//! using e.g. [`Aes128::new_const`], which allows defining `static` cipher
+//! instances with keys baked in at compile time:
+//!
+//! ```
+//! use aes::{Aes128, Block, BlockEncrypt};
+//!
+//! static CIPHER: Aes128 = Aes128::new_const(&[0x42; 16]);
+//!
+//! let mut block = Block::default();
+//! CIPHER.encrypt_block(&mut block);
+//! ```
+//!
 //! Enabling the `compact` Cargo feature will reduce the code size of this
 //! backend at the cost of decreased performance (using a modified form of
 //! the fixslicing technique called "semi-fixslicing").
 //!
-//! ## ARMv8 intrinsics (nightly-only)
+//! On 64-bit targets batches of 8 and 16 blocks are fixsliced together by
+//! widening the state words to arrays of `u64` lanes, which the compiler
+//! maps to vector registers, e.g. AVX2 when built with
+//! `RUSTFLAGS=-Ctarget-feature=+avx2`.
+//!
+//! The same 64-bit code path is used on 32-bit ARM targets with NEON enabled
+//! (e.g. `thumbv7neon-unknown-linux-gnueabihf`), where the `u64` lanes map
+//! onto the 128-bit NEON registers instead of being split into 32-bit halves.
+//!
+//! On `wasm32` targets built with `RUSTFLAGS=-Ctarget-feature=+simd128`
+//! batches of eight blocks are likewise processed in the 128-bit vector
+//! registers of WebAssembly SIMD, which works on stable Rust.
+//!
+//! Enabling the `simd` Cargo feature (nightly-only) makes this backend
+//! process batches of eight blocks using `core::simd` vectors, which the
+//! compiler lowers to the native SIMD instructions of the target (e.g.
+//! `simd128` on WebAssembly) or to scalar code where none are available.
+//!
+//! ## ARMv8 intrinsics
 //! On `aarch64` targets including `aarch64-apple-darwin` (Apple M1) and Linux
 //! targets such as `aarch64-unknown-linux-gnu` and `aarch64-unknown-linux-musl`,
-//! support for using AES intrinsics provided by the ARMv8 Cryptography Extensions
-//! is available when using the nightly compiler, and can be enabled using the
-//! `armv8` crate feature.
+//! AES intrinsics provided by the ARMv8 Cryptography Extensions are used
+//! when available. The `armv8` crate feature is no longer needed and has no
+//! effect.
+//!
+//! On Linux and macOS support for AES intrinsics is autodetected at runtime.
+//! On other platforms the `aes` target feature must be enabled via RUSTFLAGS.
+//!
+//! ## POWER8 intrinsics (nightly-only)
+//! On `powerpc64` and `powerpc64le` targets the `power8` crate feature enables
+//! a backend based on the `vcipher`/`vncipher` instructions of the POWER8
+//! vector crypto facility, which is also present on POWER9 and newer. This
+//! requires the nightly compiler.
+//!
+//! On Linux support for these instructions is autodetected at runtime. On
+//! other platforms the `power8-crypto` target feature must be enabled via
+//! RUSTFLAGS, otherwise the "soft" backend is used.
 //!
-//! On Linux and macOS, when the `armv8` feature is enabled support for AES
-//! intrinsics is autodetected at runtime. On other platforms the `aes`
-//! target feature must be enabled via RUSTFLAGS.
+//! ## RISC-V scalar cryptography
+//! On `riscv32` and `riscv64` targets the AES instructions of the Zkne and
+//! Zknd extensions (part of Zkn and Zk) are used when the `zkne` and `zknd`
+//! target features are enabled at compile time, e.g. via
+//! `RUSTFLAGS=-Ctarget-feature=+zkne,+zknd`. There is no runtime detection
+//! for these extensions, without the target features the "soft" backend is
+//! used.
 //!
 //! ## `x86`/`x86_64` intrinsics (AES-NI)
 //! By default this crate uses runtime detection on `i686`/`x86_64` targets
@@ -39,20 +91,35 @@
 //! Programs built in this manner will crash with an illegal instruction on
 //! CPUs which do not have AES-NI enabled.
 //!
+//! On `x86_64` bulk processing via `encrypt_blocks`/`decrypt_blocks` and the
+//! CTR mode types interleave 16 blocks at a time, which keeps the AES units
+//! of modern CPUs busier than the 8 blocks of `encrypt_par_blocks`.
+//!
+//! On `x86_64` the `vaes` crate feature (requires Rust 1.89 or newer)
+//! additionally enables a backend based on the VAES and AVX-512F extensions,
+//! which processes four blocks per register and up to 32 blocks at once. It
+//! is selected at runtime on CPUs supporting it, e.g. Intel Ice Lake and
+//! AMD Zen 4 or newer.
+//!
 //! Note: runtime detection is not possible on SGX targets. Please use the
 //! afforementioned `RUSTFLAGS` to leverage AES-NI on these targets.
 //!
+//! # Zeroization
+//! Enabling the `zeroize` Cargo feature makes all backends overwrite their
+//! expanded round keys when a cipher instance is dropped. With runtime
+//! detection the backend which was selected wipes its keys.
+//!
 //! # Usage example
 //! ```
-//! use aes::{Aes128, Block, ParBlocks};
+//! use aes::{Aes128, Block};
 //! use aes::cipher::{
-//!     BlockCipher, BlockEncrypt, BlockDecrypt, NewBlockCipher,
+//!     BlockCipher, BlockEncrypt, BlockDecrypt, NewBlockCipher, ParBlocks,
 //!     generic_array::GenericArray,
 //! };
 //!
 //! let key = GenericArray::from_slice(&[0u8; 16]);
 //! let mut block = Block::default();
-//! let mut block8 = ParBlocks::default();
+//! let mut par_blocks = ParBlocks::<Aes128>::default();
 //!
 //! // Initialize cipher
 //! let cipher = Aes128::new(&key);
@@ -66,14 +133,35 @@
 //! cipher.decrypt_block(&mut block);
 //! assert_eq!(block, block_copy);
 //!
-//! // We can encrypt 8 blocks simultaneously using
-//! // instruction-level parallelism
-//! let block8_copy = block8.clone();
-//! cipher.encrypt_par_blocks(&mut block8);
-//! cipher.decrypt_par_blocks(&mut block8);
-//! assert_eq!(block8, block8_copy);
+//! // We can encrypt 8 blocks (32 with the VAES backend) simultaneously
+//! // using instruction-level parallelism
+//! let par_blocks_copy = par_blocks.clone();
+//! cipher.encrypt_par_blocks(&mut par_blocks);
+//! cipher.decrypt_par_blocks(&mut par_blocks);
+//! assert_eq!(par_blocks, par_blocks_copy);
+//!
+//! // Slices of any number of blocks are processed in batches of the
+//! // largest size supported by the backend, with the rest done one by one
+//! let mut blocks = [Block::default(); 21];
+//! let blocks_copy = blocks.clone();
+//! cipher.encrypt_blocks(&mut blocks);
+//! cipher.decrypt_blocks(&mut blocks);
+//! assert_eq!(blocks, blocks_copy);
 //! ```
 //!
+//! These methods of the [`BlockEncrypt`] and [`BlockDecrypt`] traits are the
+//! interface for implementing custom modes of operation on top of the
+//! parallel kernels of the backends, [`BlockCipher::ParBlocks`] being the
+//! batch size of `encrypt_par_blocks` and `decrypt_par_blocks`. It is 8
+//! blocks, or 32 when the `vaes` feature is enabled on `x86_64`.
+//!
+//! If the key size is only known at runtime, e.g. because it is negotiated
+//! by a protocol, the [`Aes`] enum can be initialized from a key of any of
+//! the supported sizes instead.
+//!
+//! With the `std` Cargo feature enabled, the `Shared` type wraps a cipher
+//! instance in an `Arc`, so that its clones share a single key schedule.
+//!
 //! For implementations of block cipher modes of operation see
 //! [`block-modes`] crate.
 //!
@@ -83,9 +171,16 @@
 
 #![no_std]
 #![cfg_attr(
-    all(feature = "armv8", target_arch = "aarch64"),
-    feature(stdsimd, aarch64_target_feature)
+    all(feature = "power8", target_arch = "powerpc64"),
+    feature(
+        abi_unadjusted,
+        link_llvm_intrinsics,
+        powerpc_target_feature,
+        simd_ffi,
+        stdarch_powerpc
+    )
 )]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
@@ -93,27 +188,55 @@
 )]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "hazmat")]
 pub mod hazmat;
 
+#[cfg(feature = "std")]
+mod shared;
 mod soft;
+mod variable;
 
 use cfg_if::cfg_if;
 
 cfg_if! {
-    if #[cfg(all(target_arch = "aarch64", feature = "armv8", not(feature = "force-soft")))] {
+    if #[cfg(all(target_arch = "aarch64", not(feature = "force-soft")))] {
         mod armv8;
         mod autodetect;
         pub use autodetect::{Aes128, Aes192, Aes256};
 
+        #[cfg(feature = "ctr")]
+        pub use autodetect::ctr::{Aes128Ctr, Aes192Ctr, Aes256Ctr};
+    } else if #[cfg(all(target_arch = "powerpc64", feature = "power8", not(feature = "force-soft")))] {
+        mod autodetect;
+        mod power;
+        pub use autodetect::{Aes128, Aes192, Aes256};
+
+        #[cfg(feature = "ctr")]
+        pub use autodetect::ctr::{Aes128Ctr, Aes192Ctr, Aes256Ctr};
+    } else if #[cfg(all(
+        any(target_arch = "riscv32", target_arch = "riscv64"),
+        target_feature = "zkne",
+        target_feature = "zknd",
+        not(feature = "force-soft")
+    ))] {
+        mod autodetect;
+        mod riscv;
+        pub use autodetect::{Aes128, Aes192, Aes256};
+
         #[cfg(feature = "ctr")]
         pub use autodetect::ctr::{Aes128Ctr, Aes192Ctr, Aes256Ctr};
     } else if #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2",
         not(feature = "force-soft")
     ))] {
         mod autodetect;
         mod ni;
+        #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+        mod vaes;
         pub use autodetect::{Aes128, Aes192, Aes256};
 
         #[cfg(feature = "ctr")]
@@ -127,11 +250,33 @@ cfg_if! {
 }
 
 pub use cipher::{self, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+pub use variable::Aes;
+
+#[cfg(feature = "std")]
+pub use shared::Shared;
+
+/// Overwrite the round keys of a cipher instance (or another buffer holding
+/// secret data) with zeros.
+#[cfg(feature = "zeroize")]
+#[inline]
+fn zeroize_keys<T: Copy>(keys: &mut T) {
+    use zeroize::Zeroize;
+
+    // Safety: the round keys are plain integer, byte or SIMD vector types, for
+    // which any bit pattern, including all zeros, is valid
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(keys as *mut T as *mut u8, core::mem::size_of::<T>())
+    };
+    bytes.zeroize();
+}
 
 /// 128-bit AES block
 pub type Block = cipher::generic_array::GenericArray<u8, cipher::consts::U16>;
 
 /// 8 x 128-bit AES blocks to be processed in parallel
+///
+/// With the VAES backend the AES types process 32 blocks in parallel, see
+/// [`BlockCipher::ParBlocks`] for the batch size of a cipher type.
 pub type ParBlocks = cipher::generic_array::GenericArray<Block, cipher::consts::U8>;
 
 /// Size of an AES block (128-bits; 16-bytes)
@@ -1,3 +1,7 @@
+#[cfg(target_arch = "x86_64")]
+use super::utils::{
+    aesdec16, aesdeclast16, aesenc16, aesenclast16, load16, store16, xor16, U128x16,
+};
 use super::{
     arch::*,
     utils::{aesdec8, aesdeclast8, aesenc8, aesenclast8, load8, store8, xor8, U128x8},
@@ -19,11 +23,19 @@ type RoundKeys = [__m128i; 11];
 /// AES-128 block cipher
 #[derive(Clone)]
 pub struct Aes128 {
-    encrypt_keys: RoundKeys,
-    decrypt_keys: RoundKeys,
+    pub(crate) encrypt_keys: RoundKeys,
+    pub(crate) decrypt_keys: RoundKeys,
 }
 
 impl Aes128 {
+    /// Get the round keys of the encryption key schedule.
+    #[cfg(feature = "hazmat")]
+    pub(crate) fn round_keys(&self) -> [Block; 11] {
+        let mut round_keys = [Block::default(); 11];
+        super::utils::store_round_keys(&self.encrypt_keys, &mut round_keys);
+        round_keys
+    }
+
     #[inline(always)]
     pub(crate) fn encrypt8(&self, mut blocks: U128x8) -> U128x8 {
         #[inline]
@@ -64,6 +76,37 @@ impl Aes128 {
         }
         unsafe { aesni128_encrypt1(&self.encrypt_keys, block) }
     }
+    #[cfg(target_arch = "x86_64")]
+    #[inline(always)]
+    pub(crate) fn encrypt16(&self, mut blocks: U128x16) -> U128x16 {
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesni128_encrypt16(keys: &RoundKeys, blocks: &mut U128x16) {
+            xor16(blocks, keys[0]);
+            for &key in &keys[1..10] {
+                aesenc16(blocks, key);
+            }
+            aesenclast16(blocks, keys[10]);
+        }
+        unsafe { aesni128_encrypt16(&self.encrypt_keys, &mut blocks) };
+        blocks
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline(always)]
+    fn decrypt16(&self, mut blocks: U128x16) -> U128x16 {
+        #[inline]
+        #[target_feature(enable = "aes")]
+        unsafe fn aesni128_decrypt16(keys: &RoundKeys, blocks: &mut U128x16) {
+            xor16(blocks, keys[10]);
+            for &key in keys[1..10].iter().rev() {
+                aesdec16(blocks, key);
+            }
+            aesdeclast16(blocks, keys[0]);
+        }
+        unsafe { aesni128_decrypt16(&self.decrypt_keys, &mut blocks) };
+        blocks
+    }
 }
 
 impl NewBlockCipher for Aes128 {
@@ -104,6 +147,22 @@ impl BlockEncrypt for Aes128 {
         let b = self.encrypt8(load8(blocks));
         store8(blocks, b);
     }
+    #[cfg(target_arch = "x86_64")]
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        let mut chunks = blocks.chunks_exact_mut(16);
+        for chunk in &mut chunks {
+            let b = self.encrypt16(load16(chunk));
+            store16(chunk, b);
+        }
+        let mut chunks = chunks.into_remainder().chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            self.encrypt_par_blocks(GenericArray::from_mut_slice(chunk));
+        }
+        for block in chunks.into_remainder() {
+            self.encrypt_block(block);
+        }
+    }
 }
 
 impl BlockDecrypt for Aes128 {
@@ -158,6 +217,35 @@ impl BlockDecrypt for Aes128 {
 
         unsafe { aes128_decrypt8(blocks, &self.decrypt_keys) }
     }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        let mut chunks = blocks.chunks_exact_mut(16);
+        for chunk in &mut chunks {
+            let b = self.decrypt16(load16(chunk));
+            store16(chunk, b);
+        }
+        let mut chunks = chunks.into_remainder().chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            self.decrypt_par_blocks(GenericArray::from_mut_slice(chunk));
+        }
+        for block in chunks.into_remainder() {
+            self.decrypt_block(block);
+        }
+    }
 }
 
 opaque_debug::implement!(Aes128);
+
+#[cfg(feature = "zeroize")]
+impl Drop for Aes128 {
+    #[inline]
+    fn drop(&mut self) {
+        crate::zeroize_keys(&mut self.encrypt_keys);
+        crate::zeroize_keys(&mut self.decrypt_keys);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Aes128 {}
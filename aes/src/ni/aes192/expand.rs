@@ -30,7 +30,11 @@ macro_rules! expand_round {
 
 macro_rules! shuffle {
     ($a:expr, $b:expr, $imm:expr) => {
-        mem::transmute::<_, __m128i>(_mm_shuffle_pd(mem::transmute($a), mem::transmute($b), $imm))
+        _mm_castpd_si128(_mm_shuffle_pd(
+            _mm_castsi128_pd($a),
+            _mm_castsi128_pd($b),
+            $imm,
+        ))
     };
 }
 
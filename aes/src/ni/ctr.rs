@@ -18,6 +18,12 @@ use cipher::{
 
 const PAR_BLOCKS: usize = 8;
 const PAR_BLOCKS_SIZE: usize = PAR_BLOCKS * BLOCK_SIZE;
+#[cfg(target_arch = "x86_64")]
+const WIDE_BLOCKS: usize = 16;
+#[cfg(target_arch = "x86_64")]
+const WIDE_BLOCKS_SIZE: usize = WIDE_BLOCKS * BLOCK_SIZE;
+#[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+const VAES_BLOCKS_SIZE: usize = crate::vaes::PAR_BLOCKS * BLOCK_SIZE;
 
 #[inline(always)]
 pub fn xor(buf: &mut [u8], key: &[u8]) {
@@ -28,17 +34,17 @@ pub fn xor(buf: &mut [u8], key: &[u8]) {
 }
 
 #[inline(always)]
-fn xor_block8(buf: &mut [u8], ctr: [__m128i; 8]) {
-    debug_assert_eq!(buf.len(), PAR_BLOCKS_SIZE);
+fn xor_blocks(buf: &mut [u8], ctr: &[__m128i]) {
+    debug_assert_eq!(buf.len(), ctr.len() * BLOCK_SIZE);
 
     // Safety: `loadu` and `storeu` support unaligned access
     #[allow(clippy::cast_ptr_alignment)]
     unsafe {
         // compiler should unroll this loop
-        for i in 0..8 {
-            let ptr = buf.as_mut_ptr().offset(16 * i) as *mut __m128i;
+        for (i, c) in ctr.iter().enumerate() {
+            let ptr = buf.as_mut_ptr().add(16 * i) as *mut __m128i;
             let data = _mm_loadu_si128(ptr);
-            let data = _mm_xor_si128(data, ctr[i as usize]);
+            let data = _mm_xor_si128(data, *c);
             _mm_storeu_si128(ptr, data);
         }
     }
@@ -67,7 +73,7 @@ fn load(val: &GenericArray<u8, U16>) -> __m128i {
 }
 
 macro_rules! impl_ctr {
-    ($name:ident, $cipher:ty, $doc:expr) => {
+    ($name:ident, $cipher:ident, $doc:expr) => {
         #[doc=$doc]
         #[derive(Clone)]
         #[cfg_attr(docsrs, doc(cfg(feature = "ctr")))]
@@ -77,13 +83,47 @@ macro_rules! impl_ctr {
             cipher: $cipher,
             block: [u8; BLOCK_SIZE],
             pos: u8,
+            #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+            vaes: bool,
         }
 
         impl $name {
+            /// Create a CTR instance which generates the keystream with the
+            /// VAES backend.
+            #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+            pub(crate) fn from_vaes(
+                cipher: crate::vaes::$cipher,
+                nonce: &GenericArray<u8, U16>,
+            ) -> Self {
+                let mut ctr = Self::from_block_cipher(cipher.ni, nonce);
+                ctr.vaes = true;
+                ctr
+            }
+
+            /// Apply the keystream to `data`, which consists of whole
+            /// blocks, with the VAES backend.
+            #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+            #[inline(always)]
+            fn apply_vaes(&mut self, data: &mut [u8]) {
+                let mut ctrs: [__m128i; crate::vaes::PAR_BLOCKS] = unsafe { mem::zeroed() };
+                for chunk in data.chunks_mut(VAES_BLOCKS_SIZE) {
+                    let ctrs = &mut ctrs[..chunk.len() / BLOCK_SIZE];
+                    for c in ctrs.iter_mut() {
+                        *c = swap_bytes(self.ctr);
+                        self.ctr = inc_be(self.ctr);
+                    }
+                    // Safety: `vaes` is only set if VAES and AVX-512F are
+                    // available
+                    unsafe {
+                        crate::vaes::xor_ctr_keystream(&self.cipher.encrypt_keys, ctrs, chunk)
+                    };
+                }
+            }
+
             #[inline(always)]
             fn gen_block(&mut self) {
                 let block = self.cipher.encrypt(swap_bytes(self.ctr));
-                self.block = unsafe { mem::transmute(block) }
+                self.block = unsafe { mem::transmute::<__m128i, [u8; BLOCK_SIZE]>(block) }
             }
 
             #[inline(always)]
@@ -106,6 +146,20 @@ macro_rules! impl_ctr {
                 self.cipher.encrypt8(block8)
             }
 
+            #[cfg(target_arch = "x86_64")]
+            #[inline(always)]
+            fn next_block16(&mut self) -> [__m128i; WIDE_BLOCKS] {
+                let mut ctr = self.ctr;
+                let mut block16: [__m128i; WIDE_BLOCKS] = unsafe { mem::zeroed() };
+                for block in block16.iter_mut() {
+                    *block = swap_bytes(ctr);
+                    ctr = inc_be(ctr);
+                }
+                self.ctr = ctr;
+
+                self.cipher.encrypt16(block16)
+            }
+
             #[inline(always)]
             fn get_u64_ctr(&self) -> u64 {
                 let (ctr, nonce) = unsafe {
@@ -148,6 +202,8 @@ macro_rules! impl_ctr {
                     cipher,
                     block: [0u8; BLOCK_SIZE],
                     pos: 0,
+                    #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+                    vaes: false,
                 }
             }
         }
@@ -174,9 +230,30 @@ macro_rules! impl_ctr {
                     }
                 }
 
+                // The chunks are processed one after the other. Generating the
+                // keystream of the next chunk before XORing the current one
+                // made no measurable difference, as the iterations do not
+                // depend on each other and already overlap in the CPU.
+                #[cfg(all(target_arch = "x86_64", feature = "vaes"))]
+                if self.vaes {
+                    let n = data.len() - data.len() % bs;
+                    let (blocks, rem) = data.split_at_mut(n);
+                    self.apply_vaes(blocks);
+                    data = rem;
+                }
+
+                #[cfg(target_arch = "x86_64")]
+                {
+                    let mut chunks = data.chunks_exact_mut(WIDE_BLOCKS_SIZE);
+                    for chunk in &mut chunks {
+                        xor_blocks(chunk, &self.next_block16());
+                    }
+                    data = chunks.into_remainder();
+                }
+
                 let mut chunks = data.chunks_exact_mut(PAR_BLOCKS_SIZE);
                 for chunk in &mut chunks {
-                    xor_block8(chunk, self.next_block8());
+                    xor_blocks(chunk, &self.next_block8());
                 }
                 data = chunks.into_remainder();
 
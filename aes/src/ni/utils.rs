@@ -4,10 +4,20 @@
 #![allow(clippy::needless_range_loop)]
 
 use super::arch::*;
+#[cfg(any(target_arch = "x86_64", feature = "hazmat"))]
+use crate::Block;
 use crate::ParBlocks;
 
 pub type U128x8 = [__m128i; 8];
 
+/// Store the round keys `keys` as blocks.
+#[cfg(feature = "hazmat")]
+pub(crate) fn store_round_keys(keys: &[__m128i], round_keys: &mut [Block]) {
+    for (dst, key) in round_keys.iter_mut().zip(keys.iter()) {
+        unsafe { _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, *key) };
+    }
+}
+
 #[cfg(test)]
 pub(crate) fn check(a: &[__m128i], b: &[[u64; 2]]) {
     for (v1, v2) in a.iter().zip(b) {
@@ -88,3 +98,69 @@ pub(crate) fn aesdeclast8(buffer: &mut U128x8, key: __m128i) {
         buffer[i] = unsafe { _mm_aesdeclast_si128(buffer[i], key) };
     }
 }
+
+/// Sixteen blocks, interleaved to keep more AES units busy than with eight.
+///
+/// Only used on `x86_64`, as on `x86` there are just eight XMM registers.
+#[cfg(target_arch = "x86_64")]
+pub type U128x16 = [__m128i; 16];
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn load16(blocks: &[Block]) -> U128x16 {
+    debug_assert_eq!(blocks.len(), 16);
+    let mut b = [unsafe { _mm_setzero_si128() }; 16];
+    for i in 0..16 {
+        b[i] = unsafe { _mm_loadu_si128(blocks[i].as_ptr() as *const __m128i) };
+    }
+    b
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn store16(blocks: &mut [Block], b: U128x16) {
+    debug_assert_eq!(blocks.len(), 16);
+    for i in 0..16 {
+        unsafe { _mm_storeu_si128(blocks[i].as_mut_ptr() as *mut __m128i, b[i]) };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn xor16(b: &mut U128x16, key: __m128i) {
+    for i in 0..16 {
+        b[i] = unsafe { _mm_xor_si128(b[i], key) };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn aesenc16(buffer: &mut U128x16, key: __m128i) {
+    for i in 0..16 {
+        buffer[i] = unsafe { _mm_aesenc_si128(buffer[i], key) };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn aesenclast16(buffer: &mut U128x16, key: __m128i) {
+    for i in 0..16 {
+        buffer[i] = unsafe { _mm_aesenclast_si128(buffer[i], key) };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn aesdec16(buffer: &mut U128x16, key: __m128i) {
+    for i in 0..16 {
+        buffer[i] = unsafe { _mm_aesdec_si128(buffer[i], key) };
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn aesdeclast16(buffer: &mut U128x16, key: __m128i) {
+    for i in 0..16 {
+        buffer[i] = unsafe { _mm_aesdeclast_si128(buffer[i], key) };
+    }
+}
@@ -0,0 +1,260 @@
+//! AES block cipher implementation using the POWER8 vector crypto
+//! instructions (`vcipher`, `vcipherlast`, `vncipher` and `vncipherlast`).
+//!
+//! The round keys are computed with the shared key schedule of the soft
+//! backend.
+//!
+//! The instructions operate on the AES state in big endian byte order, so on
+//! little endian targets blocks and round keys are byte-reversed when they
+//! are moved into vector registers.
+
+#[cfg(feature = "hazmat")]
+pub(crate) mod hazmat;
+
+use crate::{Block, ParBlocks};
+use cipher::{
+    consts::{U16, U24, U32, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::{arch::powerpc64::*, mem};
+
+/// A 128-bit vector register as taken by the crypto instructions.
+type Vector = vector_unsigned_long;
+
+#[allow(improper_ctypes)]
+extern "unadjusted" {
+    #[link_name = "llvm.ppc.altivec.crypto.vcipher"]
+    fn vcipher(state: Vector, round_key: Vector) -> Vector;
+    #[link_name = "llvm.ppc.altivec.crypto.vcipherlast"]
+    fn vcipherlast(state: Vector, round_key: Vector) -> Vector;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipher"]
+    fn vncipher(state: Vector, round_key: Vector) -> Vector;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipherlast"]
+    fn vncipherlast(state: Vector, round_key: Vector) -> Vector;
+}
+
+/// Runtime detection of the POWER8 vector crypto facility.
+pub(crate) mod detect {
+    use core::sync::atomic::{AtomicU8, Ordering::Relaxed};
+
+    /// `PPC_FEATURE2_VEC_CRYPTO` bit of the `AT_HWCAP2` auxiliary vector entry.
+    #[cfg(target_os = "linux")]
+    const PPC_FEATURE2_VEC_CRYPTO: libc::c_ulong = 0x0200_0000;
+
+    const UNINIT: u8 = u8::max_value();
+
+    static STORAGE: AtomicU8 = AtomicU8::new(UNINIT);
+
+    /// Get the result of the detection, running it on first use.
+    #[inline]
+    pub fn get() -> bool {
+        if cfg!(target_feature = "power8-crypto") {
+            return true;
+        }
+
+        let val = STORAGE.load(Relaxed);
+        if val != UNINIT {
+            return val == 1;
+        }
+
+        let res = detect();
+        STORAGE.store(res as u8, Relaxed);
+        res
+    }
+
+    #[cfg(target_os = "linux")]
+    fn detect() -> bool {
+        let hwcap2 = unsafe { libc::getauxval(libc::AT_HWCAP2) };
+        hwcap2 & PPC_FEATURE2_VEC_CRYPTO != 0
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn detect() -> bool {
+        false
+    }
+}
+
+/// Move a block into a vector register.
+#[inline(always)]
+fn load(block: &[u8]) -> Vector {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(block);
+    #[cfg(target_endian = "little")]
+    bytes.reverse();
+    unsafe { mem::transmute(bytes) }
+}
+
+/// Move a vector register back into a block.
+#[inline(always)]
+fn store(block: &mut [u8], v: Vector) {
+    let mut bytes: [u8; 16] = unsafe { mem::transmute(v) };
+    #[cfg(target_endian = "little")]
+    bytes.reverse();
+    block.copy_from_slice(&bytes);
+}
+
+#[inline(always)]
+fn xor(a: Vector, b: Vector) -> Vector {
+    unsafe {
+        let a: vector_unsigned_char = mem::transmute(a);
+        let b: vector_unsigned_char = mem::transmute(b);
+        mem::transmute(vec_xor(a, b))
+    }
+}
+
+/// AES key expansion as described in FIPS 197, Section 5.2.
+fn expand_key<const L: usize, const N: usize>(key: &[u8; L]) -> [Vector; N] {
+    assert!((L == 16 && N == 11) || (L == 24 && N == 13) || (L == 32 && N == 15));
+
+    let expanded = crate::soft::expand::expand_key_slice(key);
+    let mut round_keys = [load(&[0u8; 16]); N];
+    for (round_key, bytes) in round_keys.iter_mut().zip(expanded.iter()) {
+        *round_key = load(bytes);
+    }
+    round_keys
+}
+
+/// Encrypt a single block.
+#[target_feature(enable = "power8-crypto")]
+unsafe fn encrypt<const N: usize>(keys: &[Vector; N], block: &mut Block) {
+    let mut state = xor(load(block), keys[0]);
+    for &key in &keys[1..N - 1] {
+        state = vcipher(state, key);
+    }
+    state = vcipherlast(state, keys[N - 1]);
+    store(block, state);
+}
+
+/// Encrypt eight blocks in parallel.
+#[target_feature(enable = "power8-crypto")]
+unsafe fn encrypt8<const N: usize>(keys: &[Vector; N], blocks: &mut ParBlocks) {
+    let mut state = [keys[0]; 8];
+    for (s, block) in state.iter_mut().zip(blocks.iter()) {
+        *s = xor(load(block), keys[0]);
+    }
+    for &key in &keys[1..N - 1] {
+        for s in state.iter_mut() {
+            *s = vcipher(*s, key);
+        }
+    }
+    for (s, block) in state.iter().zip(blocks.iter_mut()) {
+        store(block, vcipherlast(*s, keys[N - 1]));
+    }
+}
+
+/// Decrypt a single block.
+///
+/// `vncipher` applies the round key before `InvMixColumns`, so decryption
+/// uses the encryption key schedule in reverse order.
+#[target_feature(enable = "power8-crypto")]
+unsafe fn decrypt<const N: usize>(keys: &[Vector; N], block: &mut Block) {
+    let mut state = xor(load(block), keys[N - 1]);
+    for &key in keys[1..N - 1].iter().rev() {
+        state = vncipher(state, key);
+    }
+    state = vncipherlast(state, keys[0]);
+    store(block, state);
+}
+
+/// Decrypt eight blocks in parallel.
+#[target_feature(enable = "power8-crypto")]
+unsafe fn decrypt8<const N: usize>(keys: &[Vector; N], blocks: &mut ParBlocks) {
+    let mut state = [keys[0]; 8];
+    for (s, block) in state.iter_mut().zip(blocks.iter()) {
+        *s = xor(load(block), keys[N - 1]);
+    }
+    for &key in keys[1..N - 1].iter().rev() {
+        for s in state.iter_mut() {
+            *s = vncipher(*s, key);
+        }
+    }
+    for (s, block) in state.iter().zip(blocks.iter_mut()) {
+        store(block, vncipherlast(*s, keys[0]));
+    }
+}
+
+macro_rules! define_aes_impl {
+    (
+        $name:ident,
+        $key_size:ty,
+        $key_bytes:expr,
+        $rounds:tt,
+        $doc:expr
+    ) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $name {
+            round_keys: [Vector; $rounds],
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            #[inline]
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                let key = unsafe { &*(key as *const _ as *const [u8; $key_bytes]) };
+                let round_keys = expand_key(key);
+                Self { round_keys }
+            }
+        }
+
+        impl $name {
+            /// Get the round keys of the encryption key schedule.
+            #[cfg(feature = "hazmat")]
+            pub(crate) fn round_keys(&self) -> [Block; $rounds] {
+                let mut round_keys = [Block::default(); $rounds];
+                for (dst, key) in round_keys.iter_mut().zip(self.round_keys.iter()) {
+                    store(dst, *key);
+                }
+                round_keys
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = U16;
+            type ParBlocks = U8;
+        }
+
+        impl BlockEncrypt for $name {
+            #[inline]
+            fn encrypt_block(&self, block: &mut Block) {
+                unsafe { encrypt(&self.round_keys, block) }
+            }
+
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                unsafe { encrypt8(&self.round_keys, blocks) }
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            #[inline]
+            fn decrypt_block(&self, block: &mut Block) {
+                unsafe { decrypt(&self.round_keys, block) }
+            }
+
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                unsafe { decrypt8(&self.round_keys, blocks) }
+            }
+        }
+
+        opaque_debug::implement!($name);
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.round_keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
+    };
+}
+
+define_aes_impl!(Aes128, U16, 16, 11, "AES-128 block cipher instance");
+define_aes_impl!(Aes192, U24, 24, 13, "AES-192 block cipher instance");
+define_aes_impl!(Aes256, U32, 32, 15, "AES-256 block cipher instance");
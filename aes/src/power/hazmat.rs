@@ -0,0 +1,62 @@
+//! Low-level "hazmat" AES functions: POWER8 vector crypto support.
+//!
+//! Note: this isn't actually used in the `Aes128`/`Aes192`/`Aes256`
+//! implementations in this crate, but instead provides raw accelerated
+//! access to the AES round function gated under the `hazmat` crate feature.
+
+use super::{load, store, vcipher, vcipherlast, vncipher, vncipherlast, xor, Vector};
+use crate::{Block, ParBlocks};
+
+/// All-zero round key, the actual round key is added afterwards.
+#[inline(always)]
+fn zero() -> Vector {
+    load(&[0u8; 16])
+}
+
+/// AES cipher (encrypt) round function.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn cipher_round(block: &mut Block, round_key: &Block) {
+    // `vcipher` performs a full AES round including the round key addition
+    let state = vcipher(load(block), load(round_key));
+    store(block, state);
+}
+
+/// AES cipher (encrypt) round function: parallel version.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn cipher_round_par(blocks: &mut ParBlocks, round_keys: &ParBlocks) {
+    for (block, round_key) in blocks.iter_mut().zip(round_keys.iter()) {
+        cipher_round(block, round_key);
+    }
+}
+
+/// AES equivalent inverse cipher (decrypt) round function.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn equiv_inv_cipher_round(block: &mut Block, round_key: &Block) {
+    // `vncipher` adds the round key before `InvMixColumns`, so it is deferred
+    let state = vncipher(load(block), zero());
+    store(block, xor(state, load(round_key)));
+}
+
+/// AES equivalent inverse cipher (decrypt) round function: parallel version.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn equiv_inv_cipher_round_par(blocks: &mut ParBlocks, round_keys: &ParBlocks) {
+    for (block, round_key) in blocks.iter_mut().zip(round_keys.iter()) {
+        equiv_inv_cipher_round(block, round_key);
+    }
+}
+
+/// AES mix columns function.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn mix_columns(block: &mut Block) {
+    // undo `SubBytes` and `ShiftRows` of the following full round
+    let state = vncipherlast(load(block), zero());
+    store(block, vcipher(state, zero()));
+}
+
+/// AES inverse mix columns function.
+#[target_feature(enable = "power8-crypto")]
+pub(crate) unsafe fn inv_mix_columns(block: &mut Block) {
+    // `InvShiftRows` and `InvSubBytes` of the inverse round cancel out
+    let state = vcipherlast(load(block), zero());
+    store(block, vncipher(state, zero()));
+}
@@ -0,0 +1,166 @@
+//! AES block cipher implementation using the RISC-V scalar cryptography
+//! extensions Zkne and Zknd.
+//!
+//! On RV64 the `aes64*` instructions operate on half of the state at a time,
+//! on RV32 the `aes32*` instructions process one byte of a column each.
+//! Decryption uses the equivalent inverse cipher, i.e. the decryption round
+//! keys have `InvMixColumns` applied to them. The round keys are computed
+//! with the shared key schedule of the soft backend.
+//!
+//! There is no runtime detection for these extensions, so this backend is
+//! only used when the `zkne` and `zknd` target features are enabled at
+//! compile time.
+
+// the crate MSRV does not apply to this target-specific backend
+#![allow(clippy::incompatible_msrv)]
+
+#[cfg_attr(target_arch = "riscv32", path = "riscv/rv32.rs")]
+#[cfg_attr(target_arch = "riscv64", path = "riscv/rv64.rs")]
+mod arch;
+
+#[cfg(feature = "hazmat")]
+pub(crate) mod hazmat;
+
+#[cfg(feature = "hazmat")]
+use self::arch::unpack;
+use self::arch::{decrypt, encrypt, inv_mix_columns, pack, RoundKey};
+use crate::{Block, ParBlocks};
+use cipher::{
+    consts::{U16, U24, U32, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::convert::TryInto;
+
+/// The backend is only compiled when the target features are enabled, so
+/// there is nothing to detect at runtime.
+pub(crate) mod detect {
+    #[inline(always)]
+    pub fn get() -> bool {
+        true
+    }
+}
+
+/// AES key expansion as described in FIPS 197, Section 5.2.
+///
+/// Words are kept in little endian order, i.e. the first byte of a column
+/// is its least significant byte.
+fn expand_key<const L: usize, const N: usize>(key: &[u8; L]) -> [RoundKey; N] {
+    assert!((L == 16 && N == 11) || (L == 24 && N == 13) || (L == 32 && N == 15));
+
+    let expanded = crate::soft::expand::expand_key_slice(key);
+    let mut round_keys = [RoundKey::default(); N];
+    for (round_key, bytes) in round_keys.iter_mut().zip(expanded.iter()) {
+        let mut words = [0u32; 4];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        *round_key = pack(&words);
+    }
+    round_keys
+}
+
+/// Compute the decryption round keys of the equivalent inverse cipher.
+fn inv_expanded_keys<const N: usize>(mut round_keys: [RoundKey; N]) -> [RoundKey; N] {
+    for round_key in round_keys.iter_mut().take(N - 1).skip(1) {
+        inv_mix_columns(round_key);
+    }
+    round_keys
+}
+
+macro_rules! define_aes_impl {
+    (
+        $name:ident,
+        $key_size:ty,
+        $key_bytes:expr,
+        $rounds:tt,
+        $doc:expr
+    ) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $name {
+            encrypt_keys: [RoundKey; $rounds],
+            decrypt_keys: [RoundKey; $rounds],
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            #[inline]
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                let key: &[u8; $key_bytes] = key.as_slice().try_into().unwrap();
+                let encrypt_keys = expand_key(key);
+                let decrypt_keys = inv_expanded_keys(encrypt_keys);
+                Self {
+                    encrypt_keys,
+                    decrypt_keys,
+                }
+            }
+        }
+
+        impl $name {
+            /// Get the round keys of the encryption key schedule.
+            #[cfg(feature = "hazmat")]
+            pub(crate) fn round_keys(&self) -> [Block; $rounds] {
+                let mut round_keys = [Block::default(); $rounds];
+                for (dst, key) in round_keys.iter_mut().zip(self.encrypt_keys.iter()) {
+                    for (chunk, word) in dst.chunks_exact_mut(4).zip(unpack(key).iter()) {
+                        chunk.copy_from_slice(&word.to_le_bytes());
+                    }
+                }
+                round_keys
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = U16;
+            type ParBlocks = U8;
+        }
+
+        impl BlockEncrypt for $name {
+            #[inline]
+            fn encrypt_block(&self, block: &mut Block) {
+                encrypt(&self.encrypt_keys, block)
+            }
+
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                for block in blocks {
+                    encrypt(&self.encrypt_keys, block)
+                }
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            #[inline]
+            fn decrypt_block(&self, block: &mut Block) {
+                decrypt(&self.decrypt_keys, block)
+            }
+
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                for block in blocks {
+                    decrypt(&self.decrypt_keys, block)
+                }
+            }
+        }
+
+        opaque_debug::implement!($name);
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.encrypt_keys);
+                crate::zeroize_keys(&mut self.decrypt_keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
+    };
+}
+
+define_aes_impl!(Aes128, U16, 16, 11, "AES-128 block cipher instance");
+define_aes_impl!(Aes192, U24, 24, 13, "AES-192 block cipher instance");
+define_aes_impl!(Aes256, U32, 32, 15, "AES-256 block cipher instance");
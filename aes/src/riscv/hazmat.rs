@@ -0,0 +1,56 @@
+//! Low-level "hazmat" AES functions: RISC-V scalar cryptography support.
+//!
+//! Note: this isn't actually used in the `Aes128`/`Aes192`/`Aes256`
+//! implementations in this crate, but instead provides raw accelerated
+//! access to the AES round function gated under the `hazmat` crate feature.
+
+use super::arch::{dec_last_round, dec_round, enc_last_round, enc_round};
+use crate::{Block, ParBlocks};
+
+/// XOR the `src` block into the `dst` block in-place.
+#[inline(always)]
+fn xor_in_place(dst: &mut Block, src: &Block) {
+    for (a, b) in dst.iter_mut().zip(src.as_slice()) {
+        *a ^= *b;
+    }
+}
+
+/// AES cipher (encrypt) round function.
+pub(crate) unsafe fn cipher_round(block: &mut Block, round_key: &Block) {
+    enc_round(block);
+    xor_in_place(block, round_key);
+}
+
+/// AES cipher (encrypt) round function: parallel version.
+pub(crate) unsafe fn cipher_round_par(blocks: &mut ParBlocks, round_keys: &ParBlocks) {
+    for (block, round_key) in blocks.iter_mut().zip(round_keys.iter()) {
+        cipher_round(block, round_key);
+    }
+}
+
+/// AES equivalent inverse cipher (decrypt) round function.
+pub(crate) unsafe fn equiv_inv_cipher_round(block: &mut Block, round_key: &Block) {
+    dec_round(block);
+    xor_in_place(block, round_key);
+}
+
+/// AES equivalent inverse cipher (decrypt) round function: parallel version.
+pub(crate) unsafe fn equiv_inv_cipher_round_par(blocks: &mut ParBlocks, round_keys: &ParBlocks) {
+    for (block, round_key) in blocks.iter_mut().zip(round_keys.iter()) {
+        equiv_inv_cipher_round(block, round_key);
+    }
+}
+
+/// AES mix columns function.
+pub(crate) unsafe fn mix_columns(block: &mut Block) {
+    // undo `SubBytes` and `ShiftRows` of the following full round
+    dec_last_round(block);
+    enc_round(block);
+}
+
+/// AES inverse mix columns function.
+pub(crate) unsafe fn inv_mix_columns(block: &mut Block) {
+    // `InvShiftRows` and `InvSubBytes` of the inverse round cancel out
+    enc_last_round(block);
+    dec_round(block);
+}
@@ -0,0 +1,170 @@
+//! RV32 implementation based on the `aes32*` instructions.
+
+use crate::Block;
+use core::{arch::asm, convert::TryInto};
+
+/// Round key as four columns of the state.
+pub(super) type RoundKey = [u32; 4];
+
+/// Compute one output column: the `aes32*` instruction `$insn` is applied to
+/// byte `i` of the `i`-th input column and the results are XORed into `$k`.
+macro_rules! column {
+    ($insn:literal, $k:expr, $s0:expr, $s1:expr, $s2:expr, $s3:expr) => {{
+        let mut t: u32 = $k;
+        unsafe {
+            asm!(
+                concat!($insn, " {0}, {0}, {1}, 0"),
+                concat!($insn, " {0}, {0}, {2}, 1"),
+                concat!($insn, " {0}, {0}, {3}, 2"),
+                concat!($insn, " {0}, {0}, {4}, 3"),
+                inout(reg) t,
+                in(reg) $s0,
+                in(reg) $s1,
+                in(reg) $s2,
+                in(reg) $s3,
+                options(pure, nomem, nostack),
+            );
+        }
+        t
+    }};
+}
+
+/// Full forward round: `ShiftRows` is applied by picking byte `i` of each
+/// output column from input column `j + i`.
+macro_rules! fwd_round {
+    ($insn:literal, $k:expr, $s:expr) => {{
+        let (k, s) = ($k, $s);
+        [
+            column!($insn, k[0], s[0], s[1], s[2], s[3]),
+            column!($insn, k[1], s[1], s[2], s[3], s[0]),
+            column!($insn, k[2], s[2], s[3], s[0], s[1]),
+            column!($insn, k[3], s[3], s[0], s[1], s[2]),
+        ]
+    }};
+}
+
+/// Full inverse round: `InvShiftRows` is applied by picking byte `i` of each
+/// output column from input column `j - i`.
+macro_rules! inv_round {
+    ($insn:literal, $k:expr, $s:expr) => {{
+        let (k, s) = ($k, $s);
+        [
+            column!($insn, k[0], s[0], s[3], s[2], s[1]),
+            column!($insn, k[1], s[1], s[0], s[3], s[2]),
+            column!($insn, k[2], s[2], s[1], s[0], s[3]),
+            column!($insn, k[3], s[3], s[2], s[1], s[0]),
+        ]
+    }};
+}
+
+/// Pack four key schedule words into a round key.
+#[inline(always)]
+pub(super) fn pack(words: &[u32]) -> RoundKey {
+    [words[0], words[1], words[2], words[3]]
+}
+
+/// Unpack a round key into its four key schedule words.
+#[cfg(feature = "hazmat")]
+pub(super) fn unpack(round_key: &RoundKey) -> [u32; 4] {
+    *round_key
+}
+
+/// Apply the AES S-box to each byte of a word.
+#[inline(always)]
+fn sub_word(word: u32) -> u32 {
+    column!("aes32esi", 0, word, word, word, word)
+}
+
+/// Apply `InvMixColumns` to a round key.
+#[inline(always)]
+pub(super) fn inv_mix_columns(round_key: &mut RoundKey) {
+    for word in round_key.iter_mut() {
+        // the inverse S-box of `aes32dsmi` cancels the forward S-box
+        let s = sub_word(*word);
+        *word = column!("aes32dsmi", 0, s, s, s, s);
+    }
+}
+
+#[inline(always)]
+fn load(block: &Block) -> [u32; 4] {
+    let mut s = [0u32; 4];
+    for (word, chunk) in s.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    s
+}
+
+#[inline(always)]
+fn store(block: &mut Block, s: [u32; 4]) {
+    for (chunk, word) in block.chunks_exact_mut(4).zip(s.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+/// Encrypt a single block.
+#[inline]
+pub(super) fn encrypt(keys: &[RoundKey], block: &mut Block) {
+    let last = keys.len() - 1;
+    let mut s = load(block);
+    for (word, k) in s.iter_mut().zip(keys[0].iter()) {
+        *word ^= k;
+    }
+
+    for k in &keys[1..last] {
+        s = fwd_round!("aes32esmi", k, s);
+    }
+
+    store(block, fwd_round!("aes32esi", &keys[last], s));
+}
+
+/// Decrypt a single block using the inverse round keys.
+#[inline]
+pub(super) fn decrypt(keys: &[RoundKey], block: &mut Block) {
+    let last = keys.len() - 1;
+    let mut s = load(block);
+    for (word, k) in s.iter_mut().zip(keys[last].iter()) {
+        *word ^= k;
+    }
+
+    for k in keys[1..last].iter().rev() {
+        s = inv_round!("aes32dsmi", k, s);
+    }
+
+    store(block, inv_round!("aes32dsi", &keys[0], s));
+}
+
+macro_rules! define_round {
+    ($name:ident, $round:ident, $insn:literal, $doc:expr) => {
+        #[doc = $doc]
+        #[cfg(feature = "hazmat")]
+        #[inline(always)]
+        pub(super) fn $name(block: &mut Block) {
+            store(block, $round!($insn, [0u32; 4], load(block)));
+        }
+    };
+}
+
+define_round!(
+    enc_round,
+    fwd_round,
+    "aes32esmi",
+    "Keyless `SubBytes`, `ShiftRows` and `MixColumns`."
+);
+define_round!(
+    enc_last_round,
+    fwd_round,
+    "aes32esi",
+    "Keyless `SubBytes` and `ShiftRows`."
+);
+define_round!(
+    dec_round,
+    inv_round,
+    "aes32dsmi",
+    "Keyless `InvSubBytes`, `InvShiftRows` and `InvMixColumns`."
+);
+define_round!(
+    dec_last_round,
+    inv_round,
+    "aes32dsi",
+    "Keyless `InvSubBytes` and `InvShiftRows`."
+);
@@ -0,0 +1,149 @@
+//! RV64 implementation based on the `aes64*` instructions.
+
+use crate::Block;
+use core::{arch::asm, convert::TryInto};
+
+/// Round key as two 64-bit halves of the state.
+pub(super) type RoundKey = [u64; 2];
+
+macro_rules! aes64 {
+    ($insn:literal, $rs1:expr) => {{
+        let rd: u64;
+        unsafe {
+            asm!(
+                concat!($insn, " {0}, {1}"),
+                lateout(reg) rd,
+                in(reg) $rs1,
+                options(pure, nomem, nostack),
+            );
+        }
+        rd
+    }};
+    ($insn:literal, $rs1:expr, $rs2:expr) => {{
+        let rd: u64;
+        unsafe {
+            asm!(
+                concat!($insn, " {0}, {1}, {2}"),
+                lateout(reg) rd,
+                in(reg) $rs1,
+                in(reg) $rs2,
+                options(pure, nomem, nostack),
+            );
+        }
+        rd
+    }};
+}
+
+/// Pack four key schedule words into a round key.
+#[inline(always)]
+pub(super) fn pack(words: &[u32]) -> RoundKey {
+    [
+        u64::from(words[0]) | u64::from(words[1]) << 32,
+        u64::from(words[2]) | u64::from(words[3]) << 32,
+    ]
+}
+
+/// Unpack a round key into its four key schedule words.
+#[cfg(feature = "hazmat")]
+pub(super) fn unpack(round_key: &RoundKey) -> [u32; 4] {
+    [
+        round_key[0] as u32,
+        (round_key[0] >> 32) as u32,
+        round_key[1] as u32,
+        (round_key[1] >> 32) as u32,
+    ]
+}
+
+/// Apply `InvMixColumns` to a round key.
+#[inline(always)]
+pub(super) fn inv_mix_columns(round_key: &mut RoundKey) {
+    round_key[0] = aes64!("aes64im", round_key[0]);
+    round_key[1] = aes64!("aes64im", round_key[1]);
+}
+
+#[inline(always)]
+fn load(block: &Block) -> (u64, u64) {
+    (
+        u64::from_le_bytes(block[..8].try_into().unwrap()),
+        u64::from_le_bytes(block[8..].try_into().unwrap()),
+    )
+}
+
+#[inline(always)]
+fn store(block: &mut Block, s0: u64, s1: u64) {
+    block[..8].copy_from_slice(&s0.to_le_bytes());
+    block[8..].copy_from_slice(&s1.to_le_bytes());
+}
+
+/// Encrypt a single block.
+#[inline]
+pub(super) fn encrypt(keys: &[RoundKey], block: &mut Block) {
+    let last = keys.len() - 1;
+    let (mut s0, mut s1) = load(block);
+    s0 ^= keys[0][0];
+    s1 ^= keys[0][1];
+
+    for key in &keys[1..last] {
+        let n0 = aes64!("aes64esm", s0, s1);
+        let n1 = aes64!("aes64esm", s1, s0);
+        s0 = n0 ^ key[0];
+        s1 = n1 ^ key[1];
+    }
+
+    let n0 = aes64!("aes64es", s0, s1);
+    let n1 = aes64!("aes64es", s1, s0);
+    store(block, n0 ^ keys[last][0], n1 ^ keys[last][1]);
+}
+
+/// Decrypt a single block using the inverse round keys.
+#[inline]
+pub(super) fn decrypt(keys: &[RoundKey], block: &mut Block) {
+    let last = keys.len() - 1;
+    let (mut s0, mut s1) = load(block);
+    s0 ^= keys[last][0];
+    s1 ^= keys[last][1];
+
+    for key in keys[1..last].iter().rev() {
+        let n0 = aes64!("aes64dsm", s0, s1);
+        let n1 = aes64!("aes64dsm", s1, s0);
+        s0 = n0 ^ key[0];
+        s1 = n1 ^ key[1];
+    }
+
+    let n0 = aes64!("aes64ds", s0, s1);
+    let n1 = aes64!("aes64ds", s1, s0);
+    store(block, n0 ^ keys[0][0], n1 ^ keys[0][1]);
+}
+
+macro_rules! define_round {
+    ($name:ident, $insn:literal, $doc:expr) => {
+        #[doc = $doc]
+        #[cfg(feature = "hazmat")]
+        #[inline(always)]
+        pub(super) fn $name(block: &mut Block) {
+            let (s0, s1) = load(block);
+            store(block, aes64!($insn, s0, s1), aes64!($insn, s1, s0));
+        }
+    };
+}
+
+define_round!(
+    enc_round,
+    "aes64esm",
+    "Keyless `SubBytes`, `ShiftRows` and `MixColumns`."
+);
+define_round!(
+    enc_last_round,
+    "aes64es",
+    "Keyless `SubBytes` and `ShiftRows`."
+);
+define_round!(
+    dec_round,
+    "aes64dsm",
+    "Keyless `InvSubBytes`, `InvShiftRows` and `InvMixColumns`."
+);
+define_round!(
+    dec_last_round,
+    "aes64ds",
+    "Keyless `InvSubBytes` and `InvShiftRows`."
+);
@@ -0,0 +1,122 @@
+//! Reference-counted cipher instances.
+
+use crate::Block;
+use cipher::{
+    consts::U16, errors::InvalidLength, generic_array::GenericArray, BlockCipher, BlockDecrypt,
+    BlockEncrypt, NewBlockCipher, ParBlocks,
+};
+use core::fmt;
+use std::sync::Arc;
+
+/// Cipher instance sharing its expanded round keys between clones.
+///
+/// Cloning is cheap as only a reference count is incremented, which allows
+/// sharing a single key schedule between many threads or connections:
+///
+/// ```
+/// use aes::{Aes256, BlockEncrypt, Block, NewBlockCipher, Shared};
+///
+/// let cipher = Shared::<Aes256>::new(&[0x42; 32].into());
+/// let handle = cipher.clone();
+/// std::thread::spawn(move || {
+///     let mut block = Block::default();
+///     handle.encrypt_block(&mut block);
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+///
+/// With the `zeroize` feature enabled the round keys are wiped when the last
+/// clone is dropped.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct Shared<C> {
+    cipher: Arc<C>,
+}
+
+impl<C> Shared<C> {
+    /// Wrap an existing cipher instance.
+    #[inline]
+    pub fn from_cipher(cipher: C) -> Self {
+        Self {
+            cipher: Arc::new(cipher),
+        }
+    }
+}
+
+impl<C> Clone for Shared<C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+        }
+    }
+}
+
+impl<C> From<C> for Shared<C> {
+    #[inline]
+    fn from(cipher: C) -> Self {
+        Self::from_cipher(cipher)
+    }
+}
+
+impl<C: NewBlockCipher> NewBlockCipher for Shared<C> {
+    type KeySize = C::KeySize;
+
+    #[inline]
+    fn new(key: &GenericArray<u8, C::KeySize>) -> Self {
+        Self::from_cipher(C::new(key))
+    }
+
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        C::new_from_slice(key).map(Self::from_cipher)
+    }
+}
+
+impl<C: BlockCipher<BlockSize = U16>> BlockCipher for Shared<C> {
+    type BlockSize = U16;
+    type ParBlocks = C::ParBlocks;
+}
+
+impl<C: BlockEncrypt<BlockSize = U16>> BlockEncrypt for Shared<C> {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        self.cipher.encrypt_block(block)
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.cipher.encrypt_par_blocks(blocks)
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        self.cipher.encrypt_blocks(blocks)
+    }
+}
+
+impl<C: BlockDecrypt<BlockSize = U16>> BlockDecrypt for Shared<C> {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        self.cipher.decrypt_block(block)
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.cipher.decrypt_par_blocks(blocks)
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        self.cipher.decrypt_blocks(blocks)
+    }
+}
+
+impl<C> fmt::Debug for Shared<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Shared { ... }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Shared<C> {}
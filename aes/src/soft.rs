@@ -8,14 +8,90 @@
 
 #![deny(unsafe_code)]
 
-#[cfg_attr(not(target_pointer_width = "64"), path = "soft/fixslice32.rs")]
-#[cfg_attr(target_pointer_width = "64", path = "soft/fixslice64.rs")]
+#[cfg_attr(
+    all(
+        not(any(
+            target_pointer_width = "64",
+            all(target_arch = "arm", target_feature = "neon"),
+            all(target_arch = "wasm32", target_feature = "simd128")
+        )),
+        not(feature = "simd")
+    ),
+    path = "soft/fixslice32.rs"
+)]
+#[cfg_attr(
+    any(
+        target_pointer_width = "64",
+        all(target_arch = "arm", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128"),
+        feature = "simd"
+    ),
+    path = "soft/fixslice64.rs"
+)]
 pub(crate) mod fixslice;
 
-#[cfg(feature = "ctr")]
+pub(crate) mod expand;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "simd")
+))]
+mod wasm;
+
+#[cfg(all(
+    any(
+        target_pointer_width = "64",
+        all(target_arch = "arm", target_feature = "neon")
+    ),
+    not(feature = "simd")
+))]
+mod swar;
+
+// Only used when there is no autodetection, which provides its own CTR types
+#[cfg(all(
+    feature = "ctr",
+    not(all(
+        any(
+            target_arch = "aarch64",
+            all(target_arch = "powerpc64", feature = "power8"),
+            all(
+                any(target_arch = "riscv32", target_arch = "riscv64"),
+                target_feature = "zkne",
+                target_feature = "zknd"
+            ),
+            all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"
+            )
+        ),
+        not(feature = "force-soft")
+    ))
+))]
 mod ctr;
 
-#[cfg(feature = "ctr")]
+#[cfg(all(
+    feature = "ctr",
+    not(all(
+        any(
+            target_arch = "aarch64",
+            all(target_arch = "powerpc64", feature = "power8"),
+            all(
+                any(target_arch = "riscv32", target_arch = "riscv64"),
+                target_feature = "zkne",
+                target_feature = "zknd"
+            ),
+            all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2"
+            )
+        ),
+        not(feature = "force-soft")
+    ))
+))]
 pub use self::ctr::{Aes128Ctr, Aes192Ctr, Aes256Ctr};
 
 use crate::{Block, ParBlocks};
@@ -30,10 +106,15 @@ macro_rules! define_aes_impl {
     (
         $name:ident,
         $key_size:ty,
+        $key_bytes:expr,
+        $rounds:expr,
         $fixslice_keys:ty,
         $fixslice_key_schedule:path,
+        $fixslice_key_schedule_const:path,
         $fixslice_decrypt:path,
         $fixslice_encrypt:path,
+        $fixslice_decrypt_state:path,
+        $fixslice_encrypt_state:path,
         $doc:expr
     ) => {
         #[doc=$doc]
@@ -53,6 +134,50 @@ macro_rules! define_aes_impl {
             }
         }
 
+        impl $name {
+            /// Create a new cipher instance.
+            ///
+            /// This is the same as [`NewBlockCipher::new`], as this crate
+            /// was built with the "soft" backend only. The method exists for
+            /// API compatibility with the hardware-accelerated backends.
+            // unused when wrapped by the autodetection support
+            #[allow(dead_code)]
+            #[inline]
+            pub fn new_soft(key: &GenericArray<u8, $key_size>) -> Self {
+                Self::new(key)
+            }
+
+            /// Create a new cipher instance in a `const` context.
+            ///
+            /// This allows initializing `static` cipher instances with the
+            /// round keys computed at compile time. Evaluating it at runtime
+            /// is a lot slower than [`NewBlockCipher::new`].
+            #[inline]
+            pub const fn new_const(key: &[u8; $key_bytes]) -> Self {
+                Self {
+                    keys: $fixslice_key_schedule_const(key),
+                }
+            }
+
+            /// ⚠️ Get the round keys of the key schedule.
+            ///
+            /// Returns the round keys as described in FIPS 197 Section 5.2,
+            /// like the key expansion functions of the [`hazmat`] module.
+            ///
+            /// # ☢️️ WARNING: HAZARDOUS API ☢️
+            ///
+            /// Use this function with great care! See the [module-level
+            /// documentation][crate::hazmat] for more information.
+            ///
+            /// [`hazmat`]: crate::hazmat
+            #[cfg(feature = "hazmat")]
+            pub fn round_keys(&self) -> [Block; $rounds] {
+                let mut round_keys = [Block::default(); $rounds];
+                fixslice::round_keys(&self.keys, &mut round_keys);
+                round_keys
+            }
+        }
+
         impl BlockCipher for $name {
             type BlockSize = U16;
             type ParBlocks = U8;
@@ -67,12 +192,91 @@ macro_rules! define_aes_impl {
                 block.copy_from_slice(&blocks[0]);
             }
 
+            #[cfg(all(
+                not(any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon"),
+                    all(target_arch = "wasm32", target_feature = "simd128")
+                )),
+                not(feature = "simd")
+            ))]
             #[inline]
             fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
                 for chunk in blocks.chunks_mut(FIXSLICE_BLOCKS) {
                     $fixslice_encrypt(&self.keys, chunk);
                 }
             }
+
+            #[cfg(all(
+                not(any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon"),
+                    all(target_arch = "wasm32", target_feature = "simd128")
+                )),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn encrypt_blocks(&self, blocks: &mut [Block]) {
+                // fill both slots of the bitsliced state whenever possible,
+                // not only for complete batches of `ParBlocks`
+                let mut chunks = blocks.chunks_exact_mut(FIXSLICE_BLOCKS);
+                for chunk in &mut chunks {
+                    $fixslice_encrypt(&self.keys, chunk);
+                }
+                for block in chunks.into_remainder() {
+                    self.encrypt_block(block);
+                }
+            }
+
+            #[cfg(all(
+                any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon")
+                ),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                swar::process_blocks8(&self.keys, blocks, $fixslice_encrypt_state);
+            }
+
+            #[cfg(all(
+                any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon")
+                ),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn encrypt_blocks(&self, blocks: &mut [Block]) {
+                let mut chunks = blocks.chunks_exact_mut(16);
+                for chunk in &mut chunks {
+                    swar::process_blocks16(&self.keys, chunk, $fixslice_encrypt_state);
+                }
+                let mut chunks = chunks.into_remainder().chunks_exact_mut(8);
+                for chunk in &mut chunks {
+                    swar::process_blocks8(&self.keys, chunk, $fixslice_encrypt_state);
+                }
+                for block in chunks.into_remainder() {
+                    self.encrypt_block(block);
+                }
+            }
+
+            #[cfg(feature = "simd")]
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                simd::process_par_blocks(&self.keys, blocks, $fixslice_encrypt_state);
+            }
+
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                wasm::process_par_blocks(&self.keys, blocks, $fixslice_encrypt_state);
+            }
         }
 
         impl BlockDecrypt for $name {
@@ -84,44 +288,149 @@ macro_rules! define_aes_impl {
                 block.copy_from_slice(&blocks[0]);
             }
 
+            #[cfg(all(
+                not(any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon"),
+                    all(target_arch = "wasm32", target_feature = "simd128")
+                )),
+                not(feature = "simd")
+            ))]
             #[inline]
             fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
                 for chunk in blocks.chunks_mut(FIXSLICE_BLOCKS) {
                     $fixslice_decrypt(&self.keys, chunk);
                 }
             }
+
+            #[cfg(all(
+                not(any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon"),
+                    all(target_arch = "wasm32", target_feature = "simd128")
+                )),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn decrypt_blocks(&self, blocks: &mut [Block]) {
+                // fill both slots of the bitsliced state whenever possible,
+                // not only for complete batches of `ParBlocks`
+                let mut chunks = blocks.chunks_exact_mut(FIXSLICE_BLOCKS);
+                for chunk in &mut chunks {
+                    $fixslice_decrypt(&self.keys, chunk);
+                }
+                for block in chunks.into_remainder() {
+                    self.decrypt_block(block);
+                }
+            }
+
+            #[cfg(all(
+                any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon")
+                ),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                swar::process_blocks8(&self.keys, blocks, $fixslice_decrypt_state);
+            }
+
+            #[cfg(all(
+                any(
+                    target_pointer_width = "64",
+                    all(target_arch = "arm", target_feature = "neon")
+                ),
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn decrypt_blocks(&self, blocks: &mut [Block]) {
+                let mut chunks = blocks.chunks_exact_mut(16);
+                for chunk in &mut chunks {
+                    swar::process_blocks16(&self.keys, chunk, $fixslice_decrypt_state);
+                }
+                let mut chunks = chunks.into_remainder().chunks_exact_mut(8);
+                for chunk in &mut chunks {
+                    swar::process_blocks8(&self.keys, chunk, $fixslice_decrypt_state);
+                }
+                for block in chunks.into_remainder() {
+                    self.decrypt_block(block);
+                }
+            }
+
+            #[cfg(feature = "simd")]
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                simd::process_par_blocks(&self.keys, blocks, $fixslice_decrypt_state);
+            }
+
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                not(feature = "simd")
+            ))]
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+                wasm::process_par_blocks(&self.keys, blocks, $fixslice_decrypt_state);
+            }
         }
 
         opaque_debug::implement!($name);
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name {
+            #[inline]
+            fn drop(&mut self) {
+                crate::zeroize_keys(&mut self.keys);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
     };
 }
 
 define_aes_impl!(
     Aes128,
     U16,
+    16,
+    11,
     FixsliceKeys128,
     fixslice::aes128_key_schedule,
+    fixslice::aes128_key_schedule_const,
     fixslice::aes128_decrypt,
     fixslice::aes128_encrypt,
+    fixslice::aes128_decrypt_state,
+    fixslice::aes128_encrypt_state,
     "AES-128 block cipher instance"
 );
 
 define_aes_impl!(
     Aes192,
     U24,
+    24,
+    13,
     FixsliceKeys192,
     fixslice::aes192_key_schedule,
+    fixslice::aes192_key_schedule_const,
     fixslice::aes192_decrypt,
     fixslice::aes192_encrypt,
+    fixslice::aes192_decrypt_state,
+    fixslice::aes192_encrypt_state,
     "AES-192 block cipher instance"
 );
 
 define_aes_impl!(
     Aes256,
     U32,
+    32,
+    15,
     FixsliceKeys256,
     fixslice::aes256_key_schedule,
+    fixslice::aes256_key_schedule_const,
     fixslice::aes256_decrypt,
     fixslice::aes256_encrypt,
+    fixslice::aes256_decrypt_state,
+    fixslice::aes256_encrypt_state,
     "AES-256 block cipher instance"
 );
@@ -0,0 +1,103 @@
+//! AES key expansion usable in `const` contexts.
+//!
+//! The S-box is computed arithmetically (inversion in GF(2^8) followed by
+//! the affine transformation) without any lookup tables or data-dependent
+//! branches, so this is constant-time even when evaluated at runtime. It is
+//! a lot slower than the fixsliced key schedule though, which is why the
+//! soft backend only uses it to compute round keys at compile time.
+//!
+//! This is the key schedule shared by the backends whose instructions don't
+//! provide one, and by the hazmat API.
+
+/// Multiplication in GF(2^8) modulo the AES polynomial.
+const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    let mut i = 0;
+    while i < 8 {
+        p ^= a & 0u8.wrapping_sub(b & 1);
+        a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(a >> 7));
+        b >>= 1;
+        i += 1;
+    }
+    p
+}
+
+/// The AES S-box.
+const fn sub_byte(x: u8) -> u8 {
+    // x^254 is the multiplicative inverse of x, and maps 0 to 0
+    let x2 = gf_mul(x, x);
+    let x3 = gf_mul(x2, x);
+    let x6 = gf_mul(x3, x3);
+    let x12 = gf_mul(x6, x6);
+    let x15 = gf_mul(x12, x3);
+    let x30 = gf_mul(x15, x15);
+    let x60 = gf_mul(x30, x30);
+    let x120 = gf_mul(x60, x60);
+    let x240 = gf_mul(x120, x120);
+    let inv = gf_mul(gf_mul(x240, x12), x2);
+
+    inv ^ inv.rotate_left(1) ^ inv.rotate_left(2) ^ inv.rotate_left(3) ^ inv.rotate_left(4) ^ 0x63
+}
+
+/// AES key expansion as described in FIPS 197, Section 5.2.
+///
+/// Only the first `nk` words of `key` are used, which has to be 4, 6 or 8.
+/// The first 11, 13 or 15 round keys are set for these key sizes, the
+/// remaining ones are zero.
+pub(crate) const fn expand_key(key: &[u8; 32], nk: usize) -> [[u8; 16]; 15] {
+    let mut words = [0u8; 240];
+
+    let mut i = 0;
+    while i < 4 * nk {
+        words[i] = key[i];
+        i += 1;
+    }
+
+    let mut rcon = 1;
+    let mut i = nk;
+    while i < 4 * (nk + 7) {
+        let p = 4 * i - 4;
+        let mut word = [words[p], words[p + 1], words[p + 2], words[p + 3]];
+
+        if i % nk == 0 {
+            word = [
+                sub_byte(word[1]) ^ rcon,
+                sub_byte(word[2]),
+                sub_byte(word[3]),
+                sub_byte(word[0]),
+            ];
+            rcon = gf_mul(rcon, 2);
+        } else if nk > 6 && i % nk == 4 {
+            word = [
+                sub_byte(word[0]),
+                sub_byte(word[1]),
+                sub_byte(word[2]),
+                sub_byte(word[3]),
+            ];
+        }
+
+        let mut j = 0;
+        while j < 4 {
+            words[4 * i + j] = words[4 * (i - nk) + j] ^ word[j];
+            j += 1;
+        }
+        i += 1;
+    }
+
+    let mut round_keys = [[0u8; 16]; 15];
+    let mut i = 0;
+    while i < 240 {
+        round_keys[i / 16][i % 16] = words[i];
+        i += 1;
+    }
+    round_keys
+}
+
+/// Expand a `key` of 16, 24 or 32 bytes, see [`expand_key`].
+#[allow(dead_code)]
+pub(crate) fn expand_key_slice(key: &[u8]) -> [[u8; 16]; 15] {
+    debug_assert!(key.len() == 16 || key.len() == 24 || key.len() == 32);
+    let mut padded_key = [0u8; 32];
+    padded_key[..key.len()].copy_from_slice(key);
+    expand_key(&padded_key, key.len() / 4)
+}
@@ -241,6 +241,158 @@ pub(crate) fn aes256_key_schedule(key: &GenericArray<u8, U32>) -> FixsliceKeys25
     rkeys
 }
 
+macro_rules! define_key_schedule_const {
+    ($name:ident, $keys:ty, $key_bytes:expr, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        pub(crate) const fn $name(key: &[u8; $key_bytes]) -> $keys {
+            let mut padded_key = [0u8; 32];
+            let mut i = 0;
+            while i < $key_bytes {
+                padded_key[i] = key[i];
+                i += 1;
+            }
+            let round_keys = super::expand::expand_key(&padded_key, $key_bytes / 4);
+
+            let mut rkeys = [0u32; 8 * $rounds];
+
+            let mut i = 0;
+            while i < $rounds {
+                let rkey = fixslice_round_key(&round_keys[i], i, $rounds - 1);
+                let mut j = 0;
+                while j < 8 {
+                    rkeys[8 * i + j] = rkey[j];
+                    j += 1;
+                }
+                i += 1;
+            }
+            rkeys
+        }
+    };
+}
+
+define_key_schedule_const!(
+    aes128_key_schedule_const,
+    FixsliceKeys128,
+    16,
+    11,
+    "AES-128 key schedule which can be evaluated in a `const` context."
+);
+define_key_schedule_const!(
+    aes192_key_schedule_const,
+    FixsliceKeys192,
+    24,
+    13,
+    "AES-192 key schedule which can be evaluated in a `const` context."
+);
+define_key_schedule_const!(
+    aes256_key_schedule_const,
+    FixsliceKeys256,
+    32,
+    15,
+    "AES-256 key schedule which can be evaluated in a `const` context."
+);
+
+/// Bitslice a round key into the fixsliced representation of round `round`,
+/// i.e. apply the same adjustments as the key schedules above.
+const fn fixslice_round_key(round_key: &[u8; 16], round: usize, last: usize) -> [u32; 8] {
+    // The key is replicated over both blocks, so bit `p` of byte
+    // `4 * c + r` ends up in bits `8 * r + 2 * c + b` of the `p`-th word.
+    let mut rkey = [0u32; 8];
+    let mut i = 0;
+    while i < 16 {
+        let mut p = 0;
+        while p < 8 {
+            let bit = ((round_key[i] >> p) & 1) as u32;
+            rkey[p] |= 0u32.wrapping_sub(bit) & (0x3 << (8 * (i % 4) + 2 * (i / 4)));
+            p += 1;
+        }
+        i += 1;
+    }
+
+    if round == 0 {
+        return rkey;
+    }
+
+    let swaps = shift_rows_swaps(round, last);
+    let mut p = 0;
+    while p < 8 {
+        let mut x = rkey[p];
+        x = delta_swap_const(x, swaps[0].0, swaps[0].1);
+        x = delta_swap_const(x, swaps[1].0, swaps[1].1);
+        if is_inverted(p) {
+            x ^= 0xffffffff;
+        }
+        rkey[p] = x;
+        p += 1;
+    }
+    rkey
+}
+
+/// Recover the round key of round `round` from its fixsliced
+/// representation, i.e. the inverse of `fixslice_round_key`.
+#[cfg(feature = "hazmat")]
+fn unfixslice_round_key(rkey: &[u32], round: usize, last: usize) -> [u8; 16] {
+    let swaps = shift_rows_swaps(round, last);
+    let mut round_key = [0u8; 16];
+    for (p, &x) in rkey.iter().enumerate() {
+        let mut x = x;
+        if round != 0 {
+            if is_inverted(p) {
+                x ^= 0xffffffff;
+            }
+            x = delta_swap_const(x, swaps[1].0, swaps[1].1);
+            x = delta_swap_const(x, swaps[0].0, swaps[0].1);
+        }
+        for (i, b) in round_key.iter_mut().enumerate() {
+            *b |= (((x >> (8 * (i % 4) + 2 * (i / 4))) & 1) as u8) << p;
+        }
+    }
+    round_key
+}
+
+/// Recover the round keys from the fixsliced key schedule `rkeys`.
+#[cfg(feature = "hazmat")]
+pub(crate) fn round_keys(rkeys: &[u32], round_keys: &mut [Block]) {
+    let last = rkeys.len() / 8 - 1;
+    for (round, (dst, rkey)) in round_keys.iter_mut().zip(rkeys.chunks_exact(8)).enumerate() {
+        dst.copy_from_slice(&unfixslice_round_key(rkey, round, last));
+    }
+}
+
+/// Delta swaps implementing the `InvShiftRows` adjustment which the key
+/// schedules apply to the round key of round `round`, `(0, 0)` being a no-op.
+const fn shift_rows_swaps(round: usize, last: usize) -> [(u32, u32); 2] {
+    #[cfg(feature = "compact")]
+    if round != last && round % 2 == 1 {
+        // inv_shift_rows_1
+        return [(4, 0x030f0c00), (2, 0x33003300)];
+    }
+    #[cfg(not(feature = "compact"))]
+    if round != last {
+        match round % 4 {
+            // inv_shift_rows_1
+            1 => return [(4, 0x030f0c00), (2, 0x33003300)],
+            // inv_shift_rows_2
+            2 => return [(4, 0x0f000f00), (0, 0)],
+            // inv_shift_rows_3
+            3 => return [(4, 0x0c0f0300), (2, 0x33003300)],
+            _ => {}
+        }
+    }
+    [(0, 0); 2]
+}
+
+/// Whether bit plane `p` is inverted by `sub_bytes_nots`.
+const fn is_inverted(p: usize) -> bool {
+    p == 0 || p == 1 || p == 5 || p == 6
+}
+
+/// `const` version of `delta_swap_1`.
+const fn delta_swap_const(a: u32, shift: u32, mask: u32) -> u32 {
+    let t = (a ^ (a >> shift)) & mask;
+    a ^ t ^ (t << shift)
+}
+
 /// Fully-fixsliced AES-128 decryption (the InvShiftRows is completely omitted).
 ///
 /// Decrypts four blocks in-place and in parallel.
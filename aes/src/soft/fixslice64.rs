@@ -20,6 +20,7 @@ use cipher::{
     consts::{U16, U24, U32},
     generic_array::GenericArray,
 };
+use core::ops::{BitAnd, BitOr, BitXor, BitXorAssign};
 
 /// AES block batch size for this implementation
 pub(crate) const FIXSLICE_BLOCKS: usize = 4;
@@ -36,6 +37,42 @@ pub(crate) type FixsliceKeys256 = [u64; 120];
 /// 512-bit internal state
 pub(crate) type State = [u64; 8];
 
+/// Word holding one slice of the bitsliced state.
+///
+/// The round functions are generic over it, so that they can also operate
+/// on SIMD vectors of `u64` lanes, each lane holding an independent batch
+/// of four blocks.
+pub(crate) trait Word:
+    Copy + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self> + BitXorAssign
+{
+    fn splat(x: u64) -> Self;
+    fn shl(self, n: u32) -> Self;
+    fn shr(self, n: u32) -> Self;
+    fn ror(self, n: u32) -> Self;
+}
+
+impl Word for u64 {
+    #[inline(always)]
+    fn splat(x: u64) -> Self {
+        x
+    }
+
+    #[inline(always)]
+    fn shl(self, n: u32) -> Self {
+        self << n
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        self >> n
+    }
+
+    #[inline(always)]
+    fn ror(self, n: u32) -> Self {
+        self.rotate_right(n)
+    }
+}
+
 /// Fully bitsliced AES-128 key schedule to match the fully-fixsliced representation.
 pub(crate) fn aes128_key_schedule(key: &GenericArray<u8, U16>) -> FixsliceKeys128 {
     let mut rkeys = [0u64; 88];
@@ -263,6 +300,158 @@ pub(crate) fn aes256_key_schedule(key: &GenericArray<u8, U32>) -> FixsliceKeys25
     rkeys
 }
 
+macro_rules! define_key_schedule_const {
+    ($name:ident, $keys:ty, $key_bytes:expr, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        pub(crate) const fn $name(key: &[u8; $key_bytes]) -> $keys {
+            let mut padded_key = [0u8; 32];
+            let mut i = 0;
+            while i < $key_bytes {
+                padded_key[i] = key[i];
+                i += 1;
+            }
+            let round_keys = super::expand::expand_key(&padded_key, $key_bytes / 4);
+
+            let mut rkeys = [0u64; 8 * $rounds];
+
+            let mut i = 0;
+            while i < $rounds {
+                let rkey = fixslice_round_key(&round_keys[i], i, $rounds - 1);
+                let mut j = 0;
+                while j < 8 {
+                    rkeys[8 * i + j] = rkey[j];
+                    j += 1;
+                }
+                i += 1;
+            }
+            rkeys
+        }
+    };
+}
+
+define_key_schedule_const!(
+    aes128_key_schedule_const,
+    FixsliceKeys128,
+    16,
+    11,
+    "AES-128 key schedule which can be evaluated in a `const` context."
+);
+define_key_schedule_const!(
+    aes192_key_schedule_const,
+    FixsliceKeys192,
+    24,
+    13,
+    "AES-192 key schedule which can be evaluated in a `const` context."
+);
+define_key_schedule_const!(
+    aes256_key_schedule_const,
+    FixsliceKeys256,
+    32,
+    15,
+    "AES-256 key schedule which can be evaluated in a `const` context."
+);
+
+/// Bitslice a round key into the fixsliced representation of round `round`,
+/// i.e. apply the same adjustments as the key schedules above.
+const fn fixslice_round_key(round_key: &[u8; 16], round: usize, last: usize) -> [u64; 8] {
+    // The key is replicated over all four blocks, so bit `p` of byte
+    // `4 * c + r` ends up in bits `16 * r + 4 * c + b` of the `p`-th word.
+    let mut rkey = [0u64; 8];
+    let mut i = 0;
+    while i < 16 {
+        let mut p = 0;
+        while p < 8 {
+            let bit = ((round_key[i] >> p) & 1) as u64;
+            rkey[p] |= 0u64.wrapping_sub(bit) & (0xf << (16 * (i % 4) + 4 * (i / 4)));
+            p += 1;
+        }
+        i += 1;
+    }
+
+    if round == 0 {
+        return rkey;
+    }
+
+    let swaps = shift_rows_swaps(round, last);
+    let mut p = 0;
+    while p < 8 {
+        let mut x = rkey[p];
+        x = delta_swap_const(x, swaps[0].0, swaps[0].1);
+        x = delta_swap_const(x, swaps[1].0, swaps[1].1);
+        if is_inverted(p) {
+            x ^= 0xffffffffffffffff;
+        }
+        rkey[p] = x;
+        p += 1;
+    }
+    rkey
+}
+
+/// Recover the round key of round `round` from its fixsliced
+/// representation, i.e. the inverse of `fixslice_round_key`.
+#[cfg(feature = "hazmat")]
+fn unfixslice_round_key(rkey: &[u64], round: usize, last: usize) -> [u8; 16] {
+    let swaps = shift_rows_swaps(round, last);
+    let mut round_key = [0u8; 16];
+    for (p, &x) in rkey.iter().enumerate() {
+        let mut x = x;
+        if round != 0 {
+            if is_inverted(p) {
+                x ^= 0xffffffffffffffff;
+            }
+            x = delta_swap_const(x, swaps[1].0, swaps[1].1);
+            x = delta_swap_const(x, swaps[0].0, swaps[0].1);
+        }
+        for (i, b) in round_key.iter_mut().enumerate() {
+            *b |= (((x >> (16 * (i % 4) + 4 * (i / 4))) & 1) as u8) << p;
+        }
+    }
+    round_key
+}
+
+/// Recover the round keys from the fixsliced key schedule `rkeys`.
+#[cfg(feature = "hazmat")]
+pub(crate) fn round_keys(rkeys: &[u64], round_keys: &mut [Block]) {
+    let last = rkeys.len() / 8 - 1;
+    for (round, (dst, rkey)) in round_keys.iter_mut().zip(rkeys.chunks_exact(8)).enumerate() {
+        dst.copy_from_slice(&unfixslice_round_key(rkey, round, last));
+    }
+}
+
+/// Delta swaps implementing the `InvShiftRows` adjustment which the key
+/// schedules apply to the round key of round `round`, `(0, 0)` being a no-op.
+const fn shift_rows_swaps(round: usize, last: usize) -> [(u32, u64); 2] {
+    #[cfg(feature = "compact")]
+    if round != last && round % 2 == 1 {
+        // inv_shift_rows_1
+        return [(8, 0x000f00ff00f00000), (4, 0x0f0f00000f0f0000)];
+    }
+    #[cfg(not(feature = "compact"))]
+    if round != last {
+        match round % 4 {
+            // inv_shift_rows_1
+            1 => return [(8, 0x000f00ff00f00000), (4, 0x0f0f00000f0f0000)],
+            // inv_shift_rows_2
+            2 => return [(8, 0x00ff000000ff0000), (0, 0)],
+            // inv_shift_rows_3
+            3 => return [(8, 0x00f000ff000f0000), (4, 0x0f0f00000f0f0000)],
+            _ => {}
+        }
+    }
+    [(0, 0); 2]
+}
+
+/// Whether bit plane `p` is inverted by `sub_bytes_nots`.
+const fn is_inverted(p: usize) -> bool {
+    p == 0 || p == 1 || p == 5 || p == 6
+}
+
+/// `const` version of `delta_swap_1`.
+const fn delta_swap_const(a: u64, shift: u32, mask: u64) -> u64 {
+    let t = (a ^ (a >> shift)) & mask;
+    a ^ t ^ (t << shift)
+}
+
 /// Fully-fixsliced AES-128 decryption (the InvShiftRows is completely omitted).
 ///
 /// Decrypts four blocks in-place and in parallel.
@@ -271,53 +460,56 @@ pub(crate) fn aes128_decrypt(rkeys: &FixsliceKeys128, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes128_decrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[80..]);
-    inv_sub_bytes(&mut state);
+/// AES-128 decryption rounds on an already bitsliced state.
+pub(crate) fn aes128_decrypt_state<W: Word>(rkeys: &FixsliceKeys128, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[80..]);
+    inv_sub_bytes(state);
 
     #[cfg(not(feature = "compact"))]
     {
-        inv_shift_rows_2(&mut state);
+        inv_shift_rows_2(state);
     }
 
     let mut rk_off = 72;
     loop {
         #[cfg(feature = "compact")]
         {
-            inv_shift_rows_2(&mut state);
+            inv_shift_rows_2(state);
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_1(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_1(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
 
         if rk_off == 0 {
             break;
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_0(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_0(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
 
         #[cfg(not(feature = "compact"))]
         {
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_3(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_3(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
 
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_2(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_2(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
         }
     }
 
-    add_round_key(&mut state, &rkeys[..8]);
-
-    inv_bitslice(&state, blocks);
+    add_round_key(state, &rkeys[..8]);
 }
 
 /// Fully-fixsliced AES-128 encryption (the ShiftRows is completely omitted).
@@ -328,19 +520,24 @@ pub(crate) fn aes128_encrypt(rkeys: &FixsliceKeys128, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes128_encrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[..8]);
+/// AES-128 encryption rounds on an already bitsliced state.
+pub(crate) fn aes128_encrypt_state<W: Word>(rkeys: &FixsliceKeys128, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[..8]);
 
     let mut rk_off = 8;
     loop {
-        sub_bytes(&mut state);
-        mix_columns_1(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_1(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
 
         #[cfg(feature = "compact")]
         {
-            shift_rows_2(&mut state);
+            shift_rows_2(state);
         }
 
         if rk_off == 80 {
@@ -349,32 +546,30 @@ pub(crate) fn aes128_encrypt(rkeys: &FixsliceKeys128, blocks: &mut [Block]) {
 
         #[cfg(not(feature = "compact"))]
         {
-            sub_bytes(&mut state);
-            mix_columns_2(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_2(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
 
-            sub_bytes(&mut state);
-            mix_columns_3(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_3(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
         }
 
-        sub_bytes(&mut state);
-        mix_columns_0(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_0(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
     }
 
     #[cfg(not(feature = "compact"))]
     {
-        shift_rows_2(&mut state);
+        shift_rows_2(state);
     }
 
-    sub_bytes(&mut state);
-    add_round_key(&mut state, &rkeys[80..]);
-
-    inv_bitslice(&state, blocks);
+    sub_bytes(state);
+    add_round_key(state, &rkeys[80..]);
 }
 
 /// Fully-fixsliced AES-192 decryption (the InvShiftRows is completely omitted).
@@ -385,47 +580,50 @@ pub(crate) fn aes192_decrypt(rkeys: &FixsliceKeys192, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes192_decrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[96..]);
-    inv_sub_bytes(&mut state);
+/// AES-192 decryption rounds on an already bitsliced state.
+pub(crate) fn aes192_decrypt_state<W: Word>(rkeys: &FixsliceKeys192, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[96..]);
+    inv_sub_bytes(state);
 
     let mut rk_off = 88;
     loop {
         #[cfg(feature = "compact")]
         {
-            inv_shift_rows_2(&mut state);
+            inv_shift_rows_2(state);
         }
         #[cfg(not(feature = "compact"))]
         {
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_3(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_3(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
 
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_2(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_2(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_1(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_1(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
 
         if rk_off == 0 {
             break;
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_0(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_0(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
     }
 
-    add_round_key(&mut state, &rkeys[..8]);
-
-    inv_bitslice(&state, blocks);
+    add_round_key(state, &rkeys[..8]);
 }
 
 /// Fully-fixsliced AES-192 encryption (the ShiftRows is completely omitted).
@@ -436,30 +634,35 @@ pub(crate) fn aes192_encrypt(rkeys: &FixsliceKeys192, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes192_encrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[..8]);
+/// AES-192 encryption rounds on an already bitsliced state.
+pub(crate) fn aes192_encrypt_state<W: Word>(rkeys: &FixsliceKeys192, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[..8]);
 
     let mut rk_off = 8;
     loop {
-        sub_bytes(&mut state);
-        mix_columns_1(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_1(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
 
         #[cfg(feature = "compact")]
         {
-            shift_rows_2(&mut state);
+            shift_rows_2(state);
         }
         #[cfg(not(feature = "compact"))]
         {
-            sub_bytes(&mut state);
-            mix_columns_2(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_2(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
 
-            sub_bytes(&mut state);
-            mix_columns_3(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_3(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
         }
 
@@ -467,16 +670,14 @@ pub(crate) fn aes192_encrypt(rkeys: &FixsliceKeys192, blocks: &mut [Block]) {
             break;
         }
 
-        sub_bytes(&mut state);
-        mix_columns_0(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_0(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
     }
 
-    sub_bytes(&mut state);
-    add_round_key(&mut state, &rkeys[96..]);
-
-    inv_bitslice(&state, blocks);
+    sub_bytes(state);
+    add_round_key(state, &rkeys[96..]);
 }
 
 /// Fully-fixsliced AES-256 decryption (the InvShiftRows is completely omitted).
@@ -487,53 +688,56 @@ pub(crate) fn aes256_decrypt(rkeys: &FixsliceKeys256, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes256_decrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[112..]);
-    inv_sub_bytes(&mut state);
+/// AES-256 decryption rounds on an already bitsliced state.
+pub(crate) fn aes256_decrypt_state<W: Word>(rkeys: &FixsliceKeys256, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[112..]);
+    inv_sub_bytes(state);
 
     #[cfg(not(feature = "compact"))]
     {
-        inv_shift_rows_2(&mut state);
+        inv_shift_rows_2(state);
     }
 
     let mut rk_off = 104;
     loop {
         #[cfg(feature = "compact")]
         {
-            inv_shift_rows_2(&mut state);
+            inv_shift_rows_2(state);
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_1(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_1(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
 
         if rk_off == 0 {
             break;
         }
 
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-        inv_mix_columns_0(&mut state);
-        inv_sub_bytes(&mut state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+        inv_mix_columns_0(state);
+        inv_sub_bytes(state);
         rk_off -= 8;
 
         #[cfg(not(feature = "compact"))]
         {
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_3(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_3(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
 
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
-            inv_mix_columns_2(&mut state);
-            inv_sub_bytes(&mut state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
+            inv_mix_columns_2(state);
+            inv_sub_bytes(state);
             rk_off -= 8;
         }
     }
 
-    add_round_key(&mut state, &rkeys[..8]);
-
-    inv_bitslice(&state, blocks);
+    add_round_key(state, &rkeys[..8]);
 }
 
 /// Fully-fixsliced AES-256 encryption (the ShiftRows is completely omitted).
@@ -544,19 +748,24 @@ pub(crate) fn aes256_encrypt(rkeys: &FixsliceKeys256, blocks: &mut [Block]) {
     let mut state = State::default();
 
     bitslice(&mut state, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    aes256_encrypt_state(rkeys, &mut state);
+    inv_bitslice(&state, blocks);
+}
 
-    add_round_key(&mut state, &rkeys[..8]);
+/// AES-256 encryption rounds on an already bitsliced state.
+pub(crate) fn aes256_encrypt_state<W: Word>(rkeys: &FixsliceKeys256, state: &mut [W; 8]) {
+    add_round_key(state, &rkeys[..8]);
 
     let mut rk_off = 8;
     loop {
-        sub_bytes(&mut state);
-        mix_columns_1(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_1(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
 
         #[cfg(feature = "compact")]
         {
-            shift_rows_2(&mut state);
+            shift_rows_2(state);
         }
 
         if rk_off == 112 {
@@ -565,37 +774,35 @@ pub(crate) fn aes256_encrypt(rkeys: &FixsliceKeys256, blocks: &mut [Block]) {
 
         #[cfg(not(feature = "compact"))]
         {
-            sub_bytes(&mut state);
-            mix_columns_2(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_2(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
 
-            sub_bytes(&mut state);
-            mix_columns_3(&mut state);
-            add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+            sub_bytes(state);
+            mix_columns_3(state);
+            add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
             rk_off += 8;
         }
 
-        sub_bytes(&mut state);
-        mix_columns_0(&mut state);
-        add_round_key(&mut state, &rkeys[rk_off..(rk_off + 8)]);
+        sub_bytes(state);
+        mix_columns_0(state);
+        add_round_key(state, &rkeys[rk_off..(rk_off + 8)]);
         rk_off += 8;
     }
 
     #[cfg(not(feature = "compact"))]
     {
-        shift_rows_2(&mut state);
+        shift_rows_2(state);
     }
 
-    sub_bytes(&mut state);
-    add_round_key(&mut state, &rkeys[112..]);
-
-    inv_bitslice(&state, blocks);
+    sub_bytes(state);
+    add_round_key(state, &rkeys[112..]);
 }
 
 /// Note that the 4 bitwise NOT (^= 0xffffffffffffffff) are accounted for here so that it is a true
 /// inverse of 'sub_bytes'.
-fn inv_sub_bytes(state: &mut [u64]) {
+fn inv_sub_bytes<W: Word>(state: &mut [W]) {
     debug_assert_eq!(state.len(), 8);
 
     // Scheduled using https://github.com/Ko-/aes-armcortexm/tree/public/scheduler
@@ -804,7 +1011,7 @@ fn inv_sub_bytes(state: &mut [u64]) {
 /// See: <http://www.cs.yale.edu/homes/peralta/CircuitStuff/SLP_AES_113.txt>
 ///
 /// Note that the 4 bitwise NOT (^= 0xffffffffffffffff) are moved to the key schedule.
-fn sub_bytes(state: &mut [u64]) {
+fn sub_bytes<W: Word>(state: &mut [W]) {
     debug_assert_eq!(state.len(), 8);
 
     // Scheduled using https://github.com/Ko-/aes-armcortexm/tree/public/scheduler
@@ -997,7 +1204,7 @@ macro_rules! define_mix_columns {
         $second_rotate:path
     ) => {
         #[rustfmt::skip]
-        fn $name(state: &mut State) {
+        fn $name<W: Word>(state: &mut [W; 8]) {
             let (a0, a1, a2, a3, a4, a5, a6, a7) = (
                 state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7]
             );
@@ -1032,7 +1239,7 @@ macro_rules! define_mix_columns {
         }
 
         #[rustfmt::skip]
-        fn $name_inv(state: &mut State) {
+        fn $name_inv<W: Word>(state: &mut [W; 8]) {
             let (a0, a1, a2, a3, a4, a5, a6, a7) = (
                 state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7]
             );
@@ -1119,9 +1326,9 @@ define_mix_columns!(
 );
 
 #[inline]
-fn delta_swap_1(a: &mut u64, shift: u32, mask: u64) {
-    let t = (*a ^ ((*a) >> shift)) & mask;
-    *a ^= t ^ (t << shift);
+fn delta_swap_1<W: Word>(a: &mut W, shift: u32, mask: u64) {
+    let t = (*a ^ a.shr(shift)) & W::splat(mask);
+    *a ^= t ^ t.shl(shift);
 }
 
 #[inline]
@@ -1134,7 +1341,7 @@ fn delta_swap_2(a: &mut u64, b: &mut u64, shift: u32, mask: u64) {
 /// Applies ShiftRows once on an AES state (or key).
 #[cfg(any(not(feature = "compact"), feature = "hazmat"))]
 #[inline]
-fn shift_rows_1(state: &mut [u64]) {
+fn shift_rows_1<W: Word>(state: &mut [W]) {
     debug_assert_eq!(state.len(), 8);
     for x in state.iter_mut() {
         delta_swap_1(x, 8, 0x00f000ff000f0000);
@@ -1144,7 +1351,7 @@ fn shift_rows_1(state: &mut [u64]) {
 
 /// Applies ShiftRows twice on an AES state (or key).
 #[inline]
-fn shift_rows_2(state: &mut [u64]) {
+fn shift_rows_2<W: Word>(state: &mut [W]) {
     debug_assert_eq!(state.len(), 8);
     for x in state.iter_mut() {
         delta_swap_1(x, 8, 0x00ff000000ff0000);
@@ -1153,7 +1360,7 @@ fn shift_rows_2(state: &mut [u64]) {
 
 /// Applies ShiftRows three times on an AES state (or key).
 #[inline]
-fn shift_rows_3(state: &mut [u64]) {
+fn shift_rows_3<W: Word>(state: &mut [W]) {
     debug_assert_eq!(state.len(), 8);
     for x in state.iter_mut() {
         delta_swap_1(x, 8, 0x000f00ff00f00000);
@@ -1162,18 +1369,18 @@ fn shift_rows_3(state: &mut [u64]) {
 }
 
 #[inline(always)]
-fn inv_shift_rows_1(state: &mut [u64]) {
+fn inv_shift_rows_1<W: Word>(state: &mut [W]) {
     shift_rows_3(state);
 }
 
 #[inline(always)]
-fn inv_shift_rows_2(state: &mut [u64]) {
+fn inv_shift_rows_2<W: Word>(state: &mut [W]) {
     shift_rows_2(state);
 }
 
 #[cfg(not(feature = "compact"))]
 #[inline(always)]
-fn inv_shift_rows_3(state: &mut [u64]) {
+fn inv_shift_rows_3<W: Word>(state: &mut [W]) {
     shift_rows_1(state);
 }
 
@@ -1197,7 +1404,13 @@ fn xor_columns(rkeys: &mut [u64], offset: usize, idx_xor: usize, idx_ror: u32) {
 }
 
 /// Bitslice four 128-bit input blocks input0, input1, input2, input3 into a 512-bit internal state.
-fn bitslice(output: &mut [u64], input0: &[u8], input1: &[u8], input2: &[u8], input3: &[u8]) {
+pub(crate) fn bitslice(
+    output: &mut [u64],
+    input0: &[u8],
+    input1: &[u8],
+    input2: &[u8],
+    input3: &[u8],
+) {
     debug_assert_eq!(output.len(), 8);
     debug_assert_eq!(input0.len(), 16);
     debug_assert_eq!(input1.len(), 16);
@@ -1274,7 +1487,7 @@ fn bitslice(output: &mut [u64], input0: &[u8], input1: &[u8], input2: &[u8], inp
 }
 
 /// Un-bitslice a 512-bit internal state into four 128-bit blocks of output.
-fn inv_bitslice(input: &[u64], output: &mut [Block]) {
+pub(crate) fn inv_bitslice(input: &[u64], output: &mut [Block]) {
     debug_assert_eq!(input.len(), 8);
     debug_assert_eq!(output.len(), 4);
 
@@ -1365,10 +1578,10 @@ fn memshift32(buffer: &mut [u64], src_offset: usize) {
 /// XOR the round key to the internal state. The round keys are expected to be
 /// pre-computed and to be packed in the fixsliced representation.
 #[inline]
-fn add_round_key(state: &mut State, rkey: &[u64]) {
+fn add_round_key<W: Word>(state: &mut [W; 8], rkey: &[u64]) {
     debug_assert_eq!(rkey.len(), 8);
     for (a, b) in state.iter_mut().zip(rkey) {
-        *a ^= b;
+        *a ^= W::splat(*b);
     }
 }
 
@@ -1378,8 +1591,8 @@ fn add_round_constant_bit(state: &mut [u64], bit: usize) {
 }
 
 #[inline(always)]
-fn ror(x: u64, y: u32) -> u64 {
-    x.rotate_right(y)
+fn ror<W: Word>(x: W, y: u32) -> W {
+    x.ror(y)
 }
 
 #[inline(always)]
@@ -1388,43 +1601,43 @@ fn ror_distance(rows: u32, cols: u32) -> u32 {
 }
 
 #[inline(always)]
-fn rotate_rows_1(x: u64) -> u64 {
+fn rotate_rows_1<W: Word>(x: W) -> W {
     ror(x, ror_distance(1, 0))
 }
 
 #[inline(always)]
-fn rotate_rows_2(x: u64) -> u64 {
+fn rotate_rows_2<W: Word>(x: W) -> W {
     ror(x, ror_distance(2, 0))
 }
 
 #[inline(always)]
 #[rustfmt::skip]
-fn rotate_rows_and_columns_1_1(x: u64) -> u64 {
-    (ror(x, ror_distance(1, 1)) & 0x0fff0fff0fff0fff) |
-    (ror(x, ror_distance(0, 1)) & 0xf000f000f000f000)
+fn rotate_rows_and_columns_1_1<W: Word>(x: W) -> W {
+    (ror(x, ror_distance(1, 1)) & W::splat(0x0fff0fff0fff0fff)) |
+    (ror(x, ror_distance(0, 1)) & W::splat(0xf000f000f000f000))
 }
 
 #[cfg(not(feature = "compact"))]
 #[inline(always)]
 #[rustfmt::skip]
-fn rotate_rows_and_columns_1_2(x: u64) -> u64 {
-    (ror(x, ror_distance(1, 2)) & 0x00ff00ff00ff00ff) |
-    (ror(x, ror_distance(0, 2)) & 0xff00ff00ff00ff00)
+fn rotate_rows_and_columns_1_2<W: Word>(x: W) -> W {
+    (ror(x, ror_distance(1, 2)) & W::splat(0x00ff00ff00ff00ff)) |
+    (ror(x, ror_distance(0, 2)) & W::splat(0xff00ff00ff00ff00))
 }
 
 #[cfg(not(feature = "compact"))]
 #[inline(always)]
 #[rustfmt::skip]
-fn rotate_rows_and_columns_1_3(x: u64) -> u64 {
-    (ror(x, ror_distance(1, 3)) & 0x000f000f000f000f) |
-    (ror(x, ror_distance(0, 3)) & 0xfff0fff0fff0fff0)
+fn rotate_rows_and_columns_1_3<W: Word>(x: W) -> W {
+    (ror(x, ror_distance(1, 3)) & W::splat(0x000f000f000f000f)) |
+    (ror(x, ror_distance(0, 3)) & W::splat(0xfff0fff0fff0fff0))
 }
 
 #[inline(always)]
 #[rustfmt::skip]
-fn rotate_rows_and_columns_2_2(x: u64) -> u64 {
-    (ror(x, ror_distance(2, 2)) & 0x00ff00ff00ff00ff) |
-    (ror(x, ror_distance(1, 2)) & 0xff00ff00ff00ff00)
+fn rotate_rows_and_columns_2_2<W: Word>(x: W) -> W {
+    (ror(x, ror_distance(2, 2)) & W::splat(0x00ff00ff00ff00ff)) |
+    (ror(x, ror_distance(1, 2)) & W::splat(0xff00ff00ff00ff00))
 }
 
 /// Low-level "hazmat" AES functions.
@@ -1495,7 +1708,7 @@ pub(crate) mod hazmat {
     #[inline]
     pub(crate) fn equiv_inv_cipher_round(block: &mut Block, round_key: &Block) {
         let mut state = State::default();
-        bitslice(&mut state, &block, &block, &block, &block);
+        bitslice(&mut state, block, block, block, block);
         sub_bytes_nots(&mut state);
         inv_sub_bytes(&mut state);
         inv_shift_rows_1(&mut state);
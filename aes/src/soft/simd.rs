@@ -0,0 +1,58 @@
+//! Portable SIMD variant of the 64-bit fixsliced implementation (nightly-only).
+//!
+//! Every word of the bitsliced state is widened to a vector of two `u64`
+//! lanes, each holding its own batch of four blocks, so that all eight
+//! parallel blocks are processed at once. The round keys are shared with
+//! the scalar implementation and broadcast to both lanes.
+
+use super::fixslice::{bitslice, inv_bitslice, State, Word};
+use crate::ParBlocks;
+use core::simd::u64x2;
+
+impl Word for u64x2 {
+    #[inline(always)]
+    fn splat(x: u64) -> Self {
+        u64x2::splat(x)
+    }
+
+    #[inline(always)]
+    fn shl(self, n: u32) -> Self {
+        self << u64::from(n)
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        self >> u64::from(n)
+    }
+
+    #[inline(always)]
+    fn ror(self, n: u32) -> Self {
+        let n = u64::from(n);
+        (self >> n) | (self << ((64 - n) & 63))
+    }
+}
+
+/// Apply `f` to eight blocks bitsliced into two-lane vectors.
+#[inline(always)]
+pub(crate) fn process_par_blocks<K>(keys: &K, blocks: &mut ParBlocks, f: fn(&K, &mut [u64x2; 8])) {
+    let mut lo = State::default();
+    let mut hi = State::default();
+    bitslice(&mut lo, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    bitslice(&mut hi, &blocks[4], &blocks[5], &blocks[6], &blocks[7]);
+
+    let mut state = [u64x2::splat(0); 8];
+    for i in 0..8 {
+        state[i] = u64x2::from_array([lo[i], hi[i]]);
+    }
+
+    f(keys, &mut state);
+
+    for i in 0..8 {
+        let [l, h] = state[i].to_array();
+        lo[i] = l;
+        hi[i] = h;
+    }
+    let (blocks_lo, blocks_hi) = blocks.split_at_mut(4);
+    inv_bitslice(&lo, blocks_lo);
+    inv_bitslice(&hi, blocks_hi);
+}
@@ -0,0 +1,127 @@
+//! SIMD-within-a-register variant of the 64-bit fixsliced implementation.
+//!
+//! Every word of the bitsliced state is widened to an array of `u64` lanes,
+//! each holding its own batch of four blocks, so that 8 or 16 blocks are
+//! processed by a single pass over the round functions. The lane operations
+//! are plain loops over the arrays, which the compiler maps to the vector
+//! units of the target, e.g. the 4 x `u64` words of 16-block batches fit
+//! into AVX2 registers when compiled with `-Ctarget-feature=+avx2`. Unlike
+//! the `simd` feature this works on stable Rust. The round keys are shared
+//! with the scalar implementation and broadcast to all lanes.
+
+use super::fixslice::{bitslice, inv_bitslice, State, Word};
+use crate::Block;
+use core::ops::{BitAnd, BitOr, BitXor, BitXorAssign};
+
+macro_rules! define_lanes {
+    ($name:ident, $lanes:expr, $process:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone)]
+        pub(crate) struct $name([u64; $lanes]);
+
+        impl BitAnd for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitand(mut self, rhs: Self) -> Self {
+                for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *a &= b;
+                }
+                self
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitor(mut self, rhs: Self) -> Self {
+                for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *a |= b;
+                }
+                self
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitxor(mut self, rhs: Self) -> Self {
+                self ^= rhs;
+                self
+            }
+        }
+
+        impl BitXorAssign for $name {
+            #[inline(always)]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    *a ^= b;
+                }
+            }
+        }
+
+        impl Word for $name {
+            #[inline(always)]
+            fn splat(x: u64) -> Self {
+                Self([x; $lanes])
+            }
+
+            #[inline(always)]
+            fn shl(mut self, n: u32) -> Self {
+                self.0.iter_mut().for_each(|a| *a <<= n);
+                self
+            }
+
+            #[inline(always)]
+            fn shr(mut self, n: u32) -> Self {
+                self.0.iter_mut().for_each(|a| *a >>= n);
+                self
+            }
+
+            #[inline(always)]
+            fn ror(mut self, n: u32) -> Self {
+                self.0.iter_mut().for_each(|a| *a = a.rotate_right(n));
+                self
+            }
+        }
+
+        /// Apply `f` to a batch of `4 * lanes` blocks.
+        #[inline(always)]
+        pub(crate) fn $process<K>(keys: &K, blocks: &mut [Block], f: fn(&K, &mut [$name; 8])) {
+            debug_assert_eq!(blocks.len(), 4 * $lanes);
+            let mut state = [$name::splat(0); 8];
+            for (lane, chunk) in blocks.chunks_exact(4).enumerate() {
+                let mut s = State::default();
+                bitslice(&mut s, &chunk[0], &chunk[1], &chunk[2], &chunk[3]);
+                for (w, x) in state.iter_mut().zip(s.iter()) {
+                    w.0[lane] = *x;
+                }
+            }
+
+            f(keys, &mut state);
+
+            for (lane, chunk) in blocks.chunks_exact_mut(4).enumerate() {
+                let mut s = State::default();
+                for (x, w) in s.iter_mut().zip(state.iter()) {
+                    *x = w.0[lane];
+                }
+                inv_bitslice(&s, chunk);
+            }
+        }
+    };
+}
+
+define_lanes!(
+    U64x2,
+    2,
+    process_blocks8,
+    "Two lanes of four bitsliced blocks each."
+);
+define_lanes!(
+    U64x4,
+    4,
+    process_blocks16,
+    "Four lanes of four bitsliced blocks each."
+);
@@ -0,0 +1,103 @@
+//! WebAssembly SIMD128 variant of the 64-bit fixsliced implementation.
+//!
+//! Every word of the bitsliced state is held in a `v128` register as two
+//! `u64` lanes, each holding its own batch of four blocks, so that all eight
+//! parallel blocks are processed at once. Unlike the `simd` feature this
+//! works on stable Rust, it is used when compiling with
+//! `-Ctarget-feature=+simd128`. The round keys are shared with the scalar
+//! implementation and broadcast to both lanes.
+
+// the crate MSRV does not apply to this target-specific variant
+#![allow(clippy::incompatible_msrv)]
+
+use super::fixslice::{bitslice, inv_bitslice, State, Word};
+use crate::ParBlocks;
+use core::{
+    arch::wasm32::*,
+    ops::{BitAnd, BitOr, BitXor, BitXorAssign},
+};
+
+/// Two lanes of four bitsliced blocks each.
+#[derive(Copy, Clone)]
+pub(crate) struct U64x2(v128);
+
+impl BitAnd for U64x2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(v128_and(self.0, rhs.0))
+    }
+}
+
+impl BitOr for U64x2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(v128_or(self.0, rhs.0))
+    }
+}
+
+impl BitXor for U64x2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(v128_xor(self.0, rhs.0))
+    }
+}
+
+impl BitXorAssign for U64x2 {
+    #[inline(always)]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 = v128_xor(self.0, rhs.0);
+    }
+}
+
+impl Word for U64x2 {
+    #[inline(always)]
+    fn splat(x: u64) -> Self {
+        Self(u64x2_splat(x))
+    }
+
+    #[inline(always)]
+    fn shl(self, n: u32) -> Self {
+        Self(u64x2_shl(self.0, n))
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        Self(u64x2_shr(self.0, n))
+    }
+
+    #[inline(always)]
+    fn ror(self, n: u32) -> Self {
+        // shift amounts are taken modulo the lane width, so `n == 0` works
+        Self(v128_or(u64x2_shr(self.0, n), u64x2_shl(self.0, 64 - n)))
+    }
+}
+
+/// Apply `f` to eight blocks bitsliced into two-lane vectors.
+#[inline(always)]
+pub(crate) fn process_par_blocks<K>(keys: &K, blocks: &mut ParBlocks, f: fn(&K, &mut [U64x2; 8])) {
+    let mut lo = State::default();
+    let mut hi = State::default();
+    bitslice(&mut lo, &blocks[0], &blocks[1], &blocks[2], &blocks[3]);
+    bitslice(&mut hi, &blocks[4], &blocks[5], &blocks[6], &blocks[7]);
+
+    let mut state = [U64x2::splat(0); 8];
+    for i in 0..8 {
+        state[i] = U64x2(u64x2(lo[i], hi[i]));
+    }
+
+    f(keys, &mut state);
+
+    for i in 0..8 {
+        lo[i] = u64x2_extract_lane::<0>(state[i].0);
+        hi[i] = u64x2_extract_lane::<1>(state[i].0);
+    }
+    let (blocks_lo, blocks_hi) = blocks.split_at_mut(4);
+    inv_bitslice(&lo, blocks_lo);
+    inv_bitslice(&hi, blocks_hi);
+}
@@ -0,0 +1,211 @@
+//! AES block ciphers implementation using the VAES and AVX-512F instruction
+//! set extensions.
+//!
+//! Every 512-bit register holds four blocks and up to eight registers, i.e.
+//! 32 blocks, are processed per iteration, which keeps the AES units of
+//! Ice Lake and newer cores busy. The round keys are expanded with AES-NI
+//! and broadcast to all four lanes, single blocks are processed with the
+//! AES-NI backend. The CTR mode of the AES-NI backend generates its
+//! keystream with `xor_ctr_keystream` when VAES is available.
+//!
+//! The VAES and AVX-512 intrinsics require Rust 1.89 or newer, so this
+//! backend is only compiled with the `vaes` crate feature.
+
+// the crate MSRV does not apply to this opt-in backend
+#![allow(clippy::incompatible_msrv)]
+
+use crate::{ni, Block};
+use cipher::{
+    consts::{U16, U24, U32},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::arch::x86_64::*;
+
+/// Maximum number of 512-bit registers processed per iteration.
+const REGS: usize = 8;
+
+/// Maximum number of blocks processed per iteration.
+pub(crate) const PAR_BLOCKS: usize = 4 * REGS;
+
+#[inline]
+#[target_feature(enable = "avx512f,vaes")]
+unsafe fn encrypt_regs(keys: &[__m128i], regs: &mut [__m512i]) {
+    let n = keys.len() - 1;
+    let k = _mm512_broadcast_i32x4(keys[0]);
+    for r in regs.iter_mut() {
+        *r = _mm512_xor_si512(*r, k);
+    }
+    for key in &keys[1..n] {
+        let k = _mm512_broadcast_i32x4(*key);
+        for r in regs.iter_mut() {
+            *r = _mm512_aesenc_epi128(*r, k);
+        }
+    }
+    let k = _mm512_broadcast_i32x4(keys[n]);
+    for r in regs.iter_mut() {
+        *r = _mm512_aesenclast_epi128(*r, k);
+    }
+}
+
+/// Decrypt with the AES-NI decryption key schedule, which is applied in
+/// reverse order.
+#[inline]
+#[target_feature(enable = "avx512f,vaes")]
+unsafe fn decrypt_regs(keys: &[__m128i], regs: &mut [__m512i]) {
+    let n = keys.len() - 1;
+    let k = _mm512_broadcast_i32x4(keys[n]);
+    for r in regs.iter_mut() {
+        *r = _mm512_xor_si512(*r, k);
+    }
+    for key in keys[1..n].iter().rev() {
+        let k = _mm512_broadcast_i32x4(*key);
+        for r in regs.iter_mut() {
+            *r = _mm512_aesdec_epi128(*r, k);
+        }
+    }
+    let k = _mm512_broadcast_i32x4(keys[0]);
+    for r in regs.iter_mut() {
+        *r = _mm512_aesdeclast_epi128(*r, k);
+    }
+}
+
+/// Mask selecting the 64-bit lanes of the first `blocks` blocks of a
+/// register.
+#[inline(always)]
+fn lane_mask(blocks: usize) -> __mmask8 {
+    debug_assert!(blocks <= 4);
+    ((1u16 << (2 * blocks)) - 1) as __mmask8
+}
+
+/// Encrypt or decrypt `blocks` in place in chunks of up to `PAR_BLOCKS`
+/// blocks.
+///
+/// A trailing partial register is loaded and stored with a lane mask.
+#[inline]
+#[target_feature(enable = "avx512f,vaes")]
+unsafe fn process_blocks(keys: &[__m128i], blocks: &mut [Block], decrypt: bool) {
+    let mut regs = [_mm512_setzero_si512(); REGS];
+    for chunk in blocks.chunks_mut(PAR_BLOCKS) {
+        let n = (chunk.len() + 3) / 4;
+
+        for (r, b) in regs.iter_mut().zip(chunk.chunks(4)) {
+            *r = _mm512_maskz_loadu_epi64(lane_mask(b.len()), b.as_ptr() as *const i64);
+        }
+        if decrypt {
+            decrypt_regs(keys, &mut regs[..n]);
+        } else {
+            encrypt_regs(keys, &mut regs[..n]);
+        }
+        for (r, b) in regs.iter().zip(chunk.chunks_mut(4)) {
+            _mm512_mask_storeu_epi64(b.as_mut_ptr() as *mut i64, lane_mask(b.len()), *r);
+        }
+    }
+
+    // the registers hold plaintext
+    #[cfg(feature = "zeroize")]
+    crate::zeroize_keys(&mut regs);
+}
+
+/// Encrypt the counter blocks `ctrs` and XOR the keystream into `data`,
+/// which holds one block for each of the up to `PAR_BLOCKS` counter blocks.
+#[cfg(feature = "ctr")]
+#[inline]
+#[target_feature(enable = "avx512f,vaes")]
+pub(crate) unsafe fn xor_ctr_keystream(keys: &[__m128i], ctrs: &[__m128i], data: &mut [u8]) {
+    use crate::BLOCK_SIZE;
+
+    debug_assert!(ctrs.len() <= PAR_BLOCKS);
+    debug_assert_eq!(data.len(), ctrs.len() * BLOCK_SIZE);
+    let n = (ctrs.len() + 3) / 4;
+
+    let mut regs = [_mm512_setzero_si512(); REGS];
+    for (r, c) in regs.iter_mut().zip(ctrs.chunks(4)) {
+        *r = _mm512_maskz_loadu_epi64(lane_mask(c.len()), c.as_ptr() as *const i64);
+    }
+    encrypt_regs(keys, &mut regs[..n]);
+    for (r, d) in regs.iter().zip(data.chunks_mut(4 * BLOCK_SIZE)) {
+        let mask = lane_mask(d.len() / BLOCK_SIZE);
+        let ptr = d.as_mut_ptr() as *mut i64;
+        let v = _mm512_maskz_loadu_epi64(mask, ptr);
+        _mm512_mask_storeu_epi64(ptr, mask, _mm512_xor_si512(v, *r));
+    }
+
+    // the registers hold the keystream
+    #[cfg(feature = "zeroize")]
+    crate::zeroize_keys(&mut regs);
+}
+
+macro_rules! define_aes_impl {
+    ($name:ident, $key_size:ty, $rounds:tt, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $name {
+            pub(crate) ni: ni::$name,
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            #[inline]
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                Self {
+                    ni: ni::$name::new(key),
+                }
+            }
+        }
+
+        impl $name {
+            /// Get the round keys of the encryption key schedule.
+            #[cfg(feature = "hazmat")]
+            pub(crate) fn round_keys(&self) -> [Block; $rounds] {
+                self.ni.round_keys()
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = U16;
+            type ParBlocks = U32;
+        }
+
+        impl BlockEncrypt for $name {
+            #[inline]
+            fn encrypt_block(&self, block: &mut Block) {
+                self.ni.encrypt_block(block);
+            }
+
+            #[inline]
+            fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, U32>) {
+                self.encrypt_blocks(blocks);
+            }
+
+            #[inline]
+            fn encrypt_blocks(&self, blocks: &mut [Block]) {
+                unsafe { process_blocks(&self.ni.encrypt_keys, blocks, false) }
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            #[inline]
+            fn decrypt_block(&self, block: &mut Block) {
+                self.ni.decrypt_block(block);
+            }
+
+            #[inline]
+            fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, U32>) {
+                self.decrypt_blocks(blocks);
+            }
+
+            #[inline]
+            fn decrypt_blocks(&self, blocks: &mut [Block]) {
+                unsafe { process_blocks(&self.ni.decrypt_keys, blocks, true) }
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+define_aes_impl!(Aes128, U16, 11, "AES-128 block cipher instance");
+define_aes_impl!(Aes192, U24, 13, "AES-192 block cipher instance");
+define_aes_impl!(Aes256, U32, 15, "AES-256 block cipher instance");
@@ -0,0 +1,148 @@
+//! AES with the key size selected at runtime.
+
+use crate::{Aes128, Aes192, Aes256, Block};
+use cipher::{
+    consts::U16, errors::InvalidLength, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+    ParBlocks,
+};
+
+/// AES block cipher instance with the key size selected at runtime.
+///
+/// This is useful for protocols which negotiate the key size, as the cipher
+/// can be initialized from a key slice of any of the supported lengths:
+///
+/// ```
+/// use aes::{Aes, BlockEncrypt, Block};
+///
+/// let cipher = Aes::new_from_slice(&[0x42; 24]).unwrap();
+/// assert_eq!(cipher.key_size(), 24);
+///
+/// let mut block = Block::default();
+/// cipher.encrypt_block(&mut block);
+/// ```
+// the variants are stored inline to support `no_std` targets
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+pub enum Aes {
+    /// AES-128
+    Aes128(Aes128),
+    /// AES-192
+    Aes192(Aes192),
+    /// AES-256
+    Aes256(Aes256),
+}
+
+impl Aes {
+    /// Create a new cipher instance from a 16, 24 or 32 byte long key.
+    ///
+    /// Returns [`InvalidLength`] for keys of any other length.
+    #[inline]
+    pub fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        match key.len() {
+            16 => Aes128::new_from_slice(key).map(Self::Aes128),
+            24 => Aes192::new_from_slice(key).map(Self::Aes192),
+            32 => Aes256::new_from_slice(key).map(Self::Aes256),
+            _ => Err(InvalidLength),
+        }
+    }
+
+    /// Size of the key in bytes.
+    #[inline]
+    pub fn key_size(&self) -> usize {
+        match self {
+            Self::Aes128(_) => 16,
+            Self::Aes192(_) => 24,
+            Self::Aes256(_) => 32,
+        }
+    }
+}
+
+impl From<Aes128> for Aes {
+    #[inline]
+    fn from(cipher: Aes128) -> Self {
+        Self::Aes128(cipher)
+    }
+}
+
+impl From<Aes192> for Aes {
+    #[inline]
+    fn from(cipher: Aes192) -> Self {
+        Self::Aes192(cipher)
+    }
+}
+
+impl From<Aes256> for Aes {
+    #[inline]
+    fn from(cipher: Aes256) -> Self {
+        Self::Aes256(cipher)
+    }
+}
+
+impl BlockCipher for Aes {
+    type BlockSize = U16;
+    type ParBlocks = <Aes128 as BlockCipher>::ParBlocks;
+}
+
+impl BlockEncrypt for Aes {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_block(block),
+            Self::Aes192(cipher) => cipher.encrypt_block(block),
+            Self::Aes256(cipher) => cipher.encrypt_block(block),
+        }
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_par_blocks(blocks),
+            Self::Aes192(cipher) => cipher.encrypt_par_blocks(blocks),
+            Self::Aes256(cipher) => cipher.encrypt_par_blocks(blocks),
+        }
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_blocks(blocks),
+            Self::Aes192(cipher) => cipher.encrypt_blocks(blocks),
+            Self::Aes256(cipher) => cipher.encrypt_blocks(blocks),
+        }
+    }
+}
+
+impl BlockDecrypt for Aes {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_block(block),
+            Self::Aes192(cipher) => cipher.decrypt_block(block),
+            Self::Aes256(cipher) => cipher.decrypt_block(block),
+        }
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_par_blocks(blocks),
+            Self::Aes192(cipher) => cipher.decrypt_par_blocks(blocks),
+            Self::Aes256(cipher) => cipher.decrypt_par_blocks(blocks),
+        }
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_blocks(blocks),
+            Self::Aes192(cipher) => cipher.decrypt_blocks(blocks),
+            Self::Aes256(cipher) => cipher.decrypt_blocks(blocks),
+        }
+    }
+}
+
+opaque_debug::implement!(Aes);
+
+// the cipher instance of the live variant wipes its round keys when dropped
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Aes {}
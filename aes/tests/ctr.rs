@@ -1,5 +1,8 @@
 #![cfg(feature = "ctr")]
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use aes::{Aes128Ctr, Aes256Ctr};
 
 // Random tests generated by OpenSSL
@@ -7,3 +10,45 @@ cipher::stream_cipher_test!(aes128_ctr_core, Aes128Ctr, "aes128-ctr");
 cipher::stream_cipher_test!(aes256_ctr_core, Aes256Ctr, "aes256-ctr");
 cipher::stream_cipher_seek_test!(aes128_ctr_seek, Aes128Ctr);
 cipher::stream_cipher_seek_test!(aes256_ctr_seek, Aes256Ctr);
+
+/// Check that bulk processing, which may take several blocks at once,
+/// agrees with processing the data piecewise.
+#[test]
+fn bulk_keystream() {
+    use aes::cipher::{NewCipher, StreamCipher};
+
+    let key = Default::default();
+    let nonce = Default::default();
+    let mut bulk = [0u8; 1000];
+    Aes128Ctr::new(&key, &nonce).apply_keystream(&mut bulk);
+
+    let mut piecewise = [0u8; 1000];
+    let mut cipher = Aes128Ctr::new(&key, &nonce);
+    for chunk in piecewise.chunks_mut(15) {
+        cipher.apply_keystream(chunk);
+    }
+    assert_eq!(bulk[..], piecewise[..]);
+}
+
+/// Check the wide keystream paths against the generic CTR implementation,
+/// including partially filled batches and a wrapping 64-bit counter.
+#[test]
+fn generic_ctr() {
+    use aes::{
+        cipher::{NewCipher, StreamCipher},
+        Aes128,
+    };
+
+    let key = [0x42; 16].into();
+    let mut nonce = [0x24; 16];
+    nonce[8..].copy_from_slice(&(u64::MAX - 40).to_be_bytes());
+    let nonce = nonce.into();
+    for n in (0..=1200).step_by(37) {
+        let mut buf = vec![0u8; n];
+        Aes128Ctr::new(&key, &nonce).apply_keystream(&mut buf);
+
+        let mut expected = vec![0u8; n];
+        ctr::Ctr64BE::<Aes128>::new(&key, &nonce).apply_keystream(&mut expected);
+        assert_eq!(buf, expected);
+    }
+}
@@ -3,6 +3,9 @@
 // TODO(tarcieri): support for using the hazmat functions with the `soft` backend
 #![cfg(feature = "hazmat")]
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use aes::{Block, ParBlocks};
 use hex_literal::hex;
 
@@ -153,3 +156,169 @@ fn inv_mix_columns_fips197_vector() {
     aes::hazmat::inv_mix_columns(&mut block);
     assert_eq!(block.as_slice(), &hex!("4773b91ff72f354361cb018ea1e6cf2c"))
 }
+
+#[test]
+fn aes128_round_keys_fips197_vector() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let round_keys = aes::hazmat::aes128_round_keys(&key.into());
+    assert_eq!(round_keys[0].as_slice(), &key);
+    assert_eq!(
+        round_keys[1].as_slice(),
+        &hex!("a0fafe1788542cb123a339392a6c7605")
+    );
+    assert_eq!(
+        round_keys[10].as_slice(),
+        &hex!("d014f9a8c9ee2589e13f0cc8b6630ca6")
+    );
+}
+
+#[test]
+fn aes192_round_keys_fips197_vector() {
+    let key = hex!("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b");
+    let round_keys = aes::hazmat::aes192_round_keys(&key.into());
+    assert_eq!(
+        round_keys[1].as_slice(),
+        &hex!("62f8ead2522c6b7bfe0c91f72402f5a5")
+    );
+    assert_eq!(
+        round_keys[12].as_slice(),
+        &hex!("e98ba06f448c773c8ecc720401002202")
+    );
+}
+
+#[test]
+fn aes256_round_keys_fips197_vector() {
+    let key = hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4");
+    let round_keys = aes::hazmat::aes256_round_keys(&key.into());
+    assert_eq!(
+        round_keys[2].as_slice(),
+        &hex!("9ba354118e6925afa51a8b5f2067fcde")
+    );
+    assert_eq!(
+        round_keys[14].as_slice(),
+        &hex!("fe4890d1e6188d0b046df344706c631e")
+    );
+}
+
+#[test]
+fn gf128_double() {
+    let mut block = Block::from(hex!("000000000000000000000000000000c0"));
+    aes::hazmat::gf128_double(&mut block);
+    assert_eq!(block, Block::from(hex!("87000000000000000000000000000080")));
+
+    let mut block = Block::from(hex!("c0000000000000000000000000000000"));
+    aes::hazmat::gf128_double_be(&mut block);
+    assert_eq!(block, Block::from(hex!("80000000000000000000000000000087")));
+}
+
+#[test]
+fn gf128_mul() {
+    let a = Block::from(hex!("0123456789abcdeffedcba9876543210"));
+    let b = Block::from(hex!("00112233445566778899aabbccddeeff"));
+
+    let mut one = Block::default();
+    one[0] = 1;
+    assert_eq!(aes::hazmat::gf128_mul(&a, &one), a);
+    assert_eq!(
+        aes::hazmat::gf128_mul(&a, &b),
+        aes::hazmat::gf128_mul(&b, &a)
+    );
+
+    let mut alpha = Block::default();
+    alpha[0] = 2;
+    let mut doubled = a;
+    aes::hazmat::gf128_double(&mut doubled);
+    assert_eq!(aes::hazmat::gf128_mul(&a, &alpha), doubled);
+
+    // α^128 = α^7 + α^2 + α + 1
+    let mut power = one;
+    for _ in 0..128 {
+        power = aes::hazmat::gf128_mul(&power, &alpha);
+    }
+    assert_eq!(power, Block::from(hex!("87000000000000000000000000000000")));
+}
+
+/// IEEE 1619 XTS-AES-128 test vector 1.
+#[test]
+fn xts_tweaks() {
+    use aes::{Aes128, BlockEncrypt, NewBlockCipher};
+
+    let cipher = Aes128::new(&Default::default());
+    let mut tweaks = [Block::default(); 2];
+    aes::hazmat::xts_tweaks(&cipher, &Block::default(), &mut tweaks);
+
+    let mut blocks = [Block::default(); 2];
+    for (block, tweak) in blocks.iter_mut().zip(tweaks.iter()) {
+        block.iter_mut().zip(tweak).for_each(|(b, t)| *b ^= t);
+        cipher.encrypt_block(block);
+        block.iter_mut().zip(tweak).for_each(|(b, t)| *b ^= t);
+    }
+    assert_eq!(
+        blocks[0],
+        Block::from(hex!("917cf69ebd68b2ec9b9fe9a3eadda692"))
+    );
+    assert_eq!(
+        blocks[1],
+        Block::from(hex!("cd43d2f59598ed858c02c2652fbf922e"))
+    );
+}
+
+#[test]
+fn fixslice_batch() {
+    use aes::hazmat::fixslice::{self, BatchBlocks, BATCH_BLOCKS};
+    use aes::{Aes256, BlockEncrypt, NewBlockCipher};
+
+    let key = hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4");
+    let keys = fixslice::Aes256Keys::new(&key.into());
+    let cipher = Aes256::new(&key.into());
+
+    let mut blocks = BatchBlocks::default();
+    for (i, block) in blocks.iter_mut().enumerate() {
+        *block = [i as u8; 16].into();
+    }
+    let plaintext = blocks;
+
+    fixslice::aes256_encrypt(&keys, &mut blocks);
+    for i in 0..BATCH_BLOCKS {
+        let mut expected = plaintext[i];
+        cipher.encrypt_block(&mut expected);
+        assert_eq!(blocks[i], expected);
+    }
+
+    fixslice::aes256_decrypt(&keys, &mut blocks);
+    assert_eq!(blocks, plaintext);
+}
+
+macro_rules! round_keys_accessor_test {
+    ($name:ident, $cipher:ident, $round_keys:ident, $key:expr) => {
+        #[test]
+        fn $name() {
+            use aes::{$cipher, NewBlockCipher};
+
+            let key = $key;
+            let expected = aes::hazmat::$round_keys(&key.into());
+            assert_eq!($cipher::new(&key.into()).round_keys(), expected);
+            assert_eq!($cipher::new_soft(&key.into()).round_keys(), expected);
+            assert_eq!($cipher::new_const(&key).round_keys(), expected);
+        }
+    };
+}
+
+round_keys_accessor_test!(
+    aes128_round_keys_accessor,
+    Aes128,
+    aes128_round_keys,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c")
+);
+round_keys_accessor_test!(
+    aes192_round_keys_accessor,
+    Aes192,
+    aes192_round_keys,
+    hex!("8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b")
+);
+round_keys_accessor_test!(
+    aes256_round_keys_accessor,
+    Aes256,
+    aes256_round_keys,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+);
@@ -1,6 +1,126 @@
 //! Test vectors are from NESSIE:
 //! https://www.cosic.esat.kuleuven.be/nessie/testvectors/
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 cipher::block_cipher_test!(aes128_test, "aes128", aes::Aes128);
 cipher::block_cipher_test!(aes192_test, "aes192", aes::Aes192);
 cipher::block_cipher_test!(aes256_test, "aes256", aes::Aes256);
+
+/// Check that the multi-block paths of the backends agree with single block
+/// processing, including partially filled batches.
+fn check_blocks<C>()
+where
+    C: aes::NewBlockCipher + aes::BlockEncrypt + aes::BlockDecrypt,
+    C: aes::BlockCipher<BlockSize = cipher::consts::U16>,
+{
+    use cipher::generic_array::typenum::Unsigned;
+
+    let key: Vec<u8> = (0..C::KeySize::USIZE as u8).collect();
+    let cipher = C::new_from_slice(&key).unwrap();
+    for n in 0..=40u8 {
+        let data: Vec<aes::Block> = (0..n).map(|i| [i; 16].into()).collect();
+        let mut expected = data.clone();
+        expected.iter_mut().for_each(|b| cipher.encrypt_block(b));
+
+        let mut blocks = data.clone();
+        cipher.encrypt_blocks(&mut blocks);
+        assert_eq!(blocks, expected);
+        cipher.decrypt_blocks(&mut blocks);
+        assert_eq!(blocks, data);
+    }
+}
+
+#[test]
+fn multi_block() {
+    check_blocks::<aes::Aes128>();
+    check_blocks::<aes::Aes192>();
+    check_blocks::<aes::Aes256>();
+}
+
+#[test]
+fn new_soft() {
+    use aes::{Aes128, Block, BlockEncrypt, NewBlockCipher};
+
+    let key = Default::default();
+    let mut block = Block::default();
+    let mut soft_block = Block::default();
+    Aes128::new(&key).encrypt_block(&mut block);
+    Aes128::new_soft(&key).encrypt_block(&mut soft_block);
+    assert_eq!(block, soft_block);
+}
+
+/// Check that the round keys computed by `new_const` match the ones of `new`.
+macro_rules! check_new_const {
+    ($cipher:ty, $key_bytes:expr) => {{
+        use aes::{Block, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+
+        for seed in 0..16u8 {
+            let mut key = [0u8; $key_bytes];
+            for (i, b) in key.iter_mut().enumerate() {
+                *b = seed.wrapping_mul(0x3b) ^ (i as u8).wrapping_mul(0x71);
+            }
+            let cipher = <$cipher>::new(&key.into());
+            let const_cipher = <$cipher>::new_const(&key);
+
+            let mut block = Block::from([seed; 16]);
+            let mut const_block = block;
+            cipher.encrypt_block(&mut block);
+            const_cipher.encrypt_block(&mut const_block);
+            assert_eq!(block, const_block);
+            const_cipher.decrypt_block(&mut const_block);
+            assert_eq!(const_block, Block::from([seed; 16]));
+        }
+    }};
+}
+
+#[test]
+fn new_const() {
+    check_new_const!(aes::Aes128, 16);
+    check_new_const!(aes::Aes192, 24);
+    check_new_const!(aes::Aes256, 32);
+}
+
+#[test]
+fn new_const_static() {
+    use aes::{Aes128, Block, BlockEncrypt};
+    use hex_literal::hex;
+
+    // FIPS 197, Appendix C.1
+    static CIPHER: Aes128 = Aes128::new_const(&hex!("000102030405060708090a0b0c0d0e0f"));
+
+    let mut block = Block::from(hex!("00112233445566778899aabbccddeeff"));
+    CIPHER.encrypt_block(&mut block);
+    assert_eq!(block, Block::from(hex!("69c4e0d86a7b0430d8cdb78070b4c55a")));
+}
+
+#[test]
+fn variable_key_size() {
+    use aes::{Aes, Aes128, Aes192, Aes256, Block, BlockEncrypt, NewBlockCipher};
+
+    let key: Vec<u8> = (0..32).collect();
+    let mut expected = [Block::default(); 3];
+    Aes128::new_from_slice(&key[..16])
+        .unwrap()
+        .encrypt_block(&mut expected[0]);
+    Aes192::new_from_slice(&key[..24])
+        .unwrap()
+        .encrypt_block(&mut expected[1]);
+    Aes256::new_from_slice(&key[..32])
+        .unwrap()
+        .encrypt_block(&mut expected[2]);
+
+    for (key_size, expected) in [16, 24, 32].iter().zip(expected.iter()) {
+        let cipher = Aes::new_from_slice(&key[..*key_size]).unwrap();
+        assert_eq!(cipher.key_size(), *key_size);
+
+        let mut block = Block::default();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(&block, expected);
+    }
+
+    for key_size in [0, 8, 15, 17, 31].iter() {
+        assert!(Aes::new_from_slice(&key[..*key_size]).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    errors::BlockModeError,
+    traits::BlockMode,
+    utils::{to_blocks, Block},
+    Cbc, Pcbc,
+};
+use block_padding::Padding;
+use cipher::{generic_array::typenum::Unsigned, BlockCipher};
+use core::marker::PhantomData;
+
+/// Buffered encryptor which accepts data in chunks of arbitrary length.
+///
+/// Complete blocks are encrypted as soon as they are available, the
+/// remaining partial block is buffered and padded by
+/// [`finalize`][Encryptor::finalize].
+///
+/// ```
+/// use aes::Aes128;
+/// use block_modes::{block_padding::Pkcs7, BlockMode, Pcbc, PcbcDecryptor, PcbcEncryptor};
+///
+/// let key = [0x42; 16];
+/// let iv = [0x24; 16];
+/// let mode = Pcbc::<Aes128, Pkcs7>::new_from_slices(&key, &iv).unwrap();
+/// let mut enc = PcbcEncryptor::new(mode);
+///
+/// let mut ct = [0u8; 48];
+/// let n = enc.update(b"hello ", &mut ct).unwrap().len();
+/// let n = n + enc.update(b"buffered world!!!", &mut ct[n..]).unwrap().len();
+/// let n = n + enc.finalize(&mut ct[n..]).unwrap().len();
+/// assert_eq!(n, 32);
+///
+/// let mode = Pcbc::<Aes128, Pkcs7>::new_from_slices(&key, &iv).unwrap();
+/// let mut dec = PcbcDecryptor::new(mode);
+/// let mut pt = [0u8; 48];
+/// let m = dec.update(&ct[..n], &mut pt).unwrap().len();
+/// let m = m + dec.finalize(&mut pt[m..]).unwrap().len();
+/// assert_eq!(&pt[..m], b"hello buffered world!!!");
+/// ```
+pub struct Encryptor<C: BlockCipher, P: Padding, M: BlockMode<C, P>> {
+    mode: M,
+    buf: Block<C>,
+    pos: usize,
+    _p: PhantomData<P>,
+}
+
+impl<C: BlockCipher, P: Padding, M: BlockMode<C, P>> Encryptor<C, P, M> {
+    /// Create buffered encryptor from the block mode instance.
+    pub fn new(mode: M) -> Self {
+        Self {
+            mode,
+            buf: Default::default(),
+            pos: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Encrypt `data` and write all completed blocks to `out`.
+    ///
+    /// Returns the written part of `out`, or an error if `out` is too short
+    /// for the completed blocks.
+    pub fn update<'a>(
+        &mut self,
+        data: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], BlockModeError> {
+        let bs = C::BlockSize::USIZE;
+        let pos = self.pos;
+        let n = (pos + data.len()) / bs * bs;
+        if n == 0 {
+            self.buf[pos..pos + data.len()].copy_from_slice(data);
+            self.pos += data.len();
+            return Ok(&out[..0]);
+        }
+        if out.len() < n {
+            return Err(BlockModeError);
+        }
+
+        let out = &mut out[..n];
+        out[..pos].copy_from_slice(&self.buf[..pos]);
+        let (data, rem) = data.split_at(n - pos);
+        out[pos..].copy_from_slice(data);
+        self.mode.encrypt_blocks(to_blocks(out));
+
+        self.buf[..rem.len()].copy_from_slice(rem);
+        self.pos = rem.len();
+        Ok(out)
+    }
+
+    /// Pad the buffered data and write the final block(s) to `out`.
+    ///
+    /// `out` must be at least one block long. Returns an error if the
+    /// padding fails, e.g. if there is a partial block left with
+    /// [`NoPadding`][block_padding::NoPadding].
+    pub fn finalize(mut self, out: &mut [u8]) -> Result<&[u8], BlockModeError> {
+        let bs = C::BlockSize::USIZE;
+        if out.len() < bs {
+            return Err(BlockModeError);
+        }
+        let out = &mut out[..bs];
+        out[..self.pos].copy_from_slice(&self.buf[..self.pos]);
+        let buf = P::pad(out, self.pos, bs).map_err(|_| BlockModeError)?;
+        self.mode.encrypt_blocks(to_blocks(buf));
+        Ok(buf)
+    }
+}
+
+/// Buffered decryptor which accepts data in chunks of arbitrary length.
+///
+/// The last block is held back until [`finalize`][Decryptor::finalize],
+/// which strips the padding from it.
+pub struct Decryptor<C: BlockCipher, P: Padding, M: BlockMode<C, P>> {
+    mode: M,
+    buf: Block<C>,
+    pos: usize,
+    _p: PhantomData<P>,
+}
+
+impl<C: BlockCipher, P: Padding, M: BlockMode<C, P>> Decryptor<C, P, M> {
+    /// Create buffered decryptor from the block mode instance.
+    pub fn new(mode: M) -> Self {
+        Self {
+            mode,
+            buf: Default::default(),
+            pos: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Decrypt `data` and write all completed blocks except the last one to
+    /// `out`.
+    ///
+    /// Returns the written part of `out`, or an error if `out` is too short
+    /// for the completed blocks.
+    pub fn update<'a>(
+        &mut self,
+        data: &[u8],
+        out: &'a mut [u8],
+    ) -> Result<&'a [u8], BlockModeError> {
+        let bs = C::BlockSize::USIZE;
+        let pos = self.pos;
+        let total = pos + data.len();
+        // keep at least one byte, i.e. the possibly last block, buffered
+        let n = total.saturating_sub(1) / bs * bs;
+        if n == 0 {
+            self.buf[pos..total].copy_from_slice(data);
+            self.pos = total;
+            return Ok(&out[..0]);
+        }
+        if out.len() < n {
+            return Err(BlockModeError);
+        }
+
+        let out = &mut out[..n];
+        out[..pos].copy_from_slice(&self.buf[..pos]);
+        let (data, rem) = data.split_at(n - pos);
+        out[pos..].copy_from_slice(data);
+        self.mode.decrypt_blocks(to_blocks(out));
+
+        self.buf[..rem.len()].copy_from_slice(rem);
+        self.pos = rem.len();
+        Ok(out)
+    }
+
+    /// Decrypt the last block, strip the padding and write the result to
+    /// `out`.
+    ///
+    /// `out` must be at least one block long. Returns an error if the total
+    /// length of the data is not a multiple of the block size or if the
+    /// padding is malformed. An empty ciphertext results in an empty
+    /// message.
+    pub fn finalize(mut self, out: &mut [u8]) -> Result<&[u8], BlockModeError> {
+        let bs = C::BlockSize::USIZE;
+        if self.pos == 0 {
+            return Ok(&out[..0]);
+        }
+        if self.pos != bs || out.len() < bs {
+            return Err(BlockModeError);
+        }
+        let out = &mut out[..bs];
+        out.copy_from_slice(&self.buf);
+        self.mode.decrypt_blocks(to_blocks(out));
+        P::unpad(out).map_err(|_| BlockModeError)
+    }
+}
+
+impl<C: BlockCipher, P: Padding, M: BlockMode<C, P>> core::fmt::Debug for Encryptor<C, P, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Encryptor { ... }")
+    }
+}
+
+impl<C: BlockCipher, P: Padding, M: BlockMode<C, P>> core::fmt::Debug for Decryptor<C, P, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Decryptor { ... }")
+    }
+}
+
+/// Buffered [`Cbc`] encryptor.
+pub type CbcEncryptor<C, P> = Encryptor<C, P, Cbc<C, P>>;
+/// Buffered [`Cbc`] decryptor.
+pub type CbcDecryptor<C, P> = Decryptor<C, P, Cbc<C, P>>;
+/// Buffered [`Pcbc`] encryptor.
+pub type PcbcEncryptor<C, P> = Encryptor<C, P, Pcbc<C, P>>;
+/// Buffered [`Pcbc`] decryptor.
+pub type PcbcDecryptor<C, P> = Decryptor<C, P, Pcbc<C, P>>;
@@ -1,9 +1,11 @@
-use crate::traits::{BlockMode, IvState};
-use crate::utils::{get_par_blocks, xor, Block, ParBlocks};
+use crate::traits::{BlockMode, IvState, ParallelismHint, ResetIv};
+use crate::utils::{xor, Block, ParBlocks};
 use block_padding::Padding;
-use cipher::generic_array::{typenum::Unsigned, GenericArray};
+use cipher::generic_array::GenericArray;
 use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt};
 use core::marker::PhantomData;
+#[cfg(all(feature = "std", feature = "rayon"))]
+use {crate::utils::thread_chunk_blocks, rayon::prelude::*, std::vec::Vec};
 
 /// [Cipher Block Chaining][1] (CBC) block cipher mode instance.
 ///
@@ -52,7 +54,7 @@ where
         self.iv = {
             let mut iv = &self.iv;
             for block in blocks {
-                xor(block, &iv);
+                xor(block, iv);
                 self.cipher.encrypt_block(block);
                 iv = block;
             }
@@ -61,22 +63,23 @@ where
     }
 
     fn decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
-        let pbn = C::ParBlocks::to_usize();
+        let pbn = C::PAR_BLOCKS;
         if pbn != 1 {
-            let (par_blocks, leftover) = get_par_blocks::<C>(blocks);
-            let mut iv_buf = ParBlocks::<C>::default();
-            iv_buf[0] = self.iv.clone();
-            for pb in par_blocks {
-                iv_buf[1..].clone_from_slice(&pb[..pbn - 1]);
-                let next_iv = pb[pbn - 1].clone();
-                self.cipher.decrypt_blocks(pb);
-                pb.iter_mut()
-                    .zip(iv_buf.iter())
-                    .for_each(|(a, b)| xor(a, b));
-                iv_buf[0] = next_iv;
+            // Copies of the ciphertext blocks, needed as IVs of the next ones.
+            // Decrypting the next chunk before XORing the current one needs
+            // another copy per chunk and was measured to be much slower.
+            let mut ct_buf = ParBlocks::<C>::default();
+            for chunk in blocks.chunks_mut(pbn) {
+                ct_buf[..chunk.len()].clone_from_slice(chunk);
+                self.cipher.decrypt_blocks(chunk);
+
+                let mut iv = &self.iv;
+                for (i, block) in chunk.iter_mut().enumerate() {
+                    xor(block, iv);
+                    iv = &ct_buf[i];
+                }
+                self.iv = iv.clone();
             }
-            self.iv = iv_buf[0].clone();
-            self.single_blocks_decrypt(leftover);
         } else {
             self.single_blocks_decrypt(blocks);
         }
@@ -92,3 +95,45 @@ where
         self.iv.clone()
     }
 }
+
+impl<C, P> ResetIv<C, P> for Cbc<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, <Self as BlockMode<C, P>>::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
+
+#[cfg(all(feature = "std", feature = "rayon"))]
+impl<C, P> Cbc<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt + Clone + Send + Sync,
+    P: Padding,
+{
+    /// Decrypt blocks of data using multiple threads.
+    ///
+    /// Produces the same result as [`BlockMode::decrypt_blocks`], but splits
+    /// the blocks across the rayon thread pool. This is possible since every
+    /// block only depends on the preceding ciphertext block. CBC encryption
+    /// is inherently sequential.
+    pub fn par_decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        let chunk_blocks = thread_chunk_blocks::<C>();
+        // IVs of the chunks, i.e. the last ciphertext block of the previous one
+        let ivs: Vec<Block<C>> = core::iter::once(self.iv.clone())
+            .chain(
+                blocks
+                    .chunks(chunk_blocks)
+                    .map(|chunk| chunk[chunk.len() - 1].clone()),
+            )
+            .collect();
+        self.iv = ivs[ivs.len() - 1].clone();
+
+        let cipher = &self.cipher;
+        blocks
+            .par_chunks_mut(chunk_blocks)
+            .zip(ivs.par_iter())
+            .for_each(|(chunk, iv)| Self::new(cipher.clone(), iv).decrypt_blocks(chunk));
+    }
+}
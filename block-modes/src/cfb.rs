@@ -1,12 +1,9 @@
 use crate::{
-    traits::{BlockMode, IvState},
+    traits::{BlockMode, IvState, ParallelismHint, ResetIv},
     utils::{xor, Block, ParBlocks},
 };
 use block_padding::Padding;
-use cipher::{
-    generic_array::{typenum::Unsigned, GenericArray},
-    BlockCipher, BlockEncrypt,
-};
+use cipher::{generic_array::GenericArray, BlockCipher, BlockEncrypt};
 use core::{marker::PhantomData, ptr};
 
 /// [Cipher feedback][1] (CFB) block mode instance with a full block feedback.
@@ -42,7 +39,7 @@ where
     }
 
     fn decrypt_blocks(&mut self, mut blocks: &mut [Block<C>]) {
-        let pb = C::ParBlocks::to_usize();
+        let pb = C::PAR_BLOCKS;
 
         if blocks.len() > pb {
             // SAFETY: we have checked that `blocks` has enough elements
@@ -95,9 +92,19 @@ where
     }
 }
 
+impl<C, P> ResetIv<C, P> for Cfb<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
+
 #[inline(always)]
 fn read_par_block<C: BlockCipher>(blocks: &[Block<C>]) -> ParBlocks<C> {
-    assert!(blocks.len() >= C::ParBlocks::to_usize());
+    assert!(blocks.len() >= C::PAR_BLOCKS);
     // SAFETY: assert checks that `blocks` is long enough
     #[allow(unsafe_code)]
     unsafe {
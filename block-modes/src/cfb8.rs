@@ -1,5 +1,5 @@
 use crate::{
-    traits::{BlockMode, IvState},
+    traits::{BlockMode, IvState, ResetIv},
     utils::Block,
 };
 use block_padding::Padding;
@@ -72,3 +72,13 @@ where
         self.iv.clone()
     }
 }
+
+impl<C, P> ResetIv<C, P> for Cfb8<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    traits::{BlockMode, IvState, ResetIv},
+    utils::{xor, Block},
+};
+use block_padding::Padding;
+use cipher::{
+    generic_array::{typenum::PartialDiv, ArrayLength, GenericArray},
+    BlockCipher, BlockEncrypt,
+};
+use core::marker::PhantomData;
+
+/// [Cipher feedback][1] (CFB) block mode instance with a feedback segment
+/// of `S` bytes (CFB-s of NIST SP 800-38A).
+///
+/// [`Cfb8`][crate::Cfb8] and [`Cfb`][crate::Cfb] are the special cases of
+/// one byte and full block segments, this type covers the sizes in between,
+/// e.g. `CfbS<Aes128, NoPadding, U8>` is CFB-64 with AES. The segment size
+/// must be non-zero and divide the block size, which is checked at compile
+/// time.
+///
+/// [1]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#Cipher_feedback_(CFB)
+#[derive(Clone)]
+pub struct CfbS<C, P, S>
+where
+    C: BlockCipher + BlockEncrypt,
+    C::BlockSize: PartialDiv<S>,
+    P: Padding,
+    S: ArrayLength<u8>,
+{
+    cipher: C,
+    iv: GenericArray<u8, C::BlockSize>,
+    _p: PhantomData<(P, S)>,
+}
+
+impl<C, P, S> CfbS<C, P, S>
+where
+    C: BlockCipher + BlockEncrypt,
+    C::BlockSize: PartialDiv<S>,
+    P: Padding,
+    S: ArrayLength<u8>,
+{
+    /// Process `blocks` segment by segment, feeding back the ciphertext.
+    #[inline(always)]
+    fn process(&mut self, blocks: &mut [Block<C>], decrypt: bool) {
+        let s = S::to_usize();
+        let n = self.iv.len();
+        let mut iv = self.iv.clone();
+        let mut c = GenericArray::<u8, S>::default();
+        for block in blocks.iter_mut() {
+            for segment in block.chunks_exact_mut(s) {
+                if decrypt {
+                    c.copy_from_slice(segment);
+                }
+                let mut ks = iv.clone();
+                self.cipher.encrypt_block(&mut ks);
+                xor(segment, &ks[..s]);
+                if !decrypt {
+                    c.copy_from_slice(segment);
+                }
+                iv.rotate_left(s);
+                iv[n - s..].copy_from_slice(&c);
+            }
+        }
+        self.iv = iv;
+    }
+}
+
+impl<C, P, S> BlockMode<C, P> for CfbS<C, P, S>
+where
+    C: BlockCipher + BlockEncrypt,
+    C::BlockSize: PartialDiv<S>,
+    P: Padding,
+    S: ArrayLength<u8>,
+{
+    type IvSize = C::BlockSize;
+
+    fn new(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+            _p: Default::default(),
+        }
+    }
+
+    fn encrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        self.process(blocks, false);
+    }
+
+    fn decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        self.process(blocks, true);
+    }
+}
+
+impl<C, P, S> IvState<C, P> for CfbS<C, P, S>
+where
+    C: BlockCipher + BlockEncrypt,
+    C::BlockSize: PartialDiv<S>,
+    P: Padding,
+    S: ArrayLength<u8>,
+{
+    fn iv_state(&self) -> GenericArray<u8, Self::IvSize> {
+        self.iv.clone()
+    }
+}
+
+impl<C, P, S> ResetIv<C, P> for CfbS<C, P, S>
+where
+    C: BlockCipher + BlockEncrypt,
+    C::BlockSize: PartialDiv<S>,
+    P: Padding,
+    S: ArrayLength<u8>,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
@@ -1,17 +1,16 @@
 use crate::{
     errors::InvalidKeyIvLength,
-    traits::BlockMode,
+    traits::{BlockMode, ParallelismHint},
     utils::{get_par_blocks, Block},
 };
 use block_padding::Padding;
 use cipher::{
-    generic_array::{
-        typenum::{Unsigned, U0},
-        GenericArray,
-    },
+    generic_array::{typenum::U0, GenericArray},
     BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
 };
 use core::marker::PhantomData;
+#[cfg(all(feature = "std", feature = "rayon"))]
+use {crate::utils::thread_chunk_blocks, rayon::prelude::*};
 
 /// [Electronic Codebook][1] (ECB) block cipher mode instance.
 ///
@@ -51,7 +50,7 @@ where
     }
 
     fn encrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
-        if C::ParBlocks::to_usize() != 1 {
+        if C::PAR_BLOCKS != 1 {
             let (par_blocks, blocks) = get_par_blocks::<C>(blocks);
             par_blocks
                 .iter_mut()
@@ -67,7 +66,7 @@ where
     }
 
     fn decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
-        if C::ParBlocks::to_usize() != 1 {
+        if C::PAR_BLOCKS != 1 {
             let (par_blocks, blocks) = get_par_blocks::<C>(blocks);
             par_blocks
                 .iter_mut()
@@ -82,3 +81,30 @@ where
         }
     }
 }
+
+#[cfg(all(feature = "std", feature = "rayon"))]
+impl<C, P> Ecb<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt + Sync,
+    P: Padding,
+{
+    /// Encrypt blocks of data using multiple threads.
+    ///
+    /// Produces the same result as [`BlockMode::encrypt_blocks`], but splits
+    /// the blocks across the rayon thread pool. Useful for bulk data, e.g.
+    /// disk images.
+    pub fn par_encrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        let cipher = &self.cipher;
+        blocks
+            .par_chunks_mut(thread_chunk_blocks::<C>())
+            .for_each(|chunk| cipher.encrypt_blocks(chunk));
+    }
+
+    /// Decrypt blocks of data using multiple threads.
+    pub fn par_decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        let cipher = &self.cipher;
+        blocks
+            .par_chunks_mut(thread_chunk_blocks::<C>())
+            .for_each(|chunk| cipher.decrypt_blocks(chunk));
+    }
+}
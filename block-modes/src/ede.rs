@@ -0,0 +1,121 @@
+use crate::utils::Block;
+use cipher::{consts::U1, BlockCipher, BlockDecrypt, BlockEncrypt};
+use core::fmt;
+
+/// Encrypt-decrypt-encrypt (EDE) composition of three block ciphers.
+///
+/// A block is encrypted with the first cipher, decrypted with the second
+/// one and encrypted with the third one. The result is a block cipher
+/// itself, so e.g. `Ede<Des, Des, Des>` is equivalent to
+/// [`TdesEde3`][1] and can be used with any mode. The ciphers may be of
+/// different types, but must have the same block size.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::{cipher::BlockEncrypt, Ede};
+///
+/// let k1 = Aes128::new(&[1; 16].into());
+/// let k2 = Aes128::new(&[2; 16].into());
+/// let ede = Ede::new_ede2(k1, k2);
+/// let mut block = Default::default();
+/// ede.encrypt_block(&mut block);
+/// ```
+///
+/// [1]: https://docs.rs/des/latest/des/struct.TdesEde3.html
+#[derive(Clone)]
+pub struct Ede<C1, C2, C3>
+where
+    C1: BlockCipher,
+    C2: BlockCipher<BlockSize = C1::BlockSize>,
+    C3: BlockCipher<BlockSize = C1::BlockSize>,
+{
+    c1: C1,
+    c2: C2,
+    c3: C3,
+}
+
+impl<C1, C2, C3> Ede<C1, C2, C3>
+where
+    C1: BlockCipher,
+    C2: BlockCipher<BlockSize = C1::BlockSize>,
+    C3: BlockCipher<BlockSize = C1::BlockSize>,
+{
+    /// Compose the three ciphers, in the order they are applied during
+    /// encryption.
+    pub fn new(c1: C1, c2: C2, c3: C3) -> Self {
+        Self { c1, c2, c3 }
+    }
+}
+
+impl<C1, C2> Ede<C1, C2, C1>
+where
+    C1: BlockCipher + Clone,
+    C2: BlockCipher<BlockSize = C1::BlockSize>,
+{
+    /// Compose two ciphers with the first one also used as the third, as in
+    /// two-key triple DES.
+    pub fn new_ede2(c1: C1, c2: C2) -> Self {
+        Self::new(c1.clone(), c2, c1)
+    }
+}
+
+impl<C1, C2, C3> BlockCipher for Ede<C1, C2, C3>
+where
+    C1: BlockCipher,
+    C2: BlockCipher<BlockSize = C1::BlockSize>,
+    C3: BlockCipher<BlockSize = C1::BlockSize>,
+{
+    type BlockSize = C1::BlockSize;
+    type ParBlocks = U1;
+}
+
+impl<C1, C2, C3> BlockEncrypt for Ede<C1, C2, C3>
+where
+    C1: BlockCipher + BlockEncrypt,
+    C2: BlockCipher<BlockSize = C1::BlockSize> + BlockDecrypt,
+    C3: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt,
+{
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.c1.encrypt_block(block);
+        self.c2.decrypt_block(block);
+        self.c3.encrypt_block(block);
+    }
+
+    // process the whole slice layer by layer to use the parallelism of the
+    // underlying ciphers
+    fn encrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.c1.encrypt_blocks(blocks);
+        self.c2.decrypt_blocks(blocks);
+        self.c3.encrypt_blocks(blocks);
+    }
+}
+
+impl<C1, C2, C3> BlockDecrypt for Ede<C1, C2, C3>
+where
+    C1: BlockCipher + BlockDecrypt,
+    C2: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt,
+    C3: BlockCipher<BlockSize = C1::BlockSize> + BlockDecrypt,
+{
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        self.c3.decrypt_block(block);
+        self.c2.encrypt_block(block);
+        self.c1.decrypt_block(block);
+    }
+
+    fn decrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.c3.decrypt_blocks(blocks);
+        self.c2.encrypt_blocks(blocks);
+        self.c1.decrypt_blocks(blocks);
+    }
+}
+
+impl<C1, C2, C3> fmt::Debug for Ede<C1, C2, C3>
+where
+    C1: BlockCipher,
+    C2: BlockCipher<BlockSize = C1::BlockSize>,
+    C3: BlockCipher<BlockSize = C1::BlockSize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Ede { ... }")
+    }
+}
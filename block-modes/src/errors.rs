@@ -1,11 +1,15 @@
 use core::fmt;
 #[cfg(feature = "std")]
-use std::error;
+use std::{error, io};
 
 /// Block mode error.
 #[derive(Clone, Copy, Debug)]
 pub struct BlockModeError;
 
+/// Computation fault detected by [`FaultCheck`][crate::FaultCheck].
+#[derive(Clone, Copy, Debug)]
+pub struct FaultError;
+
 /// Invalid key or IV length error.
 #[derive(Clone, Copy, Debug)]
 pub struct InvalidKeyIvLength;
@@ -31,3 +35,42 @@ impl fmt::Display for InvalidKeyIvLength {
 
 #[cfg(feature = "std")]
 impl error::Error for InvalidKeyIvLength {}
+
+impl fmt::Display for FaultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("FaultError")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for FaultError {}
+
+/// Error returned when moving a key schedule into protected memory.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum KeyProtectionError {
+    /// Key has invalid length.
+    InvalidLength,
+    /// Memory could not be locked.
+    Lock(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for KeyProtectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            KeyProtectionError::InvalidLength => f.write_str("invalid key length"),
+            KeyProtectionError::Lock(err) => write!(f, "failed to lock memory: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for KeyProtectionError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            KeyProtectionError::InvalidLength => None,
+            KeyProtectionError::Lock(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{ivgen::IvGenerator, utils::Block};
+use cipher::{BlockCipher, BlockEncrypt};
+#[cfg(feature = "digest")]
+use {crate::errors::InvalidKeyIvLength, cipher::NewBlockCipher, digest::Digest};
+
+/// [ESSIV][1] (Encrypted Salt-Sector Initialization Vector) generator.
+///
+/// The IV of a sector is the encryption of its number, encoded as 64-bit
+/// little-endian integer and padded with zeros to a full block, under the
+/// hash of the data key: `IV = E_{H(K)}(sector)`. The result can be used as
+/// IV for [`Cbc`][crate::Cbc] or as tweak for [`Xex`][crate::Xex], which
+/// corresponds to e.g. `aes-cbc-essiv:sha256` of dm-crypt.
+///
+/// ```
+/// use aes::{Aes128, Aes256, NewBlockCipher};
+/// use block_modes::{block_padding::NoPadding, BlockMode, Cbc, Essiv, IvGenerator};
+/// use sha2::{Digest, Sha256};
+///
+/// let key = [0x42; 16];
+/// // with the `digest` feature: `Essiv::<Aes256>::from_key::<Sha256>(&key)`
+/// let essiv = Essiv::new(Aes256::new(&Sha256::digest(&key)));
+///
+/// let mut sector = [0u8; 512];
+/// let iv = essiv.sector_iv(7);
+/// let cbc = Cbc::<Aes128, NoPadding>::new_from_slices(&key, &iv).unwrap();
+/// cbc.encrypt(&mut sector, 512).unwrap();
+/// ```
+///
+/// [1]: https://en.wikipedia.org/wiki/Disk_encryption_theory#Encrypted_salt-sector_initialization_vector_(ESSIV)
+#[derive(Clone)]
+pub struct Essiv<C: BlockCipher + BlockEncrypt> {
+    cipher: C,
+}
+
+impl<C: BlockCipher + BlockEncrypt> Essiv<C> {
+    /// Create generator from the `cipher` initialized with the hash of the
+    /// data key.
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+
+    /// Create generator from the data `key`, hashed with `D`.
+    ///
+    /// Returns [`InvalidKeyIvLength`] if the digest size is not a valid key
+    /// size of `C`.
+    #[cfg(feature = "digest")]
+    pub fn from_key<D: Digest>(key: &[u8]) -> Result<Self, InvalidKeyIvLength>
+    where
+        C: NewBlockCipher,
+    {
+        C::new_from_slice(&D::digest(key))
+            .map(Self::new)
+            .map_err(|_| InvalidKeyIvLength)
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> IvGenerator<C::BlockSize> for Essiv<C> {
+    fn sector_iv(&self, sector: u64) -> Block<C> {
+        let mut iv = Block::<C>::default();
+        iv[..8].copy_from_slice(&sector.to_le_bytes());
+        self.cipher.encrypt_block(&mut iv);
+        iv
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> core::fmt::Debug for Essiv<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Essiv { ... }")
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{errors::BlockModeError, traits::BlockMode, Cbc};
+use block_padding::Padding;
+use cipher::{
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher,
+};
+use core::marker::PhantomData;
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+type Iv<C, P, M> = GenericArray<u8, <M as BlockMode<C, P>>::IvSize>;
+
+/// Explicit IV framing, as used e.g. by TLS 1.1+ records.
+///
+/// A fresh IV is prepended to every ciphertext and parsed back on
+/// decryption, so a single instance can process many messages. Encryption
+/// with a random IV requires the `rand_core` feature.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::{block_padding::Pkcs7, CbcExplicitIv};
+///
+/// let framing = CbcExplicitIv::<_, Pkcs7>::new(Aes128::new(&[0x42; 16].into()));
+///
+/// // space for the IV, the message and the padding
+/// let mut buf = [0u8; 48];
+/// buf[..13].copy_from_slice(b"hello, world!");
+/// let ct = framing.encrypt_with_iv(&[0x24; 16].into(), &mut buf, 13).unwrap();
+/// assert_eq!(ct.len(), 32);
+/// assert_eq!(ct[..16], [0x24; 16]);
+///
+/// let n = ct.len();
+/// assert_eq!(framing.decrypt(&mut buf[..n]).unwrap(), b"hello, world!");
+/// ```
+#[derive(Clone)]
+pub struct ExplicitIv<C, P, M>
+where
+    C: BlockCipher + Clone,
+    P: Padding,
+    M: BlockMode<C, P>,
+{
+    cipher: C,
+    _p: PhantomData<(P, M)>,
+}
+
+impl<C, P, M> ExplicitIv<C, P, M>
+where
+    C: BlockCipher + Clone,
+    P: Padding,
+    M: BlockMode<C, P>,
+{
+    /// Create explicit IV framing for the given cipher.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            _p: PhantomData,
+        }
+    }
+
+    /// Encrypt message `&buffer[..pos]` in-place with the given `iv`.
+    ///
+    /// The message is moved behind the IV, so `buffer` must have space for
+    /// the IV and the padding, otherwise `Err(BlockModeError)` is returned.
+    /// The IV must never be reused with the same key.
+    pub fn encrypt_with_iv<'a>(
+        &self,
+        iv: &Iv<C, P, M>,
+        buffer: &'a mut [u8],
+        pos: usize,
+    ) -> Result<&'a [u8], BlockModeError> {
+        let iv_len = M::IvSize::USIZE;
+        if buffer.len() < iv_len + pos {
+            return Err(BlockModeError);
+        }
+        buffer.copy_within(..pos, iv_len);
+        let (head, tail) = buffer.split_at_mut(iv_len);
+        head.copy_from_slice(iv);
+        let n = M::new(self.cipher.clone(), iv).encrypt(tail, pos)?.len();
+        Ok(&buffer[..iv_len + n])
+    }
+
+    /// Encrypt message `&buffer[..pos]` in-place with a random IV.
+    ///
+    /// See [`encrypt_with_iv`][ExplicitIv::encrypt_with_iv] for details.
+    #[cfg(feature = "rand_core")]
+    pub fn encrypt<'a>(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        buffer: &'a mut [u8],
+        pos: usize,
+    ) -> Result<&'a [u8], BlockModeError> {
+        let mut iv = Iv::<C, P, M>::default();
+        rng.fill_bytes(&mut iv);
+        self.encrypt_with_iv(&iv, buffer, pos)
+    }
+
+    /// Decrypt IV and ciphertext in-place.
+    ///
+    /// Returns an error if `buffer` is shorter than the IV, if the
+    /// ciphertext length is invalid or if the padding is malformed.
+    pub fn decrypt<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a [u8], BlockModeError> {
+        let iv_len = M::IvSize::USIZE;
+        if buffer.len() < iv_len {
+            return Err(BlockModeError);
+        }
+        let (iv, ct) = buffer.split_at_mut(iv_len);
+        M::new(self.cipher.clone(), GenericArray::from_slice(iv)).decrypt(ct)
+    }
+
+    /// Encrypt message with a random IV and return the IV followed by the
+    /// ciphertext.
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    pub fn encrypt_vec(&self, rng: impl CryptoRng + RngCore, plaintext: &[u8]) -> Vec<u8> {
+        let bs = C::BlockSize::USIZE;
+        let pos = plaintext.len();
+        let n = M::IvSize::USIZE + pos + bs;
+        let mut buf = Vec::with_capacity(n);
+        buf.extend_from_slice(plaintext);
+        buf.resize(n, 0);
+        let len = self
+            .encrypt(rng, &mut buf, pos)
+            .expect("enough space for padding is allocated")
+            .len();
+        buf.truncate(len);
+        buf
+    }
+
+    /// Decrypt IV and ciphertext and return the message.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt_vec(&self, data: &[u8]) -> Result<Vec<u8>, BlockModeError> {
+        let mut buf = data.to_vec();
+        self.decrypt(&mut buf).map(|pt| pt.to_vec())
+    }
+}
+
+impl<C, P, M> core::fmt::Debug for ExplicitIv<C, P, M>
+where
+    C: BlockCipher + Clone,
+    P: Padding,
+    M: BlockMode<C, P>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("ExplicitIv { ... }")
+    }
+}
+
+/// [`Cbc`] with explicit IV framing.
+pub type CbcExplicitIv<C, P> = ExplicitIv<C, P, Cbc<C, P>>;
@@ -0,0 +1,245 @@
+use crate::{errors::FaultError, traits::BlockMode};
+use block_padding::Padding;
+use cipher::{
+    generic_array::{typenum::U1, GenericArray},
+    Block, BlockCipher, BlockDecrypt, BlockEncrypt,
+};
+use core::cell::Cell;
+
+/// Block cipher wrapper which checks every result for computation faults.
+///
+/// Each encrypted block is immediately decrypted again (and each decrypted
+/// block re-encrypted) and compared against the input. A mismatch indicates
+/// a hardware fault or a deliberate glitch, in which case the output is
+/// wiped and [`FaultError`] is returned, so that faulty ciphertexts (the
+/// raw material of differential fault analysis) are never released.
+///
+/// This roughly doubles the cost of every operation and only makes sense
+/// on hardware exposed to physical attacks.
+///
+/// The wrapper also implements [`BlockEncrypt`] and [`BlockDecrypt`], so a
+/// reference to it can be used as the cipher of any block mode. Those traits
+/// can't report errors, so faults are recorded instead and returned by
+/// [`FaultCheck::check`]. Use [`CheckedMode`] to have them checked after
+/// every mode operation.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::FaultCheck;
+///
+/// let cipher = FaultCheck::new(Aes128::new(&[0x42; 16].into()));
+///
+/// let mut block = [0u8; 16].into();
+/// cipher.encrypt_block(&mut block).unwrap();
+/// cipher.decrypt_block(&mut block).unwrap();
+/// assert_eq!(block, [0u8; 16].into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct FaultCheck<C> {
+    cipher: C,
+    fault: Cell<bool>,
+}
+
+impl<C> FaultCheck<C> {
+    /// Wrap block cipher instance.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            fault: Cell::new(false),
+        }
+    }
+
+    /// Get reference to the wrapped block cipher.
+    pub fn get_ref(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Unwrap the block cipher instance.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+
+    /// Check for faults detected by the [`BlockEncrypt`] and [`BlockDecrypt`]
+    /// impls since the last call, which are then cleared.
+    pub fn check(&self) -> Result<(), FaultError> {
+        if self.fault.replace(false) {
+            Err(FaultError)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt + BlockDecrypt> FaultCheck<C> {
+    /// Encrypt block in-place, verifying the result by decrypting it.
+    ///
+    /// On error `block` is zeroed.
+    pub fn encrypt_block(&self, block: &mut Block<C>) -> Result<(), FaultError> {
+        let input = block.clone();
+        self.cipher.encrypt_block(block);
+        let mut check = block.clone();
+        self.cipher.decrypt_block(&mut check);
+        verify(check == input, block)
+    }
+
+    /// Decrypt block in-place, verifying the result by encrypting it.
+    ///
+    /// On error `block` is zeroed.
+    pub fn decrypt_block(&self, block: &mut Block<C>) -> Result<(), FaultError> {
+        let input = block.clone();
+        self.cipher.decrypt_block(block);
+        let mut check = block.clone();
+        self.cipher.encrypt_block(&mut check);
+        verify(check == input, block)
+    }
+
+    /// Encrypt several blocks in-place, verifying every result.
+    ///
+    /// On error all of `blocks` are zeroed, including ones which were
+    /// processed correctly.
+    pub fn encrypt_blocks(&self, blocks: &mut [Block<C>]) -> Result<(), FaultError> {
+        self.process_blocks(blocks, Self::encrypt_block)
+    }
+
+    /// Decrypt several blocks in-place, verifying every result.
+    ///
+    /// On error all of `blocks` are zeroed, including ones which were
+    /// processed correctly.
+    pub fn decrypt_blocks(&self, blocks: &mut [Block<C>]) -> Result<(), FaultError> {
+        self.process_blocks(blocks, Self::decrypt_block)
+    }
+
+    fn process_blocks(
+        &self,
+        blocks: &mut [Block<C>],
+        f: fn(&Self, &mut Block<C>) -> Result<(), FaultError>,
+    ) -> Result<(), FaultError> {
+        for i in 0..blocks.len() {
+            if let Err(err) = f(self, &mut blocks[i]) {
+                for block in blocks.iter_mut() {
+                    wipe(block);
+                }
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: BlockCipher> BlockCipher for FaultCheck<C> {
+    type BlockSize = C::BlockSize;
+    type ParBlocks = U1;
+}
+
+impl<C: BlockCipher + BlockEncrypt + BlockDecrypt> BlockEncrypt for FaultCheck<C> {
+    /// Encrypt block in-place, recording a fault if the result is wrong.
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        if FaultCheck::encrypt_block(self, block).is_err() {
+            self.fault.set(true);
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt + BlockDecrypt> BlockDecrypt for FaultCheck<C> {
+    /// Decrypt block in-place, recording a fault if the result is wrong.
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        if FaultCheck::decrypt_block(self, block).is_err() {
+            self.fault.set(true);
+        }
+    }
+}
+
+/// Block mode running over a [`FaultCheck`] cipher, which checks for faults
+/// after every operation.
+///
+/// If a fault is detected all processed blocks are zeroed and [`FaultError`]
+/// is returned. The IV state of the mode is garbage at that point, so the
+/// instance should be discarded.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::{block_padding::NoPadding, Cbc, CheckedMode, FaultCheck};
+///
+/// let cipher = FaultCheck::new(Aes128::new(&[0x42; 16].into()));
+/// let iv = [0x24; 16].into();
+///
+/// let mut blocks = [[0u8; 16].into(); 4];
+/// let mut mode = CheckedMode::<_, Cbc<_, NoPadding>>::new(&cipher, &iv);
+/// mode.encrypt_blocks(&mut blocks).unwrap();
+///
+/// let mut mode = CheckedMode::<_, Cbc<_, NoPadding>>::new(&cipher, &iv);
+/// mode.decrypt_blocks(&mut blocks).unwrap();
+/// assert_eq!(blocks, [[0u8; 16].into(); 4]);
+/// ```
+pub struct CheckedMode<'a, C, M> {
+    cipher: &'a FaultCheck<C>,
+    mode: M,
+}
+
+impl<'a, C, M> CheckedMode<'a, C, M>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+{
+    /// Create block mode `M` over `cipher` with the given IV.
+    pub fn new<P>(cipher: &'a FaultCheck<C>, iv: &GenericArray<u8, M::IvSize>) -> Self
+    where
+        M: BlockMode<&'a FaultCheck<C>, P>,
+        P: Padding,
+    {
+        Self {
+            cipher,
+            mode: M::new(cipher, iv),
+        }
+    }
+
+    /// Encrypt blocks in-place, checking for faults afterwards.
+    ///
+    /// On error all of `blocks` are zeroed.
+    pub fn encrypt_blocks<P>(&mut self, blocks: &mut [Block<C>]) -> Result<(), FaultError>
+    where
+        M: BlockMode<&'a FaultCheck<C>, P>,
+        P: Padding,
+    {
+        self.mode.encrypt_blocks(blocks);
+        self.check(blocks)
+    }
+
+    /// Decrypt blocks in-place, checking for faults afterwards.
+    ///
+    /// On error all of `blocks` are zeroed.
+    pub fn decrypt_blocks<P>(&mut self, blocks: &mut [Block<C>]) -> Result<(), FaultError>
+    where
+        M: BlockMode<&'a FaultCheck<C>, P>,
+        P: Padding,
+    {
+        self.mode.decrypt_blocks(blocks);
+        self.check(blocks)
+    }
+
+    fn check(&self, blocks: &mut [Block<C>]) -> Result<(), FaultError> {
+        let res = self.cipher.check();
+        if res.is_err() {
+            for block in blocks.iter_mut() {
+                wipe(block);
+            }
+        }
+        res
+    }
+}
+
+#[inline]
+fn verify(ok: bool, block: &mut [u8]) -> Result<(), FaultError> {
+    if ok {
+        Ok(())
+    } else {
+        wipe(block);
+        Err(FaultError)
+    }
+}
+
+#[inline]
+fn wipe(block: &mut [u8]) {
+    for b in block.iter_mut() {
+        *b = 0;
+    }
+}
@@ -0,0 +1,35 @@
+//! Constant-time arithmetic in `GF(2^128)` with the polynomial
+//! `x^128 + x^7 + x^2 + x + 1`.
+//!
+//! Shared by the tweakable and wide-block modes of this and related crates,
+//! e.g. [`XexMask`](crate::XexMask), EME2 and CMC.
+
+use cipher::generic_array::{typenum::U16, GenericArray};
+use core::convert::TryInto;
+
+/// Reduction constant of `x^128 + x^7 + x^2 + x + 1`.
+const R: u128 = 0x87;
+
+/// Multiply an element of `GF(2^128)`, given as integer with the
+/// coefficient of `x^i` in bit `i`, by `α`.
+#[inline(always)]
+pub fn mul_alpha(x: u128) -> u128 {
+    (x << 1) ^ ((x >> 127) * R)
+}
+
+/// Multiply `block` by `α` with the little-endian bit order of IEEE 1619
+/// (XTS, EME2), i.e. bit `i` of byte `j` is the coefficient of
+/// `x^(8 * j + i)`.
+#[inline]
+pub fn double(block: &mut GenericArray<u8, U16>) {
+    let x = u128::from_le_bytes(block.as_slice().try_into().unwrap());
+    block.copy_from_slice(&mul_alpha(x).to_le_bytes());
+}
+
+/// Multiply `block` by `α` with the big-endian bit order used by CMAC, OCB
+/// and CMC, in which the first byte holds the highest coefficients.
+#[inline]
+pub fn double_be(block: &mut GenericArray<u8, U16>) {
+    let x = u128::from_be_bytes(block.as_slice().try_into().unwrap());
+    block.copy_from_slice(&mul_alpha(x).to_be_bytes());
+}
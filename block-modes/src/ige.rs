@@ -1,6 +1,7 @@
 use crate::{
-    traits::{BlockMode, IvState},
-    utils::{xor, Block},
+    errors::BlockModeError,
+    traits::{BlockMode, IvState, ResetIv},
+    utils::{to_blocks, xor, Block},
 };
 use block_padding::Padding;
 use cipher::{
@@ -84,3 +85,153 @@ where
         self.y.clone().concat(self.x.clone())
     }
 }
+
+impl<C, P> ResetIv<C, P> for Ige<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+    C::BlockSize: Add,
+    IgeIvBlockSize<C>: ArrayLength<u8>,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        let (y, x) = iv.split_at(C::BlockSize::to_usize());
+        self.x = GenericArray::clone_from_slice(x);
+        self.y = GenericArray::clone_from_slice(y);
+    }
+}
+
+impl<C, P> Ige<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+    C::BlockSize: Add,
+    IgeIvBlockSize<C>: ArrayLength<u8>,
+{
+    /// Encrypt unpadded data in-place, e.g. an MTProto message.
+    ///
+    /// Returns an error if the data length is not a multiple of the block
+    /// size. The IV state is updated, so the data may be processed in
+    /// several calls.
+    ///
+    /// ```
+    /// use aes::Aes256;
+    /// use block_modes::{block_padding::NoPadding, BlockMode, Ige};
+    ///
+    /// let key = [0x42; 32];
+    /// let iv = [0x24; 32];
+    /// let mut data = [0u8; 48];
+    /// let mut ige = Ige::<Aes256, NoPadding>::new_from_slices(&key, &iv).unwrap();
+    /// ige.encrypt_slice(&mut data).unwrap();
+    /// assert!(ige.encrypt_slice(&mut [0u8; 15]).is_err());
+    ///
+    /// let mut ige = Ige::<Aes256, NoPadding>::new_from_slices(&key, &iv).unwrap();
+    /// ige.decrypt_slice(&mut data).unwrap();
+    /// assert_eq!(data, [0u8; 48]);
+    /// ```
+    pub fn encrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockModeError> {
+        if data.len() % C::BlockSize::USIZE != 0 {
+            return Err(BlockModeError);
+        }
+        self.encrypt_blocks(to_blocks(data));
+        Ok(())
+    }
+
+    /// Decrypt unpadded data in-place.
+    ///
+    /// Returns an error if the data length is not a multiple of the block
+    /// size.
+    pub fn decrypt_slice(&mut self, data: &mut [u8]) -> Result<(), BlockModeError> {
+        if data.len() % C::BlockSize::USIZE != 0 {
+            return Err(BlockModeError);
+        }
+        self.decrypt_blocks(to_blocks(data));
+        Ok(())
+    }
+}
+
+/// Bidirectional [IGE][1] (bi-IGE) block cipher mode instance.
+///
+/// The message is encrypted with IGE under the first cipher and IV, and the
+/// result is encrypted again with IGE under the second cipher and IV, this
+/// time starting from the last block. Changing any ciphertext block garbles
+/// the whole decrypted message, not only the following blocks.
+///
+/// Unlike the other modes the whole message has to be processed at once, so
+/// bi-IGE does not implement [`BlockMode`]. The two IVs correspond to the
+/// first and second half of the 4-block IV of OpenSSL's
+/// `AES_bi_ige_encrypt`.
+///
+/// [1]: https://www.links.org/files/openssl-ige.pdf
+pub struct BiIge<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+    C::BlockSize: Add,
+    IgeIvBlockSize<C>: ArrayLength<u8>,
+{
+    forward: Ige<C, P>,
+    backward: Ige<C, P>,
+}
+
+impl<C, P> BiIge<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+    C::BlockSize: Add,
+    IgeIvBlockSize<C>: ArrayLength<u8>,
+{
+    /// Create a new bi-IGE instance from the ciphers and IVs of the forward
+    /// and the backward pass.
+    pub fn new(
+        cipher1: C,
+        iv1: &GenericArray<u8, IgeIvBlockSize<C>>,
+        cipher2: C,
+        iv2: &GenericArray<u8, IgeIvBlockSize<C>>,
+    ) -> Self {
+        Self {
+            forward: Ige::new(cipher1, iv1),
+            backward: Ige::new(cipher2, iv2),
+        }
+    }
+
+    /// Encrypt all blocks of a message.
+    pub fn encrypt_blocks(mut self, blocks: &mut [Block<C>]) {
+        self.forward.encrypt_blocks(blocks);
+        blocks.reverse();
+        self.backward.encrypt_blocks(blocks);
+        blocks.reverse();
+    }
+
+    /// Decrypt all blocks of a message.
+    pub fn decrypt_blocks(mut self, blocks: &mut [Block<C>]) {
+        blocks.reverse();
+        self.backward.decrypt_blocks(blocks);
+        blocks.reverse();
+        self.forward.decrypt_blocks(blocks);
+    }
+
+    /// Encrypt message in-place.
+    ///
+    /// `&buffer[..pos]` is used as a message and `&buffer[pos..]` as a reserved
+    /// space for padding. The padding space should be big enough for padding,
+    /// otherwise method will return `Err(BlockModeError)`.
+    pub fn encrypt(self, buffer: &mut [u8], pos: usize) -> Result<&[u8], BlockModeError> {
+        let bs = C::BlockSize::to_usize();
+        let buf = P::pad(buffer, pos, bs).map_err(|_| BlockModeError)?;
+        self.encrypt_blocks(to_blocks(buf));
+        Ok(buf)
+    }
+
+    /// Decrypt message in-place.
+    ///
+    /// Returns an error if `buffer` length is not multiple of block size and
+    /// if after decoding message has malformed padding.
+    pub fn decrypt(self, buffer: &mut [u8]) -> Result<&[u8], BlockModeError> {
+        let bs = C::BlockSize::to_usize();
+        if buffer.len() % bs != 0 {
+            return Err(BlockModeError);
+        }
+        self.decrypt_blocks(to_blocks(buffer));
+        P::unpad(buffer).map_err(|_| BlockModeError)
+    }
+}
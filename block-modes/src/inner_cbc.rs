@@ -0,0 +1,94 @@
+use crate::{
+    errors::BlockModeError,
+    traits::BlockMode,
+    utils::{to_blocks, Block},
+    Cbc,
+};
+use block_padding::Padding;
+use cipher::{generic_array::typenum::Unsigned, BlockCipher, BlockDecrypt, BlockEncrypt};
+
+/// Triple encryption with inner CBC chaining (inner-CBC EDE).
+///
+/// The message is encrypted in CBC mode under the first cipher, decrypted
+/// in CBC mode under the second one and encrypted again in CBC mode under
+/// the third one, each layer with its own IV. This is the inner-CBC
+/// variant of e.g. DES-EDE3 used by some legacy formats, as opposed to the
+/// outer-CBC variant, which is plain [`Cbc`] over an EDE block cipher.
+///
+/// The ciphers may be of different types, but must have the same block
+/// size.
+#[derive(Clone)]
+pub struct InnerCbcEde<C1, C2, C3, P>
+where
+    C1: BlockCipher + BlockEncrypt + BlockDecrypt,
+    C2: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt + BlockDecrypt,
+    C3: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+{
+    cbc1: Cbc<C1, P>,
+    cbc2: Cbc<C2, P>,
+    cbc3: Cbc<C3, P>,
+}
+
+impl<C1, C2, C3, P> InnerCbcEde<C1, C2, C3, P>
+where
+    C1: BlockCipher + BlockEncrypt + BlockDecrypt,
+    C2: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt + BlockDecrypt,
+    C3: BlockCipher<BlockSize = C1::BlockSize> + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+{
+    /// Create mode instance from the ciphers and IVs of the three layers.
+    pub fn new(
+        cipher1: C1,
+        iv1: &Block<C1>,
+        cipher2: C2,
+        iv2: &Block<C1>,
+        cipher3: C3,
+        iv3: &Block<C1>,
+    ) -> Self {
+        Self {
+            cbc1: Cbc::new(cipher1, iv1),
+            cbc2: Cbc::new(cipher2, iv2),
+            cbc3: Cbc::new(cipher3, iv3),
+        }
+    }
+
+    /// Encrypt blocks of data.
+    pub fn encrypt_blocks(&mut self, blocks: &mut [Block<C1>]) {
+        self.cbc1.encrypt_blocks(blocks);
+        self.cbc2.decrypt_blocks(blocks);
+        self.cbc3.encrypt_blocks(blocks);
+    }
+
+    /// Decrypt blocks of data.
+    pub fn decrypt_blocks(&mut self, blocks: &mut [Block<C1>]) {
+        self.cbc3.decrypt_blocks(blocks);
+        self.cbc2.encrypt_blocks(blocks);
+        self.cbc1.decrypt_blocks(blocks);
+    }
+
+    /// Encrypt message in-place.
+    ///
+    /// `&buffer[..pos]` is used as a message and `&buffer[pos..]` as a reserved
+    /// space for padding. The padding space should be big enough for padding,
+    /// otherwise method will return `Err(BlockModeError)`.
+    pub fn encrypt(mut self, buffer: &mut [u8], pos: usize) -> Result<&[u8], BlockModeError> {
+        let bs = C1::BlockSize::to_usize();
+        let buf = P::pad(buffer, pos, bs).map_err(|_| BlockModeError)?;
+        self.encrypt_blocks(to_blocks(buf));
+        Ok(buf)
+    }
+
+    /// Decrypt message in-place.
+    ///
+    /// Returns an error if `buffer` length is not multiple of block size and
+    /// if after decoding message has malformed padding.
+    pub fn decrypt(mut self, buffer: &mut [u8]) -> Result<&[u8], BlockModeError> {
+        let bs = C1::BlockSize::to_usize();
+        if buffer.len() % bs != 0 {
+            return Err(BlockModeError);
+        }
+        self.decrypt_blocks(to_blocks(buffer));
+        P::unpad(buffer).map_err(|_| BlockModeError)
+    }
+}
@@ -0,0 +1,52 @@
+use cipher::generic_array::{ArrayLength, GenericArray};
+
+/// Generator of per-sector IVs for sector-addressed (disk) encryption.
+///
+/// Generated IVs are used as IV for [`Cbc`][crate::Cbc] or as tweak for
+/// [`Xex`][crate::Xex]. Implementations follow the IV generators of
+/// dm-crypt with the same names: [`Plain`], [`Plain64`], [`Plain64Be`] and
+/// [`Essiv`][crate::Essiv].
+pub trait IvGenerator<N: ArrayLength<u8>> {
+    /// Generate IV for the `sector`.
+    fn sector_iv(&self, sector: u64) -> GenericArray<u8, N>;
+}
+
+/// `plain` IV generator: the low 32 bits of the sector number in
+/// little-endian at the start of the IV, padded with zeros.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Plain;
+
+/// `plain64` IV generator: the 64-bit sector number in little-endian at the
+/// start of the IV, padded with zeros.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Plain64;
+
+/// `plain64be` IV generator: the 64-bit sector number in big-endian at the
+/// end of the IV, preceded by zeros.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Plain64Be;
+
+impl<N: ArrayLength<u8>> IvGenerator<N> for Plain {
+    fn sector_iv(&self, sector: u64) -> GenericArray<u8, N> {
+        let mut iv = GenericArray::default();
+        iv[..4].copy_from_slice(&(sector as u32).to_le_bytes());
+        iv
+    }
+}
+
+impl<N: ArrayLength<u8>> IvGenerator<N> for Plain64 {
+    fn sector_iv(&self, sector: u64) -> GenericArray<u8, N> {
+        let mut iv = GenericArray::default();
+        iv[..8].copy_from_slice(&sector.to_le_bytes());
+        iv
+    }
+}
+
+impl<N: ArrayLength<u8>> IvGenerator<N> for Plain64Be {
+    fn sector_iv(&self, sector: u64) -> GenericArray<u8, N> {
+        let mut iv = GenericArray::<u8, N>::default();
+        let n = iv.len();
+        iv[n - 8..].copy_from_slice(&sector.to_be_bytes());
+        iv
+    }
+}
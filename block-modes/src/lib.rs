@@ -84,28 +84,71 @@ extern crate alloc;
 extern crate std;
 
 mod errors;
+mod fault;
+pub mod gf128;
+#[cfg(feature = "std")]
+mod protect;
+#[cfg(feature = "alloc")]
+mod shared;
 mod traits;
 mod utils;
+#[cfg(feature = "subtle")]
+mod verify;
 
+mod buffered;
 mod cbc;
 mod cfb;
 mod cfb8;
+mod cfbs;
 mod ecb;
+mod ede;
+mod essiv;
+mod explicit_iv;
 mod ige;
+mod inner_cbc;
+mod ivgen;
 mod ofb;
+mod ofb8;
 mod pcbc;
+mod xex;
 
 pub use block_padding;
 pub use cipher;
+#[cfg(feature = "digest")]
+pub use digest;
+#[cfg(feature = "rand_core")]
+pub use rand_core;
+#[cfg(feature = "subtle")]
+pub use subtle;
 
 pub use crate::{
+    buffered::{CbcDecryptor, CbcEncryptor, Decryptor, Encryptor, PcbcDecryptor, PcbcEncryptor},
     cbc::Cbc,
     cfb::Cfb,
     cfb8::Cfb8,
+    cfbs::CfbS,
     ecb::Ecb,
-    errors::{BlockModeError, InvalidKeyIvLength},
-    ige::Ige,
-    ofb::Ofb,
+    ede::Ede,
+    errors::{BlockModeError, FaultError, InvalidKeyIvLength},
+    essiv::Essiv,
+    explicit_iv::{CbcExplicitIv, ExplicitIv},
+    fault::{CheckedMode, FaultCheck},
+    ige::{BiIge, Ige},
+    inner_cbc::InnerCbcEde,
+    ivgen::{IvGenerator, Plain, Plain64, Plain64Be},
+    ofb::{Ofb, OfbStream},
+    ofb8::Ofb8,
     pcbc::Pcbc,
-    traits::{BlockMode, IvState},
+    traits::{BlockMode, IvState, ParallelismHint, Rekey, ResetIv},
+    xex::{Xex, XexMask},
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::shared::SharedCipher;
+#[cfg(feature = "subtle")]
+pub use crate::verify::{verify_block, verify_key};
+#[cfg(feature = "std")]
+pub use crate::{
+    errors::KeyProtectionError,
+    protect::{Locked, MemoryLock, SecretKey},
 };
@@ -1,10 +1,14 @@
 use crate::{
-    traits::{BlockMode, IvState},
+    traits::{BlockMode, IvState, ResetIv},
     utils::{xor, Block},
 };
 use block_padding::Padding;
-use cipher::{generic_array::GenericArray, BlockCipher, BlockEncrypt};
-use core::marker::PhantomData;
+use cipher::{
+    errors::LoopError,
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockEncrypt, FromBlockCipher, StreamCipher,
+};
+use core::{fmt, marker::PhantomData};
 
 /// [Output feedback][1] (OFB) block mode instance with a full block feedback.
 ///
@@ -52,3 +56,80 @@ where
         self.iv.clone()
     }
 }
+
+impl<C, P> ResetIv<C, P> for Ofb<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, <Self as BlockMode<C, P>>::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
+
+/// [Output feedback][1] (OFB) mode as a stream cipher.
+///
+/// Produces the same keystream as [`Ofb`], but can be applied to data of
+/// arbitrary length, partially used keystream blocks are kept between
+/// calls. Use [`NewCipher`][cipher::NewCipher] or [`FromBlockCipher`] to
+/// create an instance.
+///
+/// ```
+/// use aes::Aes128;
+/// use block_modes::{
+///     cipher::{NewCipher, StreamCipher},
+///     OfbStream,
+/// };
+///
+/// let mut ofb = OfbStream::<Aes128>::new(&[0x42; 16].into(), &[0x24; 16].into());
+/// let mut buf = *b"hello world";
+/// ofb.apply_keystream(&mut buf[..3]);
+/// ofb.apply_keystream(&mut buf[3..]);
+/// ```
+///
+/// [1]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#Output_feedback_(OFB)
+#[derive(Clone)]
+pub struct OfbStream<C: BlockCipher + BlockEncrypt> {
+    cipher: C,
+    block: Block<C>,
+    pos: usize,
+}
+
+impl<C: BlockCipher + BlockEncrypt> FromBlockCipher for OfbStream<C> {
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher(cipher: C, iv: &Block<C>) -> Self {
+        let mut block = iv.clone();
+        cipher.encrypt_block(&mut block);
+        Self {
+            cipher,
+            block,
+            pos: 0,
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> StreamCipher for OfbStream<C> {
+    fn try_apply_keystream(&mut self, mut data: &mut [u8]) -> Result<(), LoopError> {
+        let bs = C::BlockSize::USIZE;
+        while !data.is_empty() {
+            let n = core::cmp::min(bs - self.pos, data.len());
+            let (l, r) = { data }.split_at_mut(n);
+            data = r;
+            xor(l, &self.block[self.pos..self.pos + n]);
+            self.pos += n;
+            if self.pos == bs {
+                self.cipher.encrypt_block(&mut self.block);
+                self.pos = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> fmt::Debug for OfbStream<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("OfbStream { ... }")
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    traits::{BlockMode, IvState, ResetIv},
+    utils::Block,
+};
+use block_padding::Padding;
+use cipher::{generic_array::GenericArray, BlockCipher, BlockEncrypt};
+use core::marker::PhantomData;
+
+/// [Output feedback][1] (OFB) block mode instance with an 8-bit feedback.
+///
+/// Every cipher invocation produces a single keystream byte, which is also
+/// shifted into the register, so it is `n` times slower than [`Ofb`][crate::Ofb]
+/// for a block size of `n` bytes.
+///
+/// [1]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#Output_feedback_(OFB)
+#[derive(Clone)]
+pub struct Ofb8<C: BlockCipher + BlockEncrypt, P: Padding> {
+    cipher: C,
+    iv: GenericArray<u8, C::BlockSize>,
+    _p: PhantomData<P>,
+}
+
+impl<C, P> BlockMode<C, P> for Ofb8<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    type IvSize = C::BlockSize;
+
+    fn new(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+            _p: Default::default(),
+        }
+    }
+
+    fn encrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        let mut iv = self.iv.clone();
+        let n = iv.len();
+        for block in blocks.iter_mut() {
+            for b in block.iter_mut() {
+                let mut ks = iv.clone();
+                self.cipher.encrypt_block(&mut ks);
+                *b ^= ks[0];
+                iv.rotate_left(1);
+                iv[n - 1] = ks[0];
+            }
+        }
+        self.iv = iv;
+    }
+
+    fn decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
+        self.encrypt_blocks(blocks)
+    }
+}
+
+impl<C, P> IvState<C, P> for Ofb8<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    fn iv_state(&self) -> GenericArray<u8, Self::IvSize> {
+        self.iv.clone()
+    }
+}
+
+impl<C, P> ResetIv<C, P> for Ofb8<C, P>
+where
+    C: BlockCipher + BlockEncrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
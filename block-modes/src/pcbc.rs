@@ -1,6 +1,6 @@
 use crate::{
-    traits::{BlockMode, IvState},
-    utils::{xor, Block},
+    traits::{BlockMode, IvState, ParallelismHint, ResetIv},
+    utils::{get_par_blocks, xor, Block},
 };
 use block_padding::Padding;
 use cipher::{generic_array::GenericArray, BlockCipher, BlockDecrypt, BlockEncrypt};
@@ -29,6 +29,17 @@ where
             _p: Default::default(),
         }
     }
+
+    #[inline(always)]
+    fn single_blocks_decrypt(&mut self, blocks: &mut [Block<C>]) {
+        for block in blocks {
+            let ciphertext = block.clone();
+            self.cipher.decrypt_block(block);
+            xor(block, &self.iv);
+            self.iv = ciphertext;
+            xor(&mut self.iv, block);
+        }
+    }
 }
 
 impl<C, P> BlockMode<C, P> for Pcbc<C, P>
@@ -57,12 +68,22 @@ where
     }
 
     fn decrypt_blocks(&mut self, blocks: &mut [Block<C>]) {
-        for block in blocks {
-            let ciphertext = block.clone();
-            self.cipher.decrypt_block(block);
-            xor(block, &self.iv);
-            self.iv = ciphertext;
-            xor(&mut self.iv, block);
+        if C::PAR_BLOCKS != 1 {
+            // the block decryptions do not depend on the chaining value, so
+            // they can be done in parallel and the XORs applied afterwards
+            let (par_blocks, leftover) = get_par_blocks::<C>(blocks);
+            for pb in par_blocks {
+                let ciphertexts = pb.clone();
+                self.cipher.decrypt_blocks(pb);
+                for (block, ciphertext) in pb.iter_mut().zip(ciphertexts.iter()) {
+                    xor(block, &self.iv);
+                    self.iv = ciphertext.clone();
+                    xor(&mut self.iv, block);
+                }
+            }
+            self.single_blocks_decrypt(leftover);
+        } else {
+            self.single_blocks_decrypt(blocks);
         }
     }
 }
@@ -76,3 +97,13 @@ where
         self.iv.clone()
     }
 }
+
+impl<C, P> ResetIv<C, P> for Pcbc<C, P>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+{
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>) {
+        self.iv = iv.clone();
+    }
+}
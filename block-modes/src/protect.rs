@@ -0,0 +1,194 @@
+//! Hooks for protecting key schedules in memory.
+
+use crate::errors::KeyProtectionError;
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher, ParBlocks};
+use core::{
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+use std::{boxed::Box, io};
+
+/// Memory locking primitive, e.g. `mlock(2)`/`munlock(2)` on Unix or
+/// `VirtualLock`/`VirtualUnlock` on Windows.
+///
+/// This crate does not call into the operating system itself, so
+/// applications supply the implementation matching their platform and
+/// threat model.
+pub trait MemoryLock {
+    /// Prevent the memory region from being swapped out.
+    fn lock(&self, ptr: *const u8, len: usize) -> io::Result<()>;
+
+    /// Release a region previously locked with [`MemoryLock::lock`].
+    fn unlock(&self, ptr: *const u8, len: usize);
+}
+
+/// Container holding secret key material, such as a type from a secrets
+/// management library.
+///
+/// The key is only exposed for the duration of `f`, which allows
+/// containers to keep their contents encrypted or access-protected
+/// otherwise.
+pub trait SecretKey {
+    /// Call `f` with the raw key bytes.
+    fn with_exposed<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R;
+}
+
+impl SecretKey for [u8] {
+    fn with_exposed<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+        f(self)
+    }
+}
+
+/// Block cipher with its key schedule stored in locked heap memory.
+///
+/// The schedule is boxed, so it stays at a fixed address, and the region
+/// is locked for the whole lifetime of the wrapper. When the wrapper is
+/// dropped, or if locking the region fails, the region is overwritten with
+/// zeros before it is unlocked and freed. Note that the compiler may still
+/// place temporary copies on the stack while the cipher is being
+/// constructed.
+///
+/// ```
+/// use aes::Aes128;
+/// use block_modes::{block_padding::Pkcs7, BlockMode, Cbc, Locked, MemoryLock};
+/// use std::io;
+///
+/// struct NoLock;
+///
+/// impl MemoryLock for NoLock {
+///     fn lock(&self, _ptr: *const u8, _len: usize) -> io::Result<()> {
+///         // call `mlock` here
+///         Ok(())
+///     }
+///
+///     fn unlock(&self, _ptr: *const u8, _len: usize) {}
+/// }
+///
+/// let key: &[u8] = &[0x42; 16];
+/// let cipher = Locked::<Aes128, _>::from_secret(key, NoLock).unwrap();
+/// let ct = Cbc::<_, Pkcs7>::new(&cipher, &[1; 16].into()).encrypt_vec(b"hello");
+/// let pt = Cbc::<_, Pkcs7>::new(&cipher, &[1; 16].into()).decrypt_vec(&ct).unwrap();
+/// assert_eq!(pt, b"hello");
+/// ```
+pub struct Locked<C, L: MemoryLock> {
+    cipher: ManuallyDrop<Box<C>>,
+    lock: L,
+}
+
+impl<C, L: MemoryLock> Locked<C, L> {
+    /// Move block cipher instance into locked memory.
+    pub fn new(cipher: C, lock: L) -> io::Result<Self> {
+        let cipher = Box::new(cipher);
+        let (ptr, len) = region(&*cipher);
+        if let Err(err) = lock.lock(ptr, len) {
+            wipe(cipher, |_, _| ());
+            return Err(err);
+        }
+        Ok(Self {
+            cipher: ManuallyDrop::new(cipher),
+            lock,
+        })
+    }
+}
+
+impl<C: NewBlockCipher, L: MemoryLock> Locked<C, L> {
+    /// Initialize block cipher from key held by a secret container and
+    /// store its schedule in locked memory.
+    pub fn from_secret<K>(key: &K, lock: L) -> Result<Self, KeyProtectionError>
+    where
+        K: SecretKey + ?Sized,
+    {
+        let cipher = key
+            .with_exposed(|key| C::new_from_slice(key).map(Box::new))
+            .map_err(|_| KeyProtectionError::InvalidLength)?;
+        let (ptr, len) = region(&*cipher);
+        if let Err(err) = lock.lock(ptr, len) {
+            wipe(cipher, |_, _| ());
+            return Err(KeyProtectionError::Lock(err));
+        }
+        Ok(Self {
+            cipher: ManuallyDrop::new(cipher),
+            lock,
+        })
+    }
+}
+
+impl<C, L: MemoryLock> AsRef<C> for Locked<C, L> {
+    fn as_ref(&self) -> &C {
+        &self.cipher
+    }
+}
+
+impl<C, L: MemoryLock> Drop for Locked<C, L> {
+    fn drop(&mut self) {
+        // the field is not accessed anymore after this
+        #[allow(unsafe_code)]
+        let cipher = unsafe { ManuallyDrop::into_inner(ptr::read(&self.cipher)) };
+        let lock = &self.lock;
+        wipe(cipher, |ptr, len| lock.unlock(ptr, len));
+    }
+}
+
+impl<C: BlockCipher, L: MemoryLock> BlockCipher for Locked<C, L> {
+    type BlockSize = C::BlockSize;
+    type ParBlocks = C::ParBlocks;
+}
+
+impl<C: BlockEncrypt, L: MemoryLock> BlockEncrypt for Locked<C, L> {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.cipher.encrypt_block(block);
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.cipher.encrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.cipher.encrypt_blocks(blocks);
+    }
+}
+
+impl<C: BlockDecrypt, L: MemoryLock> BlockDecrypt for Locked<C, L> {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        self.cipher.decrypt_block(block);
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.cipher.decrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.cipher.decrypt_blocks(blocks);
+    }
+}
+
+fn region<C>(cipher: &C) -> (*const u8, usize) {
+    (cipher as *const C as *const u8, mem::size_of::<C>())
+}
+
+/// Drop `cipher` and overwrite its memory with zeros. `f` is called with
+/// the wiped region before the memory is freed.
+#[allow(unsafe_code)]
+fn wipe<C>(cipher: Box<C>, f: impl FnOnce(*const u8, usize)) {
+    let len = mem::size_of::<C>();
+    let ptr = Box::into_raw(cipher);
+    // SAFETY: `ptr` comes from a `Box`, the value is dropped exactly once
+    // and the memory is freed as `MaybeUninit<C>` with the same layout
+    unsafe {
+        ptr::drop_in_place(ptr);
+        let bytes = ptr as *mut u8;
+        for i in 0..len {
+            ptr::write_volatile(bytes.add(i), 0);
+        }
+        compiler_fence(Ordering::SeqCst);
+        f(bytes, len);
+        drop(Box::from_raw(ptr as *mut MaybeUninit<C>));
+    }
+}
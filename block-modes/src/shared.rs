@@ -0,0 +1,91 @@
+use alloc::sync::Arc;
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, ParBlocks};
+
+/// Block cipher instance which can be cheaply shared between mode instances.
+///
+/// Expanded key schedules can take up several hundred bytes, so servers
+/// handling many streams under the same key may want to initialize the
+/// cipher once and spawn lightweight per-stream mode states (IVs, counters)
+/// from it. Cloning a `SharedCipher` only increments a reference count.
+///
+/// Short-lived mode instances can also borrow the cipher instead, since
+/// block cipher traits are implemented for `&C`.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::{block_padding::Pkcs7, BlockMode, Cbc, SharedCipher};
+///
+/// let cipher = SharedCipher::new(Aes128::new(&[0x42; 16].into()));
+///
+/// let ct1 = Cbc::<_, Pkcs7>::new(cipher.clone(), &[1; 16].into()).encrypt_vec(b"hello");
+/// let ct2 = Cbc::<_, Pkcs7>::new(cipher.clone(), &[2; 16].into()).encrypt_vec(b"hello");
+/// assert_ne!(ct1, ct2);
+///
+/// let pt = Cbc::<_, Pkcs7>::new(cipher, &[1; 16].into()).decrypt_vec(&ct1).unwrap();
+/// assert_eq!(pt, b"hello");
+/// ```
+pub struct SharedCipher<C>(Arc<C>);
+
+impl<C> SharedCipher<C> {
+    /// Wrap block cipher instance.
+    pub fn new(cipher: C) -> Self {
+        Self(Arc::new(cipher))
+    }
+}
+
+impl<C> Clone for SharedCipher<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C> From<Arc<C>> for SharedCipher<C> {
+    fn from(cipher: Arc<C>) -> Self {
+        Self(cipher)
+    }
+}
+
+impl<C> AsRef<C> for SharedCipher<C> {
+    fn as_ref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C: BlockCipher> BlockCipher for SharedCipher<C> {
+    type BlockSize = C::BlockSize;
+    type ParBlocks = C::ParBlocks;
+}
+
+impl<C: BlockEncrypt> BlockEncrypt for SharedCipher<C> {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.0.encrypt_block(block);
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.0.encrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.0.encrypt_blocks(blocks);
+    }
+}
+
+impl<C: BlockDecrypt> BlockDecrypt for SharedCipher<C> {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        self.0.decrypt_block(block);
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.0.decrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.0.decrypt_blocks(blocks);
+    }
+}
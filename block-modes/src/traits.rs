@@ -7,6 +7,7 @@ use crate::{
 };
 use block_padding::Padding;
 use cipher::{
+    errors::InvalidLength,
     generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
     BlockCipher, NewBlockCipher,
 };
@@ -126,3 +127,48 @@ where
     /// NOT be exposed to attackers.
     fn iv_state(&self) -> GenericArray<u8, Self::IvSize>;
 }
+
+/// Trait for a BlockMode which can be restarted with a new IV while keeping
+/// the initialized block cipher.
+pub trait ResetIv<C, P>: BlockMode<C, P>
+where
+    C: BlockCipher,
+    P: Padding,
+{
+    /// Reset mode state to the one produced by `BlockMode::new` with `iv`.
+    fn reset_iv(&mut self, iv: &GenericArray<u8, Self::IvSize>);
+}
+
+/// Trait for re-initializing a block cipher with a new key in place.
+///
+/// Implemented for all block ciphers, so key rotation can happen through a
+/// mutable borrow without re-creating objects which own the cipher.
+pub trait Rekey: NewBlockCipher {
+    /// Replace key schedule with the one derived from `key`.
+    fn rekey(&mut self, key: &Key<Self>) {
+        *self = Self::new(key);
+    }
+
+    /// Replace key schedule with the one derived from variable size `key`.
+    ///
+    /// Returns an error and leaves the cipher unchanged if `key` has
+    /// unsupported length.
+    fn rekey_from_slice(&mut self, key: &[u8]) -> Result<(), InvalidLength> {
+        *self = Self::new_from_slice(key)?;
+        Ok(())
+    }
+}
+
+impl<C: NewBlockCipher> Rekey for C {}
+
+/// Trait exposing the preferred parallelism of a block cipher, used by the
+/// modes to size their batches and temporary buffers.
+///
+/// Ciphers report their preference through [`BlockCipher::ParBlocks`], e.g.
+/// the AES types of the `aes` crate use 32 blocks with the VAES backend.
+pub trait ParallelismHint: BlockCipher {
+    /// Number of blocks the cipher processes in parallel.
+    const PAR_BLOCKS: usize = <Self as BlockCipher>::ParBlocks::USIZE;
+}
+
+impl<C: BlockCipher> ParallelismHint for C {}
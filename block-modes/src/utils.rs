@@ -1,5 +1,6 @@
+use crate::traits::ParallelismHint;
 use cipher::{
-    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+    generic_array::{ArrayLength, GenericArray},
     BlockCipher, NewBlockCipher,
 };
 use core::slice;
@@ -16,6 +17,19 @@ pub(crate) type Key<C> = GenericArray<u8, <C as NewBlockCipher>::KeySize>;
 pub(crate) type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
 pub(crate) type ParBlocks<C> = GenericArray<Block<C>, <C as BlockCipher>::ParBlocks>;
 
+/// Number of blocks processed by a thread at once in the multithreaded
+/// methods.
+#[cfg(all(feature = "std", feature = "rayon"))]
+pub(crate) const THREAD_CHUNK_BLOCKS: usize = 1024;
+
+/// `THREAD_CHUNK_BLOCKS` rounded up to a multiple of the cipher's
+/// `PAR_BLOCKS`, so every thread hands whole batches to the cipher.
+#[cfg(all(feature = "std", feature = "rayon"))]
+pub(crate) fn thread_chunk_blocks<C: BlockCipher>() -> usize {
+    let batch = C::PAR_BLOCKS;
+    (THREAD_CHUNK_BLOCKS + batch - 1) / batch * batch
+}
+
 pub(crate) fn to_blocks<N>(data: &mut [u8]) -> &mut [GenericArray<u8, N>]
 where
     N: ArrayLength<u8>,
@@ -32,7 +46,7 @@ where
 pub(crate) fn get_par_blocks<C: BlockCipher>(
     blocks: &mut [Block<C>],
 ) -> (&mut [ParBlocks<C>], &mut [Block<C>]) {
-    let pb = C::ParBlocks::to_usize();
+    let pb = C::PAR_BLOCKS;
     let n_par = blocks.len() / pb;
 
     let (par, single) = blocks.split_at_mut(n_par * pb);
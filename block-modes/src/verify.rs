@@ -0,0 +1,19 @@
+//! Constant-time comparison of cipher blocks and keys.
+
+use cipher::{Block, BlockCipher, BlockCipherKey, NewBlockCipher};
+use subtle::{Choice, ConstantTimeEq};
+
+/// Compare two blocks of the block cipher `C` in constant time.
+///
+/// Use this instead of `==` when checking cipher outputs which must stay
+/// secret, e.g. when verifying an authentication tag built on top of `C`.
+#[inline]
+pub fn verify_block<C: BlockCipher>(a: &Block<C>, b: &Block<C>) -> Choice {
+    a.as_slice().ct_eq(b.as_slice())
+}
+
+/// Compare two keys of the block cipher `C` in constant time.
+#[inline]
+pub fn verify_key<C: NewBlockCipher>(a: &BlockCipherKey<C>, b: &BlockCipherKey<C>) -> Choice {
+    a.as_slice().ct_eq(b.as_slice())
+}
@@ -0,0 +1,209 @@
+use crate::errors::BlockModeError;
+use crate::gf128;
+use crate::utils::{to_blocks, xor, Block};
+use cipher::generic_array::{typenum::U16, GenericArray};
+use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt};
+
+/// [XEX][1] (xor-encrypt-xor) tweakable block cipher construction.
+///
+/// A block is processed as `E_K1(P ^ Δ) ^ Δ`, where the mask `Δ` starts as
+/// the encryption of the tweak under `K2` and is multiplied by `α` (doubled
+/// in `GF(2^128)`) for every following block, see [`XexMask`]. This is the
+/// building block of XTS and of OCB-like constructions.
+///
+/// ```
+/// use aes::{Aes128, NewBlockCipher};
+/// use block_modes::Xex;
+/// use cipher::generic_array::GenericArray;
+///
+/// let xex = Xex::new(Aes128::new(&[1; 16].into()), Aes128::new(&[2; 16].into()));
+/// // the tweak is e.g. the sector number
+/// let mut mask = xex.mask(&GenericArray::from(42u128.to_le_bytes()));
+/// let mut sector = [GenericArray::default(); 4];
+/// xex.encrypt_blocks(&mut sector, &mut mask);
+/// ```
+///
+/// [1]: https://www.cs.ucdavis.edu/~rogaway/papers/offsets.pdf
+#[derive(Clone)]
+pub struct Xex<C: BlockCipher<BlockSize = U16> + BlockEncrypt> {
+    cipher: C,
+    tweak_cipher: C,
+}
+
+impl<C> Xex<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
+{
+    /// Create XEX instance from the data `cipher` and the `tweak_cipher`
+    /// used to encrypt tweaks, initialized with independent keys as in XTS.
+    pub fn new(cipher: C, tweak_cipher: C) -> Self {
+        Self {
+            cipher,
+            tweak_cipher,
+        }
+    }
+
+    /// Compute the initial mask `Δ = E_K2(tweak)`.
+    pub fn mask(&self, tweak: &Block<C>) -> XexMask {
+        let mut delta = *tweak;
+        self.tweak_cipher.encrypt_block(&mut delta);
+        XexMask { delta }
+    }
+
+    /// Encrypt a single block with the current mask.
+    ///
+    /// The mask is not advanced.
+    pub fn encrypt_block(&self, block: &mut Block<C>, mask: &XexMask) {
+        xor(block, &mask.delta);
+        self.cipher.encrypt_block(block);
+        xor(block, &mask.delta);
+    }
+
+    /// Encrypt consecutive blocks, doubling the mask after every block.
+    pub fn encrypt_blocks(&self, blocks: &mut [Block<C>], mask: &mut XexMask) {
+        for block in blocks {
+            self.encrypt_block(block, mask);
+            mask.double();
+        }
+    }
+
+    /// Encrypt an XTS data unit (e.g. a disk sector) in-place.
+    ///
+    /// The tweak is the little-endian `sector` number as in IEEE 1619. A
+    /// trailing partial block is handled with ciphertext stealing, so the
+    /// data unit may have any length of at least one block, otherwise an
+    /// error is returned.
+    pub fn encrypt_data_unit(&self, sector: u128, data: &mut [u8]) -> Result<(), BlockModeError> {
+        if data.len() < 16 {
+            return Err(BlockModeError);
+        }
+        let mut mask = self.mask(&GenericArray::from(sector.to_le_bytes()));
+        let r = data.len() % 16;
+        if r == 0 {
+            self.encrypt_blocks(to_blocks(data), &mut mask);
+            return Ok(());
+        }
+
+        let n = data.len() - r - 16;
+        let (head, tail) = data.split_at_mut(n);
+        self.encrypt_blocks(to_blocks(head), &mut mask);
+
+        let (last_full, partial) = tail.split_at_mut(16);
+        let mut cc = Block::<C>::clone_from_slice(last_full);
+        self.encrypt_block(&mut cc, &mask);
+        mask.double();
+
+        let mut pp = cc;
+        pp[..r].copy_from_slice(partial);
+        partial.copy_from_slice(&cc[..r]);
+        self.encrypt_block(&mut pp, &mask);
+        last_full.copy_from_slice(&pp);
+        Ok(())
+    }
+
+    /// Get reference to the data cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Get reference to the tweak cipher.
+    pub fn tweak_cipher(&self) -> &C {
+        &self.tweak_cipher
+    }
+}
+
+impl<C> Xex<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Decrypt a single block with the current mask.
+    ///
+    /// The mask is not advanced.
+    pub fn decrypt_block(&self, block: &mut Block<C>, mask: &XexMask) {
+        xor(block, &mask.delta);
+        self.cipher.decrypt_block(block);
+        xor(block, &mask.delta);
+    }
+
+    /// Decrypt consecutive blocks, doubling the mask after every block.
+    pub fn decrypt_blocks(&self, blocks: &mut [Block<C>], mask: &mut XexMask) {
+        for block in blocks {
+            self.decrypt_block(block, mask);
+            mask.double();
+        }
+    }
+
+    /// Decrypt an XTS data unit (e.g. a disk sector) in-place.
+    ///
+    /// See [`encrypt_data_unit`][Xex::encrypt_data_unit] for details.
+    pub fn decrypt_data_unit(&self, sector: u128, data: &mut [u8]) -> Result<(), BlockModeError> {
+        if data.len() < 16 {
+            return Err(BlockModeError);
+        }
+        let mut mask = self.mask(&GenericArray::from(sector.to_le_bytes()));
+        let r = data.len() % 16;
+        if r == 0 {
+            self.decrypt_blocks(to_blocks(data), &mut mask);
+            return Ok(());
+        }
+
+        let n = data.len() - r - 16;
+        let (head, tail) = data.split_at_mut(n);
+        self.decrypt_blocks(to_blocks(head), &mut mask);
+
+        // the last two blocks are processed with swapped masks
+        let prev_mask = mask.clone();
+        mask.double();
+        let (last_full, partial) = tail.split_at_mut(16);
+        let mut pp = Block::<C>::clone_from_slice(last_full);
+        self.decrypt_block(&mut pp, &mask);
+
+        let mut cc = pp;
+        cc[..r].copy_from_slice(partial);
+        partial.copy_from_slice(&pp[..r]);
+        self.decrypt_block(&mut cc, &prev_mask);
+        last_full.copy_from_slice(&cc);
+        Ok(())
+    }
+}
+
+impl<C> Xex<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + Clone,
+{
+    /// Create XEX instance using the same key for data and tweaks, as in
+    /// Rogaway's original construction.
+    pub fn new_single_key(cipher: C) -> Self {
+        Self::new(cipher.clone(), cipher)
+    }
+}
+
+/// Mask `Δ` of the [`Xex`] construction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XexMask {
+    delta: GenericArray<u8, U16>,
+}
+
+impl XexMask {
+    /// Create mask from a raw value, e.g. an externally computed `Δ`.
+    pub fn from_block(delta: GenericArray<u8, U16>) -> Self {
+        Self { delta }
+    }
+
+    /// Get the current mask value.
+    pub fn as_block(&self) -> &GenericArray<u8, U16> {
+        &self.delta
+    }
+
+    /// Multiply the mask by `α` with the little-endian bit order of
+    /// IEEE 1619 (XTS).
+    pub fn double(&mut self) {
+        gf128::double(&mut self.delta);
+    }
+
+    /// Multiply the mask by `α` with the big-endian bit order used by OCB
+    /// and CMAC.
+    pub fn double_be(&mut self) {
+        gf128::double_be(&mut self.delta);
+    }
+}
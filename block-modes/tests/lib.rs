@@ -1,10 +1,13 @@
 //! Test vectors generated with OpenSSL
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use aes::{Aes128, BlockCipher, NewBlockCipher};
 use block_modes::block_padding::NoPadding;
 use block_modes::{BlockMode, IvState};
 use block_modes::{Cbc, Cfb, Ecb, Ige, Ofb};
-use cipher::generic_array::{ArrayLength, GenericArray};
+use cipher::generic_array::{typenum::PartialDiv, ArrayLength, GenericArray};
 
 #[test]
 fn ecb_aes128() {
@@ -180,6 +183,64 @@ fn cfb_aes128_2_continued() {
     }
 }
 
+/// CFB8 and CFB128 vectors of NIST SP 800-38A, F.3.7 and F.3.13. The
+/// CFB8 vector is cut to the first block.
+#[test]
+fn cfbs_aes128() {
+    use block_modes::CfbS;
+    use cipher::consts::{U1, U16, U2, U4, U8};
+    use hex_literal::hex;
+
+    const KEY: [u8; 16] = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    const IV: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+    const PLAINTEXT: [u8; 64] = hex!("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710");
+
+    fn check<S: ArrayLength<u8>>(plaintext: &[u8], ciphertext: &[u8])
+    where
+        U16: PartialDiv<S>,
+    {
+        let mode = CfbS::<Aes128, NoPadding, S>::new_from_slices(&KEY, &IV).unwrap();
+        assert_eq!(mode.encrypt_vec(plaintext), ciphertext);
+
+        let mode = CfbS::<Aes128, NoPadding, S>::new_from_slices(&KEY, &IV).unwrap();
+        assert_eq!(mode.decrypt_vec(ciphertext).unwrap(), plaintext);
+
+        // interrupt after every block and continue from the IV state
+        let mut buf = plaintext.to_vec();
+        let mut mode = CfbS::<Aes128, NoPadding, S>::new_from_slices(&KEY, &IV).unwrap();
+        for block in to_blocks::<U16>(&mut buf).chunks_mut(1) {
+            mode.encrypt_blocks(block);
+            mode = CfbS::new(Aes128::new(&KEY.into()), &mode.iv_state());
+        }
+        assert_eq!(buf, ciphertext);
+    }
+
+    check::<U1>(&PLAINTEXT[..16], &hex!("3b79424c9c0dd436bace9e0ed4586a4f"));
+    check::<U16>(
+        &PLAINTEXT,
+        &hex!("3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6"),
+    );
+
+    // segment sizes in between have no published vectors, check that they
+    // agree with themselves across interruptions
+    let ciphertext = |s: usize| match s {
+        2 => CfbS::<Aes128, NoPadding, U2>::new_from_slices(&KEY, &IV)
+            .unwrap()
+            .encrypt_vec(&PLAINTEXT),
+        4 => CfbS::<Aes128, NoPadding, U4>::new_from_slices(&KEY, &IV)
+            .unwrap()
+            .encrypt_vec(&PLAINTEXT),
+        _ => CfbS::<Aes128, NoPadding, U8>::new_from_slices(&KEY, &IV)
+            .unwrap()
+            .encrypt_vec(&PLAINTEXT),
+    };
+    check::<U2>(&PLAINTEXT, &ciphertext(2));
+    check::<U4>(&PLAINTEXT, &ciphertext(4));
+    check::<U8>(&PLAINTEXT, &ciphertext(8));
+    // only the first segment of the keystream matches CFB128
+    assert_eq!(ciphertext(8)[..8], hex!("3b3fd92eb72dad20"));
+}
+
 #[test]
 fn ofb_aes128() {
     let key = include_bytes!("data/aes128.key.bin");
@@ -194,6 +255,63 @@ fn ofb_aes128() {
     assert_eq!(mode.decrypt_vec(ciphertext).unwrap(), &plaintext[..]);
 }
 
+#[test]
+fn ofb_stream_aes128() {
+    use block_modes::{
+        cipher::{NewCipher, StreamCipher},
+        OfbStream,
+    };
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let ciphertext = include_bytes!("data/ofb-aes128.ciphertext.bin");
+
+    for chunk in [1, 5, 16, 17, 40].iter() {
+        let mut ofb = OfbStream::<Aes128>::new_from_slices(key, iv).unwrap();
+        let mut buf = plaintext.to_vec();
+        for part in buf.chunks_mut(*chunk) {
+            ofb.apply_keystream(part);
+        }
+        assert_eq!(buf, &ciphertext[..]);
+    }
+
+    // a trailing partial block is supported
+    let n = plaintext.len() - 7;
+    let mut ofb = OfbStream::<Aes128>::new_from_slices(key, iv).unwrap();
+    let mut buf = ciphertext[..n].to_vec();
+    ofb.apply_keystream(&mut buf);
+    assert_eq!(buf, &plaintext[..n]);
+}
+
+/// Vector generated with a reference model, which yields the OpenSSL
+/// vector for full block feedback.
+/// OpenSSL has no OFB8, so the ciphertext was computed one byte at a time
+/// from the raw cipher of `openssl enc`, starting with `reg` set to the IV:
+///
+/// ```sh
+/// for b in $(xxd -p -c1 aes128.plaintext.bin); do
+///   k=$(echo $reg | xxd -r -p | openssl enc -aes-128-ecb -K $key -nopad | xxd -p | cut -c1-2)
+///   reg=${reg:2}$k
+///   printf '%02x' $((0x$b ^ 0x$k))
+/// done | xxd -r -p > ofb8-aes128.ciphertext.bin
+/// ```
+#[test]
+fn ofb8_aes128() {
+    use block_modes::Ofb8;
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let ciphertext = include_bytes!("data/ofb8-aes128.ciphertext.bin");
+
+    let mode = Ofb8::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+    assert_eq!(mode.encrypt_vec(plaintext), &ciphertext[..]);
+
+    let mode = Ofb8::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+    assert_eq!(mode.decrypt_vec(ciphertext).unwrap(), &plaintext[..]);
+}
+
 #[test]
 fn ofb_aes128_continued() {
     type BlockSize = <Aes128 as BlockCipher>::BlockSize;
@@ -256,6 +374,7 @@ fn par_blocks() {
     run::<block_modes::Cfb8<_, _>>();
     run::<block_modes::Ecb<_, _>>();
     run::<block_modes::Ofb<_, _>>();
+    run::<block_modes::Ofb8<_, _>>();
     run::<block_modes::Pcbc<_, _>>();
 }
 
@@ -273,6 +392,56 @@ fn ige_aes256_1() {
     assert_eq!(mode.decrypt_vec(ciphertext).unwrap(), &plaintext[..]);
 }
 
+#[test]
+fn ige_slice() {
+    let key = include_bytes!("data/ige-aes128-1.key.bin");
+    let iv = include_bytes!("data/ige-aes128-1.iv.bin");
+    let plaintext = include_bytes!("data/ige-aes128-1.plaintext.bin");
+    let ciphertext = include_bytes!("data/ige-aes128-1.ciphertext.bin");
+
+    let mut ige = Ige::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+    let mut buf = *plaintext;
+    let (l, r) = buf.split_at_mut(16);
+    ige.encrypt_slice(l).unwrap();
+    ige.encrypt_slice(r).unwrap();
+    assert_eq!(buf, *ciphertext);
+
+    let mut ige = Ige::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+    assert!(ige.decrypt_slice(&mut buf[..17]).is_err());
+    ige.decrypt_slice(&mut buf).unwrap();
+    assert_eq!(buf, *plaintext);
+}
+
+/// bi-IGE with the keys and IVs of the IGE vectors, ciphertext generated
+/// with an independent implementation following OpenSSL's
+/// `AES_bi_ige_encrypt`.
+#[test]
+fn bi_ige_aes128() {
+    use block_modes::BiIge;
+
+    let key1 = GenericArray::from_slice(include_bytes!("data/ige-aes128-1.key.bin"));
+    let key2 = GenericArray::from_slice(include_bytes!("data/ige-aes128-2.key.bin"));
+    let iv1 = GenericArray::from_slice(include_bytes!("data/ige-aes128-1.iv.bin"));
+    let iv2 = GenericArray::from_slice(include_bytes!("data/ige-aes128-2.iv.bin"));
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let ciphertext = include_bytes!("data/bi-ige-aes128.ciphertext.bin");
+    let mode = || BiIge::<_, NoPadding>::new(Aes128::new(key1), iv1, Aes128::new(key2), iv2);
+
+    let mut buf = *plaintext;
+    mode().encrypt(&mut buf, plaintext.len()).unwrap();
+    assert_eq!(buf[..], ciphertext[..]);
+    mode().decrypt(&mut buf).unwrap();
+    assert_eq!(buf[..], plaintext[..]);
+
+    // errors propagate to all blocks in both directions
+    let mut buf = *ciphertext;
+    buf[100] ^= 1;
+    mode().decrypt(&mut buf).unwrap();
+    for (a, b) in buf.chunks(16).zip(plaintext.chunks(16)) {
+        assert_ne!(a, b);
+    }
+}
+
 #[test]
 fn ige_aes256_1_continued() {
     type BlockSize = <Aes128 as BlockCipher>::BlockSize;
@@ -365,6 +534,280 @@ fn ige_aes256_2_continued() {
     }
 }
 
+#[test]
+#[cfg(feature = "subtle")]
+fn verify_block_and_key() {
+    use aes::BlockEncrypt;
+    use block_modes::{verify_block, verify_key};
+
+    let key = GenericArray::from_slice(include_bytes!("data/aes128.key.bin"));
+    let mut other_key = *key;
+    other_key[15] ^= 1;
+    assert!(bool::from(verify_key::<Aes128>(key, key)));
+    assert!(!bool::from(verify_key::<Aes128>(key, &other_key)));
+
+    let mut block = GenericArray::default();
+    Aes128::new(key).encrypt_block(&mut block);
+    let mut other_block = block;
+    other_block[0] ^= 0x80;
+    assert!(bool::from(verify_block::<Aes128>(&block, &block)));
+    assert!(!bool::from(verify_block::<Aes128>(&block, &other_block)));
+}
+
+#[test]
+fn fault_check() {
+    use aes::{BlockDecrypt, BlockEncrypt};
+    use block_modes::{CheckedMode, FaultCheck};
+    use cipher::{consts::U1, Block};
+    use core::cell::Cell;
+
+    /// AES-128 which flips a bit of the n-th encryption output.
+    struct Glitchy {
+        cipher: Aes128,
+        glitch_at: Cell<usize>,
+    }
+
+    impl BlockCipher for Glitchy {
+        type BlockSize = <Aes128 as BlockCipher>::BlockSize;
+        type ParBlocks = U1;
+    }
+
+    impl BlockEncrypt for Glitchy {
+        fn encrypt_block(&self, block: &mut Block<Self>) {
+            self.cipher.encrypt_block(block);
+            let n = self.glitch_at.get();
+            if n == 0 {
+                block[3] ^= 0x10;
+            }
+            self.glitch_at.set(n.wrapping_sub(1));
+        }
+    }
+
+    impl BlockDecrypt for Glitchy {
+        fn decrypt_block(&self, block: &mut Block<Self>) {
+            self.cipher.decrypt_block(block);
+        }
+    }
+
+    let key = GenericArray::from_slice(include_bytes!("data/aes128.key.bin"));
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let ciphertext = include_bytes!("data/ecb-aes128.ciphertext.bin");
+
+    let cipher = FaultCheck::new(Glitchy {
+        cipher: Aes128::new(key),
+        glitch_at: Cell::new(usize::MAX),
+    });
+    let mut buf = plaintext.to_vec();
+    cipher.encrypt_blocks(to_blocks(&mut buf)).unwrap();
+    assert_eq!(buf, &ciphertext[..]);
+    cipher.decrypt_blocks(to_blocks(&mut buf)).unwrap();
+    assert_eq!(buf, &plaintext[..]);
+
+    // fault during encryption
+    cipher.get_ref().glitch_at.set(2);
+    let mut buf = plaintext.to_vec();
+    assert!(cipher.encrypt_blocks(to_blocks(&mut buf)).is_err());
+    assert!(buf.iter().all(|&b| b == 0));
+
+    // fault during the verifying encryption of decrypted data
+    cipher.get_ref().glitch_at.set(0);
+    let mut block = *GenericArray::from_slice(&ciphertext[..16]);
+    assert!(cipher.decrypt_block(&mut block).is_err());
+    assert_eq!(block, Default::default());
+
+    // mode-level checks
+    type Mode<'a> = Cbc<&'a FaultCheck<Glitchy>, NoPadding>;
+    let iv = GenericArray::from_slice(include_bytes!("data/aes128.iv.bin"));
+    let ciphertext = include_bytes!("data/cbc-aes128.ciphertext.bin");
+
+    cipher.get_ref().glitch_at.set(usize::MAX);
+    let mut buf = plaintext.to_vec();
+    let mut mode = CheckedMode::<_, Mode<'_>>::new(&cipher, iv);
+    mode.encrypt_blocks(to_blocks(&mut buf)).unwrap();
+    assert_eq!(buf, &ciphertext[..]);
+    let mut mode = CheckedMode::<_, Mode<'_>>::new(&cipher, iv);
+    mode.decrypt_blocks(to_blocks(&mut buf)).unwrap();
+    assert_eq!(buf, &plaintext[..]);
+
+    cipher.get_ref().glitch_at.set(3);
+    let mut mode = CheckedMode::<_, Mode<'_>>::new(&cipher, iv);
+    assert!(mode.encrypt_blocks(to_blocks(&mut buf)).is_err());
+    assert!(buf.iter().all(|&b| b == 0));
+    assert!(cipher.check().is_ok());
+
+    // faults of modes used without the wrapper are recorded
+    cipher.get_ref().glitch_at.set(1);
+    let mut buf = plaintext.to_vec();
+    Mode::new(&cipher, iv).encrypt_blocks(to_blocks(&mut buf));
+    assert!(cipher.check().is_err());
+    assert!(cipher.check().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn locked_key_schedule() {
+    use aes::BlockEncrypt;
+    use block_modes::{KeyProtectionError, Locked, MemoryLock};
+    use std::{cell::RefCell, io, rc::Rc};
+
+    type Entry = (&'static str, usize, usize);
+
+    #[derive(Clone, Default)]
+    struct Log(Rc<RefCell<Vec<Entry>>>);
+
+    impl MemoryLock for Log {
+        fn lock(&self, ptr: *const u8, len: usize) -> io::Result<()> {
+            self.0.borrow_mut().push(("lock", ptr as usize, len));
+            Ok(())
+        }
+
+        fn unlock(&self, ptr: *const u8, len: usize) {
+            self.0.borrow_mut().push(("unlock", ptr as usize, len));
+        }
+    }
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let log = Log::default();
+    let cipher = Locked::<Aes128, _>::from_secret(&key[..], log.clone()).unwrap();
+
+    let mut block = GenericArray::default();
+    let mut expected = GenericArray::default();
+    cipher.encrypt_block(&mut block);
+    Aes128::new(GenericArray::from_slice(key)).encrypt_block(&mut expected);
+    assert_eq!(block, expected);
+
+    let addr = cipher.as_ref() as *const Aes128 as usize;
+    let len = core::mem::size_of::<Aes128>();
+    drop(cipher);
+    assert_eq!(
+        *log.0.borrow(),
+        [("lock", addr, len), ("unlock", addr, len)]
+    );
+
+    match Locked::<Aes128, _>::from_secret(&key[1..], log) {
+        Err(KeyProtectionError::InvalidLength) => (),
+        _ => panic!("expected InvalidLength error"),
+    }
+}
+
+#[test]
+fn rekey_and_reset_iv() {
+    use aes::BlockEncrypt;
+    use block_modes::{Rekey, ResetIv};
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let ciphertext = include_bytes!("data/cbc-aes128.ciphertext.bin");
+
+    let mut other_key = *GenericArray::from_slice(key);
+    other_key[0] ^= 1;
+    let mut cipher = Aes128::new(&other_key);
+    cipher.rekey(GenericArray::from_slice(key));
+    let mut block = GenericArray::default();
+    let mut expected = GenericArray::default();
+    cipher.encrypt_block(&mut block);
+    Aes128::new(GenericArray::from_slice(key)).encrypt_block(&mut expected);
+    assert_eq!(block, expected);
+    assert!(cipher.rekey_from_slice(&key[1..]).is_err());
+
+    let mut mode = Cbc::<_, NoPadding>::new(cipher, &Default::default());
+    let mut buf = plaintext.to_vec();
+    mode.encrypt_blocks(to_blocks(&mut buf));
+    assert_ne!(buf, &ciphertext[..]);
+
+    mode.reset_iv(GenericArray::from_slice(iv));
+    let mut buf = plaintext.to_vec();
+    mode.encrypt_blocks(to_blocks(&mut buf));
+    assert_eq!(buf, &ciphertext[..]);
+}
+
+/// XTS-AES-128 test vectors 1 and 2 from IEEE 1619-2007
+#[test]
+fn xex_xts_aes128() {
+    use block_modes::Xex;
+    use hex_literal::hex;
+
+    let vectors = [
+        (
+            [0u8; 16],
+            [0u8; 16],
+            0u128,
+            [0u8; 32],
+            hex!("917cf69ebd68b2ec9b9fe9a3eadda692cd43d2f59598ed858c02c2652fbf922e"),
+        ),
+        (
+            [0x11; 16],
+            [0x22; 16],
+            0x33_3333_3333,
+            [0x44; 32],
+            hex!("c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0"),
+        ),
+    ];
+    for (key1, key2, sector, pt, ct) in vectors.iter() {
+        let xex = Xex::new(
+            Aes128::new(GenericArray::from_slice(key1)),
+            Aes128::new(GenericArray::from_slice(key2)),
+        );
+        let tweak = GenericArray::from(sector.to_le_bytes());
+        let mut buf = pt.to_vec();
+        xex.encrypt_blocks(to_blocks(&mut buf), &mut xex.mask(&tweak));
+        assert_eq!(buf, &ct[..]);
+        xex.decrypt_blocks(to_blocks(&mut buf), &mut xex.mask(&tweak));
+        assert_eq!(buf, &pt[..]);
+    }
+}
+
+/// XTS-AES-128 data units with ciphertext stealing
+#[test]
+fn xts_data_unit_aes128() {
+    use block_modes::Xex;
+    use hex_literal::hex;
+
+    let key1 = hex!("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0");
+    let key2 = hex!("bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0");
+    let sector = 0x9a_7856_3412;
+    let xex = Xex::new(
+        Aes128::new(GenericArray::from_slice(&key1)),
+        Aes128::new(GenericArray::from_slice(&key2)),
+    );
+    let vectors: [&[u8]; 4] = [
+        &hex!("641610679dcbf92e505c41333fb06c2a95"),
+        &hex!("c03f4c6088fcf14c308aa39f7938980995c871f6522469cc737109594ab0fe"),
+        &hex!("95c871f6522469cc737109594ab0feda383a90c3320b91b5ba5bc8bcf089a09e"),
+        &hex!("95c871f6522469cc737109594ab0feda8b5c41000de0a28dd3ffdb9dcaa4d0d238"),
+    ];
+    for ct in vectors.iter() {
+        let pt: Vec<u8> = (0..ct.len() as u8).collect();
+        let mut buf = pt.clone();
+        xex.encrypt_data_unit(sector, &mut buf).unwrap();
+        assert_eq!(buf, *ct);
+        xex.decrypt_data_unit(sector, &mut buf).unwrap();
+        assert_eq!(buf, pt);
+    }
+
+    let mut short = [0u8; 15];
+    assert!(xex.encrypt_data_unit(sector, &mut short).is_err());
+    assert!(xex.decrypt_data_unit(sector, &mut short).is_err());
+}
+
+#[test]
+fn xex_mask_doubling() {
+    use block_modes::XexMask;
+
+    let mut mask = XexMask::from_block(GenericArray::from([0x80; 16]));
+    mask.double();
+    let mut expected = [0x01; 16];
+    expected[0] = 0x87;
+    assert_eq!(mask.as_block()[..], expected);
+
+    let mut mask = XexMask::from_block(GenericArray::from([0x80; 16]));
+    mask.double_be();
+    let mut expected = [0x01; 16];
+    expected[15] = 0x87;
+    assert_eq!(mask.as_block()[..], expected);
+}
+
 fn to_blocks<N>(data: &mut [u8]) -> &mut [GenericArray<u8, N>]
 where
     N: ArrayLength<u8>,
@@ -378,3 +821,298 @@ where
         slice::from_raw_parts_mut(data.as_ptr() as *mut GenericArray<u8, N>, data.len() / n)
     }
 }
+
+/// ESSIV with SHA-256 as used by `aes-cbc-essiv:sha256` of dm-crypt,
+/// cross-checked against an independent implementation.
+#[test]
+fn essiv_aes_cbc() {
+    use aes::Aes256;
+    use block_modes::{Essiv, IvGenerator};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    let key = hex!("000102030405060708090a0b0c0d0e0f");
+    let essiv = Essiv::new(Aes256::new(&Sha256::digest(&key)));
+    assert_eq!(
+        essiv.sector_iv(0)[..],
+        hex!("ae0e4eeac063684505721b0643b24ae3")
+    );
+    assert_eq!(
+        essiv.sector_iv(1)[..],
+        hex!("c10509c8cf7d6eee55d7205db7845a6f")
+    );
+    assert_eq!(
+        essiv.sector_iv(0x0123_4567_89ab_cdef)[..],
+        hex!("d4b2a9a4115063c105f68b76215c3af6")
+    );
+
+    let mut sector = [0u8; 64];
+    let cbc = Cbc::<Aes128, NoPadding>::new_from_slices(&key, &essiv.sector_iv(1)).unwrap();
+    cbc.encrypt(&mut sector, 64).unwrap();
+    assert_eq!(
+        sector[..],
+        hex!("fe58062f6a4a8afefc6fa80c447ff6d3c05214a34adabb8ab0828a34b6f76b6e753f8846e4ab5064ea25a0781f8e53db07e73572f17c3bee557af1ffcbefdc24")[..]
+    );
+
+    #[cfg(feature = "digest")]
+    assert_eq!(
+        Essiv::<Aes256>::from_key::<Sha256>(&key)
+            .unwrap()
+            .sector_iv(1),
+        essiv.sector_iv(1)
+    );
+}
+
+#[test]
+fn plain_iv_generators() {
+    use block_modes::{IvGenerator, Plain, Plain64, Plain64Be};
+    use cipher::consts::{U16, U8};
+    use hex_literal::hex;
+
+    let sector = 0x0123_4567_89ab_cdef;
+    let iv: GenericArray<u8, U16> = Plain.sector_iv(sector);
+    assert_eq!(iv[..], hex!("efcdab89000000000000000000000000"));
+    let iv: GenericArray<u8, U16> = Plain64.sector_iv(sector);
+    assert_eq!(iv[..], hex!("efcdab89674523010000000000000000"));
+    let iv: GenericArray<u8, U16> = Plain64Be.sector_iv(sector);
+    assert_eq!(iv[..], hex!("00000000000000000123456789abcdef"));
+    let iv: GenericArray<u8, U8> = Plain64Be.sector_iv(sector);
+    assert_eq!(iv[..], hex!("0123456789abcdef"));
+}
+
+/// Buffered PCBC with arbitrary chunk sizes matches the one-shot API.
+#[test]
+fn pcbc_buffered() {
+    use block_modes::{block_padding::Pkcs7, Pcbc, PcbcDecryptor, PcbcEncryptor};
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let mode = || Pcbc::<Aes128, Pkcs7>::new_from_slices(key, iv).unwrap();
+
+    for len in [0, 1, 15, 16, 17, 100, plaintext.len()].iter() {
+        let msg = &plaintext[..*len];
+        let ciphertext = mode().encrypt_vec(msg);
+        for chunk in 1..40 {
+            let mut enc = PcbcEncryptor::new(mode());
+            let mut ct = vec![0u8; ciphertext.len()];
+            let mut n = 0;
+            for part in msg.chunks(chunk) {
+                n += enc.update(part, &mut ct[n..]).unwrap().len();
+            }
+            n += enc.finalize(&mut ct[n..]).unwrap().len();
+            assert_eq!(n, ct.len());
+            assert_eq!(ct, ciphertext);
+
+            let mut dec = PcbcDecryptor::new(mode());
+            let mut pt = vec![0u8; ciphertext.len()];
+            let mut n = 0;
+            for part in ciphertext.chunks(chunk) {
+                n += dec.update(part, &mut pt[n..]).unwrap().len();
+            }
+            n += dec.finalize(&mut pt[n..]).unwrap().len();
+            assert_eq!(&pt[..n], msg);
+        }
+    }
+
+    // truncated ciphertext and short output buffers are rejected
+    let mut dec = PcbcDecryptor::new(mode());
+    let mut pt = [0u8; 32];
+    assert_eq!(dec.update(&[0u8; 20], &mut pt).unwrap().len(), 16);
+    assert!(dec.finalize(&mut pt).is_err());
+    let mut enc = PcbcEncryptor::new(mode());
+    assert!(enc.update(&[0u8; 32], &mut pt[..16]).is_err());
+}
+
+#[cfg(all(feature = "std", feature = "rayon"))]
+#[test]
+fn ecb_cbc_multithreaded() {
+    use block_modes::{Cbc, Ecb};
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let data: Vec<u8> = (0..5000 * 16).map(|i| i as u8).collect();
+
+    let mut expected = data.clone();
+    Ecb::<Aes128, NoPadding>::new_from_slices(key, &[])
+        .unwrap()
+        .encrypt_blocks(to_blocks(&mut expected));
+    let mut ecb = Ecb::<Aes128, NoPadding>::new_from_slices(key, &[]).unwrap();
+    let mut buf = data.clone();
+    ecb.par_encrypt_blocks(to_blocks(&mut buf));
+    assert_eq!(buf, expected);
+    ecb.par_decrypt_blocks(to_blocks(&mut buf));
+    assert_eq!(buf, data);
+
+    let mut ct = data.clone();
+    let mut cbc = Cbc::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+    cbc.encrypt_blocks(to_blocks(&mut ct));
+    for &n in [0, 1, 1024, 1025, 5000].iter() {
+        let mut cbc = Cbc::<Aes128, NoPadding>::new_from_slices(key, iv).unwrap();
+        let mut buf = ct.clone();
+        let (l, r) = buf.split_at_mut(n * 16);
+        cbc.par_decrypt_blocks(to_blocks(l));
+        cbc.par_decrypt_blocks(to_blocks(r));
+        assert_eq!(buf, data);
+    }
+}
+
+#[test]
+fn parallelism_hint() {
+    use block_modes::ParallelismHint;
+    use cipher::generic_array::typenum::Unsigned;
+
+    let pb = <Aes128 as BlockCipher>::ParBlocks::USIZE;
+    assert_eq!(Aes128::PAR_BLOCKS, pb);
+}
+
+#[test]
+fn ede_des() {
+    use block_modes::{
+        cipher::{BlockDecrypt, BlockEncrypt},
+        Ede,
+    };
+    use des::{Des, TdesEde2, TdesEde3};
+
+    let key: Vec<u8> = (1..=24).collect();
+    let des = |k: &[u8]| Des::new_from_slice(k).unwrap();
+    let ede3 = Ede::new(des(&key[..8]), des(&key[8..16]), des(&key[16..]));
+    let ede2 = Ede::new_ede2(des(&key[..8]), des(&key[8..16]));
+    let tdes3 = TdesEde3::new_from_slice(&key).unwrap();
+    let tdes2 = TdesEde2::new_from_slice(&key[..16]).unwrap();
+
+    let mut data: Vec<u8> = (0..80).collect();
+    let mut expected = data.clone();
+    for block in to_blocks(&mut expected) {
+        tdes3.encrypt_block(block);
+    }
+    let mut buf = data.clone();
+    ede3.encrypt_blocks(to_blocks(&mut buf));
+    assert_eq!(buf, expected);
+    ede3.decrypt_blocks(to_blocks(&mut buf));
+    assert_eq!(buf, data);
+
+    let block = to_blocks(&mut data[..8]);
+    let mut expected = block[0];
+    tdes2.encrypt_block(&mut expected);
+    ede2.encrypt_block(&mut block[0]);
+    assert_eq!(block[0], expected);
+    ede2.decrypt_block(&mut block[0]);
+    assert_eq!(block[0][..], (0..8).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn cbc_explicit_iv() {
+    use block_modes::{block_padding::Pkcs7, Cbc, CbcExplicitIv};
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let framing = CbcExplicitIv::<_, Pkcs7>::new(Aes128::new_from_slice(key).unwrap());
+
+    for len in [0, 15, 16, 33, plaintext.len()].iter() {
+        let msg = &plaintext[..*len];
+        let mut expected = iv.to_vec();
+        expected.extend(
+            Cbc::<Aes128, Pkcs7>::new_from_slices(key, iv)
+                .unwrap()
+                .encrypt_vec(msg),
+        );
+
+        let mut buf = vec![0u8; expected.len()];
+        buf[..msg.len()].copy_from_slice(msg);
+        let ct = framing
+            .encrypt_with_iv(iv.into(), &mut buf, msg.len())
+            .unwrap();
+        assert_eq!(ct, &expected[..]);
+        assert_eq!(framing.decrypt_vec(&expected).unwrap(), msg);
+    }
+
+    // too short buffers and truncated input are rejected
+    let mut buf = [0u8; 31];
+    assert!(framing.encrypt_with_iv(iv.into(), &mut buf, 16).is_err());
+    assert!(framing.decrypt(&mut buf[..15]).is_err());
+    assert!(framing.decrypt(&mut buf[..16]).is_err());
+}
+
+#[test]
+fn cbc_buffered() {
+    use block_modes::{block_padding::Pkcs7, Cbc, CbcDecryptor, CbcEncryptor};
+
+    let key = include_bytes!("data/aes128.key.bin");
+    let iv = include_bytes!("data/aes128.iv.bin");
+    let plaintext = include_bytes!("data/aes128.plaintext.bin");
+    let mode = || Cbc::<Aes128, Pkcs7>::new_from_slices(key, iv).unwrap();
+
+    for len in [0, 15, 16, 33, plaintext.len()].iter() {
+        let msg = &plaintext[..*len];
+        let ciphertext = mode().encrypt_vec(msg);
+        for chunk in [1, 7, 16, 31].iter() {
+            let mut enc = CbcEncryptor::new(mode());
+            let mut ct = vec![0u8; ciphertext.len()];
+            let mut n = 0;
+            for part in msg.chunks(*chunk) {
+                n += enc.update(part, &mut ct[n..]).unwrap().len();
+            }
+            n += enc.finalize(&mut ct[n..]).unwrap().len();
+            assert_eq!(n, ct.len());
+            assert_eq!(ct, ciphertext);
+
+            let mut dec = CbcDecryptor::new(mode());
+            let mut pt = vec![0u8; ciphertext.len()];
+            let mut n = 0;
+            for part in ciphertext.chunks(*chunk) {
+                n += dec.update(part, &mut pt[n..]).unwrap().len();
+            }
+            n += dec.finalize(&mut pt[n..]).unwrap().len();
+            assert_eq!(&pt[..n], msg);
+        }
+    }
+}
+
+/// Inner-CBC DES-EDE3, cross-checked against an independent implementation.
+#[test]
+fn inner_cbc_des_ede3() {
+    use block_modes::InnerCbcEde;
+    use des::Des;
+    use hex_literal::hex;
+
+    let keys = [
+        hex!("0123456789abcdef"),
+        hex!("23456789abcdef01"),
+        hex!("456789abcdef0123"),
+    ];
+    let ivs = [
+        hex!("0001020304050607"),
+        hex!("1011121314151617"),
+        hex!("2021222324252627"),
+    ];
+    let pt = b"The quick brown fox jumps over t";
+    let ct = hex!("d1aacb5b699089a957b28cc40504233913ed521e0be51b8b19cdbd715c623b2d");
+
+    let mode = || {
+        InnerCbcEde::<_, _, _, NoPadding>::new(
+            Des::new(&keys[0].into()),
+            &ivs[0].into(),
+            Des::new(&keys[1].into()),
+            &ivs[1].into(),
+            Des::new(&keys[2].into()),
+            &ivs[2].into(),
+        )
+    };
+
+    let mut buf = *pt;
+    mode().encrypt(&mut buf, pt.len()).unwrap();
+    assert_eq!(buf, ct);
+    mode().decrypt(&mut buf).unwrap();
+    assert_eq!(&buf, pt);
+
+    // processing in pieces keeps the chaining state of all layers
+    let mut mode = mode();
+    let mut buf = *pt;
+    let blocks = to_blocks(&mut buf);
+    for block in blocks.chunks_mut(1) {
+        mode.encrypt_blocks(block);
+    }
+    assert_eq!(buf, ct);
+}
@@ -0,0 +1,82 @@
+//! Check that `Locked` wipes the key schedule before its memory is freed.
+//!
+//! This needs its own test binary, as it installs a global allocator which
+//! inspects the watched allocation when it is freed.
+#![cfg(feature = "std")]
+
+use aes::{cipher::NewBlockCipher, Aes128};
+use block_modes::{KeyProtectionError, Locked, MemoryLock};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Address of the allocation to inspect when it is freed.
+static WATCH: AtomicUsize = AtomicUsize::new(0);
+/// Number of bytes of the watched allocation which were not zero when it
+/// was freed, `core::usize::MAX` if it was not freed yet.
+static NONZERO: AtomicUsize = AtomicUsize::new(core::usize::MAX);
+
+struct CheckWipe;
+
+unsafe impl GlobalAlloc for CheckWipe {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr as usize == WATCH.load(Ordering::SeqCst) {
+            let bytes = std::slice::from_raw_parts(ptr, layout.size());
+            let n = bytes.iter().filter(|&&b| b != 0).count();
+            NONZERO.store(n, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CheckWipe = CheckWipe;
+
+/// Memory lock which records the locked region and optionally fails.
+struct Watch {
+    fail: bool,
+}
+
+impl MemoryLock for Watch {
+    fn lock(&self, ptr: *const u8, _len: usize) -> io::Result<()> {
+        WATCH.store(ptr as usize, Ordering::SeqCst);
+        NONZERO.store(core::usize::MAX, Ordering::SeqCst);
+        if self.fail {
+            Err(io::Error::new(io::ErrorKind::Other, "lock failed"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn unlock(&self, ptr: *const u8, len: usize) {
+        // still allocated, but must already be wiped
+        let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+        assert!(bytes.iter().all(|&b| b == 0));
+    }
+}
+
+// both cases are checked in one test, as they share the watched address
+#[test]
+fn locked_wipes_key_schedule() {
+    let key = [0x42; 16];
+
+    let cipher = Locked::<Aes128, _>::from_secret(&key[..], Watch { fail: false }).unwrap();
+    drop(cipher);
+    assert_eq!(NONZERO.load(Ordering::SeqCst), 0);
+
+    match Locked::<Aes128, _>::from_secret(&key[..], Watch { fail: true }) {
+        Err(KeyProtectionError::Lock(_)) => (),
+        _ => panic!("expected lock error"),
+    }
+    assert_eq!(NONZERO.load(Ordering::SeqCst), 0);
+
+    let cipher = Aes128::new(&key.into());
+    assert!(Locked::new(cipher, Watch { fail: true }).is_err());
+    assert_eq!(NONZERO.load(Ordering::SeqCst), 0);
+}
@@ -113,7 +113,7 @@ impl<T: ByteOrder> NewBlockCipher for Blowfish<T> {
     type KeySize = U56;
 
     fn new(key: &GenericArray<u8, U56>) -> Self {
-        Self::new_from_slice(&key).unwrap()
+        Self::new_from_slice(key).unwrap()
     }
 
     fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
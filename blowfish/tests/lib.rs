@@ -1 +1,4 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 cipher::block_cipher_test!(blowfish_test, "blowfish", blowfish::Blowfish);
@@ -39,7 +39,7 @@ impl Cast5 {
     /// https://tools.ietf.org/html/rfc2144#section-2.4
     fn key_schedule(&mut self, key: &[u8]) {
         let mut x = [0; 4];
-        BigEndian::read_u32_into(&key, &mut x);
+        BigEndian::read_u32_into(key, &mut x);
 
         let mut z = [0u32; 4];
         let mut k = [0u32; 16];
@@ -87,7 +87,7 @@ impl NewBlockCipher for Cast5 {
     type KeySize = U16;
 
     fn new(key: &GenericArray<u8, U16>) -> Self {
-        Self::new_from_slice(&key).unwrap()
+        Self::new_from_slice(key).unwrap()
     }
 
     fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
@@ -1,3 +1,8 @@
+#![allow(clippy::clone_on_copy, clippy::needless_borrow)]
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use cast5::Cast5;
 use cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher};
 use hex_literal::hex;
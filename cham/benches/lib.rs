@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(cham::Cham128_128, 16);
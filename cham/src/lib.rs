@@ -0,0 +1,169 @@
+//! An implementation of the [CHAM][1] family of lightweight block ciphers.
+//!
+//! CHAM is a four-branch generalized Feistel network built only from
+//! modular additions, rotations and XORs (ARX). It uses no lookup tables and
+//! no data dependent branches, so the implementation runs in constant time.
+//! The following members of the family are provided:
+//!
+//! | Type            | Block size | Key size | Rounds |
+//! |-----------------|------------|----------|--------|
+//! | [`Cham64_128`]  | 64 bits    | 128 bits | 80     |
+//! | [`Cham128_128`] | 128 bits   | 128 bits | 80     |
+//!
+//! The round numbers are the ones of the original specification. Note that
+//! the revised version of CHAM published in 2019 increases them to 88 and 112
+//! respectively and is therefore not interoperable with these types.
+//!
+//! Blocks and keys are encoded as sequences of little-endian words.
+//!
+//! # Examples
+//! ```
+//! use cham::{
+//!     cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Cham64_128,
+//! };
+//!
+//! let key = GenericArray::from([
+//!     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+//!     0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+//! ]);
+//! let pt = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+//! let cipher = Cham64_128::new(&key);
+//! let mut block = GenericArray::from(pt);
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x3c, 0x45, 0xbc, 0x63, 0xfa, 0xdc, 0x4e, 0xbf]);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block[..], pt);
+//! ```
+//!
+//! [1]: https://doi.org/10.1007/978-3-319-78556-1_1
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U16, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::convert::TryInto;
+
+macro_rules! define_cham {
+    (
+        $name:ident, $doc:expr, $word:ty, $rounds:expr, $key_words:expr,
+        $block_size:ty, $key_size:ty
+    ) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone)]
+        pub struct $name {
+            round_keys: [$word; 2 * $key_words],
+        }
+
+        impl $name {
+            const WORD_BYTES: usize = core::mem::size_of::<$word>();
+
+            #[inline(always)]
+            fn read_block(block: &GenericArray<u8, $block_size>) -> [$word; 4] {
+                let mut x = [0; 4];
+                for (x, b) in x.iter_mut().zip(block.chunks_exact(Self::WORD_BYTES)) {
+                    *x = <$word>::from_le_bytes(b.try_into().unwrap());
+                }
+                x
+            }
+
+            #[inline(always)]
+            fn write_block(block: &mut GenericArray<u8, $block_size>, x: [$word; 4]) {
+                for (x, b) in x.iter().zip(block.chunks_exact_mut(Self::WORD_BYTES)) {
+                    b.copy_from_slice(&x.to_le_bytes());
+                }
+            }
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                let mut round_keys = [0; 2 * $key_words];
+                for (i, b) in key.chunks_exact(Self::WORD_BYTES).enumerate() {
+                    let k = <$word>::from_le_bytes(b.try_into().unwrap());
+                    let t = k ^ k.rotate_left(1);
+                    round_keys[i] = t ^ k.rotate_left(8);
+                    round_keys[(i + $key_words) ^ 1] = t ^ k.rotate_left(11);
+                }
+                Self { round_keys }
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = $block_size;
+            type ParBlocks = U1;
+        }
+
+        impl BlockEncrypt for $name {
+            fn encrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let [mut x0, mut x1, mut x2, mut x3] = Self::read_block(block);
+                let rk = &self.round_keys;
+                // the round constant is the round index, rounds alternate
+                // between the (1, 8) and (8, 1) rotation amounts
+                for i in (0..$rounds).step_by(2) {
+                    let k0 = rk[i % rk.len()];
+                    let k1 = rk[(i + 1) % rk.len()];
+                    let t = (x0 ^ i as $word).wrapping_add(x1.rotate_left(1) ^ k0);
+                    x0 = x1;
+                    x1 = x2;
+                    x2 = x3;
+                    x3 = t.rotate_left(8);
+                    let t = (x0 ^ (i + 1) as $word).wrapping_add(x1.rotate_left(8) ^ k1);
+                    x0 = x1;
+                    x1 = x2;
+                    x2 = x3;
+                    x3 = t.rotate_left(1);
+                }
+                Self::write_block(block, [x0, x1, x2, x3]);
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            fn decrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let [mut x0, mut x1, mut x2, mut x3] = Self::read_block(block);
+                let rk = &self.round_keys;
+                for i in (0..$rounds).step_by(2).rev() {
+                    let k0 = rk[i % rk.len()];
+                    let k1 = rk[(i + 1) % rk.len()];
+                    let t = x3.rotate_right(1).wrapping_sub(x0.rotate_left(8) ^ k1);
+                    x3 = x2;
+                    x2 = x1;
+                    x1 = x0;
+                    x0 = t ^ (i + 1) as $word;
+                    let t = x3.rotate_right(8).wrapping_sub(x0.rotate_left(1) ^ k0);
+                    x3 = x2;
+                    x2 = x1;
+                    x1 = x0;
+                    x0 = t ^ i as $word;
+                }
+                Self::write_block(block, [x0, x1, x2, x3]);
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+define_cham!(Cham64_128, "CHAM-64/128 block cipher.", u16, 80, 8, U8, U16);
+define_cham!(
+    Cham128_128,
+    "CHAM-128/128 block cipher.",
+    u32,
+    80,
+    4,
+    U16,
+    U16
+);
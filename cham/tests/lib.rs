@@ -0,0 +1,39 @@
+//! Test vectors are from "CHAM: A Family of Lightweight Block Ciphers for
+//! Resource-Constrained Devices", converted to little-endian byte order
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cham::{Cham128_128, Cham64_128};
+use cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+
+#[test]
+fn cham64_128() {
+    let key = hex!("000102030405060708090a0b0c0d0e0f");
+    let plaintext = hex!("0011223344556677");
+    let ciphertext = hex!("3c45bc63fadc4ebf");
+    let cipher = Cham64_128::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn cham128_128() {
+    let key = hex!("000102030405060708090a0b0c0d0e0f");
+    let plaintext = hex!("00112233445566778899aabbccddeeff");
+    let ciphertext = hex!("346074c3c50057b532ec648df7329348");
+    let cipher = Cham128_128::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
@@ -0,0 +1,356 @@
+//! Registry mapping standard algorithm identifiers to block cipher and mode
+//! of operation combinations implemented in this repository.
+//!
+//! Front-ends like PKCS#7/CMS or PKCS#11 usually receive an algorithm
+//! identifier together with a key and IV. This crate provides a single
+//! table which can be searched by [name][by_name], [OID][by_oid] or
+//! [IANA TLS cipher suite id][by_tls_id], and returns type-erased
+//! [`DynCipher`] instances.
+//!
+//! Block modes (ECB, CBC) use PKCS#7 padding as specified by CMS, with the
+//! exception of the GOST R 34.13-2015 CBC mode which uses the padding
+//! procedure 2 of that standard. CFB, OFB and CTR modes are used as stream
+//! ciphers, i.e. without padding.
+//!
+//! # Examples
+//! ```
+//! use hex_literal::hex;
+//!
+//! // id-aes128-CBC
+//! let alg = cipher_registry::by_oid("2.16.840.1.101.3.4.1.2").unwrap();
+//! assert_eq!(alg.name(), "AES-128-CBC");
+//!
+//! let key = hex!("000102030405060708090a0b0c0d0e0f");
+//! let iv = hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+//! let ct = alg.new_cipher(&key, &iv).unwrap().encrypt_vec(b"hello world");
+//! let pt = alg.new_cipher(&key, &iv).unwrap().decrypt_vec(&ct).unwrap();
+//! assert_eq!(pt, b"hello world");
+//! ```
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+extern crate alloc;
+
+use aes::{Aes128, Aes128Ctr, Aes192, Aes192Ctr, Aes256, Aes256Ctr};
+use alloc::{boxed::Box, vec::Vec};
+use block_modes::{
+    block_padding::{Padding, Pkcs7},
+    cipher::{
+        generic_array::typenum::Unsigned, AsyncStreamCipher, BlockCipher, BlockDecrypt,
+        BlockEncrypt, NewBlockCipher, NewCipher, StreamCipher,
+    },
+    BlockMode, Cbc, Ecb,
+};
+use cast5::Cast5;
+use core::{fmt, marker::PhantomData};
+use des::{Des, TdesEde3};
+use gost_modes::{GostCbc, GostCfb, GostCtr128, GostCtr64, GostOfb, GostPadding};
+use idea::Idea;
+use kuznyechik::Kuznyechik;
+use magma::Magma;
+use serpent::Serpent;
+use sm4::Sm4;
+use twofish::Twofish;
+
+pub use block_modes::{BlockModeError, InvalidKeyIvLength};
+
+/// Type-erased cipher instance initialized with key and IV.
+///
+/// Instances are single-use: both methods consume the whole message, as
+/// block modes have to apply padding to the final block.
+pub trait DynCipher {
+    /// Encrypt message, applying padding if required by the mode.
+    fn encrypt_vec(self: Box<Self>, plaintext: &[u8]) -> Vec<u8>;
+
+    /// Decrypt message and remove padding if required by the mode.
+    fn decrypt_vec(self: Box<Self>, ciphertext: &[u8]) -> Result<Vec<u8>, BlockModeError>;
+}
+
+struct BlockModeCipher<C, P, M> {
+    mode: M,
+    _pd: PhantomData<(C, P)>,
+}
+
+impl<C, P, M> DynCipher for BlockModeCipher<C, P, M>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    P: Padding,
+    M: BlockMode<C, P>,
+{
+    fn encrypt_vec(self: Box<Self>, plaintext: &[u8]) -> Vec<u8> {
+        self.mode.encrypt_vec(plaintext)
+    }
+
+    fn decrypt_vec(self: Box<Self>, ciphertext: &[u8]) -> Result<Vec<u8>, BlockModeError> {
+        self.mode.decrypt_vec(ciphertext)
+    }
+}
+
+struct StreamModeCipher<S>(S);
+
+impl<S: StreamCipher> DynCipher for StreamModeCipher<S> {
+    fn encrypt_vec(mut self: Box<Self>, plaintext: &[u8]) -> Vec<u8> {
+        let mut buf = plaintext.to_vec();
+        self.0.apply_keystream(&mut buf);
+        buf
+    }
+
+    fn decrypt_vec(mut self: Box<Self>, ciphertext: &[u8]) -> Result<Vec<u8>, BlockModeError> {
+        let mut buf = ciphertext.to_vec();
+        self.0.apply_keystream(&mut buf);
+        Ok(buf)
+    }
+}
+
+struct AsyncStreamModeCipher<S>(S);
+
+impl<S: AsyncStreamCipher> DynCipher for AsyncStreamModeCipher<S> {
+    fn encrypt_vec(mut self: Box<Self>, plaintext: &[u8]) -> Vec<u8> {
+        let mut buf = plaintext.to_vec();
+        self.0.encrypt(&mut buf);
+        buf
+    }
+
+    fn decrypt_vec(mut self: Box<Self>, ciphertext: &[u8]) -> Result<Vec<u8>, BlockModeError> {
+        let mut buf = ciphertext.to_vec();
+        self.0.decrypt(&mut buf);
+        Ok(buf)
+    }
+}
+
+type Constructor = fn(&[u8], &[u8]) -> Result<Box<dyn DynCipher>, InvalidKeyIvLength>;
+
+fn block_mode<C, P, M>(key: &[u8], iv: &[u8]) -> Result<Box<dyn DynCipher>, InvalidKeyIvLength>
+where
+    C: BlockCipher + NewBlockCipher + BlockEncrypt + BlockDecrypt + 'static,
+    P: Padding + 'static,
+    M: BlockMode<C, P> + 'static,
+{
+    let mode = M::new_from_slices(key, iv)?;
+    Ok(Box::new(BlockModeCipher {
+        mode,
+        _pd: PhantomData,
+    }))
+}
+
+fn stream_mode<S>(key: &[u8], iv: &[u8]) -> Result<Box<dyn DynCipher>, InvalidKeyIvLength>
+where
+    S: NewCipher + StreamCipher + 'static,
+{
+    let mode = S::new_from_slices(key, iv).map_err(|_| InvalidKeyIvLength)?;
+    Ok(Box::new(StreamModeCipher(mode)))
+}
+
+fn async_stream_mode<S>(key: &[u8], iv: &[u8]) -> Result<Box<dyn DynCipher>, InvalidKeyIvLength>
+where
+    S: NewCipher + AsyncStreamCipher + 'static,
+{
+    let mode = S::new_from_slices(key, iv).map_err(|_| InvalidKeyIvLength)?;
+    Ok(Box::new(AsyncStreamModeCipher(mode)))
+}
+
+/// Block cipher and mode of operation combination.
+pub struct Algorithm {
+    name: &'static str,
+    oid: Option<&'static str>,
+    tls_ids: &'static [u16],
+    key_size: usize,
+    iv_size: usize,
+    new: Constructor,
+}
+
+impl Algorithm {
+    /// Canonical name, e.g. `AES-128-CBC`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Object identifier in dotted decimal notation, if one is assigned.
+    pub fn oid(&self) -> Option<&'static str> {
+        self.oid
+    }
+
+    /// IANA TLS cipher suite ids using this algorithm for bulk encryption.
+    pub fn tls_ids(&self) -> &'static [u16] {
+        self.tls_ids
+    }
+
+    /// Key size in bytes.
+    pub fn key_size(&self) -> usize {
+        self.key_size
+    }
+
+    /// IV (or nonce) size in bytes, zero for modes without IV.
+    pub fn iv_size(&self) -> usize {
+        self.iv_size
+    }
+
+    /// Initialize cipher instance with key and IV.
+    pub fn new_cipher(
+        &self,
+        key: &[u8],
+        iv: &[u8],
+    ) -> Result<Box<dyn DynCipher>, InvalidKeyIvLength> {
+        (self.new)(key, iv)
+    }
+}
+
+impl fmt::Debug for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Algorithm")
+            .field("name", &self.name)
+            .field("oid", &self.oid)
+            .field("tls_ids", &self.tls_ids)
+            .finish()
+    }
+}
+
+macro_rules! block_mode {
+    ($name:expr, $oid:expr, $tls:expr, $cipher:ty, $padding:ty, $mode:ty) => {
+        Algorithm {
+            name: $name,
+            oid: $oid,
+            tls_ids: $tls,
+            key_size: <$cipher as NewBlockCipher>::KeySize::USIZE,
+            iv_size: <$mode as BlockMode<$cipher, $padding>>::IvSize::USIZE,
+            new: block_mode::<$cipher, $padding, $mode>,
+        }
+    };
+}
+
+macro_rules! stream_mode {
+    ($name:expr, $oid:expr, $tls:expr, $mode:ty, $ctor:ident) => {
+        Algorithm {
+            name: $name,
+            oid: $oid,
+            tls_ids: $tls,
+            key_size: <$mode as NewCipher>::KeySize::USIZE,
+            iv_size: <$mode as NewCipher>::NonceSize::USIZE,
+            new: $ctor::<$mode>,
+        }
+    };
+}
+
+macro_rules! ecb {
+    ($name:expr, $oid:expr, $cipher:ty) => {
+        block_mode!($name, $oid, &[], $cipher, Pkcs7, Ecb<$cipher, Pkcs7>)
+    };
+}
+
+macro_rules! cbc {
+    ($name:expr, $oid:expr, $tls:expr, $cipher:ty) => {
+        block_mode!($name, $oid, $tls, $cipher, Pkcs7, Cbc<$cipher, Pkcs7>)
+    };
+}
+
+macro_rules! ofb {
+    ($name:expr, $oid:expr, $cipher:ty) => {
+        stream_mode!($name, $oid, &[], GostOfb<$cipher>, stream_mode)
+    };
+}
+
+macro_rules! cfb {
+    ($name:expr, $oid:expr, $cipher:ty) => {
+        stream_mode!($name, $oid, &[], GostCfb<$cipher>, async_stream_mode)
+    };
+}
+
+/// All registered algorithms.
+pub static ALGORITHMS: &[Algorithm] = &[
+    // NIST CSOR, RFC 3565
+    ecb!("AES-128-ECB", Some("2.16.840.1.101.3.4.1.1"), Aes128),
+    cbc!(
+        "AES-128-CBC",
+        Some("2.16.840.1.101.3.4.1.2"),
+        &[0x002f, 0x003c],
+        Aes128
+    ),
+    ofb!("AES-128-OFB", Some("2.16.840.1.101.3.4.1.3"), Aes128),
+    cfb!("AES-128-CFB", Some("2.16.840.1.101.3.4.1.4"), Aes128),
+    stream_mode!("AES-128-CTR", None, &[], Aes128Ctr, stream_mode),
+    ecb!("AES-192-ECB", Some("2.16.840.1.101.3.4.1.21"), Aes192),
+    cbc!("AES-192-CBC", Some("2.16.840.1.101.3.4.1.22"), &[], Aes192),
+    ofb!("AES-192-OFB", Some("2.16.840.1.101.3.4.1.23"), Aes192),
+    cfb!("AES-192-CFB", Some("2.16.840.1.101.3.4.1.24"), Aes192),
+    stream_mode!("AES-192-CTR", None, &[], Aes192Ctr, stream_mode),
+    ecb!("AES-256-ECB", Some("2.16.840.1.101.3.4.1.41"), Aes256),
+    cbc!(
+        "AES-256-CBC",
+        Some("2.16.840.1.101.3.4.1.42"),
+        &[0x0035, 0x003d],
+        Aes256
+    ),
+    ofb!("AES-256-OFB", Some("2.16.840.1.101.3.4.1.43"), Aes256),
+    cfb!("AES-256-CFB", Some("2.16.840.1.101.3.4.1.44"), Aes256),
+    stream_mode!("AES-256-CTR", None, &[], Aes256Ctr, stream_mode),
+    // RFC 2144, RFC 2984
+    cbc!("CAST5-CBC", Some("1.2.840.113533.7.66.10"), &[], Cast5),
+    // OIW, RFC 3370
+    cbc!("DES-CBC", Some("1.3.14.3.2.7"), &[0x0009], Des),
+    cbc!(
+        "DES-EDE3-CBC",
+        Some("1.2.840.113549.3.7"),
+        &[0x000a],
+        TdesEde3
+    ),
+    // RFC 3058
+    cbc!("IDEA-CBC", Some("1.3.6.1.4.1.188.7.1.1.2"), &[0x0007], Idea),
+    // GOST R 34.13-2015, RFC 9189
+    block_mode!(
+        "Kuznyechik-CBC",
+        None,
+        &[],
+        Kuznyechik,
+        GostPadding,
+        GostCbc<Kuznyechik, GostPadding>
+    ),
+    stream_mode!(
+        "Kuznyechik-CTR",
+        None,
+        &[0xc100],
+        GostCtr128<Kuznyechik>,
+        stream_mode
+    ),
+    ofb!("Kuznyechik-OFB", None, Kuznyechik),
+    cfb!("Kuznyechik-CFB", None, Kuznyechik),
+    block_mode!(
+        "Magma-CBC",
+        None,
+        &[],
+        Magma,
+        GostPadding,
+        GostCbc<Magma, GostPadding>
+    ),
+    stream_mode!("Magma-CTR", None, &[0xc101], GostCtr64<Magma>, stream_mode),
+    ofb!("Magma-OFB", None, Magma),
+    cfb!("Magma-CFB", None, Magma),
+    cbc!("Serpent-CBC", None, &[], Serpent),
+    // GM/T 0006-2012
+    ecb!("SM4-ECB", Some("1.2.156.10197.1.104.1"), Sm4),
+    cbc!("SM4-CBC", Some("1.2.156.10197.1.104.2"), &[], Sm4),
+    ofb!("SM4-OFB", Some("1.2.156.10197.1.104.3"), Sm4),
+    cfb!("SM4-CFB", Some("1.2.156.10197.1.104.4"), Sm4),
+    cbc!("Twofish-CBC", None, &[], Twofish),
+];
+
+/// Find algorithm by its canonical name, ignoring ASCII case.
+pub fn by_name(name: &str) -> Option<&'static Algorithm> {
+    ALGORITHMS
+        .iter()
+        .find(|a| a.name.eq_ignore_ascii_case(name))
+}
+
+/// Find algorithm by OID in dotted decimal notation.
+pub fn by_oid(oid: &str) -> Option<&'static Algorithm> {
+    ALGORITHMS.iter().find(|a| a.oid == Some(oid))
+}
+
+/// Find bulk encryption algorithm of an IANA TLS cipher suite.
+pub fn by_tls_id(id: u16) -> Option<&'static Algorithm> {
+    ALGORITHMS.iter().find(|a| a.tls_ids.contains(&id))
+}
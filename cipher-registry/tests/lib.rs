@@ -0,0 +1,95 @@
+//! Test vectors from NIST SP 800-38A
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher_registry::{by_name, by_oid, by_tls_id, ALGORITHMS};
+use hex_literal::hex;
+
+const KEY: [u8; 16] = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+const IV: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+const PT: [u8; 64] = hex!(
+    "
+    6bc1bee22e409f96e93d7e117393172a
+    ae2d8a571e03ac9c9eb76fac45af8e51
+    30c81c46a35ce411e5fbc1191a0a52ef
+    f69f2445df4f9b17ad2b417be66c3710
+    "
+);
+
+fn check(oid: &str, name: &str, ct: &[u8]) {
+    let alg = by_oid(oid).unwrap();
+    assert_eq!(alg.name(), name);
+    assert_eq!(by_name(name).unwrap().oid(), Some(oid));
+
+    let res = alg.new_cipher(&KEY, &IV).unwrap().encrypt_vec(&PT);
+    assert_eq!(&res[..64], ct);
+    let res = alg
+        .new_cipher(&KEY, &IV)
+        .unwrap()
+        .decrypt_vec(&res)
+        .unwrap();
+    assert_eq!(res, &PT[..]);
+}
+
+#[test]
+fn aes128_nist() {
+    check(
+        "2.16.840.1.101.3.4.1.2",
+        "AES-128-CBC",
+        &hex!(
+            "
+            7649abac8119b246cee98e9b12e9197d
+            5086cb9b507219ee95db113a917678b2
+            73bed6b8e3c1743b7116e69e22229516
+            3ff1caa1681fac09120eca307586e1a7
+            "
+        ),
+    );
+    check(
+        "2.16.840.1.101.3.4.1.3",
+        "AES-128-OFB",
+        &hex!(
+            "
+            3b3fd92eb72dad20333449f8e83cfb4a
+            7789508d16918f03f53c52dac54ed825
+            9740051e9c5fecf64344f7a82260edcc
+            304c6528f659c77866a510d9c1d6ae5e
+            "
+        ),
+    );
+    check(
+        "2.16.840.1.101.3.4.1.4",
+        "AES-128-CFB",
+        &hex!(
+            "
+            3b3fd92eb72dad20333449f8e83cfb4a
+            c8a64537a0b3a93fcde3cdad9f1ce58b
+            26751f67a3cbb140b1808cf187a4f4df
+            c04b05357c5d1c0eeac4c66f9ff7f2e6
+            "
+        ),
+    );
+}
+
+#[test]
+fn lookups() {
+    assert_eq!(by_tls_id(0x002f).unwrap().name(), "AES-128-CBC");
+    assert_eq!(by_tls_id(0xc100).unwrap().name(), "Kuznyechik-CTR");
+    assert!(by_tls_id(0x1301).is_none());
+    assert_eq!(by_name("magma-cfb").unwrap().key_size(), 32);
+    assert!(by_oid("1.2.3.4").is_none());
+
+    for alg in ALGORITHMS {
+        let key = vec![0x42; alg.key_size()];
+        let iv = vec![0x24; alg.iv_size()];
+        let ct = alg
+            .new_cipher(&key, &iv)
+            .unwrap()
+            .encrypt_vec(b"test message");
+        let pt = alg.new_cipher(&key, &iv).unwrap().decrypt_vec(&ct).unwrap();
+        assert_eq!(pt, b"test message", "{}", alg.name());
+        if !iv.is_empty() {
+            assert!(alg.new_cipher(&key, &iv[1..]).is_err());
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Generic implementation of the [CMC][1] (CBC-Mask-CBC) wide-block
+//! encryption mode by Halevi and Rogaway.
+//!
+//! CMC turns a block cipher into a tweakable pseudorandom permutation over a
+//! whole sector: every bit of the ciphertext depends on every bit of the
+//! plaintext and of the tweak. It is length-preserving and consists of a CBC
+//! encryption pass, a masking step and a second CBC encryption pass over the
+//! blocks in reverse order. Compared to EME it is inherently sequential, but
+//! works with any block size and requires no key material besides the block
+//! cipher keys.
+//!
+//! The mode key consists of the block cipher key and a second key for the
+//! cipher used to encrypt the tweak, which is a single block.
+//!
+//! Only data consisting of whole blocks is supported, which covers sector
+//! encryption. The mask is computed in `GF(2^n)`, which is implemented for
+//! block sizes of 32, 48, 64, 128, 256, 512 and 1024 bits.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use cmc::Cmc;
+//!
+//! let cmc = Cmc::new(Aes128::new(&[0x42; 16].into()), Aes128::new(&[0x24; 16].into()));
+//!
+//! let mut sector = [0u8; 512];
+//! let tweak = 7u128.to_le_bytes().into();
+//! cmc.encrypt(&tweak, &mut sector).unwrap();
+//! cmc.decrypt(&tweak, &mut sector).unwrap();
+//! assert_eq!(sector, [0u8; 512]);
+//! ```
+//!
+//! [1]: https://eprint.iacr.org/2003/148
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use block_modes::gf128;
+use cipher::{
+    errors::InvalidLength,
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockDecrypt, BlockEncrypt,
+};
+
+/// Block of the underlying cipher.
+pub type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
+
+/// Low terms of the irreducible polynomial used for doubling blocks of the
+/// given size in bytes.
+fn reduction(block_size: usize) -> Option<u32> {
+    Some(match block_size {
+        // x^32 + x^7 + x^3 + x^2 + 1
+        4 => 0x8d,
+        // x^48 + x^5 + x^3 + x^2 + 1
+        6 => 0x2d,
+        // x^64 + x^4 + x^3 + x + 1
+        8 => 0x1b,
+        // x^128 + x^7 + x^2 + x + 1
+        16 => 0x87,
+        // x^256 + x^10 + x^5 + x^2 + 1
+        32 => 0x425,
+        // x^512 + x^8 + x^5 + x^2 + 1
+        64 => 0x125,
+        // x^1024 + x^19 + x^6 + x + 1
+        128 => 0x8_0043,
+        _ => return None,
+    })
+}
+
+/// Multiply `block` by `x` in `GF(2^n)` with big-endian bit order in
+/// constant time.
+fn double(block: &mut [u8], r: u32) {
+    if block.len() == 16 {
+        gf128::double_be(GenericArray::from_mut_slice(block));
+        return;
+    }
+
+    // 0xff if the highest coefficient is set, 0 otherwise
+    let carry = 0u8.wrapping_sub(block[0] >> 7);
+    for i in 0..block.len() - 1 {
+        block[i] = (block[i] << 1) | (block[i + 1] >> 7);
+    }
+    let n = block.len();
+    block[n - 1] <<= 1;
+    for (b, r) in block.iter_mut().rev().zip(r.to_le_bytes().iter()) {
+        *b ^= r & carry;
+    }
+}
+
+#[inline(always)]
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= b;
+    }
+}
+
+/// CMC wide-block encryption mode instance.
+#[derive(Clone)]
+pub struct Cmc<C: BlockCipher> {
+    cipher: C,
+    tweak_cipher: C,
+    reduction: u32,
+}
+
+impl<C> Cmc<C>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+{
+    /// Create mode instance from the data `cipher` and the `tweak_cipher`
+    /// used to encrypt tweaks, initialized with independent keys.
+    ///
+    /// # Panics
+    /// If the block size of `C` is not supported, see the crate
+    /// documentation.
+    pub fn new(cipher: C, tweak_cipher: C) -> Self {
+        let reduction = reduction(C::BlockSize::to_usize()).expect("unsupported block size");
+        Self {
+            cipher,
+            tweak_cipher,
+            reduction,
+        }
+    }
+
+    /// Get reference to the data cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Get reference to the tweak cipher.
+    pub fn tweak_cipher(&self) -> &C {
+        &self.tweak_cipher
+    }
+
+    /// Encrypt `data` in place under `tweak`.
+    ///
+    /// Returns [`InvalidLength`] if `data` is empty or not a multiple of
+    /// the block size.
+    pub fn encrypt(&self, tweak: &Block<C>, data: &mut [u8]) -> Result<(), InvalidLength> {
+        self.check_len(data)?;
+        self.process(tweak, data, |c, b| c.encrypt_block(b));
+        Ok(())
+    }
+
+    /// Decrypt `data` in place under `tweak`.
+    ///
+    /// Returns [`InvalidLength`] if `data` is empty or not a multiple of
+    /// the block size.
+    pub fn decrypt(&self, tweak: &Block<C>, data: &mut [u8]) -> Result<(), InvalidLength> {
+        self.check_len(data)?;
+        self.process(tweak, data, |c, b| c.decrypt_block(b));
+        Ok(())
+    }
+
+    fn check_len(&self, data: &[u8]) -> Result<(), InvalidLength> {
+        if data.is_empty() || data.len() % C::BlockSize::to_usize() != 0 {
+            Err(InvalidLength)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// CMC is symmetric: decryption is encryption with the inverse block
+    /// cipher (the tweak is still encrypted), so both directions share this
+    /// function.
+    fn process(&self, tweak: &Block<C>, data: &mut [u8], f: impl Fn(&C, &mut Block<C>)) {
+        let bs = C::BlockSize::to_usize();
+        let mut t = tweak.clone();
+        self.tweak_cipher.encrypt_block(&mut t);
+
+        // first CBC pass: PPP_i = E(P_i ^ PPP_{i-1}) with PPP_0 = T'
+        let mut prev = t.clone();
+        for chunk in data.chunks_exact_mut(bs) {
+            let block = GenericArray::from_mut_slice(chunk);
+            xor(block, &prev);
+            f(&self.cipher, block);
+            prev = block.clone();
+        }
+
+        // mask: M = 2 * (PPP_1 ^ PPP_m)
+        let mut m = prev;
+        xor(&mut m, &data[..bs]);
+        double(&mut m, self.reduction);
+
+        // reverse the order of the blocks: CCC_i = PPP_{m+1-i} ^ M
+        data.reverse();
+        for chunk in data.chunks_exact_mut(bs) {
+            chunk.reverse();
+            xor(chunk, &m);
+        }
+
+        // second CBC pass: C_i = E(CCC_i) ^ CCC_{i-1} with CCC_0 = 0
+        let mut prev = Block::<C>::default();
+        for chunk in data.chunks_exact_mut(bs) {
+            let block = GenericArray::from_mut_slice(chunk);
+            let ccc = block.clone();
+            f(&self.cipher, block);
+            xor(block, &prev);
+            prev = ccc;
+        }
+        xor(&mut data[..bs], &t);
+    }
+}
+
+impl<C: BlockCipher> core::fmt::Debug for Cmc<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Cmc { ... }")
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, BlockEncrypt, NewBlockCipher};
+use cipher::generic_array::GenericArray;
+use cmc::Cmc;
+use des::Des;
+use hex_literal::hex;
+
+fn cmc() -> Cmc<Aes128> {
+    Cmc::new(
+        Aes128::new(&[0x42; 16].into()),
+        Aes128::new(&[0x24; 16].into()),
+    )
+}
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 + i / 256) as u8).collect()
+}
+
+/// Known-answer tests computed with an independent Python implementation of
+/// CMC, written from the algorithm description in the Halevi–Rogaway paper
+/// and using AES-128 from pyca/cryptography 48.0 (OpenSSL 3.5). The keys are
+/// `00..0f` for the block cipher and `10..1f` for the tweak cipher.
+///
+/// (tweak, plaintext, ciphertext), the 512-byte plaintext is `data(512)`
+const VECTORS: &[(&[u8; 16], &[u8], &[u8])] = &[
+    (
+        &hex!("00000000000000000000000000000000"),
+        &hex!("00070e151c232a31383f464d545b6269"),
+        &hex!("1c474f606b4b98d5d18d8cf3d3d7d097"),
+    ),
+    (
+        &hex!("202122232425262728292a2b2c2d2e2f"),
+        &hex!("00070e151c232a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c737a81888f969da4abb2b9"),
+        &hex!("48ed333e455a3cb7fd0ca0f18c5ac56c8daaa498c0c2f299d1f908ba9adc929bdecff68d206d02262663763e484982dc094026dbdab2a039d03c0dbc19159051"),
+    ),
+];
+
+const SECTOR_TWEAK: [u8; 16] = hex!("07000000000000000000000000000000");
+const SECTOR_CT: [u8; 512] = hex!("2ea09b9c5e0dd1505dfb60fdccdbb0bedef44fa3dbf13b4ae16c644c6cbb424ce564cf2a09c58daf5bec879194d016542ae66d99a1c3e41adcd2447d45a2f4cae3a6c270ec5c241aa12fd79b8899cc9e1ccd1fa2f7bf7dad2e683de461a13d596c4c40268ee39e639db86d216117a66b26421cd60bd8cf619583de8fa244ed1d88f016a92612b693db3cc9d2f8aff88eac5f3c35f26798e4425267194eacdd509b78d8b55b1c7d8b20c7f84e883decb1f9d577fa97267b41eaffff1ccf21637e651d10299f8c57c9c4806a2d652e63817518a11fdcb2701d54e2c72bfe7a06fd1f089b0fff7c0fadf8164413b9e4c9b413cfd63dfbe215bb821b6f37cc804d55e33dc04ee72cc5706da5f43a80ffb5825d071c06f744caceb2f79d45683f247fe56e459e951b79889662891c9ad01066c64a20b2ef96e37e99c3907139e10be76636c5640459a2c324884ad77c5c5e810682c89c0a8686bbeeb9652587533d0c59b370853bce868cd1cf9dc7968ca75e7d047067b5e4263983e5fdd4be242646f6a0e12d6bee7e3c6b0495e828b3f9401c18d5c54fb5690af9c315cac528ad174c595d2bb0104a0dc3a44cf847431d6eb8137ae48d0bb005853fe9aa0de1674300d15f7d2b67983d960f805981a5971a8b814a4b902e8ce716ff339c74a2dd28e945ca8c3ef3394692f2839dc66a81e7b31811490d2696552feba4886c02aea8");
+
+#[test]
+fn aes128_kat() {
+    let key: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    let cmc = Cmc::new(Aes128::new(key[..16].into()), Aes128::new(key[16..].into()));
+
+    let sector = data(512);
+    let sector_vector = (&SECTOR_TWEAK, &sector[..], &SECTOR_CT[..]);
+    for &(tweak, pt, ct) in VECTORS.iter().chain(Some(&sector_vector)) {
+        let tweak = GenericArray::from(*tweak);
+        let mut buf = pt.to_vec();
+        cmc.encrypt(&tweak, &mut buf).unwrap();
+        assert_eq!(buf, ct);
+        cmc.decrypt(&tweak, &mut buf).unwrap();
+        assert_eq!(buf, pt);
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let cmc = cmc();
+    for &blocks in [1, 2, 3, 32, 33].iter() {
+        let tweak = GenericArray::from([blocks as u8; 16]);
+        let pt = data(16 * blocks);
+        let mut buf = pt.clone();
+        cmc.encrypt(&tweak, &mut buf).unwrap();
+        assert_ne!(buf, pt);
+        cmc.decrypt(&tweak, &mut buf).unwrap();
+        assert_eq!(buf, pt);
+    }
+}
+
+#[test]
+fn roundtrip_64bit_blocks() {
+    let cmc = Cmc::new(Des::new(&[1; 8].into()), Des::new(&[2; 8].into()));
+    let tweak = GenericArray::from([3; 8]);
+    let pt = data(512);
+    let mut buf = pt.clone();
+    cmc.encrypt(&tweak, &mut buf).unwrap();
+    assert_ne!(buf, pt);
+    cmc.decrypt(&tweak, &mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+/// For a single block the mask is zero and CMC reduces to
+/// `E(E(P ^ T')) ^ T'` with `T' = E'(T)`.
+#[test]
+fn single_block() {
+    let cmc = cmc();
+    let tweak = GenericArray::from([0x11; 16]);
+    let mut t = tweak;
+    cmc.tweak_cipher().encrypt_block(&mut t);
+
+    let mut expected = GenericArray::from([0x5a; 16]);
+    for (a, b) in expected.iter_mut().zip(t.iter()) {
+        *a ^= b;
+    }
+    cmc.cipher().encrypt_block(&mut expected);
+    cmc.cipher().encrypt_block(&mut expected);
+    for (a, b) in expected.iter_mut().zip(t.iter()) {
+        *a ^= b;
+    }
+
+    let mut buf = [0x5a; 16];
+    cmc.encrypt(&tweak, &mut buf).unwrap();
+    assert_eq!(buf[..], expected[..]);
+}
+
+#[test]
+fn wide_block_diffusion() {
+    let cmc = cmc();
+    let tweak = GenericArray::from([0x11; 16]);
+    let pt = data(4096);
+    let mut a = pt.clone();
+    cmc.encrypt(&tweak, &mut a).unwrap();
+
+    // flipping the last plaintext bit changes every ciphertext block
+    let mut b = pt.clone();
+    *b.last_mut().unwrap() ^= 1;
+    cmc.encrypt(&tweak, &mut b).unwrap();
+    for (x, y) in a.chunks(16).zip(b.chunks(16)) {
+        assert_ne!(x, y);
+    }
+
+    // and so does changing the tweak
+    let mut c = pt;
+    cmc.encrypt(&GenericArray::from([0x12; 16]), &mut c)
+        .unwrap();
+    for (x, y) in a.chunks(16).zip(c.chunks(16)) {
+        assert_ne!(x, y);
+    }
+}
+
+#[test]
+fn invalid_length() {
+    let cmc = cmc();
+    let tweak = GenericArray::default();
+    assert!(cmc.encrypt(&tweak, &mut []).is_err());
+    assert!(cmc.encrypt(&tweak, &mut [0; 17]).is_err());
+    assert!(cmc.decrypt(&tweak, &mut [0; 31]).is_err());
+}
@@ -0,0 +1,254 @@
+//! Additional counter flavors for the [`ctr`] crate.
+//!
+//! Unlike the flavors of the `ctr` crate, which operate on 32-bit chunks,
+//! these keep the initial counter block as is and only add the counter to
+//! the low byte or word, so they work with any block size. [`CustomCtr`]
+//! generalizes this to counters of 1 to 8 bytes at an arbitrary offset.
+
+use cipher::generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use core::{convert::TryInto, fmt, marker::PhantomData};
+use ctr::flavors::CtrFlavor;
+
+macro_rules! impl_flavor {
+    (
+        $name:ident, $backend:ty, $size:expr,
+        $range:expr, $from_bytes:ident, $to_bytes:ident, $doc:expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Default, Copy, Clone, Debug)]
+        #[repr(transparent)]
+        pub struct $name($backend);
+
+        impl<B: ArrayLength<u8>> CtrFlavor<B> for $name {
+            type Nonce = GenericArray<u8, B>;
+            type Backend = $backend;
+
+            #[inline]
+            fn generate_block(&self, nonce: &Self::Nonce) -> GenericArray<u8, B> {
+                let mut block = nonce.clone();
+                let range = $range(block.len());
+                let ctr = <$backend>::$from_bytes(block[range.clone()].try_into().unwrap());
+                block[range].copy_from_slice(&ctr.wrapping_add(self.0).$to_bytes());
+                block
+            }
+
+            #[inline]
+            fn load(block: &GenericArray<u8, B>) -> Self::Nonce {
+                assert!(block.len() >= $size, "block is smaller than the counter");
+                block.clone()
+            }
+
+            #[inline]
+            fn checked_add(&self, rhs: usize) -> Option<Self> {
+                rhs.try_into()
+                    .ok()
+                    .and_then(|rhs| self.0.checked_add(rhs))
+                    .map(Self)
+            }
+
+            #[inline]
+            fn increment(&mut self) {
+                self.0 = self.0.wrapping_add(1);
+            }
+
+            #[inline]
+            fn to_backend(&self) -> Self::Backend {
+                self.0
+            }
+
+            #[inline]
+            fn from_backend(v: Self::Backend) -> Self {
+                Self(v)
+            }
+        }
+    };
+}
+
+impl_flavor!(
+    Ctr8,
+    u8,
+    1,
+    |n: usize| n - 1..n,
+    from_be_bytes,
+    to_be_bytes,
+    "8-bit counter flavor, incrementing the last byte of the block."
+);
+impl_flavor!(
+    Ctr16BE,
+    u16,
+    2,
+    |n: usize| n - 2..n,
+    from_be_bytes,
+    to_be_bytes,
+    "16-bit big endian counter flavor, incrementing the last two bytes of the block."
+);
+impl_flavor!(
+    Ctr16LE,
+    u16,
+    2,
+    |_| 0..2,
+    from_le_bytes,
+    to_le_bytes,
+    "16-bit little endian counter flavor, incrementing the first two bytes of the block."
+);
+
+/// Byte order of a [`CustomCtr`] counter.
+pub trait Endianness {
+    /// Read counter from `bytes`.
+    fn read(bytes: &[u8]) -> u64;
+    /// Write counter to `bytes`, truncating it to their length.
+    fn write(v: u64, bytes: &mut [u8]);
+}
+
+/// Big endian counter byte order.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct BigEndian;
+
+impl Endianness for BigEndian {
+    fn read(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0, |v, &b| (v << 8) | b as u64)
+    }
+
+    fn write(mut v: u64, bytes: &mut [u8]) {
+        for b in bytes.iter_mut().rev() {
+            *b = v as u8;
+            v >>= 8;
+        }
+    }
+}
+
+/// Little endian counter byte order.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct LittleEndian;
+
+impl Endianness for LittleEndian {
+    fn read(bytes: &[u8]) -> u64 {
+        bytes.iter().rev().fold(0, |v, &b| (v << 8) | b as u64)
+    }
+
+    fn write(mut v: u64, bytes: &mut [u8]) {
+        for b in bytes.iter_mut() {
+            *b = v as u8;
+            v >>= 8;
+        }
+    }
+}
+
+/// User-defined counter flavor with a counter of `W` bytes (1 to 8) at the
+/// byte offset `O` of the block, stored in byte order `E`.
+///
+/// The remaining bytes of the initial counter block are kept as is and the
+/// counter wraps within its width, e.g. `CustomCtr<U2, U14, BigEndian>` is
+/// equivalent to [`Ctr16BE`] for 16 byte blocks.
+///
+/// ```
+/// use aes::Aes128;
+/// use ctr_flavors::{
+///     cipher::{consts::{U3, U4}, NewCipher, StreamCipher},
+///     ctr::Ctr,
+///     flavors::{CustomCtr, LittleEndian},
+/// };
+///
+/// // 24-bit little endian counter in bytes 4..7
+/// type MyCtr = Ctr<Aes128, CustomCtr<U3, U4, LittleEndian>>;
+///
+/// let mut ctr = MyCtr::new(&[0x42; 16].into(), &[0x24; 16].into());
+/// let mut buf = [0u8; 40];
+/// ctr.apply_keystream(&mut buf);
+/// ```
+pub struct CustomCtr<W, O, E = BigEndian> {
+    ctr: u64,
+    _p: PhantomData<(W, O, E)>,
+}
+
+impl<W: Unsigned, O: Unsigned, E> CustomCtr<W, O, E> {
+    fn max_value() -> u64 {
+        u64::max_value() >> (64 - 8 * W::U32)
+    }
+}
+
+impl<W, O, E> Default for CustomCtr<W, O, E> {
+    fn default() -> Self {
+        Self {
+            ctr: 0,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<W, O, E> Clone for CustomCtr<W, O, E> {
+    fn clone(&self) -> Self {
+        Self {
+            ctr: self.ctr,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<W, O, E> fmt::Debug for CustomCtr<W, O, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("CustomCtr { ... }")
+    }
+}
+
+impl<B, W, O, E> CtrFlavor<B> for CustomCtr<W, O, E>
+where
+    B: ArrayLength<u8>,
+    W: Unsigned,
+    O: Unsigned,
+    E: Endianness,
+{
+    type Nonce = GenericArray<u8, B>;
+    type Backend = u64;
+
+    #[inline]
+    fn generate_block(&self, nonce: &Self::Nonce) -> GenericArray<u8, B> {
+        let mut block = nonce.clone();
+        let range = O::USIZE..O::USIZE + W::USIZE;
+        let ctr = E::read(&block[range.clone()]).wrapping_add(self.ctr);
+        E::write(ctr, &mut block[range]);
+        block
+    }
+
+    #[inline]
+    fn load(block: &GenericArray<u8, B>) -> Self::Nonce {
+        assert!(
+            W::USIZE >= 1 && W::USIZE <= 8,
+            "counter width must be between 1 and 8 bytes"
+        );
+        assert!(
+            O::USIZE + W::USIZE <= block.len(),
+            "counter does not fit into the block"
+        );
+        block.clone()
+    }
+
+    #[inline]
+    fn checked_add(&self, rhs: usize) -> Option<Self> {
+        (rhs as u64)
+            .checked_add(self.ctr)
+            .filter(|&v| v <= Self::max_value())
+            .map(|ctr| Self {
+                ctr,
+                _p: PhantomData,
+            })
+    }
+
+    #[inline]
+    fn increment(&mut self) {
+        self.ctr = self.ctr.wrapping_add(1) & Self::max_value();
+    }
+
+    #[inline]
+    fn to_backend(&self) -> Self::Backend {
+        self.ctr
+    }
+
+    #[inline]
+    fn from_backend(v: Self::Backend) -> Self {
+        Self {
+            ctr: v,
+            _p: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Generic implementation of [counter (CTR) mode][1] variants with custom
+//! counter layouts.
+//!
+//! The `ctr` crate treats the whole block (or a fixed 32, 64 or 128-bit
+//! part of it) as counter state. This crate covers layouts used by
+//! protocols and hardware which do not fit those, e.g. [`NonceCtr`], which
+//! splits the IV into an explicit nonce and a big-endian counter of the
+//! remaining bytes, as in RFC 3686 (IPsec), and the 8 and 16-bit counter
+//! [`flavors`] for [`ctr::Ctr`], e.g. for constrained protocols which only
+//! increment the low byte or word of the IV.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use ctr_flavors::{cipher::StreamCipher, Rfc3686Ctr};
+//! use hex_literal::hex;
+//!
+//! let cipher = Aes128::new(&hex!("ae6852f8121067cc4bf7a5765577f39e").into());
+//! let mut ctr = Rfc3686Ctr::new_rfc3686(cipher, &hex!("00000030"), &[0; 8]);
+//!
+//! let mut buf = *b"Single block msg";
+//! ctr.apply_keystream(&mut buf);
+//! assert_eq!(buf, hex!("e4095d4fb7a7b3792d6175a3261311b8"));
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#Counter_(CTR)
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+pub use ctr;
+
+pub mod flavors;
+mod nonce;
+
+pub use nonce::{NonceCtr, Rfc3686Ctr};
+
+/// CTR mode with 8-bit counter.
+pub type Ctr8<B> = ctr::Ctr<B, flavors::Ctr8>;
+/// CTR mode with 16-bit big endian counter.
+pub type Ctr16BE<B> = ctr::Ctr<B, flavors::Ctr16BE>;
+/// CTR mode with 16-bit little endian counter.
+pub type Ctr16LE<B> = ctr::Ctr<B, flavors::Ctr16LE>;
+
+#[inline(always)]
+pub(crate) fn xor(buf: &mut [u8], key: &[u8]) {
+    debug_assert_eq!(buf.len(), key.len());
+    for (a, b) in buf.iter_mut().zip(key) {
+        *a ^= *b;
+    }
+}
@@ -0,0 +1,223 @@
+use crate::xor;
+use cipher::{
+    errors::{LoopError, OverflowError},
+    generic_array::{
+        typenum::{Unsigned, U12},
+        ArrayLength, GenericArray,
+    },
+    Block, BlockCipher, BlockEncrypt, FromBlockCipher, SeekNum, StreamCipher, StreamCipherSeek,
+};
+
+/// Counter (CTR) mode with the IV split into an `N`-byte nonce and a
+/// big-endian counter filling the rest of the block.
+///
+/// Only the counter part is incremented, it wraps around within its width,
+/// which is limited to 1 to 8 bytes. The counter is incremented by 1 for
+/// every block by default, [`NonceCtr::with_step`] sets a different step,
+/// e.g. to match hardware which increments it by a block count.
+///
+/// Applying keystream past the point where the counter repeats (after
+/// `2^w` blocks for a `w`-bit counter and an odd step) returns
+/// [`LoopError`].
+///
+/// The IV accepted by [`FromBlockCipher`] is the full initial counter block
+/// `nonce || counter`. Use [`NonceCtr::new_with_counter`] to pass the nonce
+/// and the initial counter value separately.
+#[derive(Clone)]
+pub struct NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    cipher: C,
+    nonce: GenericArray<u8, N>,
+    start: u64,
+    step: u64,
+    ctr: u64,
+    block: Block<C>,
+    pos: u8,
+}
+
+/// CTR mode of RFC 3686 with a 96-bit nonce and a 32-bit counter, use
+/// [`NonceCtr::new_rfc3686`] to create it.
+pub type Rfc3686Ctr<C> = NonceCtr<C, U12>;
+
+impl<C, N> NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    /// Create mode instance from the initialized block `cipher`, the
+    /// `nonce` and the initial `counter` value.
+    ///
+    /// # Panics
+    /// If the counter width is not between 1 and 8 bytes or `counter` does
+    /// not fit into it.
+    pub fn new_with_counter(cipher: C, nonce: &GenericArray<u8, N>, counter: u64) -> Self {
+        let w = Self::width();
+        assert!(
+            (1..=8).contains(&w),
+            "counter width must be between 1 and 8 bytes"
+        );
+        assert!(
+            w == 8 || counter >> (8 * w) == 0,
+            "counter does not fit into its width"
+        );
+        Self {
+            cipher,
+            nonce: nonce.clone(),
+            start: counter,
+            step: 1,
+            ctr: 0,
+            block: Default::default(),
+            pos: 0,
+        }
+    }
+
+    /// Set the value added to the counter for every block.
+    ///
+    /// # Panics
+    /// If `step` is zero or does not fit into the counter width.
+    pub fn with_step(mut self, step: u64) -> Self {
+        let w = Self::width();
+        assert!(
+            step != 0 && (w == 8 || step >> (8 * w) == 0),
+            "invalid counter step"
+        );
+        self.step = step;
+        self
+    }
+
+    /// Counter width in bytes.
+    fn width() -> usize {
+        C::BlockSize::USIZE.saturating_sub(N::USIZE)
+    }
+
+    /// Number of keystream blocks before the counter repeats, i.e.
+    /// `2^w / gcd(step, 2^w)`.
+    fn max_blocks(&self) -> u128 {
+        let bits = 8 * Self::width() as u32;
+        1 << (bits - self.step.trailing_zeros().min(bits))
+    }
+
+    fn gen_block(&self, ctr: u64) -> Block<C> {
+        let w = Self::width();
+        let ctr = self.step.wrapping_mul(ctr).wrapping_add(self.start);
+        let ctr = ctr.to_be_bytes();
+        let mut block = Block::<C>::default();
+        block[..N::USIZE].copy_from_slice(&self.nonce);
+        block[N::USIZE..].copy_from_slice(&ctr[8 - w..]);
+        self.cipher.encrypt_block(&mut block);
+        block
+    }
+}
+
+impl<C> NonceCtr<C, U12>
+where
+    C: BlockCipher + BlockEncrypt,
+{
+    /// Create mode instance from the 32-bit `nonce` of the security
+    /// association and the 64-bit per-packet `iv` as defined in RFC 3686.
+    ///
+    /// The counter starts at 1.
+    pub fn new_rfc3686(cipher: C, nonce: &[u8; 4], iv: &[u8; 8]) -> Self {
+        let mut n = GenericArray::<u8, U12>::default();
+        n[..4].copy_from_slice(nonce);
+        n[4..].copy_from_slice(iv);
+        Self::new_with_counter(cipher, &n, 1)
+    }
+}
+
+impl<C, N> FromBlockCipher for NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher(cipher: C, iv: &Block<C>) -> Self {
+        let (nonce, ctr) = iv.split_at(N::USIZE.min(iv.len()));
+        let counter = ctr.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+        Self::new_with_counter(cipher, GenericArray::from_slice(nonce), counter)
+    }
+}
+
+impl<C, N> StreamCipher for NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    fn try_apply_keystream(&mut self, mut data: &mut [u8]) -> Result<(), LoopError> {
+        let bs = C::BlockSize::USIZE;
+        let end = self.ctr as u128 * bs as u128 + self.pos as u128 + data.len() as u128;
+        if end > self.max_blocks() * bs as u128 {
+            return Err(LoopError);
+        }
+
+        let pos = self.pos as usize;
+        let mut ctr = self.ctr;
+
+        if pos != 0 {
+            if data.len() < bs - pos {
+                let n = data.len();
+                xor(data, &self.block[pos..pos + n]);
+                self.pos += n as u8;
+                return Ok(());
+            }
+            let (l, r) = { data }.split_at_mut(bs - pos);
+            data = r;
+            xor(l, &self.block[pos..]);
+            ctr = ctr.wrapping_add(1);
+        }
+
+        let mut iter = data.chunks_exact_mut(bs);
+        for chunk in &mut iter {
+            xor(chunk, &self.gen_block(ctr));
+            ctr = ctr.wrapping_add(1);
+        }
+        let rem = iter.into_remainder();
+        self.pos = rem.len() as u8;
+        self.ctr = ctr;
+        if !rem.is_empty() {
+            self.block = self.gen_block(ctr);
+            xor(rem, &self.block[..rem.len()]);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, N> StreamCipherSeek for NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        T::from_block_byte(self.ctr, self.pos, C::BlockSize::U8)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        let res: (u64, u8) = pos.to_block_byte(C::BlockSize::U8)?;
+        let bs = C::BlockSize::USIZE as u128;
+        if u128::from(res.0) * bs + u128::from(res.1) > self.max_blocks() * bs {
+            return Err(LoopError);
+        }
+        self.ctr = res.0;
+        self.pos = res.1;
+        if self.pos != 0 {
+            self.block = self.gen_block(res.0);
+        }
+        Ok(())
+    }
+}
+
+impl<C, N> core::fmt::Debug for NonceCtr<C, N>
+where
+    C: BlockCipher + BlockEncrypt,
+    N: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("NonceCtr { ... }")
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, NewBlockCipher};
+use cipher::{
+    consts::{U14, U15},
+    generic_array::GenericArray,
+    NewCipher, StreamCipher, StreamCipherSeek,
+};
+use ctr_flavors::{NonceCtr, Rfc3686Ctr};
+use hex_literal::hex;
+
+/// Test vectors 1 and 2 of RFC 3686.
+#[test]
+fn rfc3686_aes128() {
+    let key = hex!("ae6852f8121067cc4bf7a5765577f39e");
+    let mut ctr = Rfc3686Ctr::new_rfc3686(Aes128::new(&key.into()), &hex!("00000030"), &[0; 8]);
+    let mut buf = *b"Single block msg";
+    ctr.apply_keystream(&mut buf);
+    assert_eq!(buf, hex!("e4095d4fb7a7b3792d6175a3261311b8"));
+
+    let key = hex!("7e24067817fae0d743d6ce1f32539163");
+    let ct = hex!("5104a106168a72d9790d41ee8edad388eb2e1efc46da57c8fce630df9141be28");
+    let cipher = Aes128::new(&key.into());
+    let ctr = Rfc3686Ctr::new_rfc3686(cipher, &hex!("006cb6db"), &hex!("c0543b59da48d90b"));
+    for n in 1..32 {
+        let mut ctr = ctr.clone();
+        let mut buf: Vec<u8> = (0..32).collect();
+        for chunk in buf.chunks_mut(n) {
+            ctr.apply_keystream(chunk);
+        }
+        assert_eq!(buf, &ct[..]);
+    }
+
+    // the full initial counter block is accepted as IV
+    let iv = hex!("006cb6dbc0543b59da48d90b00000001");
+    let mut ctr = Rfc3686Ctr::<Aes128>::new(&key.into(), &iv.into());
+    let mut buf = ct;
+    ctr.apply_keystream(&mut buf);
+    assert_eq!(buf[..], (0..32).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn seek() {
+    let cipher = Aes128::new(&[0x42; 16].into());
+    let nonce = GenericArray::clone_from_slice(&[0x24; 12]);
+    let ctr = Rfc3686Ctr::new_with_counter(cipher, &nonce, 7);
+
+    let mut ks = [0u8; 100];
+    ctr.clone().apply_keystream(&mut ks);
+    for pos in 0..100 {
+        let mut c = ctr.clone();
+        c.seek(pos as u64);
+        assert_eq!(c.current_pos::<u64>(), pos as u64);
+        let mut buf = [0u8; 100];
+        c.apply_keystream(&mut buf[pos..]);
+        assert_eq!(buf[pos..], ks[pos..]);
+    }
+}
+
+/// The counter wraps within its width and the keystream ends before it
+/// repeats.
+#[test]
+fn counter_width() {
+    let cipher = Aes128::new(&[0x42; 16].into());
+    let nonce = GenericArray::clone_from_slice(&[0x24; 15]);
+    let mut ctr = NonceCtr::<_, U15>::new_with_counter(cipher.clone(), &nonce, 0xff);
+
+    let mut ks = [0u8; 32];
+    ctr.apply_keystream(&mut ks);
+    let mut block = GenericArray::clone_from_slice(&[0x24; 16]);
+    block[15] = 0xff;
+    aes::BlockEncrypt::encrypt_block(&cipher, &mut block);
+    assert_eq!(ks[..16], block[..]);
+    block = GenericArray::clone_from_slice(&[0x24; 16]);
+    block[15] = 0x00;
+    aes::BlockEncrypt::encrypt_block(&cipher, &mut block);
+    assert_eq!(ks[16..], block[..]);
+
+    let mut buf = [0u8; 254 * 16];
+    ctr.apply_keystream(&mut buf);
+    assert!(ctr.try_apply_keystream(&mut [0u8; 1]).is_err());
+    assert!(ctr.try_seek(256 * 16 + 1).is_err());
+
+    let nonce = GenericArray::clone_from_slice(&[0x24; 14]);
+    let mut ctr = NonceCtr::<_, U14>::new_with_counter(cipher, &nonce, 0);
+    assert!(ctr.try_seek(0xffff * 16).is_ok());
+    assert!(ctr.try_apply_keystream(&mut [0u8; 16]).is_ok());
+    assert!(ctr.try_apply_keystream(&mut [0u8; 1]).is_err());
+}
+
+/// Compare the 8 and 16-bit flavors against the corresponding layouts of
+/// `NonceCtr` and an explicit counter block.
+#[test]
+fn small_flavors() {
+    use ctr_flavors::{Ctr16BE, Ctr16LE, Ctr8};
+
+    let key = [0x42; 16].into();
+    let mut iv = GenericArray::clone_from_slice(&[0x24; 16]);
+    iv[15] = 0xf0;
+
+    let mut expected = [0u8; 15 * 16];
+    let mut ctr = NonceCtr::<Aes128, U15>::new(&key, &iv);
+    ctr.apply_keystream(&mut expected);
+    let mut buf = [0u8; 15 * 16];
+    let mut ctr = Ctr8::<Aes128>::new(&key, &iv);
+    ctr.apply_keystream(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+    // the 8-bit counter is exhausted after 255 blocks
+    let mut ctr = Ctr8::<Aes128>::new(&key, &iv);
+    assert!(ctr.try_apply_keystream(&mut [0u8; 255 * 16]).is_ok());
+    assert!(ctr.try_apply_keystream(&mut [0u8; 16]).is_err());
+
+    iv[14] = 0xff;
+    let mut expected = [0u8; 32 * 16];
+    let mut ctr = NonceCtr::<Aes128, U14>::new(&key, &iv);
+    ctr.apply_keystream(&mut expected);
+    let mut buf = [0u8; 32 * 16];
+    let mut ctr = Ctr16BE::<Aes128>::new(&key, &iv);
+    ctr.apply_keystream(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+
+    let cipher = Aes128::new(&key);
+    let mut buf = [0u8; 32];
+    let mut ctr = Ctr16LE::<Aes128>::new(&key, &iv);
+    ctr.apply_keystream(&mut buf);
+    for (i, chunk) in buf.chunks(16).enumerate() {
+        let mut block = iv;
+        block[..2].copy_from_slice(&(0x2424u16 + i as u16).to_le_bytes());
+        aes::BlockEncrypt::encrypt_block(&cipher, &mut block);
+        assert_eq!(chunk, &block[..]);
+    }
+}
+
+#[test]
+fn counter_step() {
+    use aes::BlockEncrypt;
+
+    let cipher = Aes128::new(&[0x42; 16].into());
+    let nonce = GenericArray::clone_from_slice(&[0x24; 14]);
+    let mut ctr = NonceCtr::<_, U14>::new_with_counter(cipher.clone(), &nonce, 0xfff0).with_step(8);
+
+    let mut buf = [0u8; 48];
+    ctr.apply_keystream(&mut buf);
+    for (chunk, c) in buf.chunks(16).zip([0xfff0u16, 0xfff8, 0x0000].iter()) {
+        let mut block = GenericArray::clone_from_slice(&[0x24; 16]);
+        block[14..].copy_from_slice(&c.to_be_bytes());
+        cipher.encrypt_block(&mut block);
+        assert_eq!(chunk, &block[..]);
+    }
+
+    // a step of 8 repeats the 16-bit counter after 2^13 blocks
+    let mut c = ctr.clone();
+    assert!(c.try_seek((1u64 << 13) * 16).is_ok());
+    assert!(c.try_apply_keystream(&mut [0u8; 1]).is_err());
+    assert!(ctr.try_seek((1u64 << 13) * 16 + 1).is_err());
+
+    // seeking matches the step
+    let mut c = ctr.clone();
+    c.seek(16u64);
+    let mut block = [0u8; 16];
+    c.apply_keystream(&mut block);
+    assert_eq!(block, buf[16..32]);
+}
+
+#[test]
+fn custom_flavor() {
+    use aes::BlockEncrypt;
+    use cipher::consts::{U0, U2, U3, U5};
+    use ctr_flavors::{
+        ctr::Ctr,
+        flavors::{BigEndian, CustomCtr, LittleEndian},
+        Ctr16BE, Ctr16LE,
+    };
+
+    let key = [0x42; 16].into();
+    let mut iv = GenericArray::clone_from_slice(&[0x24; 16]);
+    iv[14] = 0xff;
+    iv[15] = 0xf0;
+
+    // equivalent to the fixed 16-bit flavors
+    let mut expected = [0u8; 32 * 16];
+    Ctr16BE::<Aes128>::new(&key, &iv).apply_keystream(&mut expected);
+    let mut buf = [0u8; 32 * 16];
+    Ctr::<Aes128, CustomCtr<U2, U14, BigEndian>>::new(&key, &iv).apply_keystream(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+
+    Ctr16LE::<Aes128>::new(&key, &iv).apply_keystream(&mut expected);
+    Ctr::<Aes128, CustomCtr<U2, U0, LittleEndian>>::new(&key, &iv).apply_keystream(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+
+    // 24-bit little endian counter in the middle of the block
+    let cipher = Aes128::new(&key);
+    let mut iv = GenericArray::clone_from_slice(&[0x24; 16]);
+    iv[5..8].copy_from_slice(&[0xfe, 0xff, 0xff]);
+    let mut ctr = Ctr::<Aes128, CustomCtr<U3, U5, LittleEndian>>::new(&key, &iv);
+    let mut buf = [0u8; 48];
+    ctr.apply_keystream(&mut buf);
+    for (chunk, c) in buf
+        .chunks(16)
+        .zip([[0xfe, 0xff, 0xff], [0xff; 3], [0; 3]].iter())
+    {
+        let mut block = iv;
+        block[5..8].copy_from_slice(c);
+        cipher.encrypt_block(&mut block);
+        assert_eq!(chunk, &block[..]);
+    }
+
+    // the counter is exhausted before it repeats
+    let mut ctr = Ctr::<Aes128, CustomCtr<U2, U3>>::new(&key, &iv);
+    assert!(ctr.try_seek(0xffff * 16).is_ok());
+    assert!(ctr.try_apply_keystream(&mut [0u8; 16]).is_err());
+}
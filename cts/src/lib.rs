@@ -0,0 +1,261 @@
+//! Generic implementation of the CBC mode with [ciphertext stealing][1].
+//!
+//! Ciphertext stealing allows to encrypt messages which are not a multiple
+//! of the block size without padding, the ciphertext has exactly the length
+//! of the plaintext. The final partial plaintext block is padded with
+//! ciphertext "stolen" from the previous block, which is then truncated.
+//! NIST SP 800-38A Addendum defines three variants, which differ only in
+//! the order of the last two ciphertext blocks:
+//!
+//! - [`Cs1`] keeps the order of CBC, i.e. the truncated block comes first.
+//! - [`Cs2`] swaps the last two blocks, unless the message is a multiple
+//!   of the block size, in which case the ciphertext equals CBC.
+//! - [`Cs3`] always swaps the last two blocks. This is the variant used by
+//!   Kerberos ([RFC 3962][2]).
+//!
+//! Messages must be at least one block long. A message of exactly one block
+//! is encrypted with plain CBC by all variants.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use cts::{CbcCts, Cs3};
+//! use hex_literal::hex;
+//!
+//! let key = b"chicken teriyaki";
+//! let cts = CbcCts::<_, Cs3>::new(Aes128::new(key.into()), &Default::default());
+//!
+//! let mut buf = hex!("4920776f756c64206c696b652074686520");
+//! cts.encrypt(&mut buf).unwrap();
+//! assert_eq!(buf, hex!("c6353568f2bf8cb4d8a580362da7ff7f97"));
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/publications/detail/sp/800-38a/addendum/final
+//! [2]: https://tools.ietf.org/html/rfc3962
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    errors::InvalidLength,
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::marker::PhantomData;
+
+/// Block of the underlying cipher.
+pub type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Ciphertext stealing variant, i.e. the order of the last two ciphertext
+/// blocks.
+pub trait Variant: private::Sealed {
+    /// Returns `true` if the last two blocks are swapped for a message with
+    /// the final block length `last_len`.
+    fn swap(last_len: usize, block_size: usize) -> bool;
+}
+
+/// CBC-CS1 variant, the last two blocks are never swapped.
+#[derive(Copy, Clone, Debug)]
+pub struct Cs1;
+
+/// CBC-CS2 variant, the last two blocks are swapped if the final block is
+/// partial.
+#[derive(Copy, Clone, Debug)]
+pub struct Cs2;
+
+/// CBC-CS3 variant, the last two blocks are always swapped.
+#[derive(Copy, Clone, Debug)]
+pub struct Cs3;
+
+impl private::Sealed for Cs1 {}
+impl private::Sealed for Cs2 {}
+impl private::Sealed for Cs3 {}
+
+impl Variant for Cs1 {
+    fn swap(_: usize, _: usize) -> bool {
+        false
+    }
+}
+
+impl Variant for Cs2 {
+    fn swap(last_len: usize, block_size: usize) -> bool {
+        last_len != block_size
+    }
+}
+
+impl Variant for Cs3 {
+    fn swap(_: usize, _: usize) -> bool {
+        true
+    }
+}
+
+#[inline(always)]
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= b;
+    }
+}
+
+/// CBC mode with ciphertext stealing.
+///
+/// As with the modes in the `block-modes` crate an instance processes a
+/// single message and is consumed by [`encrypt`][CbcCts::encrypt] and
+/// [`decrypt`][CbcCts::decrypt].
+#[derive(Clone)]
+pub struct CbcCts<C: BlockCipher, V: Variant> {
+    cipher: C,
+    iv: Block<C>,
+    _v: PhantomData<V>,
+}
+
+impl<C, V> CbcCts<C, V>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+    V: Variant,
+{
+    /// Create mode instance from the initialized block `cipher` and `iv`.
+    pub fn new(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            iv: iv.clone(),
+            _v: PhantomData,
+        }
+    }
+
+    /// Create mode instance from key and IV slices.
+    pub fn new_from_slices(key: &[u8], iv: &[u8]) -> Result<Self, InvalidLength>
+    where
+        C: NewBlockCipher,
+    {
+        if iv.len() != C::BlockSize::to_usize() {
+            return Err(InvalidLength);
+        }
+        let cipher = C::new_from_slice(key)?;
+        Ok(Self::new(cipher, GenericArray::from_slice(iv)))
+    }
+
+    /// Encrypt `data` in place.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than a block.
+    pub fn encrypt(self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        let bs = C::BlockSize::to_usize();
+        let (head_len, last_len) = split(data.len(), bs)?;
+        let (head, tail) = data.split_at_mut(head_len);
+
+        // plain CBC for all full blocks but the last one
+        let mut iv = self.iv;
+        for chunk in head.chunks_exact_mut(bs) {
+            let block = GenericArray::from_mut_slice(chunk);
+            xor(block, &iv);
+            self.cipher.encrypt_block(block);
+            iv = block.clone();
+        }
+        if tail.len() == last_len {
+            // single block message
+            let block = GenericArray::from_mut_slice(tail);
+            xor(block, &iv);
+            self.cipher.encrypt_block(block);
+            return Ok(());
+        }
+
+        // C_{n-1} = E(P_{n-1} ^ C_{n-2}), C_n = E((P_n || 0) ^ C_{n-1})
+        let mut c1 = Block::<C>::clone_from_slice(&tail[..bs]);
+        xor(&mut c1, &iv);
+        self.cipher.encrypt_block(&mut c1);
+        let mut c2 = c1.clone();
+        xor(&mut c2[..last_len], &tail[bs..]);
+        self.cipher.encrypt_block(&mut c2);
+
+        if V::swap(last_len, bs) {
+            tail[..bs].copy_from_slice(&c2);
+            tail[bs..].copy_from_slice(&c1[..last_len]);
+        } else {
+            tail[..last_len].copy_from_slice(&c1[..last_len]);
+            tail[last_len..].copy_from_slice(&c2);
+        }
+        Ok(())
+    }
+
+    /// Decrypt `data` in place.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than a block.
+    pub fn decrypt(self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        let bs = C::BlockSize::to_usize();
+        let (head_len, last_len) = split(data.len(), bs)?;
+        let (head, tail) = data.split_at_mut(head_len);
+        let iv = match head.len() {
+            0 => self.iv.clone(),
+            n => Block::<C>::clone_from_slice(&head[n - bs..]),
+        };
+
+        if tail.len() == last_len {
+            // single block message
+            let block = GenericArray::from_mut_slice(tail);
+            self.cipher.decrypt_block(block);
+            xor(block, &iv);
+        } else {
+            // C_{n-1}* is the truncated block
+            let (mut c1, mut c2) = (Block::<C>::default(), Block::<C>::default());
+            if V::swap(last_len, bs) {
+                c2.copy_from_slice(&tail[..bs]);
+                c1[..last_len].copy_from_slice(&tail[bs..]);
+            } else {
+                c1[..last_len].copy_from_slice(&tail[..last_len]);
+                c2.copy_from_slice(&tail[last_len..]);
+            }
+
+            // D(C_n) = (P_n || 0) ^ C_{n-1}, which also recovers the stolen
+            // part of C_{n-1}
+            self.cipher.decrypt_block(&mut c2);
+            c1[last_len..].copy_from_slice(&c2[last_len..]);
+            xor(&mut c2[..last_len], &c1[..last_len]);
+            tail[bs..].copy_from_slice(&c2[..last_len]);
+
+            self.cipher.decrypt_block(&mut c1);
+            xor(&mut c1, &iv);
+            tail[..bs].copy_from_slice(&c1);
+        }
+
+        let mut iv = self.iv;
+        for chunk in head.chunks_exact_mut(bs) {
+            let block = GenericArray::from_mut_slice(chunk);
+            let next_iv = block.clone();
+            self.cipher.decrypt_block(block);
+            xor(block, &iv);
+            iv = next_iv;
+        }
+        Ok(())
+    }
+}
+
+/// Split a message of `len` bytes into the part processed with plain CBC
+/// and the last two blocks, returns the length of the former and of the
+/// (possibly partial) final block.
+fn split(len: usize, bs: usize) -> Result<(usize, usize), InvalidLength> {
+    if len < bs {
+        return Err(InvalidLength);
+    }
+    let last_len = match len % bs {
+        0 => bs,
+        n => n,
+    };
+    let head_len = if len == bs { 0 } else { len - last_len - bs };
+    Ok((head_len, last_len))
+}
+
+impl<C: BlockCipher, V: Variant> core::fmt::Debug for CbcCts<C, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("CbcCts { ... }")
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, NewBlockCipher};
+use cts::{CbcCts, Cs1, Cs2, Cs3, Variant};
+use hex_literal::hex;
+
+const KEY: &[u8; 16] = b"chicken teriyaki";
+
+fn cts<V: Variant>() -> CbcCts<Aes128, V> {
+    CbcCts::new(Aes128::new(KEY.into()), &Default::default())
+}
+
+fn check<V: Variant>(pt: &[u8], ct: &[u8]) {
+    let mut buf = pt.to_vec();
+    cts::<V>().encrypt(&mut buf).unwrap();
+    assert_eq!(buf, ct);
+    cts::<V>().decrypt(&mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+/// Test vectors from RFC 3962, Appendix B.
+#[test]
+fn rfc3962() {
+    let pt = hex!("4920776f756c64206c696b65207468652047656e6572616c20476175277320436869636b656e2c20706c656173652c20616e6420776f6e746f6e20736f75702e");
+    let vectors: &[(usize, &[u8])] = &[
+        (17, &hex!("c6353568f2bf8cb4d8a580362da7ff7f97")),
+        (
+            31,
+            &hex!("fc00783e0efdb2c1d445d4c8eff7ed2297687268d6ecccc0c07b25e25ecfe5"),
+        ),
+        (
+            32,
+            &hex!("39312523a78662d5be7fcbcc98ebf5a897687268d6ecccc0c07b25e25ecfe584"),
+        ),
+        (
+            47,
+            &hex!("97687268d6ecccc0c07b25e25ecfe584b3fffd940c16a18c1b5549d2f838029e39312523a78662d5be7fcbcc98ebf5"),
+        ),
+        (
+            48,
+            &hex!("97687268d6ecccc0c07b25e25ecfe5849dad8bbb96c4cdc03bc103e1a194bbd839312523a78662d5be7fcbcc98ebf5a8"),
+        ),
+        (
+            64,
+            &hex!("97687268d6ecccc0c07b25e25ecfe58439312523a78662d5be7fcbcc98ebf5a84807efe836ee89a526730dbc2f7bc8409dad8bbb96c4cdc03bc103e1a194bbd8"),
+        ),
+    ];
+    for &(len, ct) in vectors {
+        check::<Cs3>(&pt[..len], ct);
+    }
+}
+
+/// CS1 and CS2 only differ from CS3 in the order of the last two blocks.
+#[test]
+fn variants() {
+    let pt: Vec<u8> = (0..80).collect();
+    for len in 16..=pt.len() {
+        let pt = &pt[..len];
+        let mut cs3 = pt.to_vec();
+        cts::<Cs3>().encrypt(&mut cs3).unwrap();
+
+        let last = match len % 16 {
+            0 => 16,
+            n => n,
+        };
+        let mut cs1 = cs3.clone();
+        if len > 16 {
+            let tail = &mut cs1[len - 16 - last..];
+            tail.rotate_left(16);
+        }
+        check::<Cs1>(pt, &cs1);
+        check::<Cs2>(pt, if last == 16 { &cs1 } else { &cs3 });
+        check::<Cs3>(pt, &cs3);
+    }
+}
+
+#[test]
+fn invalid_length() {
+    assert!(cts::<Cs1>().encrypt(&mut []).is_err());
+    assert!(cts::<Cs2>().encrypt(&mut [0; 15]).is_err());
+    assert!(cts::<Cs3>().decrypt(&mut [0; 15]).is_err());
+}
@@ -0,0 +1,41 @@
+#![feature(test)]
+extern crate test;
+
+use deoxys_bc::{cipher::NewBlockCipher, Block, DeoxysBc256, DeoxysBc384, ParBlocks, Tweak};
+use test::Bencher;
+
+#[bench]
+fn deoxys_bc_256_encrypt(bh: &mut Bencher) {
+    let cipher = DeoxysBc256::new(&Default::default());
+    let tweak = Tweak::default();
+    let mut block = Block::default();
+    bh.iter(|| {
+        cipher.encrypt_block_with_tweak(&mut block, &tweak);
+        test::black_box(&block);
+    });
+    bh.bytes = block.len() as u64;
+}
+
+#[bench]
+fn deoxys_bc_384_encrypt(bh: &mut Bencher) {
+    let cipher = DeoxysBc384::new(&Default::default());
+    let tweak = Tweak::default();
+    let mut block = Block::default();
+    bh.iter(|| {
+        cipher.encrypt_block_with_tweak(&mut block, &tweak);
+        test::black_box(&block);
+    });
+    bh.bytes = block.len() as u64;
+}
+
+#[bench]
+fn deoxys_bc_256_encrypt_par(bh: &mut Bencher) {
+    let cipher = DeoxysBc256::new(&Default::default());
+    let tweaks = ParBlocks::default();
+    let mut blocks = ParBlocks::default();
+    bh.iter(|| {
+        cipher.encrypt_par_blocks_with_tweaks(&mut blocks, &tweaks);
+        test::black_box(&blocks);
+    });
+    bh.bytes = (blocks.len() * 16) as u64;
+}
@@ -0,0 +1,304 @@
+//! An implementation of the [Deoxys-BC][1] tweakable block ciphers.
+//!
+//! Deoxys-BC is an AES-based tweakable block cipher designed within the
+//! TWEAKEY framework and used by the Deoxys authenticated encryption
+//! schemes. Two variants are provided:
+//!
+//! - [`DeoxysBc256`]: 128-bit key, 128-bit tweak, 14 rounds
+//! - [`DeoxysBc384`]: 256-bit key, 128-bit tweak, 16 rounds
+//!
+//! Every round is a full AES round, which is computed with the AES round
+//! functions of the `aes` crate. Those use AES-NI (or the ARMv8 crypto
+//! extensions) when available and fall
+//! back to the constant-time bitsliced software implementation otherwise.
+//!
+//! Since every block is processed under its own tweak, the ciphers do not
+//! implement the [`BlockEncrypt`][cipher::BlockEncrypt] and
+//! [`BlockDecrypt`][cipher::BlockDecrypt] traits. Instead they provide
+//! methods taking the tweak as an additional argument, including variants
+//! processing [`ParBlocks`] blocks at once to make use of the instruction
+//! level parallelism of hardware AES implementations.
+//!
+//! # ⚠️ Security Warning: Hazmat!
+//!
+//! This crate provides only the raw tweakable block cipher. Using it
+//! securely requires a tweakable mode of operation such as Deoxys-I or
+//! Deoxys-II, and in particular never reusing a tweak under the same key
+//! for different purposes.
+//!
+//! # Examples
+//! ```
+//! use deoxys_bc::{cipher::NewBlockCipher, Block, DeoxysBc256, Tweak};
+//!
+//! let cipher = DeoxysBc256::new(&[0x42; 16].into());
+//! let tweak = Tweak::from([0x24; 16]);
+//! let mut block = Block::default();
+//!
+//! cipher.encrypt_block_with_tweak(&mut block, &tweak);
+//! cipher.decrypt_block_with_tweak(&mut block, &tweak);
+//! assert_eq!(block, Block::default());
+//! ```
+//!
+//! [1]: https://competitions.cr.yp.to/round3/deoxysv141.pdf
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use aes::{Block, ParBlocks};
+pub use cipher;
+
+use aes::hazmat::{
+    cipher_round, cipher_round_par, equiv_inv_cipher_round, equiv_inv_cipher_round_par,
+    inv_mix_columns, mix_columns,
+};
+use cipher::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    NewBlockCipher,
+};
+
+/// Deoxys-BC tweak.
+pub type Tweak = GenericArray<u8, U16>;
+
+/// Maximum number of rounds of all variants.
+const MAX_ROUNDS: usize = 16;
+
+/// Tweakey byte permutation `h`.
+const H: [usize; 16] = [1, 6, 11, 12, 5, 10, 15, 0, 9, 14, 3, 4, 13, 2, 7, 8];
+
+/// Round constants, `RCON[i]` is used for the subtweakey of round `i`.
+const RCON: [u8; MAX_ROUNDS + 1] = [
+    0x2f, 0x5e, 0xbc, 0x63, 0xc6, 0x97, 0x35, 0x6a, 0xd4, 0xb3, 0x7d, 0xfa, 0xef, 0xc5, 0x91, 0x39,
+    0x72,
+];
+
+/// Round keys derived from the key part of the tweakey.
+type RoundKeys = [Block; MAX_ROUNDS + 1];
+
+#[inline(always)]
+fn h(tk: &Block) -> Block {
+    let mut res = Block::default();
+    for (r, &i) in res.iter_mut().zip(H.iter()) {
+        *r = tk[i];
+    }
+    res
+}
+
+#[inline(always)]
+fn lfsr2(tk: &mut Block) {
+    for b in tk.iter_mut() {
+        *b = (*b << 1) | (((*b >> 7) ^ (*b >> 5)) & 1);
+    }
+}
+
+#[inline(always)]
+fn lfsr3(tk: &mut Block) {
+    for b in tk.iter_mut() {
+        *b = (*b >> 1) | (((*b << 7) ^ (*b << 1)) & 0x80);
+    }
+}
+
+#[inline(always)]
+fn xor(a: &mut Block, b: &Block) {
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a ^= b;
+    }
+}
+
+/// Add round constant `RC_i` to `block`, i.e. `1, 2, 4, 8` to the first
+/// column and `RCON[i]` to every byte of the second column.
+fn add_round_constant(block: &mut Block, round: usize) {
+    for (b, &c) in block[..4].iter_mut().zip([1u8, 2, 4, 8].iter()) {
+        *b ^= c;
+    }
+    for b in block[4..8].iter_mut() {
+        *b ^= RCON[round];
+    }
+}
+
+/// Compute the key dependent part of the subtweakeys.
+///
+/// `tk2` is updated with LFSR2 and `tk3` (if present) with LFSR3, round
+/// constants are included.
+fn expand_key(mut tk2: Block, mut tk3: Option<Block>, rounds: usize) -> RoundKeys {
+    let mut keys = RoundKeys::default();
+    for (i, key) in keys.iter_mut().take(rounds + 1).enumerate() {
+        if i != 0 {
+            tk2 = h(&tk2);
+            lfsr2(&mut tk2);
+            if let Some(tk3) = tk3.as_mut() {
+                *tk3 = h(tk3);
+                lfsr3(tk3);
+            }
+        }
+        *key = tk2;
+        if let Some(tk3) = &tk3 {
+            xor(key, tk3);
+        }
+        add_round_constant(key, i);
+    }
+    keys
+}
+
+/// Compute the subtweakeys for `tweak`.
+fn subtweakeys(keys: &RoundKeys, tweak: &Tweak, rounds: usize) -> RoundKeys {
+    let mut res = *keys;
+    let mut tk1 = *tweak;
+    for (i, key) in res.iter_mut().take(rounds + 1).enumerate() {
+        if i != 0 {
+            tk1 = h(&tk1);
+        }
+        xor(key, &tk1);
+    }
+    res
+}
+
+fn encrypt(keys: &RoundKeys, rounds: usize, block: &mut Block, tweak: &Tweak) {
+    let stk = subtweakeys(keys, tweak, rounds);
+    xor(block, &stk[0]);
+    for key in &stk[1..=rounds] {
+        cipher_round(block, key);
+    }
+}
+
+fn decrypt(keys: &RoundKeys, rounds: usize, block: &mut Block, tweak: &Tweak) {
+    let mut stk = subtweakeys(keys, tweak, rounds);
+    // `AESDEC` applies `InvMixColumns` after adding the round key, so the
+    // state and inner round keys are kept in the `InvMixColumns` domain
+    xor(block, &stk[rounds]);
+    inv_mix_columns(block);
+    for key in stk[1..rounds].iter_mut().rev() {
+        inv_mix_columns(key);
+        equiv_inv_cipher_round(block, key);
+    }
+    equiv_inv_cipher_round(block, &Block::default());
+    mix_columns(block);
+    xor(block, &stk[0]);
+}
+
+fn encrypt_par(keys: &RoundKeys, rounds: usize, blocks: &mut ParBlocks, tweaks: &ParBlocks) {
+    let mut stk = ParBlocks::default();
+    let mut tk1 = *tweaks;
+    for (block, tk1) in blocks.iter_mut().zip(tk1.iter()) {
+        xor(block, tk1);
+        xor(block, &keys[0]);
+    }
+    for key in &keys[1..=rounds] {
+        for (stk, tk1) in stk.iter_mut().zip(tk1.iter_mut()) {
+            *tk1 = h(tk1);
+            *stk = *key;
+            xor(stk, tk1);
+        }
+        cipher_round_par(blocks, &stk);
+    }
+}
+
+fn decrypt_par(keys: &RoundKeys, rounds: usize, blocks: &mut ParBlocks, tweaks: &ParBlocks) {
+    let mut stks = [RoundKeys::default(); 8];
+    for (stk, tweak) in stks.iter_mut().zip(tweaks.iter()) {
+        *stk = subtweakeys(keys, tweak, rounds);
+    }
+    for (block, stk) in blocks.iter_mut().zip(stks.iter()) {
+        xor(block, &stk[rounds]);
+        inv_mix_columns(block);
+    }
+    let mut round_keys = ParBlocks::default();
+    for i in (1..rounds).rev() {
+        for (key, stk) in round_keys.iter_mut().zip(stks.iter()) {
+            *key = stk[i];
+            inv_mix_columns(key);
+        }
+        equiv_inv_cipher_round_par(blocks, &round_keys);
+    }
+    equiv_inv_cipher_round_par(blocks, &ParBlocks::default());
+    for (block, stk) in blocks.iter_mut().zip(stks.iter()) {
+        mix_columns(block);
+        xor(block, &stk[0]);
+    }
+}
+
+macro_rules! define_deoxys_bc {
+    ($name:ident, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            keys: RoundKeys,
+        }
+
+        impl $name {
+            /// Number of rounds.
+            pub const ROUNDS: usize = $rounds;
+
+            /// Encrypt `block` in place using `tweak`.
+            pub fn encrypt_block_with_tweak(&self, block: &mut Block, tweak: &Tweak) {
+                encrypt(&self.keys, Self::ROUNDS, block, tweak);
+            }
+
+            /// Decrypt `block` in place using `tweak`.
+            pub fn decrypt_block_with_tweak(&self, block: &mut Block, tweak: &Tweak) {
+                decrypt(&self.keys, Self::ROUNDS, block, tweak);
+            }
+
+            /// Encrypt several blocks in parallel, each with its own tweak.
+            pub fn encrypt_par_blocks_with_tweaks(
+                &self,
+                blocks: &mut ParBlocks,
+                tweaks: &ParBlocks,
+            ) {
+                encrypt_par(&self.keys, Self::ROUNDS, blocks, tweaks);
+            }
+
+            /// Decrypt several blocks in parallel, each with its own tweak.
+            pub fn decrypt_par_blocks_with_tweaks(
+                &self,
+                blocks: &mut ParBlocks,
+                tweaks: &ParBlocks,
+            ) {
+                decrypt_par(&self.keys, Self::ROUNDS, blocks, tweaks);
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+define_deoxys_bc!(
+    DeoxysBc256,
+    14,
+    "Deoxys-BC-256 tweakable block cipher with 128-bit key and 128-bit tweak."
+);
+
+define_deoxys_bc!(
+    DeoxysBc384,
+    16,
+    "Deoxys-BC-384 tweakable block cipher with 256-bit key and 128-bit tweak."
+);
+
+impl NewBlockCipher for DeoxysBc256 {
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        Self {
+            keys: expand_key(*key, None, Self::ROUNDS),
+        }
+    }
+}
+
+impl NewBlockCipher for DeoxysBc384 {
+    type KeySize = U32;
+
+    /// The tweakey is `key || tweak`, so the first half of the key forms
+    /// `TK3` and the second half `TK2`.
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        let tk3 = Block::clone_from_slice(&key[..16]);
+        let tk2 = Block::clone_from_slice(&key[16..]);
+        Self {
+            keys: expand_key(tk2, Some(tk3), Self::ROUNDS),
+        }
+    }
+}
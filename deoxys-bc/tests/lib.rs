@@ -0,0 +1,103 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use deoxys_bc::{cipher::NewBlockCipher, Block, DeoxysBc256, DeoxysBc384, ParBlocks, Tweak};
+use hex_literal::hex;
+
+/// Check the cipher against the ciphertext of a Deoxys-I message consisting
+/// of full blocks without associated data. Every such block is encrypted
+/// with a single call of Deoxys-BC under the tweak `0000 || nonce || index`.
+fn check_deoxys_i<F>(encrypt: F, nonce: &[u8; 8], pt: &[u8], ct: &[u8])
+where
+    F: Fn(&mut Block, &Tweak),
+{
+    for (i, (pt, ct)) in pt.chunks(16).zip(ct.chunks(16)).enumerate() {
+        let mut tweak = Tweak::default();
+        tweak[0] = nonce[0] >> 4;
+        for j in 1..8 {
+            tweak[j] = (nonce[j - 1] << 4) | (nonce[j] >> 4);
+        }
+        tweak[8..].copy_from_slice(&(i as u64).to_be_bytes());
+        tweak[8] |= nonce[7] << 4;
+
+        let mut block = Block::clone_from_slice(pt);
+        encrypt(&mut block, &tweak);
+        assert_eq!(block[..], ct[..]);
+    }
+}
+
+// Test vectors of Deoxys-I-128 and Deoxys-I-256 from the CAESAR submission,
+// which use Deoxys-BC-256 and Deoxys-BC-384 respectively
+
+const PT: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+#[test]
+fn deoxys_bc_256() {
+    let cipher = DeoxysBc256::new(&hex!("101112131415161718191a1b1c1d1e1f").into());
+    check_deoxys_i(
+        |block, tweak| cipher.encrypt_block_with_tweak(block, tweak),
+        &hex!("2021222324252627"),
+        &PT,
+        &hex!("4bf8c5ecec375b25acabd687aa605f1a8bb296face74f82527d4944dbb11b757"),
+    );
+}
+
+#[test]
+fn deoxys_bc_384() {
+    let cipher = DeoxysBc384::new(
+        &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f").into(),
+    );
+    check_deoxys_i(
+        |block, tweak| cipher.encrypt_block_with_tweak(block, tweak),
+        &hex!("0001020304050607"),
+        &PT,
+        &hex!("2c36c041fa3b1436c5153214131d493be9d014689a6a1e93e4a50989f0342941"),
+    );
+}
+
+#[test]
+fn decrypt() {
+    let tweak = Tweak::from([0x24; 16]);
+    let cipher = DeoxysBc256::new(&[0x42; 16].into());
+    let mut block = Block::from([0x11; 16]);
+    cipher.encrypt_block_with_tweak(&mut block, &tweak);
+    cipher.decrypt_block_with_tweak(&mut block, &tweak);
+    assert_eq!(block, Block::from([0x11; 16]));
+
+    let cipher = DeoxysBc384::new(&[0x42; 32].into());
+    cipher.encrypt_block_with_tweak(&mut block, &tweak);
+    cipher.decrypt_block_with_tweak(&mut block, &tweak);
+    assert_eq!(block, Block::from([0x11; 16]));
+}
+
+#[test]
+fn tweak_changes_output() {
+    let cipher = DeoxysBc256::new(&[0x42; 16].into());
+    let mut a = Block::default();
+    let mut b = Block::default();
+    cipher.encrypt_block_with_tweak(&mut a, &Tweak::from([0; 16]));
+    cipher.encrypt_block_with_tweak(&mut b, &Tweak::from([1; 16]));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn par_blocks() {
+    let cipher = DeoxysBc384::new(&[0x42; 32].into());
+    let mut blocks = ParBlocks::default();
+    let mut tweaks = ParBlocks::default();
+    for (i, (block, tweak)) in blocks.iter_mut().zip(tweaks.iter_mut()).enumerate() {
+        *block = Block::from([i as u8; 16]);
+        *tweak = Tweak::from([0x80 | i as u8; 16]);
+    }
+
+    let mut res = blocks;
+    cipher.encrypt_par_blocks_with_tweaks(&mut res, &tweaks);
+    for ((block, tweak), res) in blocks.iter().zip(tweaks.iter()).zip(res.iter()) {
+        let mut expected = *block;
+        cipher.encrypt_block_with_tweak(&mut expected, tweak);
+        assert_eq!(&expected, res);
+    }
+
+    cipher.decrypt_par_blocks_with_tweaks(&mut res, &tweaks);
+    assert_eq!(res, blocks);
+}
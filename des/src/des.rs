@@ -150,7 +150,7 @@ fn round(input: u64, key: u64) -> u64 {
 }
 
 fn f(input: u64, key: u64) -> u64 {
-    let mut val = e(input as u64);
+    let mut val = e(input);
     val ^= key;
     val = apply_sboxes(val);
     p(val)
@@ -174,7 +174,7 @@ impl Des {
         for key in &self.keys {
             data = round(data, *key);
         }
-        fp((data << 32) | (data >> 32))
+        fp(data.rotate_left(32))
     }
 
     pub(crate) fn decrypt(&self, mut data: u64) -> u64 {
@@ -182,7 +182,7 @@ impl Des {
         for key in self.keys.iter().rev() {
             data = round(data, *key);
         }
-        fp((data << 32) | (data >> 32))
+        fp(data.rotate_left(32))
     }
 }
 
@@ -1,6 +1,9 @@
 //! Test vectors are from NESSIE:
 //! https://www.cosic.esat.kuleuven.be/nessie/testvectors/
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 cipher::block_cipher_test!(des_test, "des", des::Des);
 cipher::block_cipher_test!(tdes_ede3_test, "tdes", des::TdesEde3);
 cipher::block_cipher_test!(tdes_ede2_test, "tdes2", des::TdesEde2);
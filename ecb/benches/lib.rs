@@ -0,0 +1,28 @@
+#![feature(test)]
+extern crate test;
+
+use aes::{Aes128, NewBlockCipher};
+use ecb::Ecb;
+use test::Bencher;
+
+#[bench]
+fn ecb_aes128_encrypt(bh: &mut Bencher) {
+    let ecb = Ecb::new(Aes128::new(&Default::default()));
+    let mut buf = [0u8; 1024];
+    bh.iter(|| {
+        ecb.encrypt(&mut buf).unwrap();
+        test::black_box(&buf);
+    });
+    bh.bytes = buf.len() as u64;
+}
+
+#[bench]
+fn ecb_aes128_decrypt(bh: &mut Bencher) {
+    let ecb = Ecb::new(Aes128::new(&Default::default()));
+    let mut buf = [0u8; 1024];
+    bh.iter(|| {
+        ecb.decrypt(&mut buf).unwrap();
+        test::black_box(&buf);
+    });
+    bh.bytes = buf.len() as u64;
+}
@@ -0,0 +1,160 @@
+//! Generic implementation of the [Electronic Codebook][1] (ECB) block
+//! cipher mode.
+//!
+//! ECB encrypts every block independently, so identical plaintext blocks
+//! result in identical ciphertext blocks. It should not be used for
+//! encrypting messages, but is a building block of other modes and
+//! protocols, e.g. key wrapping or counter-based keystream generation.
+//!
+//! Unlike the `Ecb` type of the `block-modes` crate this crate does not
+//! handle padding and processes blocks with the parallel block functions of
+//! the cipher ([`BlockEncrypt::encrypt_par_blocks`] and
+//! [`BlockDecrypt::decrypt_par_blocks`]), which for example allows AES-NI
+//! to process 8 blocks at a time.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use ecb::Ecb;
+//! use hex_literal::hex;
+//!
+//! let ecb = Ecb::new(Aes128::new(&hex!("2b7e151628aed2a6abf7158809cf4f3c").into()));
+//!
+//! let mut buf = hex!("6bc1bee22e409f96e93d7e117393172a");
+//! ecb.encrypt(&mut buf).unwrap();
+//! assert_eq!(buf, hex!("3ad77bb40d7a3660a89ecaf32466ef97"));
+//! ecb.decrypt(&mut buf).unwrap();
+//! assert_eq!(buf, hex!("6bc1bee22e409f96e93d7e117393172a"));
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#ECB
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    errors::InvalidLength,
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+/// Block of the underlying cipher.
+pub type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
+/// Blocks processed in parallel by the underlying cipher.
+pub type ParBlocks<C> = GenericArray<Block<C>, <C as BlockCipher>::ParBlocks>;
+
+/// ECB mode instance.
+#[derive(Clone)]
+pub struct Ecb<C: BlockCipher> {
+    cipher: C,
+}
+
+impl<C: BlockCipher> Ecb<C> {
+    /// Create mode instance from the initialized block `cipher`.
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+
+    /// Create mode instance from a key slice.
+    pub fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength>
+    where
+        C: NewBlockCipher,
+    {
+        C::new_from_slice(key).map(Self::new)
+    }
+
+    /// Get reference to the underlying block cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> Ecb<C> {
+    /// Encrypt `blocks` in place.
+    #[inline]
+    pub fn encrypt_blocks(&self, blocks: &mut [Block<C>]) {
+        self.cipher.encrypt_blocks(blocks);
+    }
+
+    /// Encrypt `data` in place.
+    ///
+    /// Returns [`InvalidLength`] if the length of `data` is not a multiple
+    /// of the block size.
+    pub fn encrypt(&self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        process::<C>(
+            data,
+            |pb| self.cipher.encrypt_par_blocks(pb),
+            |b| self.cipher.encrypt_block(b),
+        )
+    }
+}
+
+impl<C: BlockCipher + BlockDecrypt> Ecb<C> {
+    /// Decrypt `blocks` in place.
+    #[inline]
+    pub fn decrypt_blocks(&self, blocks: &mut [Block<C>]) {
+        self.cipher.decrypt_blocks(blocks);
+    }
+
+    /// Decrypt `data` in place.
+    ///
+    /// Returns [`InvalidLength`] if the length of `data` is not a multiple
+    /// of the block size.
+    pub fn decrypt(&self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        process::<C>(
+            data,
+            |pb| self.cipher.decrypt_par_blocks(pb),
+            |b| self.cipher.decrypt_block(b),
+        )
+    }
+}
+
+/// Apply `par` to chunks of `ParBlocks` blocks and `single` to the
+/// remaining blocks of `data`.
+///
+/// Parallel blocks are copied to a buffer of blocks, which is negligible
+/// compared to the cost of encryption.
+fn process<C: BlockCipher>(
+    data: &mut [u8],
+    par: impl Fn(&mut ParBlocks<C>),
+    single: impl Fn(&mut Block<C>),
+) -> Result<(), InvalidLength> {
+    let bs = C::BlockSize::to_usize();
+    let pbn = C::ParBlocks::to_usize();
+    if data.len() % bs != 0 {
+        return Err(InvalidLength);
+    }
+
+    let mut data = data;
+    if pbn > 1 {
+        let mut iter = data.chunks_exact_mut(bs * pbn);
+        let mut buf = ParBlocks::<C>::default();
+        for chunk in &mut iter {
+            for (block, src) in buf.iter_mut().zip(chunk.chunks_exact(bs)) {
+                block.copy_from_slice(src);
+            }
+            par(&mut buf);
+            for (block, dst) in buf.iter().zip(chunk.chunks_exact_mut(bs)) {
+                dst.copy_from_slice(block);
+            }
+        }
+        data = iter.into_remainder();
+    }
+    for chunk in data.chunks_exact_mut(bs) {
+        single(GenericArray::from_mut_slice(chunk));
+    }
+    Ok(())
+}
+
+impl<C: BlockCipher> core::fmt::Debug for Ecb<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Ecb { ... }")
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, BlockEncrypt, NewBlockCipher};
+use cipher::generic_array::GenericArray;
+use ecb::Ecb;
+use hex_literal::hex;
+
+const KEY: [u8; 16] = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+
+/// Test vectors from NIST SP 800-38A, F.1.1 and F.1.2.
+#[test]
+fn ecb_aes128() {
+    let pt = hex!("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710");
+    let ct = hex!("3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4");
+
+    let ecb = Ecb::<Aes128>::new_from_slice(&KEY).unwrap();
+    let mut buf = pt;
+    ecb.encrypt(&mut buf).unwrap();
+    assert_eq!(buf, ct);
+    ecb.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+/// Cover full parallel chunks as well as the remainder.
+#[test]
+fn par_blocks() {
+    let ecb = Ecb::new(Aes128::new(&KEY.into()));
+    for blocks in 0..=20 {
+        let pt: Vec<u8> = (0..16 * blocks).map(|i| i as u8).collect();
+        let mut expected = pt.clone();
+        for chunk in expected.chunks_exact_mut(16) {
+            ecb.cipher()
+                .encrypt_block(GenericArray::from_mut_slice(chunk));
+        }
+
+        let mut buf = pt.clone();
+        ecb.encrypt(&mut buf).unwrap();
+        assert_eq!(buf, expected);
+
+        let mut blocks: Vec<_> = pt.chunks(16).map(GenericArray::clone_from_slice).collect();
+        ecb.encrypt_blocks(&mut blocks);
+        assert_eq!(blocks.concat(), expected);
+        ecb.decrypt_blocks(&mut blocks);
+        assert_eq!(blocks.concat(), pt);
+
+        ecb.decrypt(&mut buf).unwrap();
+        assert_eq!(buf, pt);
+    }
+}
+
+#[test]
+fn invalid_length() {
+    let ecb = Ecb::new(Aes128::new(&KEY.into()));
+    assert!(ecb.encrypt(&mut [0; 17]).is_err());
+    assert!(ecb.decrypt(&mut [0; 15]).is_err());
+}
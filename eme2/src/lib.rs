@@ -0,0 +1,240 @@
+//! Generic implementation of the [EME2][1] wide-block encryption mode
+//! (EME\* by Halevi, standardized as IEEE P1619.2).
+//!
+//! EME2 turns a 128-bit block cipher into a tweakable pseudorandom
+//! permutation over a whole sector: every bit of the ciphertext depends on
+//! every bit of the plaintext and of the tweak. It is length-preserving and
+//! processes the data in two ECB passes with a masking pass in between,
+//! where the mask is refreshed every 128 blocks to support long sectors.
+//!
+//! The mode key consists of the block cipher key and two additional 128-bit
+//! keys: `K2`, the initial ECB mask, and `K3`, used to process the tweak.
+//!
+//! Data must be at least one block long and may end in a partial block,
+//! which is handled as in IEEE P1619.2. Tweaks may have any length.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use eme2::Eme2;
+//!
+//! let cipher = Aes128::new(&[0x42; 16].into());
+//! let eme = Eme2::new(cipher, &[0x24; 16].into(), &[0x33; 16].into());
+//!
+//! let mut sector = [0u8; 512];
+//! let tweak = 7u64.to_le_bytes();
+//! eme.encrypt(&tweak, &mut sector).unwrap();
+//! eme.decrypt(&tweak, &mut sector).unwrap();
+//! assert_eq!(sector, [0u8; 512]);
+//! ```
+//!
+//! [1]: https://eprint.iacr.org/2004/125
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use block_modes::gf128;
+use cipher::{
+    consts::U16, errors::InvalidLength, generic_array::GenericArray, BlockCipher, BlockDecrypt,
+    BlockEncrypt,
+};
+
+/// EME2 block.
+pub type Block = GenericArray<u8, U16>;
+
+/// Number of blocks after which the mask is refreshed.
+const MASK_PERIOD: usize = 128;
+
+const BS: usize = 16;
+
+#[inline(always)]
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a ^= b;
+    }
+}
+
+#[inline(always)]
+fn as_block(chunk: &mut [u8]) -> &mut Block {
+    GenericArray::from_mut_slice(chunk)
+}
+
+/// EME2 wide-block encryption mode instance.
+#[derive(Clone)]
+pub struct Eme2<C: BlockCipher<BlockSize = U16>> {
+    cipher: C,
+    k2: Block,
+    k3: Block,
+}
+
+impl<C> Eme2<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Create mode instance from the initialized block `cipher`, the ECB
+    /// mask key `k2` and the tweak key `k3`.
+    pub fn new(cipher: C, k2: &Block, k3: &Block) -> Self {
+        Self {
+            cipher,
+            k2: *k2,
+            k3: *k3,
+        }
+    }
+
+    /// Get reference to the underlying block cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Encrypt `data` in place under `tweak`.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than one block.
+    pub fn encrypt(&self, tweak: &[u8], data: &mut [u8]) -> Result<(), InvalidLength> {
+        check_len(data)?;
+        self.process(tweak, data, |c, b| c.encrypt_block(b));
+        Ok(())
+    }
+
+    /// Decrypt `data` in place under `tweak`.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than one block.
+    pub fn decrypt(&self, tweak: &[u8], data: &mut [u8]) -> Result<(), InvalidLength> {
+        check_len(data)?;
+        self.process(tweak, data, |c, b| c.decrypt_block(b));
+        Ok(())
+    }
+
+    /// Compute the tweak value `T*`.
+    ///
+    /// The tweak is split into blocks, the last one padded with `10*` if
+    /// partial, which are masked with `K3 * x^i` for `i` starting at one
+    /// (with an additional multiplication for a padded block) and encrypted,
+    /// similar to PMAC.
+    fn tweak_value(&self, tweak: &[u8]) -> Block {
+        let mut res = Block::default();
+        if tweak.is_empty() {
+            res = self.k3;
+            self.cipher.encrypt_block(&mut res);
+            return res;
+        }
+        let mut mask = self.k3;
+        gf128::double(&mut mask);
+        for chunk in tweak.chunks(BS) {
+            let mut block = if chunk.len() < BS {
+                gf128::double(&mut mask);
+                pad(chunk)
+            } else {
+                Block::clone_from_slice(chunk)
+            };
+            xor(&mut block, &mask);
+            self.cipher.encrypt_block(&mut block);
+            xor(&mut block, &mask);
+            xor(&mut res, &block);
+            gf128::double(&mut mask);
+        }
+        res
+    }
+
+    /// EME2 is symmetric: decryption is encryption with the inverse block
+    /// cipher, so both directions share this function.
+    ///
+    /// A partial last block `P_m` skips both ECB passes: it enters the
+    /// masking pass padded with `10*` and is encrypted by XORing it with
+    /// `MM`, an extra encryption of the mixed value `MP`.
+    fn process(&self, tweak: &[u8], data: &mut [u8], f: impl Fn(&C, &mut Block)) {
+        let t = self.tweak_value(tweak);
+        let (data, tail) = data.split_at_mut(data.len() - data.len() % BS);
+
+        // first ECB pass: PPP_i = E(P_i ^ L * x^(i-1))
+        let mut l = self.k2;
+        let mut sum = t;
+        for chunk in data.chunks_exact_mut(BS) {
+            let block = as_block(chunk);
+            xor(block, &l);
+            f(&self.cipher, block);
+            xor(&mut sum, block);
+            gf128::double(&mut l);
+        }
+        if !tail.is_empty() {
+            xor(&mut sum, &pad(tail));
+        }
+
+        // masking pass
+        let (first, rest) = data.split_at_mut(BS);
+        let mut mp = sum;
+        let mut mm = Block::default();
+        if !tail.is_empty() {
+            f(&self.cipher, &mut mp);
+            mm = mp;
+        }
+        let mut mc = mp;
+        f(&self.cipher, &mut mc);
+        let mut m1 = sum;
+        xor(&mut m1, &mc);
+        let mut m = m1;
+        let mut sum = Block::default();
+        for (i, chunk) in rest.chunks_exact_mut(BS).enumerate() {
+            let block = as_block(chunk);
+            if (i + 1) % MASK_PERIOD != 0 {
+                gf128::double(&mut m);
+                xor(block, &m);
+            } else {
+                // refresh the mask with another encryption
+                xor(block, &m1);
+                let mp = *block;
+                f(&self.cipher, block);
+                m = mp;
+                xor(&mut m, block);
+                xor(block, &m1);
+            }
+            xor(&mut sum, block);
+        }
+        if !tail.is_empty() {
+            // C_m = P_m ^ MM, enters the sum padded like P_m
+            xor(tail, &mm);
+            xor(&mut sum, &pad(tail));
+        }
+        let first = as_block(first);
+        first.copy_from_slice(&mc);
+        xor(first, &t);
+        xor(first, &sum);
+
+        // second ECB pass: C_i = E(CCC_i) ^ L * x^(i-1)
+        let mut l = self.k2;
+        for chunk in data.chunks_exact_mut(BS) {
+            let block = as_block(chunk);
+            f(&self.cipher, block);
+            xor(block, &l);
+            gf128::double(&mut l);
+        }
+    }
+}
+
+/// Pad a partial block with `10*`.
+fn pad(partial: &[u8]) -> Block {
+    let mut block = Block::default();
+    block[..partial.len()].copy_from_slice(partial);
+    block[partial.len()] = 0x80;
+    block
+}
+
+fn check_len(data: &[u8]) -> Result<(), InvalidLength> {
+    if data.len() < BS {
+        Err(InvalidLength)
+    } else {
+        Ok(())
+    }
+}
+
+impl<C: BlockCipher<BlockSize = U16>> core::fmt::Debug for Eme2<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("Eme2 { ... }")
+    }
+}
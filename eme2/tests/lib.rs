@@ -0,0 +1,234 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, Aes256, NewBlockCipher};
+use core::convert::TryInto;
+use eme2::Eme2;
+use hex_literal::hex;
+
+fn eme() -> Eme2<Aes128> {
+    let cipher = Aes128::new(&[0x42; 16].into());
+    Eme2::new(cipher, &[0x24; 16].into(), &[0x33; 16].into())
+}
+
+/// Split an EME2-AES-256 key `Key1 | Key2 | Key3` as in IEEE P1619.2.
+fn eme_aes256(key: &[u8; 64]) -> Eme2<Aes256> {
+    let cipher = Aes256::new(key[..32].into());
+    Eme2::new(cipher, key[32..48].into(), key[48..].into())
+}
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 + i / 256) as u8).collect()
+}
+
+#[test]
+fn roundtrip() {
+    let eme = eme();
+    // cover the mask refresh every 128 blocks
+    for &blocks in [1, 2, 3, 32, 128, 129, 256, 257, 300].iter() {
+        for tweak in [
+            &b""[..],
+            b"short",
+            b"exactly 16 bytes",
+            b"longer than a single block",
+        ]
+        .iter()
+        {
+            let pt = data(16 * blocks);
+            let mut buf = pt.clone();
+            eme.encrypt(tweak, &mut buf).unwrap();
+            assert_ne!(buf, pt);
+            eme.decrypt(tweak, &mut buf).unwrap();
+            assert_eq!(buf, pt);
+        }
+    }
+}
+
+#[test]
+fn wide_block_diffusion() {
+    let eme = eme();
+    let pt = data(4096);
+    let mut a = pt.clone();
+    eme.encrypt(b"sector", &mut a).unwrap();
+
+    // flipping the last plaintext bit changes every ciphertext block
+    let mut b = pt.clone();
+    *b.last_mut().unwrap() ^= 1;
+    eme.encrypt(b"sector", &mut b).unwrap();
+    for (x, y) in a.chunks(16).zip(b.chunks(16)) {
+        assert_ne!(x, y);
+    }
+
+    // and so does changing the tweak
+    let mut c = pt;
+    eme.encrypt(b"sectos", &mut c).unwrap();
+    for (x, y) in a.chunks(16).zip(c.chunks(16)) {
+        assert_ne!(x, y);
+    }
+}
+
+#[test]
+fn partial_last_block() {
+    let eme = eme();
+    for &len in [17, 31, 33, 16 * 128 + 1, 16 * 129 + 15, 16 * 300 + 9].iter() {
+        let pt = data(len);
+        let mut buf = pt.clone();
+        eme.encrypt(b"tweak", &mut buf).unwrap();
+        assert_ne!(buf, pt);
+        eme.decrypt(b"tweak", &mut buf).unwrap();
+        assert_eq!(buf, pt);
+    }
+}
+
+#[test]
+fn invalid_length() {
+    let eme = eme();
+    assert!(eme.encrypt(b"", &mut []).is_err());
+    assert!(eme.encrypt(b"", &mut [0; 15]).is_err());
+    assert!(eme.decrypt(b"", &mut [0; 1]).is_err());
+}
+
+/// Generated with an EME2-AES implementation in Python written from
+/// Tables 1 and 2 of the IEEE P1619.2 draft (section 5), on top of the AES
+/// of pyca/cryptography 48.0, as no EME2 vectors are published.
+///
+/// Keys are `00 01 02 ..` (48 bytes for AES-128, 64 for AES-256), tweaks
+/// count up from `80` and plaintexts from `40`.
+///
+/// (key, tweak, plaintext, ciphertext)
+type Vector = (&'static [u8], &'static [u8], &'static [u8], &'static [u8]);
+
+const VECTORS: &[Vector] = &[
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        &hex!(""),
+        &hex!("404142434445464748494a4b4c4d4e4f"),
+        &hex!("af3b0d55be9d8636ebd3e133bfebaaa6"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        &hex!("8081828384"),
+        &hex!("404142434445464748494a4b4c4d4e4f50"),
+        &hex!("05e26f1ce563e2e005ad33870043682bdc"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        &hex!("808182838485868788898a8b8c8d8e8f"),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e"),
+        &hex!("d9c151c2275ade2eaaeedde84a0602e381fe696a0a7060d2b504afc85d8584"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        &hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4"),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f"),
+        &hex!("712e426b72af6773407be72a8ff252b15449498579f39904664b469bf1424f7534b1d1439e45dd645ee43522c7873301562907bf456fb9fa7af19294ba38f33d"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"),
+        &hex!(""),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f7071"),
+        &hex!("e75e7650d8b0a6b0fc234046fa5793dd3def86886948819c25d881a7a9f7fad79683e914f9b79151d2a9e9a55c9c1840fbd1"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        &hex!(""),
+        &hex!("404142434445464748494a4b4c4d4e4f"),
+        &hex!("f911a09731b8d0b8c6489ea705b371db"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        &hex!("8081828384"),
+        &hex!("404142434445464748494a4b4c4d4e4f50"),
+        &hex!("a650ac3da093185f56f8ebc897e8e278bb"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        &hex!("808182838485868788898a8b8c8d8e8f"),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e"),
+        &hex!("19eedd547d8fcbfcf2b89f7c886efc8216b668c4422a3fb537a2a301bdbe39805cfd5e6326b235cfda107a9725cea1"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        &hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4"),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f"),
+        &hex!("a2ce4d80e55ec854875d83cddc4a9819f2264ff0e03aa0c12f25719fcec37d1bb375f3c23015211b6e02e85030dfaefa"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"),
+        &hex!("80"),
+        &hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e"),
+        &hex!("23c123d8a2df5f68b462835fcb23ec46b95bf46acf1f8f4852dfb784d063f8d3a4b4f2311719d56da93dd172359ede4a807c5df7f932275a7218b19b34bf2c"),
+    ),
+];
+
+#[test]
+fn eme2_aes_kat() {
+    for &(key, tweak, pt, ct) in VECTORS.iter() {
+        let mut buf = pt.to_vec();
+        match key.len() {
+            48 => {
+                let cipher = Aes128::new(key[..16].into());
+                let eme = Eme2::new(cipher, key[16..32].into(), key[32..].into());
+                eme.encrypt(tweak, &mut buf).unwrap();
+                assert_eq!(buf, ct);
+                eme.decrypt(tweak, &mut buf).unwrap();
+            }
+            _ => {
+                let eme = eme_aes256(key.try_into().unwrap());
+                eme.encrypt(tweak, &mut buf).unwrap();
+                assert_eq!(buf, ct);
+                eme.decrypt(tweak, &mut buf).unwrap();
+            }
+        }
+        assert_eq!(buf, pt);
+    }
+}
+
+/// Cover the mask refresh at block 129, computed with the same
+/// implementation. Only the first block depends on all intermediate
+/// values, so it is checked together with the blocks around the refresh.
+#[test]
+fn eme2_aes256_mask_refresh() {
+    let mut key = [0; 64];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let eme = eme_aes256(&key);
+    let pt: Vec<u8> = (0..130 * 16).map(|i| i as u8).collect();
+    let mut buf = pt.clone();
+    eme.encrypt(b"sector 0x1234", &mut buf).unwrap();
+
+    let blocks: Vec<&[u8]> = buf.chunks(16).collect();
+    assert_eq!(blocks[0], hex!("3011203a6b1cbb088ed65d34a9b51569"));
+    assert_eq!(blocks[126], hex!("735437ecdf1bb539503dd8dbf2646726"));
+    assert_eq!(blocks[127], hex!("3ad9c72cf0cd939d404157298c12ed7f"));
+    assert_eq!(blocks[128], hex!("446e979a55c30ecfbd7b261aed2a4add"));
+    assert_eq!(blocks[129], hex!("288334f8b7dfade90b45eb3c7120d5c3"));
+
+    eme.decrypt(b"sector 0x1234", &mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
+
+/// As above, with a partial block after the refresh.
+#[test]
+fn eme2_aes256_mask_refresh_partial() {
+    let mut key = [0; 64];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let eme = eme_aes256(&key);
+    let pt: Vec<u8> = (0..130 * 16 + 5).map(|i| i as u8).collect();
+    let mut buf = pt.clone();
+    eme.encrypt(b"sector 0x1234", &mut buf).unwrap();
+
+    let blocks: Vec<&[u8]> = buf.chunks(16).collect();
+    assert_eq!(blocks[0], hex!("36d63222ce2eb0cc18e99788c2e2aeb1"));
+    assert_eq!(blocks[126], hex!("2ac4d98390b6ea5b485751608e8b510a"));
+    assert_eq!(blocks[127], hex!("c48665411fa7f7cdf2dbfe635971eeec"));
+    assert_eq!(blocks[128], hex!("21cbb327186b532204fac3cc213330dc"));
+    assert_eq!(blocks[129], hex!("dc26e82d8ece0bb6b7fc21b0dea32dc2"));
+    assert_eq!(blocks[130], hex!("d0315da8be"));
+
+    eme.decrypt(b"sector 0x1234", &mut buf).unwrap();
+    assert_eq!(buf, pt);
+}
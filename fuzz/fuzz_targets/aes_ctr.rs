@@ -0,0 +1,18 @@
+#![no_main]
+
+use aes::{Aes128Ctr, Aes256Ctr};
+use block_ciphers_fuzz::{check_seekable, Op};
+use gost_modes::{generic_array::GenericArray, NewCipher};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: ([u8; 32], [u8; 16], Vec<Op>)| {
+    let (key, nonce, ops) = input;
+    let nonce = GenericArray::from_slice(&nonce);
+
+    let key128 = GenericArray::from_slice(&key[..16]);
+    check_seekable(|| Aes128Ctr::new(key128, nonce), &ops);
+    check_seekable(
+        || Aes256Ctr::new(GenericArray::from_slice(&key), nonce),
+        &ops,
+    );
+});
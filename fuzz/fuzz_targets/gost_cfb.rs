@@ -0,0 +1,32 @@
+#![no_main]
+
+use block_ciphers_fuzz::{check_async, Op};
+use gost_modes::{
+    consts::{U16, U32, U5},
+    generic_array::GenericArray,
+    GostCfb, NewCipher,
+};
+use kuznyechik::Kuznyechik;
+use libfuzzer_sys::fuzz_target;
+use magma::Magma;
+
+fuzz_target!(|input: ([u8; 32], [u8; 32], Vec<u8>, Vec<Op>)| {
+    let (key, iv, msg, ops) = input;
+    let key = GenericArray::from_slice(&key);
+
+    check_async(
+        GostCfb::<Kuznyechik, U32>::new(key, GenericArray::from_slice(&iv)),
+        &msg,
+        &ops,
+    );
+    check_async(
+        GostCfb::<Kuznyechik, U16, U5>::new(key, GenericArray::from_slice(&iv[..16])),
+        &msg,
+        &ops,
+    );
+    check_async(
+        GostCfb::<Magma, U16>::new(key, GenericArray::from_slice(&iv[..16])),
+        &msg,
+        &ops,
+    );
+});
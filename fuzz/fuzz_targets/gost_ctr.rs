@@ -0,0 +1,24 @@
+#![no_main]
+
+use block_ciphers_fuzz::{check_seekable, Op};
+use gost_modes::{
+    consts::{U14, U5},
+    generic_array::GenericArray,
+    GostCtr128, GostCtr64, NewCipher,
+};
+use kuznyechik::Kuznyechik;
+use libfuzzer_sys::fuzz_target;
+use magma::Magma;
+
+fuzz_target!(|input: ([u8; 32], [u8; 8], Vec<Op>)| {
+    let (key, nonce, ops) = input;
+    let key = GenericArray::from_slice(&key);
+
+    let nonce128 = GenericArray::from_slice(&nonce);
+    check_seekable(|| GostCtr128::<Kuznyechik>::new(key, nonce128), &ops);
+    check_seekable(|| GostCtr128::<Kuznyechik, U14>::new(key, nonce128), &ops);
+
+    let nonce64 = GenericArray::from_slice(&nonce[..4]);
+    check_seekable(|| GostCtr64::<Magma>::new(key, nonce64), &ops);
+    check_seekable(|| GostCtr64::<Magma, U5>::new(key, nonce64), &ops);
+});
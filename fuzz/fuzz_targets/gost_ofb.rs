@@ -0,0 +1,29 @@
+#![no_main]
+
+use block_ciphers_fuzz::{check_stream, Op};
+use gost_modes::{
+    consts::{U1, U2, U3},
+    generic_array::GenericArray,
+    GostOfb, NewCipher,
+};
+use kuznyechik::Kuznyechik;
+use libfuzzer_sys::fuzz_target;
+use magma::Magma;
+
+fuzz_target!(|input: ([u8; 32], [u8; 32], Vec<Op>)| {
+    let (key, iv, ops) = input;
+    let key = GenericArray::from_slice(&key);
+
+    check_stream(
+        GostOfb::<Kuznyechik, U2>::new(key, GenericArray::from_slice(&iv)),
+        &ops,
+    );
+    check_stream(
+        GostOfb::<Kuznyechik, U1, U3>::new(key, GenericArray::from_slice(&iv[..16])),
+        &ops,
+    );
+    check_stream(
+        GostOfb::<Magma, U3>::new(key, GenericArray::from_slice(&iv[..24])),
+        &ops,
+    );
+});
@@ -0,0 +1,116 @@
+//! Shared harness for the stream mode fuzz targets.
+//!
+//! Every target drives a mode wrapper with an arbitrary sequence of
+//! operations on odd-sized chunks and checks each output against the result
+//! of processing the whole message in one shot with a fresh instance.
+
+use gost_modes::{AsyncStreamCipher, StreamCipher, StreamCipherSeek};
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+
+/// Length of the reference message.
+const MSG_LEN: usize = 1 << 12;
+
+/// Operation performed on a mode instance.
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub enum Op {
+    /// Process the next `n` bytes of the message.
+    Apply(u8),
+    /// Seek to the given position (ignored by non-seekable modes).
+    Seek(u16),
+    /// Check the reported position and continue with a copy of the state.
+    Export,
+}
+
+/// Check a seekable stream cipher against its one-shot keystream.
+///
+/// State is exported by reading the current position and restoring it into
+/// a freshly created instance.
+pub fn check_seekable<C, F>(new: F, ops: &[Op])
+where
+    C: StreamCipher + StreamCipherSeek,
+    F: Fn() -> C,
+{
+    let mut keystream = vec![0u8; MSG_LEN];
+    new().apply_keystream(&mut keystream);
+
+    let mut cipher = new();
+    let mut pos = 0;
+    for op in ops {
+        match *op {
+            Op::Apply(n) => {
+                let n = (n as usize).min(MSG_LEN - pos);
+                let mut buf = vec![0u8; n];
+                cipher.apply_keystream(&mut buf);
+                assert_eq!(buf[..], keystream[pos..pos + n]);
+                pos += n;
+            }
+            Op::Seek(p) => {
+                pos = p as usize % (MSG_LEN + 1);
+                cipher.seek(pos as u64);
+            }
+            Op::Export => {
+                let exported: u64 = cipher.current_pos();
+                assert_eq!(exported, pos as u64);
+                cipher = new();
+                cipher.seek(exported);
+            }
+        }
+    }
+}
+
+/// Check a non-seekable stream cipher against its one-shot keystream.
+pub fn check_stream<C>(cipher: C, ops: &[Op])
+where
+    C: StreamCipher + Clone,
+{
+    let mut keystream = vec![0u8; MSG_LEN];
+    cipher.clone().apply_keystream(&mut keystream);
+
+    let mut cipher = cipher;
+    let mut pos = 0;
+    for op in ops {
+        match *op {
+            Op::Apply(n) => {
+                let n = (n as usize).min(MSG_LEN - pos);
+                let mut buf = vec![0u8; n];
+                cipher.apply_keystream(&mut buf);
+                assert_eq!(buf[..], keystream[pos..pos + n]);
+                pos += n;
+            }
+            Op::Seek(_) => {}
+            Op::Export => cipher = cipher.clone(),
+        }
+    }
+}
+
+/// Check an asynchronous stream cipher against one-shot encryption of `msg`
+/// and chunked decryption against the original message.
+pub fn check_async<C>(cipher: C, msg: &[u8], ops: &[Op])
+where
+    C: AsyncStreamCipher + Clone,
+{
+    let mut ct = msg.to_vec();
+    cipher.clone().encrypt(&mut ct);
+
+    let mut enc = cipher.clone();
+    let mut dec = cipher;
+    let mut pos = 0;
+    for op in ops {
+        match *op {
+            Op::Apply(n) => {
+                let n = (n as usize).min(msg.len() - pos);
+                let mut buf = msg[pos..pos + n].to_vec();
+                enc.encrypt(&mut buf);
+                assert_eq!(buf[..], ct[pos..pos + n]);
+                dec.decrypt(&mut buf);
+                assert_eq!(buf[..], msg[pos..pos + n]);
+                pos += n;
+            }
+            Op::Seek(_) => {}
+            Op::Export => {
+                enc = enc.clone();
+                dec = dec.clone();
+            }
+        }
+    }
+}
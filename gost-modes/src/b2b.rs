@@ -0,0 +1,69 @@
+use cipher::{errors::LoopError, AsyncStreamCipher, StreamCipher};
+
+/// Buffer-to-buffer keystream application for synchronous stream ciphers.
+///
+/// Implemented for every [`StreamCipher`], so it can be used with the CTR
+/// and OFB modes of this crate as well as with third-party stream ciphers.
+pub trait StreamCipherB2B: StreamCipher {
+    /// Apply keystream to `input`, writing the result into `output`.
+    ///
+    /// `input` is left untouched. Returns [`LoopError`] if the end of the
+    /// keystream would be reached, in which case the contents of `output`
+    /// are unspecified.
+    ///
+    /// # Panics
+    /// If `input` and `output` have different lengths.
+    fn try_apply_keystream_b2b(&mut self, input: &[u8], output: &mut [u8])
+        -> Result<(), LoopError>;
+
+    /// Apply keystream to `input`, writing the result into `output`.
+    ///
+    /// # Panics
+    /// If `input` and `output` have different lengths or if the end of the
+    /// keystream would be reached.
+    #[inline]
+    fn apply_keystream_b2b(&mut self, input: &[u8], output: &mut [u8]) {
+        self.try_apply_keystream_b2b(input, output).unwrap();
+    }
+}
+
+impl<C: StreamCipher> StreamCipherB2B for C {
+    #[inline]
+    fn try_apply_keystream_b2b(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), LoopError> {
+        assert_eq!(input.len(), output.len());
+        output.copy_from_slice(input);
+        self.try_apply_keystream(output)
+    }
+}
+
+/// Buffer-to-buffer encryption and decryption for asynchronous stream
+/// ciphers, e.g. the CFB mode.
+pub trait AsyncStreamCipherB2B: AsyncStreamCipher {
+    /// Encrypt `input`, writing the ciphertext into `output`.
+    ///
+    /// # Panics
+    /// If `input` and `output` have different lengths.
+    #[inline]
+    fn encrypt_b2b(&mut self, input: &[u8], output: &mut [u8]) {
+        assert_eq!(input.len(), output.len());
+        output.copy_from_slice(input);
+        self.encrypt(output);
+    }
+
+    /// Decrypt `input`, writing the plaintext into `output`.
+    ///
+    /// # Panics
+    /// If `input` and `output` have different lengths.
+    #[inline]
+    fn decrypt_b2b(&mut self, input: &[u8], output: &mut [u8]) {
+        assert_eq!(input.len(), output.len());
+        output.copy_from_slice(input);
+        self.decrypt(output);
+    }
+}
+
+impl<C: AsyncStreamCipher> AsyncStreamCipherB2B for C {}
@@ -1,6 +1,6 @@
 use crate::{utils::xor, GostPadding};
 use block_modes::{block_padding::Padding, BlockMode};
-use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt};
 use core::{marker::PhantomData, ops::Mul};
 use generic_array::typenum::{
     type_operators::{IsGreater, IsLessOrEqual},
@@ -20,7 +20,7 @@ use generic_array::{ArrayLength, GenericArray};
 #[derive(Clone)]
 pub struct GostCbc<C, P = GostPadding, Z = U1>
 where
-    C: BlockCipher + NewBlockCipher,
+    C: BlockCipher,
     C::BlockSize: IsLessOrEqual<U255>,
     Z: ArrayLength<Block<C>> + Unsigned + Mul<C::BlockSize> + IsGreater<U0> + IsLessOrEqual<U255>,
     Prod<Z, C::BlockSize>: ArrayLength<u8>,
@@ -34,7 +34,7 @@ where
 
 impl<C, P, Z> BlockMode<C, P> for GostCbc<C, P, Z>
 where
-    C: BlockCipher + BlockEncrypt + BlockDecrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     Z: ArrayLength<Block<C>> + Unsigned + Mul<C::BlockSize> + IsGreater<U0> + IsLessOrEqual<U255>,
     Prod<Z, C::BlockSize>: ArrayLength<u8>,
@@ -1,7 +1,5 @@
 use crate::utils::{xor_set1, xor_set2};
-use cipher::{
-    AsyncStreamCipher, Block, BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher,
-};
+use cipher::{AsyncStreamCipher, Block, BlockCipher, BlockEncrypt, FromBlockCipher};
 use core::ops::Sub;
 use generic_array::{
     typenum::{
@@ -13,8 +11,6 @@ use generic_array::{
 
 type BlockSize<C> = <C as BlockCipher>::BlockSize;
 
-type Tail<C, M> = GenericArray<u8, Diff<M, <C as BlockCipher>::BlockSize>>;
-
 /// Cipher feedback (CFB) mode of operation as defined in GOST R 34.13-2015
 ///
 /// Type parameters:
@@ -27,7 +23,7 @@ type Tail<C, M> = GenericArray<u8, Diff<M, <C as BlockCipher>::BlockSize>>;
 #[derive(Clone)]
 pub struct GostCfb<C, M = BlockSize<C>, S = BlockSize<C>>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     M: Unsigned + ArrayLength<u8> + IsGreaterOrEqual<C::BlockSize> + Sub<C::BlockSize>,
     S: Unsigned + ArrayLength<u8> + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
@@ -35,35 +31,26 @@ where
 {
     cipher: C,
     block: GenericArray<u8, S>,
-    tail: Tail<C, M>,
+    register: GenericArray<u8, M>,
     pos: u8,
 }
 
 impl<C, M, S> GostCfb<C, M, S>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     M: Unsigned + ArrayLength<u8> + IsGreaterOrEqual<C::BlockSize> + Sub<C::BlockSize>,
     S: Unsigned + ArrayLength<u8> + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
     Diff<M, C::BlockSize>: ArrayLength<u8>,
 {
+    /// Shift `S` bytes of ciphertext into the register and encrypt its
+    /// leading block to get the next gamma block.
     fn gen_block(&mut self) {
         let s = S::USIZE;
-        let ts = self.tail.len();
-        let mut block: Block<C> = Default::default();
-        if ts <= s {
-            let d = s - ts;
-            block[..ts].copy_from_slice(&self.tail);
-            block[ts..].copy_from_slice(&self.block[..d]);
-            self.tail = GenericArray::clone_from_slice(&self.block[d..]);
-        } else {
-            let d = ts - s;
-            let mut tail: Tail<C, M> = Default::default();
-            tail[..d].copy_from_slice(&self.tail[s..]);
-            tail[d..].copy_from_slice(&self.block);
-            block = GenericArray::clone_from_slice(&self.tail[..s]);
-            self.tail = tail;
-        }
+        let m = M::USIZE;
+        self.register.copy_within(s.., 0);
+        self.register[m - s..].copy_from_slice(&self.block);
+        let mut block = Block::<C>::clone_from_slice(&self.register[..C::BlockSize::USIZE]);
         self.cipher.encrypt_block(&mut block);
         self.block.copy_from_slice(&block[..s]);
     }
@@ -71,7 +58,7 @@ where
 
 impl<C, M, S> FromBlockCipher for GostCfb<C, M, S>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     M: Unsigned + ArrayLength<u8> + IsGreaterOrEqual<C::BlockSize> + Sub<C::BlockSize>,
     S: Unsigned + ArrayLength<u8> + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
@@ -87,7 +74,7 @@ where
         Self {
             cipher,
             block: GenericArray::clone_from_slice(&full_block[..S::USIZE]),
-            tail: GenericArray::clone_from_slice(&nonce[bs..]),
+            register: nonce.clone(),
             pos: 0,
         }
     }
@@ -95,7 +82,7 @@ where
 
 impl<C, M, S> AsyncStreamCipher for GostCfb<C, M, S>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     M: Unsigned + ArrayLength<u8> + IsGreaterOrEqual<C::BlockSize> + Sub<C::BlockSize>,
     S: Unsigned + ArrayLength<u8> + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
@@ -0,0 +1,139 @@
+use crate::utils::xor;
+use cipher::{
+    errors::{LoopError, OverflowError},
+    Block, BlockCipher, BlockEncrypt, FromBlockCipher, SeekNum, StreamCipher, StreamCipherSeek,
+};
+use generic_array::typenum::U8;
+use generic_array::GenericArray;
+
+/// Constant added to `N4` modulo `2^32 - 1`.
+const C1: u32 = 0x0101_0104;
+/// Constant added to `N3` modulo `2^32`.
+const C2: u32 = 0x0101_0101;
+/// Modulus of the `N4` addition.
+const M: u64 = 0xffff_ffff;
+
+/// Gamma (CNT) mode of operation as defined in GOST 28147-89
+///
+/// Unlike CTR of GOST R 34.13-2015 the initial counter is the encryption of
+/// the IV, and its halves `N3` and `N4` are updated for every block by
+/// adding the constants `C2` modulo `2^32` and `C1` modulo `2^32 - 1`
+/// respectively.
+///
+/// Halves are mapped to blocks in the same way as by the `magma` crate,
+/// i.e. `N3` (filled from `N1`) is the second big-endian word of the block.
+/// Implementations using the little-endian byte order of GOST 28147-89
+/// (e.g. GnuTLS) match it with every 32-bit key word and every 8-byte block
+/// of the IV and data byte-reversed.
+#[derive(Clone)]
+pub struct GostCnt<C>
+where
+    C: BlockCipher<BlockSize = U8> + BlockEncrypt,
+{
+    cipher: C,
+    n3: u32,
+    n4: u32,
+    ctr: u64,
+    block: Block<C>,
+    pos: u8,
+}
+
+impl<C> GostCnt<C>
+where
+    C: BlockCipher<BlockSize = U8> + BlockEncrypt,
+{
+    /// Generate keystream block with the (zero-based) index `ctr`.
+    fn gen_block(&self, ctr: u64) -> Block<C> {
+        // the registers are updated before generating every block
+        let steps = ctr + 1;
+        let n3 = self.n3.wrapping_add((steps as u32).wrapping_mul(C2));
+        let n4 = (u64::from(self.n4) + (steps % M) * u64::from(C1)) % M;
+        // after at least one addition zero is represented as 2^32 - 1
+        let n4 = if n4 == 0 { M as u32 } else { n4 as u32 };
+
+        let mut block = Block::<C>::default();
+        block[..4].copy_from_slice(&n4.to_be_bytes());
+        block[4..].copy_from_slice(&n3.to_be_bytes());
+        self.cipher.encrypt_block(&mut block);
+        block
+    }
+}
+
+impl<C> FromBlockCipher for GostCnt<C>
+where
+    C: BlockCipher<BlockSize = U8> + BlockEncrypt,
+{
+    type BlockCipher = C;
+    type NonceSize = U8;
+
+    fn from_block_cipher(cipher: C, nonce: &GenericArray<u8, U8>) -> Self {
+        let mut s = *nonce;
+        cipher.encrypt_block(&mut s);
+        let (n4, n3) = s.split_at(4);
+        Self {
+            cipher,
+            n3: u32::from_be_bytes([n3[0], n3[1], n3[2], n3[3]]),
+            n4: u32::from_be_bytes([n4[0], n4[1], n4[2], n4[3]]),
+            ctr: 0,
+            block: Default::default(),
+            pos: 0,
+        }
+    }
+}
+
+impl<C> StreamCipher for GostCnt<C>
+where
+    C: BlockCipher<BlockSize = U8> + BlockEncrypt,
+{
+    fn try_apply_keystream(&mut self, mut data: &mut [u8]) -> Result<(), LoopError> {
+        let pos = self.pos as usize;
+        let mut ctr = self.ctr;
+
+        if pos != 0 {
+            if data.len() < 8 - pos {
+                let n = data.len();
+                xor(data, &self.block[pos..pos + n]);
+                self.pos += n as u8;
+                return Ok(());
+            }
+            let (l, r) = { data }.split_at_mut(8 - pos);
+            data = r;
+            xor(l, &self.block[pos..]);
+            ctr += 1;
+        }
+
+        let mut iter = data.chunks_exact_mut(8);
+        for chunk in &mut iter {
+            xor(chunk, &self.gen_block(ctr));
+            ctr += 1;
+        }
+        let rem = iter.into_remainder();
+        self.pos = rem.len() as u8;
+        self.ctr = ctr;
+        if !rem.is_empty() {
+            self.block = self.gen_block(ctr);
+            xor(rem, &self.block[..rem.len()]);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> StreamCipherSeek for GostCnt<C>
+where
+    C: BlockCipher<BlockSize = U8> + BlockEncrypt,
+{
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        T::from_block_byte(self.ctr, self.pos, 8)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        let res: (u64, u8) = pos.to_block_byte(8)?;
+        self.ctr = res.0;
+        self.pos = res.1;
+        if self.pos != 0 {
+            self.block = self.gen_block(res.0);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::utils::xor;
+use block_modes::ParallelismHint;
 use cipher::{
     errors::{LoopError, OverflowError},
-    Block, BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher, SeekNum, StreamCipher,
+    Block, BlockCipher, BlockEncrypt, FromBlockCipher, ParBlocks, SeekNum, StreamCipher,
     StreamCipherSeek,
 };
 use generic_array::typenum::{
@@ -19,7 +20,7 @@ use generic_array::{ArrayLength, GenericArray};
 #[derive(Clone)]
 pub struct GostCtr128<C, S = <C as BlockCipher>::BlockSize>
 where
-    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
     C::ParBlocks: ArrayLength<GenericArray<u8, U16>>,
     S: ArrayLength<u8> + Unsigned + IsGreater<U0> + IsLessOrEqual<U16>,
 {
@@ -32,24 +33,43 @@ where
 
 impl<C, S> GostCtr128<C, S>
 where
-    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
     C::ParBlocks: ArrayLength<GenericArray<u8, U16>>,
     S: ArrayLength<u8> + Unsigned + IsGreater<U0> + IsLessOrEqual<U16>,
 {
-    fn gen_block(&self, ctr: u64) -> GenericArray<u8, S> {
+    fn ctr_block(&self, ctr: u64) -> Block<C> {
         let mut block: Block<C> = Default::default();
         block[..8].copy_from_slice(&self.nonce.to_be_bytes());
         block[8..].copy_from_slice(&ctr.to_be_bytes());
+        block
+    }
+
+    fn gen_block(&self, ctr: u64) -> GenericArray<u8, S> {
+        let mut block = self.ctr_block(ctr);
         self.cipher.encrypt_block(&mut block);
         let mut res: GenericArray<u8, S> = Default::default();
         res.copy_from_slice(&block[..S::USIZE]);
         res
     }
+
+    /// Apply keystream to `C::ParBlocks` consecutive `S`-byte chunks of `data`
+    /// using a single call to `encrypt_par_blocks`.
+    fn apply_par_blocks(&self, ctr: u64, data: &mut [u8]) {
+        debug_assert_eq!(data.len(), S::USIZE * C::PAR_BLOCKS);
+        let mut blocks = ParBlocks::<C>::default();
+        for (i, block) in blocks.iter_mut().enumerate() {
+            *block = self.ctr_block(ctr + i as u64);
+        }
+        self.cipher.encrypt_par_blocks(&mut blocks);
+        for (chunk, block) in data.chunks_exact_mut(S::USIZE).zip(blocks.iter()) {
+            xor(chunk, &block[..S::USIZE]);
+        }
+    }
 }
 
 impl<C, S> FromBlockCipher for GostCtr128<C, S>
 where
-    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
     C::ParBlocks: ArrayLength<GenericArray<u8, U16>>,
     S: ArrayLength<u8> + Unsigned + IsGreater<U0> + IsLessOrEqual<U16>,
 {
@@ -69,7 +89,7 @@ where
 
 impl<C, S> StreamCipher for GostCtr128<C, S>
 where
-    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
     C::ParBlocks: ArrayLength<GenericArray<u8, U16>>,
     S: ArrayLength<u8> + Unsigned + IsGreater<U0> + IsLessOrEqual<U16>,
 {
@@ -92,6 +112,16 @@ where
             }
         }
 
+        let pb = C::PAR_BLOCKS;
+        if pb > 1 {
+            let mut iter = data.chunks_exact_mut(pb * s);
+            for chunk in &mut iter {
+                self.apply_par_blocks(ctr, chunk);
+                ctr += pb as u64;
+            }
+            data = iter.into_remainder();
+        }
+
         let mut iter = data.chunks_exact_mut(s);
         for chunk in &mut iter {
             xor(chunk, &self.gen_block(ctr));
@@ -111,7 +141,7 @@ where
 
 impl<C, S> StreamCipherSeek for GostCtr128<C, S>
 where
-    C: BlockCipher<BlockSize = U16> + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt,
     C::ParBlocks: ArrayLength<GenericArray<u8, U16>>,
     S: ArrayLength<u8> + Unsigned + IsGreater<U0> + IsLessOrEqual<U16>,
 {
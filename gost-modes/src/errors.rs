@@ -0,0 +1,29 @@
+use core::fmt;
+
+/// Error returned by [`Metered`][crate::Metered] when processing data would
+/// exceed the configured byte limit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LimitExceeded;
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("LimitExceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
+
+/// Error returned by [`KExp15`][crate::KExp15] on invalid IV, key or buffer
+/// lengths and on failed integrity check of the imported key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyWrapError;
+
+impl fmt::Display for KeyWrapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("KeyWrapError")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyWrapError {}
@@ -0,0 +1,155 @@
+use crate::{errors::KeyWrapError, utils::xor};
+use cipher::{generic_array::typenum::Unsigned, Block, BlockCipher, BlockEncrypt};
+
+/// Key export and import algorithms KExp15 and KImp15 as defined in
+/// R 1323565.1.017-2018
+///
+/// The exported key is encrypted in CTR mode under the encryption key
+/// together with its OMAC (CMAC) computed under the MAC key over the IV
+/// and the key. The IV is half a block long, and the exported blob is one
+/// block longer than the key.
+///
+/// Both 64-bit (Magma) and 128-bit (Kuznyechik) block ciphers are
+/// supported.
+#[derive(Clone)]
+pub struct KExp15<C: BlockCipher + BlockEncrypt> {
+    enc: C,
+    mac: C,
+}
+
+impl<C: BlockCipher + BlockEncrypt> KExp15<C> {
+    /// Create instance from block ciphers initialized with the encryption
+    /// key `enc` and the MAC key `mac`.
+    ///
+    /// # Panics
+    /// If the block size of `C` is neither 64 nor 128 bits.
+    pub fn new(enc: C, mac: C) -> Self {
+        reduction(C::BlockSize::USIZE).expect("unsupported block size");
+        Self { enc, mac }
+    }
+
+    /// Export `key` under the initialization vector `iv` into `out`
+    /// (KExp15).
+    ///
+    /// `iv` must be half a block long and `out` must be one block longer
+    /// than `key`.
+    pub fn export(&self, iv: &[u8], key: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError> {
+        let bs = C::BlockSize::USIZE;
+        if iv.len() != bs / 2 || key.is_empty() || out.len() != key.len() + bs {
+            return Err(KeyWrapError);
+        }
+        let (k, tag) = out.split_at_mut(key.len());
+        k.copy_from_slice(key);
+        tag.copy_from_slice(&self.omac(iv, key));
+        self.ctr(iv, 0, out);
+        Ok(())
+    }
+
+    /// Import the key exported under the initialization vector `iv` from
+    /// `data` into `out` (KImp15).
+    ///
+    /// `iv` must be half a block long and `out` must be one block shorter
+    /// than `data`. On integrity check failure `out` is filled with zeros.
+    pub fn import(&self, iv: &[u8], data: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError> {
+        let bs = C::BlockSize::USIZE;
+        if iv.len() != bs / 2 || data.len() <= bs || out.len() != data.len() - bs {
+            return Err(KeyWrapError);
+        }
+        let mut tag = Block::<C>::clone_from_slice(&data[out.len()..]);
+        out.copy_from_slice(&data[..out.len()]);
+        // decrypt the key and the tag as a single CTR stream
+        self.ctr(iv, 0, out);
+        self.ctr(iv, out.len(), &mut tag);
+
+        let expected = self.omac(iv, out);
+        let diff = expected
+            .iter()
+            .zip(tag.iter())
+            .fold(0, |d, (a, b)| d | (a ^ b));
+        if diff == 0 {
+            Ok(())
+        } else {
+            out.iter_mut().for_each(|b| *b = 0);
+            Err(KeyWrapError)
+        }
+    }
+
+    /// Apply CTR keystream with counter block `iv || 0` starting at the
+    /// byte offset `pos`.
+    fn ctr(&self, iv: &[u8], pos: usize, buf: &mut [u8]) {
+        let bs = C::BlockSize::USIZE;
+        let mut ctr = (pos / bs) as u64;
+        let mut skip = pos % bs;
+        let mut buf = buf;
+        while !buf.is_empty() {
+            let mut block = Block::<C>::default();
+            block[..bs / 2].copy_from_slice(iv);
+            let ctr_bytes = ctr.to_be_bytes();
+            block[bs / 2..].copy_from_slice(&ctr_bytes[8 - bs / 2..]);
+            self.enc.encrypt_block(&mut block);
+
+            let n = core::cmp::min(bs - skip, buf.len());
+            let (chunk, rest) = { buf }.split_at_mut(n);
+            xor(chunk, &block[skip..skip + n]);
+            buf = rest;
+            skip = 0;
+            ctr += 1;
+        }
+    }
+
+    /// OMAC (CMAC) of `iv || key` as defined in GOST R 34.13-2015.
+    fn omac(&self, iv: &[u8], key: &[u8]) -> Block<C> {
+        let bs = C::BlockSize::USIZE;
+        let r = reduction(bs).unwrap_or(0);
+
+        let mut k1 = Block::<C>::default();
+        self.mac.encrypt_block(&mut k1);
+        dbl(&mut k1, r);
+        let mut k2 = k1.clone();
+        dbl(&mut k2, r);
+
+        let mut state = Block::<C>::default();
+        let mut pos = 0;
+        for &b in iv.iter().chain(key.iter()) {
+            if pos == bs {
+                self.mac.encrypt_block(&mut state);
+                pos = 0;
+            }
+            state[pos] ^= b;
+            pos += 1;
+        }
+        if pos == bs {
+            xor(&mut state, &k1);
+        } else {
+            state[pos] ^= 0x80;
+            xor(&mut state, &k2);
+        }
+        self.mac.encrypt_block(&mut state);
+        state
+    }
+}
+
+/// Low byte of the irreducible polynomial for the block size in bytes.
+fn reduction(block_size: usize) -> Option<u8> {
+    match block_size {
+        8 => Some(0x1b),
+        16 => Some(0x87),
+        _ => None,
+    }
+}
+
+/// Multiply `block` by `x` in `GF(2^n)`.
+fn dbl(block: &mut [u8], r: u8) {
+    let carry = block[0] >> 7;
+    for i in 0..block.len() - 1 {
+        block[i] = (block[i] << 1) | (block[i + 1] >> 7);
+    }
+    let last = block.len() - 1;
+    block[last] = (block[last] << 1) ^ (r & carry.wrapping_neg());
+}
+
+impl<C: BlockCipher + BlockEncrypt> core::fmt::Debug for KExp15<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("KExp15 { ... }")
+    }
+}
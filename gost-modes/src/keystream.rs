@@ -0,0 +1,104 @@
+use cipher::StreamCipher;
+use core::{iter::FusedIterator, marker::PhantomData};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Size of the internal keystream buffer used by [`Keystream`].
+const BUF_SIZE: usize = 64;
+
+/// Iterator over the keystream bytes of a synchronous stream cipher.
+///
+/// Keystream is generated lazily in chunks of 64 bytes, so the wrapped
+/// cipher may be advanced past the last byte returned by the iterator.
+/// Iteration stops when the cipher's keystream is exhausted.
+#[derive(Clone)]
+pub struct Keystream<C: StreamCipher> {
+    cipher: C,
+    buf: [u8; BUF_SIZE],
+    pos: usize,
+    done: bool,
+}
+
+impl<C: StreamCipher> Keystream<C> {
+    /// Create a keystream iterator from a stream cipher instance.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            buf: [0; BUF_SIZE],
+            pos: BUF_SIZE,
+            done: false,
+        }
+    }
+}
+
+impl<C: StreamCipher> Iterator for Keystream<C> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == BUF_SIZE {
+            if self.done {
+                return None;
+            }
+            self.buf = [0; BUF_SIZE];
+            if self.cipher.try_apply_keystream(&mut self.buf).is_err() {
+                self.done = true;
+                return None;
+            }
+            self.pos = 0;
+        }
+        let b = self.buf[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
+}
+
+impl<C: StreamCipher> FusedIterator for Keystream<C> {}
+
+/// Iterator over the keystream of a synchronous stream cipher split into
+/// chunks of `N` bytes.
+///
+/// Iteration stops when the cipher's keystream is exhausted.
+#[derive(Clone)]
+pub struct KeystreamChunks<C: StreamCipher, N: ArrayLength<u8>> {
+    cipher: C,
+    done: bool,
+    _pd: PhantomData<N>,
+}
+
+impl<C: StreamCipher, N: ArrayLength<u8>> KeystreamChunks<C, N> {
+    /// Create a chunked keystream iterator from a stream cipher instance.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            done: false,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Get the wrapped cipher back.
+    ///
+    /// Since chunks are generated on demand, the cipher is positioned right
+    /// after the last returned chunk.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+}
+
+impl<C: StreamCipher, N: ArrayLength<u8>> Iterator for KeystreamChunks<C, N> {
+    type Item = GenericArray<u8, N>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = GenericArray::default();
+        if self.cipher.try_apply_keystream(&mut chunk).is_err() {
+            self.done = true;
+            return None;
+        }
+        Some(chunk)
+    }
+}
+
+impl<C: StreamCipher, N: ArrayLength<u8>> FusedIterator for KeystreamChunks<C, N> {}
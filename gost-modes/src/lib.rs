@@ -2,8 +2,12 @@
 //! operation][1] defined in [GOST R 34.13-2015].
 //!
 //! CTR, CFB and OFB modes are implemented in terms of traits from the [`cipher`] crate.
+//! Keystream of the synchronous modes can be pulled lazily using the
+//! [`Keystream`] and [`KeystreamChunks`] iterator adapters.
 //!
 //! MAC function defined in the GOST is implemented in the [`cmac`] crate.
+//! Key export algorithms KExp15 and KImp15 of R 1323565.1.017-2018 are
+//! implemented by [`KExp15`].
 //!
 //! # Examples
 //! ```
@@ -52,19 +56,31 @@
 #![deny(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use block_modes;
 pub use block_modes::block_padding;
 pub use cipher::{self, consts};
 pub use generic_array;
 
+#[cfg(feature = "alloc")]
+pub use block_modes::SharedCipher;
 pub use block_modes::{BlockMode, Ecb};
 pub use cipher::{AsyncStreamCipher, NewCipher, StreamCipher, StreamCipherSeek};
 
+mod b2b;
 mod cbc;
 mod cfb;
+mod cnt;
 mod ctr128;
 mod ctr64;
+mod errors;
+mod kexp15;
+mod keystream;
+mod metered;
 mod ofb;
+mod rekeying;
 mod utils;
 
 /// Block padding procedure number 2 as defined in GOST R 34.13-2015
@@ -72,8 +88,29 @@ mod utils;
 /// Fully equivalent to ISO 7816.
 pub type GostPadding = block_padding::Iso7816;
 
+pub use b2b::{AsyncStreamCipherB2B, StreamCipherB2B};
 pub use cbc::GostCbc;
 pub use cfb::GostCfb;
+pub use cnt::GostCnt;
 pub use ctr128::GostCtr128;
 pub use ctr64::GostCtr64;
+pub use errors::{KeyWrapError, LimitExceeded};
+pub use kexp15::KExp15;
+pub use keystream::{Keystream, KeystreamChunks};
+pub use metered::Metered;
 pub use ofb::GostOfb;
+pub use rekeying::{Acpkm, FnPolicy, RekeyPolicy, Rekeying};
+
+/// CTR-ACPKM mode for 128-bit block ciphers as defined in
+/// R 1323565.1.017-2018, e.g. for GOST TLS and CMS cipher suites.
+///
+/// Use [`Acpkm::new`] with the section size in blocks to create the
+/// policy.
+pub type GostCtr128Acpkm<C> = Rekeying<GostCtr128<C>, Acpkm>;
+
+/// CTR-ACPKM mode for 64-bit block ciphers as defined in
+/// R 1323565.1.017-2018.
+///
+/// Use [`Acpkm::new`] with the section size in blocks to create the
+/// policy.
+pub type GostCtr64Acpkm<C> = Rekeying<GostCtr64<C>, Acpkm>;
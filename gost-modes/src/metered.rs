@@ -0,0 +1,156 @@
+use crate::errors::LimitExceeded;
+use cipher::{errors::LoopError, AsyncStreamCipher, StreamCipher};
+
+/// Stream cipher wrapper keeping count of processed bytes.
+///
+/// An optional limit can be set, after which any attempt to process more
+/// data fails with [`LimitExceeded`] without touching the data. This allows
+/// enforcing rekeying policies (e.g. "at most 2^32 bytes per key") in one
+/// place instead of at every call site.
+///
+/// Processing is all-or-nothing: a call which would cross the limit is
+/// rejected as a whole, so [`Metered::bytes_processed`] never exceeds the
+/// limit. When used through the [`StreamCipher`] trait reaching the limit
+/// is reported as [`LoopError`], while the [`AsyncStreamCipher`] methods
+/// panic; use the inherent `try_*` methods to get [`LimitExceeded`] instead.
+///
+/// ```
+/// use gost_modes::{GostCtr128, LimitExceeded, Metered, NewCipher, StreamCipher};
+/// use kuznyechik::Kuznyechik;
+///
+/// let ctr = GostCtr128::<Kuznyechik>::new_from_slices(&[0; 32], &[0; 8]).unwrap();
+/// let mut cipher = Metered::with_limit(ctr, 64);
+///
+/// cipher.try_apply(&mut [0; 48]).unwrap();
+/// assert_eq!(cipher.bytes_processed(), 48);
+/// assert_eq!(cipher.try_apply(&mut [0; 17]), Err(LimitExceeded));
+/// assert_eq!(cipher.remaining(), Some(16));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Metered<S> {
+    inner: S,
+    processed: u64,
+    limit: Option<u64>,
+}
+
+impl<S> Metered<S> {
+    /// Wrap stream cipher without a limit.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            processed: 0,
+            limit: None,
+        }
+    }
+
+    /// Wrap stream cipher, allowing at most `limit` bytes to be processed.
+    pub fn with_limit(inner: S, limit: u64) -> Self {
+        Self {
+            inner,
+            processed: 0,
+            limit: Some(limit),
+        }
+    }
+
+    /// Total number of bytes processed so far.
+    pub fn bytes_processed(&self) -> u64 {
+        self.processed
+    }
+
+    /// Number of full or partial blocks of size `block_size` covered by
+    /// the processed bytes.
+    ///
+    /// # Panics
+    /// If `block_size` is zero.
+    pub fn blocks_processed(&self, block_size: usize) -> u64 {
+        let bs = block_size as u64;
+        self.processed / bs + (self.processed % bs != 0) as u64
+    }
+
+    /// Currently configured limit.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Change the limit, `None` removes it.
+    ///
+    /// Already processed bytes are taken into account, i.e. setting a limit
+    /// below [`Metered::bytes_processed`] blocks any further processing.
+    pub fn set_limit(&mut self, limit: Option<u64>) {
+        self.limit = limit;
+    }
+
+    /// Number of bytes which can be processed before reaching the limit.
+    pub fn remaining(&self) -> Option<u64> {
+        self.limit.map(|l| l.saturating_sub(self.processed))
+    }
+
+    /// Get reference to the wrapped stream cipher.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the stream cipher.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn check(&self, len: usize) -> Result<u64, LimitExceeded> {
+        let processed = self
+            .processed
+            .checked_add(len as u64)
+            .ok_or(LimitExceeded)?;
+        match self.limit {
+            Some(limit) if processed > limit => Err(LimitExceeded),
+            _ => Ok(processed),
+        }
+    }
+}
+
+impl<S: StreamCipher> Metered<S> {
+    /// Apply keystream to `data` unless it would exceed the limit.
+    ///
+    /// # Panics
+    /// If the end of the wrapped cipher's keystream would be reached.
+    pub fn try_apply(&mut self, data: &mut [u8]) -> Result<(), LimitExceeded> {
+        let processed = self.check(data.len())?;
+        self.inner.apply_keystream(data);
+        self.processed = processed;
+        Ok(())
+    }
+}
+
+impl<S: AsyncStreamCipher> Metered<S> {
+    /// Encrypt `data` unless it would exceed the limit.
+    pub fn try_encrypt(&mut self, data: &mut [u8]) -> Result<(), LimitExceeded> {
+        self.processed = self.check(data.len())?;
+        self.inner.encrypt(data);
+        Ok(())
+    }
+
+    /// Decrypt `data` unless it would exceed the limit.
+    pub fn try_decrypt(&mut self, data: &mut [u8]) -> Result<(), LimitExceeded> {
+        self.processed = self.check(data.len())?;
+        self.inner.decrypt(data);
+        Ok(())
+    }
+}
+
+impl<S: StreamCipher> StreamCipher for Metered<S> {
+    fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+        let processed = self.check(data.len()).map_err(|_| LoopError)?;
+        self.inner.try_apply_keystream(data)?;
+        self.processed = processed;
+        Ok(())
+    }
+}
+
+impl<S: AsyncStreamCipher> AsyncStreamCipher for Metered<S> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        self.try_encrypt(data).unwrap();
+    }
+
+    fn decrypt(&mut self, data: &mut [u8]) {
+        self.try_decrypt(data).unwrap();
+    }
+}
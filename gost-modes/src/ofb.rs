@@ -1,8 +1,5 @@
 use crate::utils::xor;
-use cipher::{
-    errors::LoopError, Block, BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher,
-    StreamCipher,
-};
+use cipher::{errors::LoopError, Block, BlockCipher, BlockEncrypt, FromBlockCipher, StreamCipher};
 use core::{marker::PhantomData, ops::Mul};
 use generic_array::typenum::{
     type_operators::{IsGreater, IsLessOrEqual},
@@ -22,7 +19,7 @@ use generic_array::{ArrayLength, GenericArray};
 #[derive(Clone)]
 pub struct GostOfb<C, Z = U1, S = <C as BlockCipher>::BlockSize>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     S: Unsigned + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
     Z: ArrayLength<Block<C>> + Unsigned + Mul<C::BlockSize> + IsGreater<U0> + IsLessOrEqual<U255>,
@@ -37,7 +34,7 @@ where
 
 impl<C, Z, S> FromBlockCipher for GostOfb<C, Z, S>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     S: Unsigned + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
     Z: ArrayLength<Block<C>> + Unsigned + Mul<C::BlockSize> + IsGreater<U0> + IsLessOrEqual<U255>,
@@ -67,7 +64,7 @@ where
 
 impl<C, Z, S> StreamCipher for GostOfb<C, Z, S>
 where
-    C: BlockCipher + BlockEncrypt + NewBlockCipher,
+    C: BlockCipher + BlockEncrypt,
     C::BlockSize: IsLessOrEqual<U255>,
     S: Unsigned + IsGreater<U0> + IsLessOrEqual<C::BlockSize>,
     Z: ArrayLength<Block<C>> + Unsigned + Mul<C::BlockSize> + IsGreater<U0> + IsLessOrEqual<U255>,
@@ -0,0 +1,205 @@
+use cipher::{
+    errors::LoopError,
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockEncrypt, FromBlockCipher, NewBlockCipher, StreamCipher, StreamCipherSeek,
+};
+
+type Key<C> = GenericArray<u8, <C as NewBlockCipher>::KeySize>;
+type Nonce<M> = GenericArray<u8, <M as FromBlockCipher>::NonceSize>;
+
+/// Policy deciding when and how [`Rekeying`] rotates the cipher key.
+pub trait RekeyPolicy<C: NewBlockCipher> {
+    /// Number of cipher blocks processed under a single key.
+    ///
+    /// Must not be zero.
+    fn section_blocks(&self) -> u64;
+
+    /// Derive the key for the next section.
+    ///
+    /// `cipher` is initialized with the current `key`, which allows
+    /// implementing schemes like ACPKM which derive the next key by
+    /// encrypting constants.
+    fn next_key(&mut self, cipher: &C, key: &Key<C>) -> Key<C>;
+}
+
+/// [`RekeyPolicy`] rotating the key every fixed number of blocks using a
+/// key derivation closure.
+#[derive(Clone, Debug)]
+pub struct FnPolicy<F> {
+    blocks: u64,
+    f: F,
+}
+
+impl<F> FnPolicy<F> {
+    /// Create policy calling `f` after every `blocks` blocks.
+    ///
+    /// # Panics
+    /// If `blocks` is zero.
+    pub fn new(blocks: u64, f: F) -> Self {
+        assert!(blocks != 0, "section length must not be zero");
+        Self { blocks, f }
+    }
+}
+
+impl<C, F> RekeyPolicy<C> for FnPolicy<F>
+where
+    C: NewBlockCipher,
+    F: FnMut(&C, &Key<C>) -> Key<C>,
+{
+    fn section_blocks(&self) -> u64 {
+        self.blocks
+    }
+
+    fn next_key(&mut self, cipher: &C, key: &Key<C>) -> Key<C> {
+        (self.f)(cipher, key)
+    }
+}
+
+/// ACPKM key derivation as defined in R 1323565.1.017-2018.
+///
+/// The key of the next section is the encryption of the constant
+/// `D = 80 81 .. 9f`, truncated to the key size, under the current key.
+/// Combined with [`Rekeying`] and a CTR mode this gives the CTR-ACPKM mode,
+/// see [`GostCtr128Acpkm`][crate::GostCtr128Acpkm] and
+/// [`GostCtr64Acpkm`][crate::GostCtr64Acpkm].
+#[derive(Clone, Debug)]
+pub struct Acpkm {
+    blocks: u64,
+}
+
+impl Acpkm {
+    /// Create policy changing the key after every `blocks` blocks, i.e.
+    /// for the section size `N` in bytes `blocks = N / block_size`.
+    ///
+    /// # Panics
+    /// If `blocks` is zero.
+    pub fn new(blocks: u64) -> Self {
+        assert!(blocks != 0, "section length must not be zero");
+        Self { blocks }
+    }
+}
+
+impl<C> RekeyPolicy<C> for Acpkm
+where
+    C: NewBlockCipher + BlockEncrypt,
+{
+    fn section_blocks(&self) -> u64 {
+        self.blocks
+    }
+
+    fn next_key(&mut self, cipher: &C, _key: &Key<C>) -> Key<C> {
+        let bs = C::BlockSize::to_usize();
+        let mut key = Key::<C>::default();
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = 0x80 + i as u8;
+        }
+        for block in key.chunks_exact_mut(bs) {
+            cipher.encrypt_block(GenericArray::from_mut_slice(block));
+        }
+        key
+    }
+}
+
+/// Stream cipher mode wrapper which transparently rotates the key of the
+/// underlying block cipher after a policy-defined number of blocks.
+///
+/// At every section boundary the next key is derived by the [`RekeyPolicy`]
+/// and the mode is re-initialized with it and the original nonce, then
+/// moved to the current keystream position. For counter-based modes this
+/// means the counter continues across key changes, as in ACPKM-like
+/// schemes.
+///
+/// ```
+/// use gost_modes::{Acpkm, GostCtr128, Rekeying, StreamCipher};
+/// use kuznyechik::Kuznyechik;
+///
+/// // change the key every 2 blocks
+/// let mut cipher = Rekeying::<GostCtr128<Kuznyechik>, _>::new(
+///     &[0x42; 32].into(),
+///     &[0x24; 8].into(),
+///     Acpkm::new(2),
+/// );
+/// let mut buf = [0u8; 100];
+/// cipher.apply_keystream(&mut buf);
+/// assert_eq!(cipher.rekeys(), 3);
+/// ```
+pub struct Rekeying<M, P>
+where
+    M: FromBlockCipher,
+    M::BlockCipher: NewBlockCipher,
+{
+    mode: M,
+    policy: P,
+    key: Key<M::BlockCipher>,
+    nonce: Nonce<M>,
+    pos: u64,
+    rekeys: u64,
+}
+
+impl<M, P> Rekeying<M, P>
+where
+    M: FromBlockCipher + StreamCipherSeek,
+    M::BlockCipher: NewBlockCipher,
+    P: RekeyPolicy<M::BlockCipher>,
+{
+    /// Initialize mode with the first section key and nonce.
+    pub fn new(key: &Key<M::BlockCipher>, nonce: &Nonce<M>, policy: P) -> Self {
+        let cipher = M::BlockCipher::new(key);
+        Self {
+            mode: M::from_block_cipher(cipher, nonce),
+            policy,
+            key: key.clone(),
+            nonce: nonce.clone(),
+            pos: 0,
+            rekeys: 0,
+        }
+    }
+
+    /// Number of key changes performed so far.
+    pub fn rekeys(&self) -> u64 {
+        self.rekeys
+    }
+
+    /// Get reference to the rekeying policy.
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    fn section_len(&self) -> u64 {
+        let bs = <M::BlockCipher as BlockCipher>::BlockSize::U64;
+        self.policy.section_blocks() * bs
+    }
+
+    fn rekey(&mut self) -> Result<(), LoopError> {
+        let cipher = M::BlockCipher::new(&self.key);
+        self.key = self.policy.next_key(&cipher, &self.key);
+        let cipher = M::BlockCipher::new(&self.key);
+        self.mode = M::from_block_cipher(cipher, &self.nonce);
+        self.mode.try_seek(self.pos)?;
+        self.rekeys += 1;
+        Ok(())
+    }
+}
+
+impl<M, P> StreamCipher for Rekeying<M, P>
+where
+    M: FromBlockCipher + StreamCipher + StreamCipherSeek,
+    M::BlockCipher: NewBlockCipher,
+    P: RekeyPolicy<M::BlockCipher>,
+{
+    fn try_apply_keystream(&mut self, mut data: &mut [u8]) -> Result<(), LoopError> {
+        let section_len = self.section_len();
+        while !data.is_empty() {
+            let offset = self.pos % section_len;
+            if offset == 0 && self.pos != 0 && self.rekeys < self.pos / section_len {
+                self.rekey()?;
+            }
+            let n = core::cmp::min(section_len - offset, data.len() as u64) as usize;
+            let (chunk, rest) = { data }.split_at_mut(n);
+            self.mode.try_apply_keystream(chunk)?;
+            self.pos += n as u64;
+            data = rest;
+        }
+        Ok(())
+    }
+}
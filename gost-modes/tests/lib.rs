@@ -1,12 +1,18 @@
 //! Test vectors from GOST R 34.13-2015:
 //! https://tc26.ru/standard/gost/GOST_R_3413-2015.pdf
 
+#![allow(clippy::needless_borrow, clippy::redundant_slicing)]
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use gost_modes::{
     block_padding::ZeroPadding,
-    consts::{U14, U16, U2, U3, U32, U5},
+    consts::{U1, U14, U16, U2, U3, U32, U5},
     generic_array::GenericArray,
-    AsyncStreamCipher, BlockMode, Ecb, GostCbc, GostCfb, GostCtr128, GostCtr64, GostOfb, NewCipher,
-    StreamCipher,
+    AsyncStreamCipher, BlockMode, Ecb, FnPolicy, GostCbc, GostCfb, GostCtr128, GostCtr64, GostOfb,
+    Keystream, KeystreamChunks, LimitExceeded, Metered, NewCipher, Rekeying, StreamCipher,
+    StreamCipherSeek,
 };
 use hex_literal::hex;
 use kuznyechik::Kuznyechik;
@@ -202,5 +208,361 @@ fn magma_modes() {
     assert_eq!(buf, &pt[..]);
 }
 
+/// With `M` equal to the block size and `S = 1` the GOST CFB mode reduces to
+/// the classic CFB-8 mode.
+#[test]
+fn cfb_s1_is_cfb8() {
+    use gost_modes::block_modes::{block_padding::NoPadding, Cfb8};
+
+    let key = GenericArray::from_slice(&[0x42; 32]);
+    let iv = GenericArray::from_slice(&[0x24; 16]);
+    let pt = [0x5a; 64];
+
+    let mut buf = pt;
+    GostCfb::<Kuznyechik, U16, U1>::new(key, iv).encrypt(&mut buf);
+    let ct = Cfb8::<Kuznyechik, NoPadding>::new_fix(key, iv).encrypt_vec(&pt);
+    assert_eq!(buf[..], ct[..]);
+
+    GostCfb::<Kuznyechik, U16, U1>::new(key, iv).decrypt(&mut buf);
+    assert_eq!(buf, pt);
+}
+
+#[test]
+fn keystream_iterators() {
+    let key = GenericArray::from_slice(&[0x42; 32]);
+    let iv = GenericArray::from_slice(&[0x24; 8]);
+    let cipher = GostCtr128::<Kuznyechik, U14>::new(key, iv);
+
+    let mut expected = [0u8; 300];
+    cipher.clone().apply_keystream(&mut expected);
+
+    let ks: Vec<u8> = Keystream::new(cipher.clone()).take(300).collect();
+    assert_eq!(ks[..], expected[..]);
+
+    let mut chunks = KeystreamChunks::<_, U3>::new(cipher);
+    for (i, chunk) in chunks.by_ref().take(50).enumerate() {
+        assert_eq!(chunk[..], expected[3 * i..3 * i + 3]);
+    }
+    let mut rest = [0u8; 150];
+    chunks.into_inner().apply_keystream(&mut rest);
+    assert_eq!(rest[..], expected[150..]);
+}
+
+/// Mode instances can borrow an already expanded cipher.
+#[test]
+fn borrowed_cipher() {
+    use gost_modes::cipher::FromBlockCipher;
+
+    let key = GenericArray::from_slice(&[0x42; 32]);
+    let nonce = GenericArray::from_slice(&[0x24; 8]);
+    let cipher = Kuznyechik::new(key);
+
+    let mut buf1 = [0u8; 100];
+    GostCtr128::<Kuznyechik>::new(key, nonce).apply_keystream(&mut buf1);
+    let mut buf2 = [0u8; 100];
+    GostCtr128::<&Kuznyechik>::from_block_cipher(&cipher, nonce).apply_keystream(&mut buf2);
+    assert_eq!(buf1[..], buf2[..]);
+
+    let iv = GenericArray::from_slice(&[0x24; 32]);
+    let mut buf1 = [0u8; 100];
+    GostOfb::<Kuznyechik, U2>::new(key, iv).apply_keystream(&mut buf1);
+    let mut buf2 = [0u8; 100];
+    GostOfb::<&Kuznyechik, U2>::from_block_cipher(&cipher, iv).apply_keystream(&mut buf2);
+    assert_eq!(buf1[..], buf2[..]);
+}
+
+#[test]
+fn buffer_to_buffer() {
+    use gost_modes::{AsyncStreamCipherB2B, StreamCipherB2B};
+
+    let key = GenericArray::from_slice(&[0x42; 32]);
+    let iv = GenericArray::from_slice(&[0x24; 32]);
+    let pt = [0x5a; 67];
+
+    let mut expected = pt;
+    GostOfb::<Kuznyechik, U2>::new(key, iv).apply_keystream(&mut expected);
+    let mut ct = [0u8; 67];
+    let mut c = GostOfb::<Kuznyechik, U2>::new(key, iv);
+    c.apply_keystream_b2b(&pt[..10], &mut ct[..10]);
+    c.apply_keystream_b2b(&pt[10..], &mut ct[10..]);
+    assert_eq!(ct[..], expected[..]);
+
+    let mut expected = pt;
+    GostCfb::<Kuznyechik, U32>::new(key, iv).encrypt(&mut expected);
+    let mut ct = [0u8; 67];
+    GostCfb::<Kuznyechik, U32>::new(key, iv).encrypt_b2b(&pt, &mut ct);
+    assert_eq!(ct[..], expected[..]);
+    let mut buf = [0u8; 67];
+    GostCfb::<Kuznyechik, U32>::new(key, iv).decrypt_b2b(&ct, &mut buf);
+    assert_eq!(buf[..], pt[..]);
+}
+
+/// Counter blocks are `nonce || ctr` with the counter encoded as big-endian
+/// integer independently of the host byte order.
+#[test]
+fn ctr_counter_encoding() {
+    use gost_modes::cipher::BlockEncrypt;
+
+    fn check<C: BlockEncrypt>(cipher: &C, ks: &[u8], blocks: &[(usize, &[u8])]) {
+        let bs = ks.len() / 258;
+        for &(i, ctr_block) in blocks {
+            let mut block = GenericArray::clone_from_slice(ctr_block);
+            cipher.encrypt_block(&mut block);
+            assert_eq!(block[..], ks[bs * i..bs * (i + 1)]);
+        }
+    }
+
+    let key = GenericArray::from_slice(&[0x42; 32]);
+
+    let mut ks = [0u8; 16 * 258];
+    let nonce = hex!("1234567890abcef0");
+    GostCtr128::<Kuznyechik>::new(key, &nonce.into()).apply_keystream(&mut ks);
+    let blocks: &[(usize, &[u8])] = &[
+        (1, &hex!("1234567890abcef00000000000000001")),
+        (255, &hex!("1234567890abcef000000000000000ff")),
+        (256, &hex!("1234567890abcef00000000000000100")),
+        (257, &hex!("1234567890abcef00000000000000101")),
+    ];
+    check(&Kuznyechik::new(key), &ks, blocks);
+
+    let mut ks = [0u8; 8 * 258];
+    let nonce = hex!("12345678");
+    GostCtr64::<Magma>::new(key, &nonce.into()).apply_keystream(&mut ks);
+    let blocks: &[(usize, &[u8])] = &[
+        (1, &hex!("1234567800000001")),
+        (255, &hex!("12345678000000ff")),
+        (256, &hex!("1234567800000100")),
+        (257, &hex!("1234567800000101")),
+    ];
+    check(&Magma::new(key), &ks, blocks);
+}
+
+#[test]
+fn metered_limit() {
+    let key = [0x42; 32];
+    let iv = [0x24; 8];
+    let pt = [0x11; 40];
+
+    let mut expected = pt;
+    GostCtr64::<Magma>::new_from_slices(&key, &iv[..4])
+        .unwrap()
+        .apply_keystream(&mut expected);
+
+    let ctr = GostCtr64::<Magma>::new_from_slices(&key, &iv[..4]).unwrap();
+    let mut cipher = Metered::with_limit(ctr, 40);
+    let mut buf = pt;
+    cipher.try_apply(&mut buf[..13]).unwrap();
+    assert_eq!(cipher.blocks_processed(8), 2);
+    assert_eq!(cipher.try_apply(&mut buf[13..]), Ok(()));
+    assert_eq!(buf, expected);
+    assert_eq!(cipher.remaining(), Some(0));
+
+    let mut tail = [0u8; 1];
+    assert_eq!(cipher.try_apply(&mut tail), Err(LimitExceeded));
+    assert!(cipher.try_apply_keystream(&mut tail).is_err());
+    assert_eq!(tail, [0]);
+    cipher.set_limit(None);
+    cipher.try_apply(&mut tail).unwrap();
+    assert_eq!(cipher.bytes_processed(), 41);
+
+    let cfb = GostCfb::<Kuznyechik>::new_from_slices(&key, &[0x24; 16]).unwrap();
+    let mut cipher = Metered::with_limit(cfb, 16);
+    let mut buf = [0u8; 17];
+    cipher.try_encrypt(&mut buf[..16]).unwrap();
+    assert_eq!(cipher.try_encrypt(&mut buf[16..]), Err(LimitExceeded));
+    assert_eq!(buf[16], 0);
+}
+
+#[test]
+fn rekeying_sections() {
+    type Key = GenericArray<u8, U32>;
+    let next = |_: &Magma, key: &Key| {
+        let mut key = *key;
+        key.iter_mut().for_each(|b| *b = b.wrapping_add(1));
+        key
+    };
+    let key = Key::clone_from_slice(&[0x42; 32]);
+    let nonce = hex!("12345678");
+
+    // three sections of 3 blocks and a partial one
+    let mut expected = [0u8; 80];
+    let mut k = key;
+    for (i, section) in expected.chunks_mut(24).enumerate() {
+        let mut ctr = GostCtr64::<Magma>::new(&k, &nonce.into());
+        ctr.seek(24 * i as u64);
+        ctr.apply_keystream(section);
+        k = next(&Magma::new(&k), &k);
+    }
+
+    for i in 1..32 {
+        let policy = FnPolicy::new(3, next);
+        let mut c = Rekeying::<GostCtr64<Magma>, _>::new(&key, &nonce.into(), policy);
+        let mut buf = [0u8; 80];
+        for chunk in buf.chunks_mut(i) {
+            c.apply_keystream(chunk);
+        }
+        assert_eq!(&buf[..], &expected[..]);
+        assert_eq!(c.rekeys(), 3);
+    }
+}
+
 cipher::stream_cipher_seek_test!(kuznyechik_ctr_seek, GostCtr128::<Kuznyechik, U14>);
 cipher::stream_cipher_seek_test!(magma_ctr_seek, GostCtr64::<Magma, U5>);
+
+/// CTR-ACPKM test vector from RFC 8645, Appendix A.1 (two sections of two
+/// blocks).
+#[test]
+fn ctr_acpkm_kuznyechik() {
+    use gost_modes::{Acpkm, GostCtr128Acpkm};
+
+    let key = hex!("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef");
+    let iv = hex!("1234567890abcef0");
+    let pt = hex!("1122334455667700ffeeddccbbaa998800112233445566778899aabbcceeff0a112233445566778899aabbcceeff0a00");
+    let ct = hex!("f195d8bec10ed1dbd57b5fa240bda1b885eee733f6a13e5df33ce4b33c45dee44bceeb8f646f4c55001706275e85e800");
+
+    for i in 1..=pt.len() {
+        let mut c = GostCtr128Acpkm::<Kuznyechik>::new(&key.into(), &iv.into(), Acpkm::new(2));
+        let mut buf = pt;
+        for chunk in buf.chunks_mut(i) {
+            c.apply_keystream(chunk);
+        }
+        assert_eq!(buf, ct);
+        assert_eq!(c.rekeys(), 1);
+    }
+}
+
+/// Check GOST 28147-89 gamma mode against a step-by-step model of the
+/// counter registers, including the carry of the addition modulo
+/// `2^32 - 1`.
+#[test]
+fn cnt_magma() {
+    use gost_modes::GostCnt;
+    use magma::cipher::{BlockDecrypt, BlockEncrypt};
+
+    let key = GenericArray::clone_from_slice(&[0x42; 32]);
+    let cipher = Magma::new(&key);
+    // choose the IV such that N4 reaches 2^32 - 1 after the first step
+    let s = hex!("fefefefb01020304");
+    let mut iv = GenericArray::clone_from_slice(&s);
+    cipher.decrypt_block(&mut iv);
+
+    let mut expected = [0u8; 80];
+    let (mut n4, mut n3) = (0xfefe_fefbu32, 0x0102_0304u32);
+    for (i, chunk) in expected.chunks_mut(8).enumerate() {
+        n3 = n3.wrapping_add(0x0101_0101);
+        let (sum, carry) = n4.overflowing_add(0x0101_0104);
+        n4 = sum + carry as u32;
+        match i {
+            0 => assert_eq!(n4, 0xffff_ffff),
+            1 => assert_eq!(n4, 0x0101_0104),
+            _ => {}
+        }
+        let mut block = GenericArray::default();
+        block[..4].copy_from_slice(&n4.to_be_bytes());
+        block[4..].copy_from_slice(&n3.to_be_bytes());
+        cipher.encrypt_block(&mut block);
+        chunk.copy_from_slice(&block);
+    }
+
+    let cnt = GostCnt::<Magma>::new(&key, &iv);
+    test_stream_cipher(cnt.clone(), &[0u8; 80], &expected);
+
+    for pos in 0..80 {
+        let mut c = cnt.clone();
+        c.seek(pos);
+        let mut buf = [0u8; 80];
+        c.apply_keystream(&mut buf[pos as usize..]);
+        assert_eq!(&buf[pos as usize..], &expected[pos as usize..]);
+        assert_eq!(c.current_pos::<u64>(), 80);
+    }
+}
+
+/// GOST 28147-89 gamma mode test vector from GnuTLS (`lib/crypto-selftests.c`)
+/// for the TC26 Z S-box, i.e. Magma.
+///
+/// GnuTLS follows the little-endian byte order of GOST 28147-89, while Magma
+/// is big-endian, so the key words and blocks of the vector are byte-swapped.
+#[test]
+fn cnt_magma_kat() {
+    use gost_modes::GostCnt;
+
+    // key: 599f84bac3f3d2f160e1e3f26a961af99c48b24ebcbbbf7cd8f3accd968d286a
+    let key = hex!(
+        "
+        ba849f59f1d2f3c3f2e3e160f91a966a
+        4eb2489c7cbfbbbccdacf3d86a288d96
+    "
+    );
+    // iv: 8dafa8d158ed058d
+    let iv = hex!("8d05ed58d1a8af8d");
+    // plaintext: 90a23966ae01b9a3524ec8ed6cdd8830
+    let pt = hex!("a3b901ae6639a2903088dd6cedc84e52");
+    // ciphertext: e8b14fc730dc25bb36ba643c17dbff99
+    let ct = hex!("bb25dc30c74fb1e899ffdb173c64ba36");
+
+    let c = GostCnt::<Magma>::new(&key.into(), &iv.into());
+    test_stream_cipher(c, &pt, &ct);
+}
+
+/// KExp15 example for Magma from R 1323565.1.017-2018, KImp15 roundtrip
+/// and integrity check for both block sizes.
+#[test]
+fn kexp15() {
+    use gost_modes::{KExp15, KeyWrapError};
+
+    let key = hex!(
+        "
+        8899aabbccddeeff0011223344556677
+        fedcba98765432100123456789abcdef
+    "
+    );
+    let k_mac = hex!(
+        "
+        08090a0b0c0d0e0f0001020304050607
+        101112131415161718191a1b1c1d1e1f
+    "
+    );
+    let k_enc = hex!(
+        "
+        202122232425262728292a2b2c2d2e2f
+        38393a3b3c3d3e3f3031323334353637
+    "
+    );
+
+    let kexp = KExp15::new(Magma::new(&k_enc.into()), Magma::new(&k_mac.into()));
+    let iv = hex!("67bed654");
+    let mut blob = [0u8; 40];
+    kexp.export(&iv, &key, &mut blob).unwrap();
+    assert_eq!(
+        blob,
+        hex!(
+            "
+        cfd5a12d5b81b6e1e99c916d07900c6a
+        c12703fb3abded55567bf3742c899c75
+        5dafe7b42e3a8bd9
+    "
+        )
+    );
+    let mut buf = [0u8; 32];
+    kexp.import(&iv, &blob, &mut buf).unwrap();
+    assert_eq!(buf, key);
+
+    blob[39] ^= 1;
+    assert_eq!(kexp.import(&iv, &blob, &mut buf), Err(KeyWrapError));
+    assert_eq!(buf, [0u8; 32]);
+
+    let kexp = KExp15::new(
+        Kuznyechik::new(&k_enc.into()),
+        Kuznyechik::new(&k_mac.into()),
+    );
+    let iv = [0x09; 8];
+    let mut blob = [0u8; 48];
+    kexp.export(&iv, &key, &mut blob).unwrap();
+    kexp.import(&iv, &blob, &mut buf).unwrap();
+    assert_eq!(buf, key);
+
+    blob[0] ^= 1;
+    assert_eq!(kexp.import(&iv, &blob, &mut buf), Err(KeyWrapError));
+    assert_eq!(kexp.import(&iv[..4], &blob, &mut buf), Err(KeyWrapError));
+    assert_eq!(kexp.export(&iv, &key, &mut blob[..40]), Err(KeyWrapError));
+}
@@ -1,5 +1,10 @@
+#![allow(clippy::needless_borrow)]
+
 use super::*;
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 #[test]
 fn test_sub_key_generation() {
     let key: [u8; 16] = [
@@ -1,4 +1,7 @@
 //! Test vectors from:
 //! https://www.cosic.esat.kuleuven.be/nessie/testvectors/bc/idea/Idea-128-64.verified.test-vectors
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 cipher::block_cipher_test!(idea_test, "idea", idea::Idea);
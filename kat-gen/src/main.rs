@@ -0,0 +1,250 @@
+//! Generator of machine-readable known-answer test vectors.
+//!
+//! Prints a JSON array to stdout with one object per supported
+//! cipher/mode combination:
+//!
+//! ```text
+//! {"cipher": "...", "mode": "...", "key": "..", "iv": "..", "plaintext": "..", "ciphertext": ".."}
+//! ```
+//!
+//! All byte strings are lower-case hex. Inputs are derived from fixed
+//! patterns, so the output is fully deterministic and can be checked into
+//! conformance suites of other implementations. Block modes are run without
+//! padding over four full blocks, stream modes over a message which is not
+//! a multiple of the block size to also cover partial keystream blocks.
+//! GOST R 34.13-2015 modes are only emitted for the GOST block ciphers.
+
+use block_modes::{
+    block_padding::NoPadding,
+    cipher::{
+        generic_array::typenum::Unsigned, AsyncStreamCipher, BlockCipher, BlockDecrypt,
+        BlockEncrypt, NewBlockCipher, NewCipher, StreamCipher,
+    },
+    BlockMode, Cbc, Cfb, Cfb8, Ecb, Ige, Ofb, Pcbc,
+};
+use gost_modes::{GostCbc, GostCfb, GostCtr128, GostCtr64, GostOfb};
+use std::io::{self, Write};
+
+/// Number of blocks processed by block modes.
+const BLOCKS: usize = 4;
+/// Number of trailing bytes beyond full blocks processed by stream modes.
+const TAIL: usize = 5;
+
+struct Vector {
+    cipher: &'static str,
+    mode: &'static str,
+    key: Vec<u8>,
+    iv: Vec<u8>,
+    plaintext: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+fn pattern(len: usize, seed: u8) -> Vec<u8> {
+    (0..len)
+        .map(|i| (i as u8).wrapping_mul(0x1d).wrapping_add(seed))
+        .collect()
+}
+
+fn block_mode<C, M>(out: &mut Vec<Vector>, cipher: &'static str, mode: &'static str)
+where
+    C: BlockCipher + NewBlockCipher + BlockEncrypt + BlockDecrypt,
+    M: BlockMode<C, NoPadding>,
+{
+    let key = pattern(C::KeySize::USIZE, 0x00);
+    let iv = pattern(M::IvSize::USIZE, 0xa5);
+    let plaintext = pattern(BLOCKS * C::BlockSize::USIZE, 0x3c);
+    let mut ciphertext = plaintext.clone();
+    M::new_from_slices(&key, &iv)
+        .expect("key and IV sizes are taken from the mode")
+        .encrypt(&mut ciphertext, plaintext.len())
+        .expect("plaintext is a multiple of the block size");
+    out.push(Vector {
+        cipher,
+        mode,
+        key,
+        iv,
+        plaintext,
+        ciphertext,
+    });
+}
+
+fn stream_mode<S>(out: &mut Vec<Vector>, cipher: &'static str, mode: &'static str, bs: usize)
+where
+    S: NewCipher + StreamCipher,
+{
+    let key = pattern(S::KeySize::USIZE, 0x00);
+    let iv = pattern(S::NonceSize::USIZE, 0xa5);
+    let plaintext = pattern(BLOCKS * bs + TAIL, 0x3c);
+    let mut ciphertext = plaintext.clone();
+    S::new_from_slices(&key, &iv)
+        .expect("key and IV sizes are taken from the mode")
+        .apply_keystream(&mut ciphertext);
+    out.push(Vector {
+        cipher,
+        mode,
+        key,
+        iv,
+        plaintext,
+        ciphertext,
+    });
+}
+
+fn async_stream_mode<S>(out: &mut Vec<Vector>, cipher: &'static str, mode: &'static str, bs: usize)
+where
+    S: NewCipher + AsyncStreamCipher,
+{
+    let key = pattern(S::KeySize::USIZE, 0x00);
+    let iv = pattern(S::NonceSize::USIZE, 0xa5);
+    let plaintext = pattern(BLOCKS * bs + TAIL, 0x3c);
+    let mut ciphertext = plaintext.clone();
+    S::new_from_slices(&key, &iv)
+        .expect("key and IV sizes are taken from the mode")
+        .encrypt(&mut ciphertext);
+    out.push(Vector {
+        cipher,
+        mode,
+        key,
+        iv,
+        plaintext,
+        ciphertext,
+    });
+}
+
+/// Emit vectors for all generic modes from the `block-modes` crate.
+macro_rules! block_modes {
+    ($out:expr, $name:expr, $cipher:ty) => {
+        block_mode::<$cipher, Ecb<$cipher, NoPadding>>($out, $name, "ECB");
+        block_mode::<$cipher, Cbc<$cipher, NoPadding>>($out, $name, "CBC");
+        block_mode::<$cipher, Pcbc<$cipher, NoPadding>>($out, $name, "PCBC");
+        block_mode::<$cipher, Cfb<$cipher, NoPadding>>($out, $name, "CFB");
+        block_mode::<$cipher, Cfb8<$cipher, NoPadding>>($out, $name, "CFB8");
+        block_mode::<$cipher, Ofb<$cipher, NoPadding>>($out, $name, "OFB");
+        block_mode::<$cipher, Ige<$cipher, NoPadding>>($out, $name, "IGE");
+    };
+}
+
+/// Emit vectors for GOST R 34.13-2015 modes.
+macro_rules! gost_modes {
+    ($out:expr, $name:expr, $cipher:ty, $ctr:ident) => {
+        let bs = <$cipher as BlockCipher>::BlockSize::USIZE;
+        block_mode::<$cipher, GostCbc<$cipher, NoPadding>>($out, $name, "GOST-CBC");
+        stream_mode::<$ctr<$cipher>>($out, $name, "GOST-CTR", bs);
+        stream_mode::<GostOfb<$cipher>>($out, $name, "GOST-OFB", bs);
+        async_stream_mode::<GostCfb<$cipher>>($out, $name, "GOST-CFB", bs);
+    };
+}
+
+fn vectors() -> Vec<Vector> {
+    let mut vectors = Vec::new();
+    let out = &mut vectors;
+
+    block_modes!(out, "AES-128", aes::Aes128);
+    block_modes!(out, "AES-192", aes::Aes192);
+    block_modes!(out, "AES-256", aes::Aes256);
+    stream_mode::<aes::Aes128Ctr>(out, "AES-128", "CTR", 16);
+    stream_mode::<aes::Aes192Ctr>(out, "AES-192", "CTR", 16);
+    stream_mode::<aes::Aes256Ctr>(out, "AES-256", "CTR", 16);
+
+    block_modes!(out, "Blowfish", blowfish::Blowfish);
+    block_modes!(out, "CAST5", cast5::Cast5);
+    block_modes!(out, "DES", des::Des);
+    block_modes!(out, "TDES-EDE2", des::TdesEde2);
+    block_modes!(out, "TDES-EDE3", des::TdesEde3);
+    block_modes!(out, "TDES-EEE2", des::TdesEee2);
+    block_modes!(out, "TDES-EEE3", des::TdesEee3);
+    block_modes!(out, "IDEA", idea::Idea);
+
+    block_modes!(out, "KATAN32", katan::Katan32);
+    block_modes!(out, "KATAN48", katan::Katan48);
+    block_modes!(out, "KATAN64", katan::Katan64);
+    block_modes!(out, "KTANTAN32", katan::Ktantan32);
+    block_modes!(out, "KTANTAN48", katan::Ktantan48);
+    block_modes!(out, "KTANTAN64", katan::Ktantan64);
+    block_modes!(out, "Kuznyechik", kuznyechik::Kuznyechik);
+    gost_modes!(out, "Kuznyechik", kuznyechik::Kuznyechik, GostCtr128);
+
+    block_modes!(out, "Magma", magma::Magma);
+    gost_modes!(out, "Magma", magma::Magma, GostCtr64);
+    block_modes!(out, "GOST28147-89-Test", magma::Gost89Test);
+    gost_modes!(out, "GOST28147-89-Test", magma::Gost89Test, GostCtr64);
+    block_modes!(out, "GOST28147-89-CryptoPro-A", magma::Gost89CryptoProA);
+    gost_modes!(
+        out,
+        "GOST28147-89-CryptoPro-A",
+        magma::Gost89CryptoProA,
+        GostCtr64
+    );
+    block_modes!(out, "GOST28147-89-CryptoPro-B", magma::Gost89CryptoProB);
+    gost_modes!(
+        out,
+        "GOST28147-89-CryptoPro-B",
+        magma::Gost89CryptoProB,
+        GostCtr64
+    );
+    block_modes!(out, "GOST28147-89-CryptoPro-C", magma::Gost89CryptoProC);
+    gost_modes!(
+        out,
+        "GOST28147-89-CryptoPro-C",
+        magma::Gost89CryptoProC,
+        GostCtr64
+    );
+    block_modes!(out, "GOST28147-89-CryptoPro-D", magma::Gost89CryptoProD);
+    gost_modes!(
+        out,
+        "GOST28147-89-CryptoPro-D",
+        magma::Gost89CryptoProD,
+        GostCtr64
+    );
+
+    block_modes!(out, "PRINCE", prince::Prince);
+    block_modes!(out, "RC2", rc2::Rc2);
+    block_modes!(out, "RC5-32/12", rc5::Rc5_32_12);
+    block_modes!(out, "RC5-64/24", rc5::Rc5_64_24);
+    block_modes!(out, "Rijndael-160/160", rijndael::Rijndael160);
+    block_modes!(out, "Rijndael-192/192", rijndael::Rijndael192);
+    block_modes!(out, "Rijndael-224/224", rijndael::Rijndael224);
+    block_modes!(out, "Rijndael-256/256", rijndael::Rijndael256);
+    block_modes!(out, "Serpent", serpent::Serpent);
+    block_modes!(out, "Simeck32/64", simeck::Simeck32_64);
+    block_modes!(out, "Simeck48/96", simeck::Simeck48_96);
+    block_modes!(out, "Simeck64/128", simeck::Simeck64_128);
+    block_modes!(out, "SM4", sm4::Sm4);
+    block_modes!(out, "Threefish-256", threefish::Threefish256);
+    block_modes!(out, "Threefish-512", threefish::Threefish512);
+    block_modes!(out, "Threefish-1024", threefish::Threefish1024);
+    block_modes!(out, "TWINE-80", twine::Twine80);
+    block_modes!(out, "TWINE-128", twine::Twine128);
+    block_modes!(out, "Twofish", twofish::Twofish);
+    block_modes!(out, "XXTEA-64", xxtea::Xxtea64);
+    block_modes!(out, "XXTEA-128", xxtea::Xxtea128);
+
+    vectors
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn main() -> io::Result<()> {
+    let vectors = vectors();
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+
+    writeln!(w, "[")?;
+    for (i, v) in vectors.iter().enumerate() {
+        let sep = if i + 1 == vectors.len() { "" } else { "," };
+        writeln!(
+            w,
+            "  {{\"cipher\": \"{}\", \"mode\": \"{}\", \"key\": \"{}\", \"iv\": \"{}\", \
+             \"plaintext\": \"{}\", \"ciphertext\": \"{}\"}}{}",
+            v.cipher,
+            v.mode,
+            hex(&v.key),
+            hex(&v.iv),
+            hex(&v.plaintext),
+            hex(&v.ciphertext),
+            sep,
+        )?;
+    }
+    writeln!(w, "]")
+}
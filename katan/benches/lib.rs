@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(katan::Katan64, 10);
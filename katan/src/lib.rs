@@ -0,0 +1,308 @@
+//! An implementation of the [KATAN and KTANTAN][1] families of block
+//! ciphers.
+//!
+//! KATAN and KTANTAN are hardware-oriented ciphers with 32, 48 or 64-bit
+//! blocks and 80-bit keys, built from two nonlinear feedback shift
+//! registers and clocked for 254 rounds. The KTANTAN variants share the
+//! round function with KATAN, but instead of a key schedule register they
+//! select key bits with the round counter, so the key can be burnt into the
+//! device.
+//!
+//! This implementation is bit-serial and mirrors the hardware description.
+//! It is meant for protocol analysis and emulation of constrained devices,
+//! not for performance.
+//!
+//! Blocks and keys are interpreted as big-endian integers: plaintext bit
+//! `i` (counted from the least significant bit) is loaded into `L2[i]` for
+//! `i < |L2|` and into `L1[i - |L2|]` otherwise, and key bit `i` is `k_i`
+//! of the specification.
+//!
+//! # Examples
+//! ```
+//! use katan::{
+//!     cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Katan32,
+//! };
+//!
+//! let cipher = Katan32::new(&GenericArray::from([0xff; 10]));
+//! let mut block = GenericArray::from([0u8; 4]);
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x7e, 0x1f, 0xf9, 0x45]);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block[..], [0u8; 4]);
+//! ```
+//!
+//! [1]: https://www.esat.kuleuven.be/cosic/publications/article-1268.pdf
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U10, U4, U6, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+mod consts;
+
+use consts::IR;
+
+const ROUNDS: usize = 254;
+
+/// Key bits `k_a` and `k_b` for every round.
+type RoundKeys = [[u8; 2]; ROUNDS];
+
+/// Register sizes and tap positions of a family member.
+struct Params {
+    /// Length of `L1`.
+    l1: usize,
+    /// Length of `L2`.
+    l2: usize,
+    /// Taps of `f_a` in `L1`.
+    x: [usize; 5],
+    /// Taps of `f_b` in `L2`.
+    y: [usize; 6],
+    /// Number of times the round function is applied per round.
+    steps: usize,
+}
+
+const PARAMS32: Params = Params {
+    l1: 13,
+    l2: 19,
+    x: [12, 7, 8, 5, 3],
+    y: [18, 7, 12, 10, 8, 3],
+    steps: 1,
+};
+
+const PARAMS48: Params = Params {
+    l1: 19,
+    l2: 29,
+    x: [18, 12, 15, 7, 6],
+    y: [28, 19, 21, 13, 15, 6],
+    steps: 2,
+};
+
+const PARAMS64: Params = Params {
+    l1: 25,
+    l2: 39,
+    x: [24, 15, 20, 11, 9],
+    y: [38, 25, 33, 21, 14, 9],
+    steps: 3,
+};
+
+#[inline(always)]
+fn bit(x: u64, i: usize) -> u64 {
+    (x >> i) & 1
+}
+
+fn key_bits(key: &GenericArray<u8, U10>) -> u128 {
+    key.iter().fold(0, |acc, &b| (acc << 8) | u128::from(b))
+}
+
+/// KATAN key schedule, an 80-bit LFSR with
+/// `k_{i+80} = k_i ^ k_{i+19} ^ k_{i+30} ^ k_{i+67}`.
+fn katan_key_schedule(key: &GenericArray<u8, U10>) -> RoundKeys {
+    let mut k = key_bits(key);
+    let mut rks = [[0u8; 2]; ROUNDS];
+    for rk in rks.iter_mut() {
+        for kb in rk.iter_mut() {
+            *kb = (k & 1) as u8;
+            let fb = k ^ (k >> 19) ^ (k >> 30) ^ (k >> 67);
+            k = (k >> 1) | ((fb & 1) << 79);
+        }
+    }
+    rks
+}
+
+/// KTANTAN key schedule, key bits are selected by the round counter.
+///
+/// With the key split into 16-bit words `w4 || w3 || w2 || w1 || w0` and
+/// the round counter `T`, bit `T7T6T5T4` of every word is selected as `a_i`
+/// and the round key bits are
+///
+/// - `k_a = !T3 & !T2 & a0 ^ (T3 | T2) & MUX4to1(a4a3a2a1, T1T0)`
+/// - `k_b = !T3 & T2 & a4 ^ (T3 | !T2) & MUX4to1(a3a2a1a0, !T1!T0)`
+fn ktantan_key_schedule(key: &GenericArray<u8, U10>) -> RoundKeys {
+    let k = key_bits(key);
+    let mut t = 0xffu8;
+    let mut rks = [[0u8; 2]; ROUNDS];
+    for rk in rks.iter_mut() {
+        t = counter_step(t);
+        let idx = u32::from(t >> 4);
+        let mut a = [0u8; 5];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = ((k >> (16 * i as u32 + idx)) & 1) as u8;
+        }
+        let sel = usize::from(t & 3);
+        let (t3, t2) = ((t >> 3) & 1, (t >> 2) & 1);
+        rk[0] = if t3 == 0 && t2 == 0 { a[0] } else { a[1 + sel] };
+        rk[1] = if t3 == 0 && t2 == 1 { a[4] } else { a[3 - sel] };
+    }
+    rks
+}
+
+/// Clock the round counter LFSR `x^8 + x^7 + x^5 + x^3 + 1`.
+///
+/// The most significant bit of the counter after clocking is the `IR` bit
+/// of the round.
+#[inline(always)]
+fn counter_step(t: u8) -> u8 {
+    let fb = (t >> 7) ^ (t >> 6) ^ (t >> 4) ^ (t >> 2);
+    (t << 1) | (fb & 1)
+}
+
+#[inline(always)]
+fn fa(p: &Params, l1: u64, ka: u64, ir: u64) -> u64 {
+    let x = &p.x;
+    bit(l1, x[0]) ^ bit(l1, x[1]) ^ (bit(l1, x[2]) & bit(l1, x[3])) ^ (bit(l1, x[4]) & ir) ^ ka
+}
+
+#[inline(always)]
+fn fb(p: &Params, l2: u64, kb: u64) -> u64 {
+    let y = &p.y;
+    bit(l2, y[0])
+        ^ bit(l2, y[1])
+        ^ (bit(l2, y[2]) & bit(l2, y[3]))
+        ^ (bit(l2, y[4]) & bit(l2, y[5]))
+        ^ kb
+}
+
+fn encrypt(p: &Params, rks: &RoundKeys, block: u64) -> u64 {
+    let (m1, m2) = ((1 << p.l1) - 1, (1 << p.l2) - 1);
+    let mut l1 = (block >> p.l2) & m1;
+    let mut l2 = block & m2;
+    for (rk, &ir) in rks.iter().zip(IR.iter()) {
+        let (ka, kb) = (u64::from(rk[0]), u64::from(rk[1]));
+        for _ in 0..p.steps {
+            let a = fa(p, l1, ka, u64::from(ir));
+            let b = fb(p, l2, kb);
+            l1 = ((l1 << 1) | b) & m1;
+            l2 = ((l2 << 1) | a) & m2;
+        }
+    }
+    (l1 << p.l2) | l2
+}
+
+/// Inverse of [`encrypt`].
+///
+/// The first taps `x[0]` and `y[0]` are the most significant register
+/// bits shifted out in every step, all other taps are still available after
+/// shifting, so the lost bits can be recovered from the feedback values.
+fn decrypt(p: &Params, rks: &RoundKeys, block: u64) -> u64 {
+    let (m1, m2) = ((1 << p.l1) - 1, (1 << p.l2) - 1);
+    let mut l1 = (block >> p.l2) & m1;
+    let mut l2 = block & m2;
+    for (rk, &ir) in rks.iter().zip(IR.iter()).rev() {
+        let (ka, kb) = (u64::from(rk[0]), u64::from(rk[1]));
+        for _ in 0..p.steps {
+            let (a, b) = (l2 & 1, l1 & 1);
+            l1 >>= 1;
+            l2 >>= 1;
+            // with the top bits cleared `fa` and `fb` contribute the
+            // remaining terms only
+            l1 |= (a ^ fa(p, l1, ka, u64::from(ir))) << p.x[0];
+            l2 |= (b ^ fb(p, l2, kb)) << p.y[0];
+        }
+    }
+    (l1 << p.l2) | l2
+}
+
+macro_rules! define_cipher {
+    ($name:ident, $doc:expr, $params:expr, $block_size:ty, $schedule:ident) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            rks: RoundKeys,
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = U10;
+
+            fn new(key: &GenericArray<u8, U10>) -> Self {
+                Self {
+                    rks: $schedule(key),
+                }
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = $block_size;
+            type ParBlocks = U1;
+        }
+
+        impl BlockEncrypt for $name {
+            fn encrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let x = block.iter().fold(0, |acc, &b| (acc << 8) | u64::from(b));
+                let x = encrypt(&$params, &self.rks, x);
+                write_block(block, x);
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            fn decrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let x = block.iter().fold(0, |acc, &b| (acc << 8) | u64::from(b));
+                let x = decrypt(&$params, &self.rks, x);
+                write_block(block, x);
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+fn write_block(block: &mut [u8], mut x: u64) {
+    for b in block.iter_mut().rev() {
+        *b = x as u8;
+        x >>= 8;
+    }
+}
+
+define_cipher!(
+    Katan32,
+    "KATAN32 block cipher.",
+    PARAMS32,
+    U4,
+    katan_key_schedule
+);
+define_cipher!(
+    Katan48,
+    "KATAN48 block cipher.",
+    PARAMS48,
+    U6,
+    katan_key_schedule
+);
+define_cipher!(
+    Katan64,
+    "KATAN64 block cipher.",
+    PARAMS64,
+    U8,
+    katan_key_schedule
+);
+define_cipher!(
+    Ktantan32,
+    "KTANTAN32 block cipher.",
+    PARAMS32,
+    U4,
+    ktantan_key_schedule
+);
+define_cipher!(
+    Ktantan48,
+    "KTANTAN48 block cipher.",
+    PARAMS48,
+    U6,
+    ktantan_key_schedule
+);
+define_cipher!(
+    Ktantan64,
+    "KTANTAN64 block cipher.",
+    PARAMS64,
+    U8,
+    ktantan_key_schedule
+);
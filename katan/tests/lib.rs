@@ -0,0 +1,93 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+use katan::{Katan32, Katan48, Katan64, Ktantan32, Ktantan48, Ktantan64};
+
+fn check<C: NewBlockCipher + BlockEncrypt + BlockDecrypt>(key: &[u8], pt: &[u8], ct: &[u8]) {
+    let cipher = C::new_from_slice(key).unwrap();
+
+    let mut buf = GenericArray::clone_from_slice(pt);
+    cipher.encrypt_block(&mut buf);
+    assert_eq!(&buf[..], ct);
+    cipher.decrypt_block(&mut buf);
+    assert_eq!(&buf[..], pt);
+}
+
+fn check_roundtrip<C: NewBlockCipher + BlockEncrypt + BlockDecrypt>() {
+    let key: Vec<u8> = (0..10u8).map(|i| i.wrapping_mul(0x13) ^ 0x5a).collect();
+    let cipher = C::new_from_slice(&key).unwrap();
+    let mut block = GenericArray::default();
+    for (i, b) in block.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(0x31) ^ 0xc4;
+    }
+    let pt = block.clone();
+    cipher.encrypt_block(&mut block);
+    assert_ne!(block, pt);
+    cipher.decrypt_block(&mut block);
+    assert_eq!(block, pt);
+}
+
+/// With the all-zero key every subkey of KATAN and KTANTAN is zero, so
+/// both ciphers of the same block size must agree.
+fn check_zero_key<K, T>()
+where
+    K: NewBlockCipher + BlockEncrypt,
+    T: NewBlockCipher<KeySize = K::KeySize> + BlockEncrypt<BlockSize = K::BlockSize>,
+{
+    let katan = K::new(&Default::default());
+    let ktantan = T::new(&Default::default());
+    for i in 0..=255u8 {
+        let mut a = GenericArray::default();
+        a.iter_mut().for_each(|b| *b = i);
+        let mut b = a.clone();
+        katan.encrypt_block(&mut a);
+        ktantan.encrypt_block(&mut b);
+        assert_eq!(a, b);
+    }
+}
+
+// Test vectors from "KATAN & KTANTAN — A Family of Small and Efficient
+// Hardware-Oriented Block Ciphers"
+
+const ONES: [u8; 10] = [0xff; 10];
+const ZEROS: [u8; 10] = [0; 10];
+
+#[test]
+fn katan32() {
+    check::<Katan32>(&ONES, &hex!("00000000"), &hex!("7e1ff945"));
+    check::<Katan32>(&ZEROS, &hex!("ffffffff"), &hex!("432e61da"));
+}
+
+#[test]
+fn katan48() {
+    check::<Katan48>(&ONES, &hex!("000000000000"), &hex!("4b7efcfb8659"));
+}
+
+#[test]
+fn katan64() {
+    check::<Katan64>(&ONES, &hex!("0000000000000000"), &hex!("21f2e99c0fab828a"));
+}
+
+#[test]
+fn ktantan32() {
+    check::<Ktantan32>(&ONES, &hex!("00000000"), &hex!("22ea3988"));
+}
+
+#[test]
+fn zero_key() {
+    check_zero_key::<Katan32, Ktantan32>();
+    check_zero_key::<Katan48, Ktantan48>();
+    check_zero_key::<Katan64, Ktantan64>();
+}
+
+#[test]
+fn roundtrip() {
+    check_roundtrip::<Katan32>();
+    check_roundtrip::<Katan48>();
+    check_roundtrip::<Katan64>();
+    check_roundtrip::<Ktantan32>();
+    check_roundtrip::<Ktantan48>();
+    check_roundtrip::<Ktantan64>();
+}
@@ -0,0 +1,266 @@
+// Columns of the linear transformation matrices over GF(2): bit `i` of the
+// little-endian state selects entry `i`.
+
+/// Linear transformation `L`
+pub static L: [u128; 128] = [
+    0x9484dd10bd275db87a486c7276a26ecf,
+    0xebcb7920b94ebab3f490d8e4ec87dc5d,
+    0x1555f240b19cb7a52be3730b1bcd7bba,
+    0x2aaa2780a1fbad895605e6163659f6b7,
+    0x54974ec3813599d1ac0a0f2c6cb22fad,
+    0xa8ed9c45c16af1619b141e58d8a75e99,
+    0x9319fb8a41d421c2f5283cb0738dbcf1,
+    0xe53235d7826b4247295078a3e6d9bb21,
+    0x202d99e9959fd449e6d576f233c82098,
+    0x405af111e9fd6b920f69ec27665340f3,
+    0x80b421221139d6e71ed21b4ecca68025,
+    0xc3ab424422726f0d3c67369c5b8fc34a,
+    0x4595848844e4de1a78ce6cfbb6dd4594,
+    0x8ae9cbd3880b7f34f05fd835af798aeb,
+    0xd7115565d316fe6823be736a9df2d715,
+    0x6d22aaca652c3fd046bfe6d4f9276d2a,
+    0x857475d05ebeb8874e62ec6b1087c674,
+    0xc9e8ea63bcbfb3cd9cc41bd620cd4fe8,
+    0x511317c6bbbda559fb4b366f40599e13,
+    0xa2262e4fb5b989b235966cde80b2ff26,
+    0x874c5c9ea9b1d1a76aefd87fc3a73d4c,
+    0xcd98b8ff91a1618dd41d73fe458d7a98,
+    0x59f3b33de181c2d96b3ae63f8ad9f4f3,
+    0xb225a57a01c14771d6740f7ed7712b25,
+    0x1096cad930682f141a170cca0c70dabf,
+    0x20ef577160d05e28342e185718e077bd,
+    0x401daee2c063bc50685c30ae3003eeb9,
+    0x803a9f0743c6bba0d0b8609f60061fb1,
+    0xc374fd0e864fb58363b3c0fdc00c3ea1,
+    0x45e8391ccf9ea9c5c6a5433943187c81,
+    0x8a1372385dff91494f8986728630f8c1,
+    0xd726e470ba3de1929ed1cfe4cf603341,
+    0xc25d97f3e91a8dcbbb06c5201c689093,
+    0x47baed251134d955b50c494038d0e3e5,
+    0x8eb7194a226871aaa918928070630509,
+    0xdfad329444d0e2979130e7c3e0c60a12,
+    0x7d9964eb886307ede1600d45034f1424,
+    0xfaf1c815d3c60e1901c01a8a069e2848,
+    0x3721532a654f1c32024334d70cff5090,
+    0x6e42a654ca9e38640486686d183da0e3,
+    0xc0774494607c128d2e2dbceb1143488e,
+    0x43ee88ebc0f824d95c5abb15228690df,
+    0x861fd31543334871b8b4b52a44cfe37d,
+    0xcf3e652a866690e2b3aba954885d05fa,
+    0x5d7cca54cfcce307a59591a8d3ba0a37,
+    0xbaf857a85d5b050e89e9e19365b7146e,
+    0xb733ae93bab60a1cd11101e5caad28dc,
+    0xad669fe5b7af1438612202095799507b,
+    0x016f5a3dbfadeeabf1c4af02d61c89f2,
+    0x02deb47abd991f95214b9d046f38d127,
+    0x047fabf4b9f13ee94296f908de70614e,
+    0x08fe952bb1217c1184ef31107fe0c29c,
+    0x103fe956a142f822cb1d6220fe0347fb,
+    0x207e11ac81843344553ac4403f068e35,
+    0x40fc229bc1cb6688aa744b807e0cdf6a,
+    0x803b44f54155ccd397e896c3fc187dd4,
+    0xfbdee0af10c9f649bee76ea46a2b9cf3,
+    0x357f039d20512f92bf0ddc8bd456fb25,
+    0x6afe06f940a25ee7bd1a7bd56bac354a,
+    0xd43f0c318087bc0db934f669d69b6a94,
+    0x6b7e1862c3cdbb1ab1682fd26ff5d4eb,
+    0xd6fc30c44559b534a1d05e67de296b15,
+    0x6f3b604b8ab2a9688163bcce7f52d62a,
+    0xde76c096d7a791d0c1c6bb5ffea46f54,
+    0x0154307bef840809d4d5a38da6a1c10a,
+    0x02a860f61dcb10126b6985d98f814114,
+    0x0493c02f3a552024d6d2c971ddc18228,
+    0x08e5435e74aa40486f6751e27941c750,
+    0x100986bce8978090decea207f2824da0,
+    0x2012cfbb13edc3e37f5f870e27c79a83,
+    0x40245db526194505febecd1c4e4df7c5,
+    0x8048baa94c328a0a3fbf59389c9a2d49,
+    0xc0b4a6ff392f546cafebe1d4d76364bf,
+    0x43ab8f3d725ea8d89d15016b6dc6c8bd,
+    0x8695dd7ae4bc9373f92a02d6da4f53b9,
+    0xcfe979f40bbbe5e63154046f779ea6b1,
+    0x5d11f22b16b5090f62a808deeeff8fa1,
+    0xba2227562ca9121ec493107f1f3ddd81,
+    0xb7444eac5891243c4be520fe3e7a79c1,
+    0xad889c9bb0e148789609403f7cf4f241,
+    0xc28d3164eceb0f2a379990c4f630b8f6,
+    0x47d962c81b151e546ef1e34b2f60b32f,
+    0x8e71c453362a3ca8dc2105965ec0a55e,
+    0xdfe24ba66c5478937b420aefbc4389bc,
+    0x7d07968fd8a8f0e5f684141dbb86d1bb,
+    0xfa0eefdd739323092fcb283ab5cf61b5,
+    0x371c1d79e6e546125e555074a95dc2a9,
+    0x6e383af20f098c24bcaaa0e891ba4791,
+    0x10d1d39191fef301b1785801496b2da9,
+    0x206165e1e13f2502a1f0b00292d65a91,
+    0x40c2ca01017e4a048123a304e76fb4e1,
+    0x8047570202fc9408c14685080ddeab01,
+    0xc38eae04043beb10418cc9101a7f9502,
+    0x45df9f080876152082db512034fee904,
+    0x8a7dfd1010ec2a40c775a240683f1108,
+    0xd7fa3920201b54804dea8780d07e2210,
+    0x8544df527fc69860d4520e65079f86ea,
+    0xc9887da4fe4ff3c06ba41cca0efdcf17,
+    0x51d3fa8b3f9e2543d68b38571c395d2e,
+    0xa26537d57eff4a866fd570ae3872ba5c,
+    0x87ca6e69fc3d94cfde69e09f70e4b7b8,
+    0xcd57dcd23b7aeb5d7fd203fde00badb3,
+    0x59ae7b6776f415bafe670639031699a5,
+    0xb29ff6ceec2b2ab73fce0c72062cf189,
+    0x203c48f84848c88e2af502dd1430448e,
+    0x40789033909053df54290479286088df,
+    0x80f0e366e3e3a67da85208f250c0d37d,
+    0xc32305cc05058ffa93a41027a04365fa,
+    0x45460a5b0a0add37e58b204e8386ca37,
+    0x8a8c14b61414796e09d5409cc5cf576e,
+    0xd7db28af2828f2dc126980fb495daedc,
+    0x6d75509d5050277b24d2c33592ba9f7b,
+    0x94a5640d89a27f4b6e16c34ce8e3d04d,
+    0xeb89c81ad187fe96dc2c45981305639a,
+    0x15d1533461cd3fef7b588af3260ac6f7,
+    0x2a61a668c2597e1df6b0d7254c144f2d,
+    0x54c28fd047b2fc3a2fa36d4a98289e5a,
+    0xa847dd638ea73b745e85da94f350ffb4,
+    0x938e79c6df8d76e8bcc977eb25a03dab,
+    0xe5dff24f7dd9ec13bb51ee154a837a95,
+    0x019484dd10bd275db87a486c7276a26e,
+    0x02ebcb7920b94ebab3f490d8e4ec87dc,
+    0x041555f240b19cb7a52be3730b1bcd7b,
+    0x082aaa2780a1fbad895605e6163659f6,
+    0x1054974ec3813599d1ac0a0f2c6cb22f,
+    0x20a8ed9c45c16af1619b141e58d8a75e,
+    0x409319fb8a41d421c2f5283cb0738dbc,
+    0x80e53235d7826b4247295078a3e6d9bb,
+];
+
+/// Inverse linear transformation `L^-1`
+pub static L_INV: [u128; 128] = [
+    0x6ea276726c487ab85d27bd10dd849401,
+    0xdc87ece4d890f4b3ba4eb92079cbeb02,
+    0x7bcd1b0b73e32ba5b79cb140f2551504,
+    0xf6593616e6055689adfba18027aa2a08,
+    0x2fb26c2c0f0aacd1993581c34e975410,
+    0x5ea7d8581e149b61f16ac1459ceda820,
+    0xbc8d73b03c28f5c221d4418afb199340,
+    0xbbd9e6a378502947426b82d73532e580,
+    0x4dd0e3e84cc3166e4b7fa2890d64a594,
+    0x9a63051398452cdc96fe87d11ac889eb,
+    0xf7c60a26f38a587bef3fcd613453d115,
+    0x2d4f144c25d7b0f61d7e59c268a6612a,
+    0x5a9e28984a6da32f3afcb247d08fc254,
+    0xb4ff50f394da855e743ba78e63dd47a8,
+    0xab3da025eb77c9bce8768ddfc6798e93,
+    0x957a834a15ee51bb13ecd97d4ff2dfe5,
+    0x8e443014dd02f52a8ec84848f8483c20,
+    0xdf88602879042954df53909033907840,
+    0x7dd3c050f20852a87da6e3e366e3f080,
+    0xfa6543a02710a493fa8f0505cc0523c3,
+    0x37ca86834e208be537dd0a0a5b0a4645,
+    0x6e57cfc59c40d5096e791414b6148c8a,
+    0xdcae5d49fb806912dcf22828af28dbd7,
+    0x7b9fba9235c3d2247b2750509d50756d,
+    0xea869f07650e52d46098c67f52df4485,
+    0x17cffd0eca1ca46bc0f34ffea47d88c9,
+    0x2e5d391c57388bd643259e3f8bfad351,
+    0x5cba7238ae70d56f864aff7ed53765a2,
+    0xb8b7e4709fe069decf943dfc696eca87,
+    0xb3ad0be0fd03d27f5deb7a3bd2dc57cd,
+    0xa5991603390667feba15f476677bae59,
+    0x89f12c06720cce3fb72a2beccef69fb2,
+    0xa92d6b49015878b101f3fe9191d3d110,
+    0x915ad69202b0f0a102253fe1e1656120,
+    0xe1b46fe704a32381044a7e0101cac240,
+    0x01abde0d088546c10894fc0202574780,
+    0x02957f1a10c98c4110eb3b0404ae8ec3,
+    0x04e9fe342051db8220157608089fdf45,
+    0x08113f6840a275c7402aec1010fd7d8a,
+    0x10227ed08087ea4d80541b202039fad7,
+    0xf6b830f6c49099372a0febec64318dc2,
+    0x2fb3602f4be3f16e541e151bc862d947,
+    0x5ea5c05e960521dca83c2a3653c4718e,
+    0xbc8943bcef0a427b9378546ca64be2df,
+    0xbbd186bb1d1484f6e5f0a8d88f96077d,
+    0xb561cfb53a28cb2f09239373ddef0efa,
+    0xa9c25da97450555e1246e5e6791d1c37,
+    0x9147ba91e8a0aabc248c090ff23a386e,
+    0xbf6463d7d4e1ebaf6c542f39ffa6b4c0,
+    0xbdc8c66d6b01159dd8a85e723d8fab43,
+    0xb9534fdad6022af97393bce47add9586,
+    0xb1a69e776f045431e6e5bb0bf479e9cf,
+    0xa18fffeede08a8620f09b5162bf2115d,
+    0x81dd3d1f7f1093c41e12a92c562722ba,
+    0xc1797a3efe20e54b3c249158ac4e44b7,
+    0x41f2f47c3f4009967848e1b09b9c88ad,
+    0x0ac1a1a68da3d5d4090884ef7b305401,
+    0x1441818fd985696b1210cb1df660a802,
+    0x2882c1dd71c9d2d62420553a2fc09304,
+    0x50c74179e251676f4840aa745e43e508,
+    0xa04d82f207a2cede908097e8bc860910,
+    0x839ac7270e875f7fe3c3ed13bbcf1220,
+    0xc5f74d4e1ccdbefe05451926b55d2440,
+    0x492d9a9c3859bf3f0a8a324ca9ba4880,
+    0xf39c2b6aa46ee7be49f6c910afe0defb,
+    0x25fb56d48bdc0dbf922f51209d037f35,
+    0x4a35ac6bd57b1abde75ea240f906fe6a,
+    0x946a9bd669f634b90dbc8780310c3fd4,
+    0xebd4f56fd22f68b11abbcdc362187e6b,
+    0x156b29de675ed0a134b55945c430fcd6,
+    0x2ad6527fcebc638168a9b28a4b603b6f,
+    0x546fa4fe5fbbc6c1d091a7d796c076de,
+    0xf2891cd602afc4f1abeeadbf3d5a6f01,
+    0x27d1386f049d4b21951f99bd7ab4de02,
+    0x4e6170de08f99642e93ef1b9f4ab7f04,
+    0x9cc2e07f1031ef84117c21b12b95fe08,
+    0xfb4703fe20621dcb22f842a156e93f10,
+    0x358e063f40c43a5544338481ac117e20,
+    0x6adf0c7e804b74aa8866cbc19b22fc40,
+    0xd47d18fcc396e897d3cc5541f5443b80,
+    0x8e484311ebbc2d2e8d127c60944477c0,
+    0xdf90862215bb5a5cd924f8c0eb88ee43,
+    0x7de3cf442ab5b4b87148334315d31f86,
+    0xfa055d8854a9abb3e29066862a653ecf,
+    0x370abad3a89195a507e3cccf54ca7c5d,
+    0x6e14b76593e1e9890e055b5da857f8ba,
+    0xdc28adcae50111d11c0ab6ba93ae33b7,
+    0x7b509957090222613814afb7e59f66ad,
+    0x9390681c20c506bbcb8d1ae9f3975dc2,
+    0xe5e3d03840490cb555d9341125edba47,
+    0x09056370809218a9aa7168224a19b78e,
+    0x120ac6e0c3e7309197e2d0449432addf,
+    0x24144f03450d60e1ed076388eb64997d,
+    0x48289e068a1ac001190ec6d315c8f1fa,
+    0x9050ff0cd7344302321c4f652a532137,
+    0xe3a03d186d68860464389eca54a6426e,
+    0xbfda700cca0c171a142f6830d9ca9610,
+    0xbd77e01857182e34285ed0607157ef20,
+    0xb9ee0330ae305c6850bc63c0e2ae1d40,
+    0xb11f06609f60b8d0a0bbc643079f3a80,
+    0xa13e0cc0fdc0b36383b54f860efd74c3,
+    0x817c18433943a5c6c5a99ecf1c39e845,
+    0xc1f830867286894f4991ff5d3872138a,
+    0x413360cfe4cfd19e92e13dba70e426d7,
+    0x74c687106bec624e87b8be5ed0757485,
+    0xe84fcd20d61bc49ccdb3bfbc63eae8c9,
+    0x139e59406f364bfb59a5bdbbc6171351,
+    0x26ffb280de6c9635b289b9b54f2e26a2,
+    0x4c3da7c37fd8ef6aa7d1b1a99e5c4c87,
+    0x987a8d45fe731dd48d61a191ffb898cd,
+    0xf3f4d98a3fe63a6bd9c281e13db3f359,
+    0x252b71d77e0f74d67147c1017aa525b2,
+    0x9820c833f276d5e649d49f95e9992d20,
+    0xf340536627ec690f926bfde911f15a40,
+    0x2580a6cc4e1bd21ee7d639112221b480,
+    0x4ac38f5b9c36673c0d6f72224442abc3,
+    0x9445ddb6fb6cce781adee44488849545,
+    0xeb8a79af35d85ff0347f0b88d3cbe98a,
+    0x15d7f29d6a73be2368fe16d3655511d7,
+    0x2a6d27f9d4e6bf46d03f2c65caaa226d,
+    0xcf6ea276726c487ab85d27bd10dd8494,
+    0x5ddc87ece4d890f4b3ba4eb92079cbeb,
+    0xba7bcd1b0b73e32ba5b79cb140f25515,
+    0xb7f6593616e6055689adfba18027aa2a,
+    0xad2fb26c2c0f0aacd1993581c34e9754,
+    0x995ea7d8581e149b61f16ac1459ceda8,
+    0xf1bc8d73b03c28f5c221d4418afb1993,
+    0x21bbd9e6a378502947426b82d73532e5,
+];
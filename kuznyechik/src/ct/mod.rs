@@ -0,0 +1,221 @@
+//! Constant-time implementation.
+//!
+//! The S-box is evaluated as a bitsliced circuit built from the minterms of
+//! the input bits and the linear transformation as a product with a binary
+//! matrix, so neither memory accesses nor branches depend on secret data.
+//! Up to four blocks are processed with a single evaluation of the circuit.
+
+pub use cipher;
+
+use crate::consts::{P, P_INV};
+use cipher::{
+    consts::{U16, U32, U4},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+mod consts;
+
+type Block = GenericArray<u8, U16>;
+type ParBlocks = U4;
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikEnc {
+    keys: [u128; 10],
+}
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikDec {
+    keys: [u128; 10],
+}
+
+#[inline(always)]
+fn load(block: &Block) -> u128 {
+    let mut buf = [0u8; 16];
+    buf.copy_from_slice(block);
+    u128::from_le_bytes(buf)
+}
+
+#[inline(always)]
+fn store(block: &mut Block, val: u128) {
+    block.copy_from_slice(&val.to_le_bytes());
+}
+
+/// Multiply `x` with the matrix given by its columns.
+#[inline(always)]
+fn linear(x: u128, cols: &[u128; 128]) -> u128 {
+    let mut res = 0;
+    for (i, col) in cols.iter().enumerate() {
+        res ^= col & 0u128.wrapping_sub((x >> i) & 1);
+    }
+    res
+}
+
+/// Apply `sbox` to every byte of up to four blocks.
+fn sub_bytes(blocks: &mut [u128], sbox: &[u8; 256]) {
+    debug_assert!(blocks.len() <= 4);
+
+    // bit `j` of byte `i` in block `n` goes to bit `16 * n + i` of plane `j`
+    let mut planes = [0u64; 8];
+    for (n, block) in blocks.iter().enumerate() {
+        for i in 0..16 {
+            let byte = (block >> (8 * i)) as u64;
+            for (j, plane) in planes.iter_mut().enumerate() {
+                *plane |= ((byte >> j) & 1) << (16 * n + i);
+            }
+        }
+    }
+
+    // minterms: bit `k` of `terms[v]` is set iff byte `k` equals `v`
+    let mut terms = [0u64; 256];
+    terms[0] = !0;
+    for (k, plane) in planes.iter().enumerate().rev() {
+        for t in (0..1 << (7 - k)).rev() {
+            let term = terms[t];
+            terms[2 * t + 1] = term & plane;
+            terms[2 * t] = term & !plane;
+        }
+    }
+
+    // the branch only depends on the public S-box
+    let mut out = [0u64; 8];
+    for (term, &s) in terms.iter().zip(sbox.iter()) {
+        for (j, plane) in out.iter_mut().enumerate() {
+            if (s >> j) & 1 == 1 {
+                *plane ^= term;
+            }
+        }
+    }
+
+    for (n, block) in blocks.iter_mut().enumerate() {
+        let mut res = 0;
+        for i in 0..16 {
+            for (j, plane) in out.iter().enumerate() {
+                res |= u128::from((plane >> (16 * n + i)) as u8 & 1) << (8 * i + j);
+            }
+        }
+        *block = res;
+    }
+}
+
+fn encrypt(keys: &[u128; 10], blocks: &mut [u128]) {
+    unroll9! {
+        i, {
+            for b in blocks.iter_mut() {
+                *b ^= keys[i];
+            }
+            sub_bytes(blocks, &P);
+            for b in blocks.iter_mut() {
+                *b = linear(*b, &consts::L);
+            }
+        }
+    };
+    for b in blocks.iter_mut() {
+        *b ^= keys[9];
+    }
+}
+
+fn decrypt(keys: &[u128; 10], blocks: &mut [u128]) {
+    unroll9! {
+        i, {
+            for b in blocks.iter_mut() {
+                *b = linear(*b ^ keys[9 - i], &consts::L_INV);
+            }
+            sub_bytes(blocks, &P_INV);
+        }
+    };
+    for b in blocks.iter_mut() {
+        *b ^= keys[0];
+    }
+}
+
+impl NewBlockCipher for KuznyechikEnc {
+    type KeySize = U32;
+
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        let mut keys = [0u128; 10];
+        let mut k1 = load(GenericArray::from_slice(&key[..16]));
+        let mut k2 = load(GenericArray::from_slice(&key[16..]));
+        keys[0] = k1;
+        keys[1] = k2;
+
+        let lsx = |k: u128, n: u128| {
+            let mut b = [k ^ linear(n << 120, &consts::L)];
+            sub_bytes(&mut b, &P);
+            linear(b[0], &consts::L)
+        };
+
+        for i in 1..5 {
+            for j in 0..4 {
+                let n = (8 * (i - 1) + 2 * j) as u128;
+                k2 ^= lsx(k1, n + 1);
+                k1 ^= lsx(k2, n + 2);
+            }
+            keys[2 * i] = k1;
+            keys[2 * i + 1] = k2;
+        }
+
+        Self { keys }
+    }
+}
+
+// decryption uses the same round keys in reverse order
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        Self { keys: enc.keys }
+    }
+}
+
+impl BlockCipher for KuznyechikEnc {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockCipher for KuznyechikDec {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockEncrypt for KuznyechikEnc {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        let mut b = [load(block)];
+        encrypt(&self.keys, &mut b);
+        store(block, b[0]);
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let mut b = [0u128; 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = load(block);
+        }
+        encrypt(&self.keys, &mut b);
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, *v);
+        }
+    }
+}
+
+impl BlockDecrypt for KuznyechikDec {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        let mut b = [load(block)];
+        decrypt(&self.keys, &mut b);
+        store(block, b[0]);
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let mut b = [0u128; 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = load(block);
+        }
+        decrypt(&self.keys, &mut b);
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, *v);
+        }
+    }
+}
@@ -1,7 +1,52 @@
 //! Pure Rust implementation of the [Kuznyechik][1] (GOST R 34.12-2015) block cipher.
 //!
+//! On `x86`/`x86_64` targets with SSE2 an implementation based on SSE2
+//! intrinsics is used, and on `aarch64` the same algorithm is implemented
+//! with NEON intrinsics. If SSSE3 is enabled at compile time (e.g. with
+//! `-C target-feature=+ssse3`), the SSE2 backend evaluates the S-box used
+//! during decryption with `pshufb` instead of scalar lookups. If the `simd` feature (nightly-only) is enabled,
+//! the SSE2 algorithm is used with `core::simd` vectors on other targets.
+//! Elsewhere a portable implementation of the same algorithm with the block
+//! state held in machine words is used.
+//!
+//! All of these use 128 KiB of precomputed tables. The `force-soft` feature
+//! selects a slower portable implementation with much smaller tables instead,
+//! which is preferable on targets with little memory.
+//!
+//! The NEON backend requires Rust 1.59 or newer. Enable the `force-soft`
+//! feature to build for `aarch64` with older compilers.
+//!
+//! All of the above are table-based and may therefore be vulnerable to cache
+//! timing attacks. The `constant-time` feature selects a bitsliced
+//! implementation instead, which is considerably slower but does not perform
+//! any memory accesses or branches depending on secret data. It takes
+//! precedence over all other backends.
+//!
+//! Besides [`Kuznyechik`], the [`KuznyechikEnc`] and [`KuznyechikDec`] types
+//! are provided, which only support encryption or decryption respectively.
+//! `KuznyechikEnc` skips computing the round keys used for decryption, which
+//! makes it the cheaper choice for modes such as CTR or OFB.
+//!
+//! The `ctr`, `ofb` and `cbc` features provide the [GOST R 34.13-2015][2]
+//! modes of operation instantiated with Kuznyechik, e.g. `KuznyechikCtr`.
+//!
 //! [1]: https://en.wikipedia.org/wiki/Kuznyechik
+//! [2]: https://tc26.ru/standard/gost/GOST_R_3413-2015.pdf
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(
+    all(
+        not(all(
+            any(target_arch = "x86_64", target_arch = "x86"),
+            target_feature = "sse2"
+        )),
+        not(all(target_arch = "aarch64", target_feature = "neon")),
+        feature = "simd",
+        not(feature = "force-soft"),
+        not(feature = "constant-time"),
+    ),
+    feature(portable_simd)
+)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
@@ -10,32 +55,161 @@
 #![allow(clippy::needless_range_loop, clippy::transmute_ptr_to_ptr)]
 
 pub use cipher;
+
+use cipher::{
+    consts::{U16, U32},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
 use core::fmt;
 
 #[macro_use]
 mod macros;
 mod consts;
+#[cfg(any(feature = "ctr", feature = "ofb", feature = "cbc"))]
+mod modes;
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
     target_feature = "sse2",
     not(feature = "force-soft"),
+    not(feature = "constant-time"),
 ))]
 #[path = "sse2/mod.rs"]
 mod imp;
 
-#[cfg(not(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse2",
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "force-soft"),
+    not(feature = "constant-time"),
+))]
+#[path = "neon/mod.rs"]
+mod imp;
+
+#[cfg(all(
+    not(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    )),
+    not(all(target_arch = "aarch64", target_feature = "neon")),
+    feature = "simd",
     not(feature = "force-soft"),
-)))]
+    not(feature = "constant-time"),
+))]
+#[path = "simd/mod.rs"]
+mod imp;
+
+#[cfg(all(
+    not(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    )),
+    not(all(target_arch = "aarch64", target_feature = "neon")),
+    not(feature = "simd"),
+    not(feature = "force-soft"),
+    not(feature = "constant-time"),
+))]
+#[path = "soft_table/mod.rs"]
+mod imp;
+
+#[cfg(all(feature = "force-soft", not(feature = "constant-time")))]
 #[path = "soft/mod.rs"]
 mod imp;
 
-pub use imp::Kuznyechik;
+#[cfg(feature = "constant-time")]
+#[path = "ct/mod.rs"]
+mod imp;
+
+pub use imp::{KuznyechikDec, KuznyechikEnc};
+
+#[cfg(feature = "cbc")]
+pub use modes::KuznyechikCbc;
+#[cfg(feature = "ctr")]
+pub use modes::KuznyechikCtr;
+#[cfg(feature = "ofb")]
+pub use modes::KuznyechikOfb;
+
+type Block = GenericArray<u8, U16>;
+type ParBlocks = GenericArray<Block, <KuznyechikEnc as BlockCipher>::ParBlocks>;
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher
+#[derive(Clone, Copy)]
+pub struct Kuznyechik {
+    encrypt: KuznyechikEnc,
+    decrypt: KuznyechikDec,
+}
+
+impl NewBlockCipher for Kuznyechik {
+    type KeySize = U32;
+
+    #[inline]
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        let encrypt = KuznyechikEnc::new(key);
+        let decrypt = KuznyechikDec::from(&encrypt);
+        Self { encrypt, decrypt }
+    }
+}
+
+impl BlockCipher for Kuznyechik {
+    type BlockSize = U16;
+    type ParBlocks = <KuznyechikEnc as BlockCipher>::ParBlocks;
+}
+
+impl BlockEncrypt for Kuznyechik {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        self.encrypt.encrypt_block(block)
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+        self.encrypt.encrypt_par_blocks(blocks)
+    }
+}
+
+impl BlockDecrypt for Kuznyechik {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        self.decrypt.decrypt_block(block)
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks) {
+        self.decrypt.decrypt_par_blocks(blocks)
+    }
+}
+
+impl NewBlockCipher for KuznyechikDec {
+    type KeySize = U32;
+
+    #[inline]
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        KuznyechikEnc::new(key).into()
+    }
+}
+
+impl From<KuznyechikEnc> for KuznyechikDec {
+    #[inline]
+    fn from(enc: KuznyechikEnc) -> Self {
+        Self::from(&enc)
+    }
+}
 
 impl fmt::Debug for Kuznyechik {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         write!(f, "Kuznyechik {{ ... }}")
     }
 }
+
+impl fmt::Debug for KuznyechikEnc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "KuznyechikEnc {{ ... }}")
+    }
+}
+
+impl fmt::Debug for KuznyechikDec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "KuznyechikDec {{ ... }}")
+    }
+}
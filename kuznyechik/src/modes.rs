@@ -0,0 +1,25 @@
+//! Kuznyechik in the modes of operation defined in GOST R 34.13-2015.
+//!
+//! The parameters follow the examples given in the standard: the full block
+//! is used for encryption and the OFB and CBC modes use an initialization
+//! vector of two blocks.
+
+use crate::Kuznyechik;
+#[cfg(any(feature = "ofb", feature = "cbc"))]
+use gost_modes::consts::U2;
+
+/// Kuznyechik in CTR mode with a 64-bit nonce
+#[cfg(feature = "ctr")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ctr")))]
+pub type KuznyechikCtr = gost_modes::GostCtr128<Kuznyechik>;
+
+/// Kuznyechik in OFB mode with a 256-bit initialization vector
+#[cfg(feature = "ofb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ofb")))]
+pub type KuznyechikOfb = gost_modes::GostOfb<Kuznyechik, U2>;
+
+/// Kuznyechik in CBC mode with a 256-bit initialization vector and padding
+/// procedure 2
+#[cfg(feature = "cbc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbc")))]
+pub type KuznyechikCbc = gost_modes::GostCbc<Kuznyechik, gost_modes::GostPadding, U2>;
@@ -0,0 +1,221 @@
+//! NEON implementation for `aarch64` using the same precomputed LS tables as
+//! the SSE2 backend.
+//!
+//! The S-box used during decryption is evaluated with `vqtbl4q_u8` lookups
+//! into the 256-byte table held in vector registers.
+
+// the crate MSRV does not apply to this target-specific backend
+#![allow(clippy::incompatible_msrv)]
+
+pub use cipher;
+
+use crate::consts::{P, P_INV};
+use cipher::{
+    consts::{U16, U32, U4},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::arch::aarch64::*;
+
+mod consts;
+
+use consts::{Table, DEC_TABLE, ENC_TABLE, RKEY_GEN};
+
+type Block = GenericArray<u8, U16>;
+type ParBlocks = U4;
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikEnc {
+    keys: [uint8x16_t; 10],
+}
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikDec {
+    keys: [uint8x16_t; 10],
+}
+
+#[inline(always)]
+unsafe fn load(block: &Block) -> uint8x16_t {
+    vld1q_u8(block.as_ptr())
+}
+
+#[inline(always)]
+unsafe fn store(block: &mut Block, val: uint8x16_t) {
+    vst1q_u8(block.as_mut_ptr(), val)
+}
+
+#[inline(always)]
+unsafe fn xor(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    veorq_u8(a, b)
+}
+
+#[inline(always)]
+unsafe fn sub_bytes(block: uint8x16_t, sbox: &[u8; 256]) -> uint8x16_t {
+    // `vqtbl4q_u8` looks up 64 table bytes at a time and returns zero for
+    // out of range indices, so every byte is picked up by exactly one lookup
+    let mut res = vdupq_n_u8(0);
+    let mut idx = block;
+    for chunk in sbox.chunks_exact(64) {
+        let p = chunk.as_ptr();
+        let t = uint8x16x4_t(
+            vld1q_u8(p),
+            vld1q_u8(p.add(16)),
+            vld1q_u8(p.add(32)),
+            vld1q_u8(p.add(48)),
+        );
+        res = veorq_u8(res, vqtbl4q_u8(t, idx));
+        idx = vsubq_u8(idx, vdupq_n_u8(64));
+    }
+    res
+}
+
+#[inline(always)]
+unsafe fn transform(block: uint8x16_t, table: &Table) -> uint8x16_t {
+    let mut bytes = [0u8; 16];
+    vst1q_u8(bytes.as_mut_ptr(), block);
+
+    let table = table.0.as_ptr();
+    // two independent XOR chains, like the SSE2 backend
+    let mut lt = vdupq_n_u8(0);
+    let mut rt = vdupq_n_u8(0);
+    for i in 0..8 {
+        let l = 16 * (256 * i + bytes[i] as usize);
+        let r = 16 * (256 * (i + 8) + bytes[i + 8] as usize);
+        lt = veorq_u8(lt, vld1q_u8(table.add(l)));
+        rt = veorq_u8(rt, vld1q_u8(table.add(r)));
+    }
+    veorq_u8(lt, rt)
+}
+
+impl NewBlockCipher for KuznyechikEnc {
+    type KeySize = U32;
+
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        unsafe {
+            let mut keys = [vdupq_n_u8(0); 10];
+
+            let mut k1 = vld1q_u8(key.as_ptr());
+            let mut k2 = vld1q_u8(key.as_ptr().add(16));
+            keys[0] = k1;
+            keys[1] = k2;
+
+            let mut consts = RKEY_GEN.0.chunks_exact(16).map(|c| vld1q_u8(c.as_ptr()));
+            for i in 1..5 {
+                for _ in 0..4 {
+                    let c = consts.next().unwrap();
+                    k2 = xor(k2, transform(xor(k1, c), &ENC_TABLE));
+                    let c = consts.next().unwrap();
+                    k1 = xor(k1, transform(xor(k2, c), &ENC_TABLE));
+                }
+
+                keys[2 * i] = k1;
+                keys[2 * i + 1] = k2;
+            }
+
+            Self { keys }
+        }
+    }
+}
+
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        let ek = &enc.keys;
+        unsafe {
+            let mut keys = [vdupq_n_u8(0); 10];
+            keys[0] = ek[9];
+            keys[9] = ek[0];
+            for i in 1..9 {
+                keys[9 - i] = transform(sub_bytes(ek[i], &P), &DEC_TABLE);
+            }
+            Self { keys }
+        }
+    }
+}
+
+impl BlockCipher for KuznyechikEnc {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockCipher for KuznyechikDec {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockEncrypt for KuznyechikEnc {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        unsafe {
+            let mut b = load(block);
+            unroll9! {
+                i, {
+                    b = transform(xor(b, k[i]), &ENC_TABLE);
+                }
+            };
+            store(block, xor(b, k[9]));
+        }
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        unsafe {
+            let mut b = [vdupq_n_u8(0); 4];
+            for (v, block) in b.iter_mut().zip(blocks.iter()) {
+                *v = load(block);
+            }
+            unroll9! {
+                i, {
+                    for v in b.iter_mut() {
+                        *v = transform(xor(*v, k[i]), &ENC_TABLE);
+                    }
+                }
+            };
+            for (v, block) in b.iter().zip(blocks.iter_mut()) {
+                store(block, xor(*v, k[9]));
+            }
+        }
+    }
+}
+
+impl BlockDecrypt for KuznyechikDec {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        unsafe {
+            let mut b = xor(load(block), k[0]);
+
+            b = transform(sub_bytes(b, &P), &DEC_TABLE);
+            unroll8! {
+                i, {
+                    b = xor(transform(b, &DEC_TABLE), k[i + 1]);
+                }
+            };
+            store(block, xor(sub_bytes(b, &P_INV), k[9]));
+        }
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        unsafe {
+            let mut b = [vdupq_n_u8(0); 4];
+            for (v, block) in b.iter_mut().zip(blocks.iter()) {
+                *v = transform(sub_bytes(xor(load(block), k[0]), &P), &DEC_TABLE);
+            }
+            unroll8! {
+                i, {
+                    for v in b.iter_mut() {
+                        *v = xor(transform(*v, &DEC_TABLE), k[i + 1]);
+                    }
+                }
+            };
+            for (v, block) in b.iter().zip(blocks.iter_mut()) {
+                store(block, xor(sub_bytes(*v, &P_INV), k[9]));
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Portable SIMD implementation (nightly-only) using the same precomputed
+//! LS tables as the SSE2 backend.
+
+pub use cipher;
+
+use crate::consts::{P, P_INV};
+use cipher::{
+    consts::{U16, U32, U4},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::simd::u8x16;
+
+mod consts;
+
+use consts::{Table, DEC_TABLE, ENC_TABLE, RKEY_GEN};
+
+type Block = GenericArray<u8, U16>;
+type ParBlocks = U4;
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikEnc {
+    keys: [u8x16; 10],
+}
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikDec {
+    keys: [u8x16; 10],
+}
+
+#[inline(always)]
+fn sub_bytes(block: u8x16, sbox: &[u8; 256]) -> u8x16 {
+    let mut res = block.to_array();
+    for b in res.iter_mut() {
+        *b = sbox[*b as usize];
+    }
+    u8x16::from_array(res)
+}
+
+#[inline(always)]
+fn transform(block: u8x16, table: &Table) -> u8x16 {
+    let block = block.to_array();
+    let mut res = u8x16::splat(0);
+    for i in 0..16 {
+        let off = 16 * (256 * i + block[i] as usize);
+        res ^= u8x16::from_slice(&table[off..off + 16]);
+    }
+    res
+}
+
+#[inline(always)]
+fn load(block: &Block) -> u8x16 {
+    u8x16::from_slice(block)
+}
+
+#[inline(always)]
+fn store(block: &mut Block, val: u8x16) {
+    val.copy_to_slice(block);
+}
+
+impl NewBlockCipher for KuznyechikEnc {
+    type KeySize = U32;
+
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        let mut keys = [u8x16::splat(0); 10];
+
+        let mut k1 = u8x16::from_slice(&key[..16]);
+        let mut k2 = u8x16::from_slice(&key[16..]);
+        keys[0] = k1;
+        keys[1] = k2;
+
+        let mut consts = RKEY_GEN.chunks_exact(16).map(u8x16::from_slice);
+        for i in 1..5 {
+            for _ in 0..4 {
+                let c = consts.next().unwrap();
+                k2 ^= transform(k1 ^ c, &ENC_TABLE);
+                let c = consts.next().unwrap();
+                k1 ^= transform(k2 ^ c, &ENC_TABLE);
+            }
+
+            keys[2 * i] = k1;
+            keys[2 * i + 1] = k2;
+        }
+
+        Self { keys }
+    }
+}
+
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        let ek = &enc.keys;
+        let mut keys = [u8x16::splat(0); 10];
+        keys[0] = ek[9];
+        keys[9] = ek[0];
+        for i in 1..9 {
+            keys[9 - i] = transform(sub_bytes(ek[i], &P), &DEC_TABLE);
+        }
+        Self { keys }
+    }
+}
+
+impl BlockCipher for KuznyechikEnc {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockCipher for KuznyechikDec {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockEncrypt for KuznyechikEnc {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        let mut b = load(block);
+        unroll9! {
+            i, {
+                b = transform(b ^ k[i], &ENC_TABLE);
+            }
+        };
+        store(block, b ^ k[9]);
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        let mut b = [u8x16::splat(0); 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = load(block);
+        }
+        unroll9! {
+            i, {
+                for v in b.iter_mut() {
+                    *v = transform(*v ^ k[i], &ENC_TABLE);
+                }
+            }
+        };
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, *v ^ k[9]);
+        }
+    }
+}
+
+impl BlockDecrypt for KuznyechikDec {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        let mut b = load(block) ^ k[0];
+
+        b = transform(sub_bytes(b, &P), &DEC_TABLE);
+        unroll8! {
+            i, {
+                b = transform(b, &DEC_TABLE) ^ k[i + 1];
+            }
+        };
+        store(block, sub_bytes(b, &P_INV) ^ k[9]);
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        let mut b = [u8x16::splat(0); 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = transform(sub_bytes(load(block) ^ k[0], &P), &DEC_TABLE);
+        }
+        unroll8! {
+            i, {
+                for v in b.iter_mut() {
+                    *v = transform(*v, &DEC_TABLE) ^ k[i + 1];
+                }
+            }
+        };
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, sub_bytes(*v, &P_INV) ^ k[9]);
+        }
+    }
+}
@@ -11,9 +11,15 @@ mod consts;
 
 type Block = GenericArray<u8, U16>;
 
-/// Kuznyechik (GOST R 34.12-2015) block cipher
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
 #[derive(Clone, Copy)]
-pub struct Kuznyechik {
+pub struct KuznyechikEnc {
+    keys: [[u8; 16]; 10],
+}
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikDec {
     keys: [[u8; 16]; 10],
 }
 
@@ -92,7 +98,7 @@ fn f(k1: &mut [u8; 16], k2: &mut [u8; 16], n: usize) {
     }
 }
 
-impl Kuznyechik {
+impl KuznyechikEnc {
     fn expand_key(&mut self, key: &GenericArray<u8, U32>) {
         let mut k1 = [0u8; 16];
         let mut k2 = [0u8; 16];
@@ -116,7 +122,9 @@ impl Kuznyechik {
         }
         x(msg, &self.keys[9])
     }
+}
 
+impl KuznyechikDec {
     fn decrypt(&self, msg: &mut [u8; 16]) {
         unroll9! {
             i, { lsx_inv(msg, &self.keys[9 - i]) ; }
@@ -125,7 +133,7 @@ impl Kuznyechik {
     }
 }
 
-impl NewBlockCipher for Kuznyechik {
+impl NewBlockCipher for KuznyechikEnc {
     type KeySize = U32;
 
     fn new(key: &GenericArray<u8, U32>) -> Self {
@@ -137,12 +145,24 @@ impl NewBlockCipher for Kuznyechik {
     }
 }
 
-impl BlockCipher for Kuznyechik {
+// decryption uses the same round keys in reverse order
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        Self { keys: enc.keys }
+    }
+}
+
+impl BlockCipher for KuznyechikEnc {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl BlockCipher for KuznyechikDec {
     type BlockSize = U16;
     type ParBlocks = U1;
 }
 
-impl BlockEncrypt for Kuznyechik {
+impl BlockEncrypt for KuznyechikEnc {
     #[inline]
     fn encrypt_block(&self, block: &mut Block) {
         #[allow(unsafe_code)]
@@ -151,7 +171,7 @@ impl BlockEncrypt for Kuznyechik {
     }
 }
 
-impl BlockDecrypt for Kuznyechik {
+impl BlockDecrypt for KuznyechikDec {
     #[inline]
     fn decrypt_block(&self, block: &mut Block) {
         #[allow(unsafe_code)]
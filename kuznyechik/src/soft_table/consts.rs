@@ -0,0 +1,7 @@
+pub type Table = [u8; 16 * 4096];
+
+pub static ENC_TABLE: Table = *include_bytes!("../sse2/enc_table.bin");
+
+pub static DEC_TABLE: Table = *include_bytes!("../sse2/dec_table.bin");
+
+pub static RKEY_GEN: [u8; 16 * 32] = *include_bytes!("../sse2/rkey_gen.bin");
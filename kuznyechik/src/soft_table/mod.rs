@@ -0,0 +1,226 @@
+//! Portable implementation using the same precomputed LS tables as the SSE2
+//! backend with the state held in machine words.
+//!
+//! Words are loaded in native byte order, so big-endian targets don't pay
+//! for byte swaps, and 32-bit targets use `u32` words instead of emulating
+//! 64-bit arithmetic.
+
+pub use cipher;
+
+use crate::consts::{P, P_INV};
+use cipher::{
+    consts::{U16, U32, U4},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+mod consts;
+
+use consts::{Table, DEC_TABLE, ENC_TABLE, RKEY_GEN};
+
+type Block = GenericArray<u8, U16>;
+// several independent blocks are interleaved to hide the latency of the
+// table lookups
+type ParBlocks = U4;
+
+#[cfg(target_pointer_width = "64")]
+type Word = u64;
+#[cfg(not(target_pointer_width = "64"))]
+type Word = u32;
+
+const WORD_BYTES: usize = core::mem::size_of::<Word>();
+
+/// Block state, words are stored in native byte order.
+type State = [Word; 16 / WORD_BYTES];
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikEnc {
+    keys: [State; 10],
+}
+
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+pub struct KuznyechikDec {
+    keys: [State; 10],
+}
+
+#[inline(always)]
+fn load(bytes: &[u8]) -> State {
+    let mut res = State::default();
+    for (w, chunk) in res.iter_mut().zip(bytes.chunks_exact(WORD_BYTES)) {
+        let mut buf = [0u8; WORD_BYTES];
+        buf.copy_from_slice(chunk);
+        *w = Word::from_ne_bytes(buf);
+    }
+    res
+}
+
+#[inline(always)]
+fn store(block: &mut Block, val: State) {
+    for (w, chunk) in val.iter().zip(block.chunks_exact_mut(WORD_BYTES)) {
+        chunk.copy_from_slice(&w.to_ne_bytes());
+    }
+}
+
+#[inline(always)]
+fn xor(mut a: State, b: State) -> State {
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a ^= b;
+    }
+    a
+}
+
+/// Bit offset of byte `i` within a word.
+#[inline(always)]
+fn shift(i: usize) -> usize {
+    if cfg!(target_endian = "little") {
+        8 * i
+    } else {
+        8 * (WORD_BYTES - 1 - i)
+    }
+}
+
+#[inline(always)]
+fn sub_bytes(block: State, sbox: &[u8; 256]) -> State {
+    let mut res = State::default();
+    for (r, w) in res.iter_mut().zip(block.iter()) {
+        for i in 0..WORD_BYTES {
+            let b = (w >> shift(i)) as u8;
+            *r |= Word::from(sbox[b as usize]) << shift(i);
+        }
+    }
+    res
+}
+
+#[inline(always)]
+fn transform(block: State, table: &Table) -> State {
+    let mut res = State::default();
+    for (j, w) in block.iter().enumerate() {
+        for i in 0..WORD_BYTES {
+            let b = (w >> shift(i)) as u8 as usize;
+            let off = 16 * (256 * (WORD_BYTES * j + i) + b);
+            res = xor(res, load(&table[off..off + 16]));
+        }
+    }
+    res
+}
+
+impl NewBlockCipher for KuznyechikEnc {
+    type KeySize = U32;
+
+    fn new(key: &GenericArray<u8, U32>) -> Self {
+        let mut keys = [State::default(); 10];
+
+        let mut k1 = load(&key[..16]);
+        let mut k2 = load(&key[16..]);
+        keys[0] = k1;
+        keys[1] = k2;
+
+        let mut consts = RKEY_GEN.chunks_exact(16).map(load);
+        for i in 1..5 {
+            for _ in 0..4 {
+                let c = consts.next().unwrap();
+                k2 = xor(k2, transform(xor(k1, c), &ENC_TABLE));
+                let c = consts.next().unwrap();
+                k1 = xor(k1, transform(xor(k2, c), &ENC_TABLE));
+            }
+
+            keys[2 * i] = k1;
+            keys[2 * i + 1] = k2;
+        }
+
+        Self { keys }
+    }
+}
+
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        let ek = &enc.keys;
+        let mut keys = [State::default(); 10];
+        keys[0] = ek[9];
+        keys[9] = ek[0];
+        for i in 1..9 {
+            keys[9 - i] = transform(sub_bytes(ek[i], &P), &DEC_TABLE);
+        }
+        Self { keys }
+    }
+}
+
+impl BlockCipher for KuznyechikEnc {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockCipher for KuznyechikDec {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockEncrypt for KuznyechikEnc {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        let mut b = load(block);
+        unroll9! {
+            i, {
+                b = transform(xor(b, k[i]), &ENC_TABLE);
+            }
+        };
+        store(block, xor(b, k[9]));
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        let mut b = [State::default(); 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = load(block);
+        }
+        unroll9! {
+            i, {
+                for v in b.iter_mut() {
+                    *v = transform(xor(*v, k[i]), &ENC_TABLE);
+                }
+            }
+        };
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, xor(*v, k[9]));
+        }
+    }
+}
+
+impl BlockDecrypt for KuznyechikDec {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        let k = &self.keys;
+        let mut b = xor(load(block), k[0]);
+
+        b = transform(sub_bytes(b, &P), &DEC_TABLE);
+        unroll8! {
+            i, {
+                b = xor(transform(b, &DEC_TABLE), k[i + 1]);
+            }
+        };
+        store(block, xor(sub_bytes(b, &P_INV), k[9]));
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
+        let k = &self.keys;
+        let mut b = [State::default(); 4];
+        for (v, block) in b.iter_mut().zip(blocks.iter()) {
+            *v = transform(sub_bytes(xor(load(block), k[0]), &P), &DEC_TABLE);
+        }
+        unroll8! {
+            i, {
+                for v in b.iter_mut() {
+                    *v = xor(transform(*v, &DEC_TABLE), k[i + 1]);
+                }
+            }
+        };
+        for (v, block) in b.iter().zip(blocks.iter_mut()) {
+            store(block, xor(sub_bytes(*v, &P_INV), k[9]));
+        }
+    }
+}
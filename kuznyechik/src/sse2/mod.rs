@@ -8,9 +8,14 @@ use cipher::{
     generic_array::{typenum::Unsigned, GenericArray},
     BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
 };
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
-type ParBlocks = cipher::consts::U4;
+// eight interleaved blocks hide the latency of the table lookups chained in
+// `transform`, the remaining blocks are processed one at a time
+type ParBlocks = cipher::consts::U8;
 
 #[rustfmt::skip]
 macro_rules! unroll_par {
@@ -19,6 +24,10 @@ macro_rules! unroll_par {
         { let $var: usize = 1; $body; }
         { let $var: usize = 2; $body; }
         { let $var: usize = 3; $body; }
+        { let $var: usize = 4; $body; }
+        { let $var: usize = 5; $body; }
+        { let $var: usize = 6; $body; }
+        { let $var: usize = 7; $body; }
     };
 }
 
@@ -28,14 +37,21 @@ use consts::{Table, DEC_TABLE, ENC_TABLE};
 
 type Block = GenericArray<u8, U16>;
 
-/// Kuznyechik (GOST R 34.12-2015) block cipher
+/// Kuznyechik (GOST R 34.12-2015) block cipher (encrypt-only)
 #[derive(Clone, Copy)]
 #[repr(align(16))]
-pub struct Kuznyechik {
-    enc_keys: [__m128i; 10],
-    dec_keys: [__m128i; 8],
+pub struct KuznyechikEnc {
+    keys: [__m128i; 10],
 }
 
+/// Kuznyechik (GOST R 34.12-2015) block cipher (decrypt-only)
+#[derive(Clone, Copy)]
+#[repr(align(16))]
+pub struct KuznyechikDec {
+    keys: [__m128i; 10],
+}
+
+#[cfg(not(target_feature = "ssse3"))]
 #[inline(always)]
 unsafe fn sub_bytes(block: __m128i, sbox: &[u8; 256]) -> __m128i {
     let t0 = _mm_extract_epi16(block, 0) as u16;
@@ -67,6 +83,26 @@ unsafe fn sub_bytes(block: __m128i, sbox: &[u8; 256]) -> __m128i {
     )
 }
 
+/// S-box lookup keeping the block in a vector register: every row of 16
+/// entries sharing the same high nibble is indexed by the low nibbles using
+/// `pshufb` and the result is kept for bytes with a matching high nibble.
+#[cfg(target_feature = "ssse3")]
+#[inline(always)]
+unsafe fn sub_bytes(block: __m128i, sbox: &[u8; 256]) -> __m128i {
+    let mask = _mm_set1_epi8(0x0F);
+    let lo = _mm_and_si128(block, mask);
+    let hi = _mm_and_si128(_mm_srli_epi16(block, 4), mask);
+
+    let rows = sbox.as_ptr() as *const __m128i;
+    let mut res = _mm_setzero_si128();
+    for i in 0..16 {
+        let row = _mm_loadu_si128(rows.add(i));
+        let sel = _mm_cmpeq_epi8(hi, _mm_set1_epi8(i as i8));
+        res = _mm_or_si128(res, _mm_and_si128(sel, _mm_shuffle_epi8(row, lo)));
+    }
+    res
+}
+
 #[inline(always)]
 unsafe fn transform(block: __m128i, table: &Table) -> __m128i {
     macro_rules! get {
@@ -113,7 +149,7 @@ unsafe fn transform(block: __m128i, table: &Table) -> __m128i {
     _mm_xor_si128(lt, rt)
 }
 
-impl NewBlockCipher for Kuznyechik {
+impl NewBlockCipher for KuznyechikEnc {
     type KeySize = U32;
 
     fn new(key: &GenericArray<u8, U32>) -> Self {
@@ -130,14 +166,13 @@ impl NewBlockCipher for Kuznyechik {
         }
 
         unsafe {
-            let mut enc_keys = [_mm_setzero_si128(); 10];
-            let mut dec_keys = [_mm_setzero_si128(); 8];
+            let mut keys = [_mm_setzero_si128(); 10];
 
             let pk: *const __m128i = key.as_ptr() as *const __m128i;
             let mut k1 = _mm_loadu_si128(pk);
             let mut k2 = _mm_loadu_si128(pk.add(1));
-            enc_keys[0] = k1;
-            enc_keys[1] = k2;
+            keys[0] = k1;
+            keys[1] = k2;
 
             let mut cidx = 0;
             for i in 1..5 {
@@ -151,29 +186,45 @@ impl NewBlockCipher for Kuznyechik {
                     k1 = _mm_xor_si128(k1, t);
                 }
 
-                enc_keys[2 * i] = k1;
-                enc_keys[2 * i + 1] = k2;
+                keys[2 * i] = k1;
+                keys[2 * i + 1] = k2;
             }
 
+            Self { keys }
+        }
+    }
+}
+
+impl From<&KuznyechikEnc> for KuznyechikDec {
+    fn from(enc: &KuznyechikEnc) -> Self {
+        let ek = enc.keys;
+        unsafe {
+            let mut keys = [_mm_setzero_si128(); 10];
+            keys[0] = ek[9];
+            keys[9] = ek[0];
             for i in 1..9 {
-                let k = sub_bytes(enc_keys[i], &P);
-                dec_keys[8 - i] = transform(k, &DEC_TABLE);
+                let k = sub_bytes(ek[i], &P);
+                keys[9 - i] = transform(k, &DEC_TABLE);
             }
-
-            Self { enc_keys, dec_keys }
+            Self { keys }
         }
     }
 }
 
-impl BlockCipher for Kuznyechik {
+impl BlockCipher for KuznyechikEnc {
     type BlockSize = U16;
     type ParBlocks = ParBlocks;
 }
 
-impl BlockEncrypt for Kuznyechik {
+impl BlockCipher for KuznyechikDec {
+    type BlockSize = U16;
+    type ParBlocks = ParBlocks;
+}
+
+impl BlockEncrypt for KuznyechikEnc {
     #[inline]
     fn encrypt_block(&self, block: &mut Block) {
-        let k = self.enc_keys;
+        let k = self.keys;
         unsafe {
             let block_ptr = block.as_ptr() as *mut __m128i;
             let mut block = _mm_loadu_si128(block_ptr);
@@ -191,7 +242,7 @@ impl BlockEncrypt for Kuznyechik {
 
     #[inline]
     fn encrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
-        let k = self.enc_keys;
+        let k = self.keys;
         unsafe {
             let bptr = blocks.as_ptr() as *mut __m128i;
             let mut blocks = [_mm_setzero_si128(); ParBlocks::USIZE];
@@ -222,16 +273,15 @@ impl BlockEncrypt for Kuznyechik {
     }
 }
 
-impl BlockDecrypt for Kuznyechik {
+impl BlockDecrypt for KuznyechikDec {
     #[inline]
     fn decrypt_block(&self, block: &mut Block) {
-        let ek = self.enc_keys;
-        let dk = self.dec_keys;
+        let k = self.keys;
         unsafe {
             let block_ptr = block.as_ptr() as *mut __m128i;
             let mut block = _mm_loadu_si128(block_ptr);
 
-            block = _mm_xor_si128(block, ek[9]);
+            block = _mm_xor_si128(block, k[0]);
 
             block = sub_bytes(block, &P);
             block = transform(block, &DEC_TABLE);
@@ -239,20 +289,19 @@ impl BlockDecrypt for Kuznyechik {
             unroll8! {
                 i, {
                     block = transform(block, &DEC_TABLE);
-                    block = _mm_xor_si128(block, dk[i]);
+                    block = _mm_xor_si128(block, k[i + 1]);
                 }
             }
 
             block = sub_bytes(block, &P_INV);
-            block = _mm_xor_si128(block, ek[0]);
+            block = _mm_xor_si128(block, k[9]);
             _mm_storeu_si128(block_ptr, block)
         }
     }
 
     #[inline]
     fn decrypt_par_blocks(&self, blocks: &mut GenericArray<Block, ParBlocks>) {
-        let ek = self.enc_keys;
-        let dk = self.dec_keys;
+        let k = self.keys;
         unsafe {
             let bptr = blocks.as_ptr() as *mut __m128i;
             let mut blocks = [_mm_setzero_si128(); ParBlocks::USIZE];
@@ -264,7 +313,7 @@ impl BlockDecrypt for Kuznyechik {
 
             unroll_par! {
                 i, {
-                    let t = _mm_xor_si128(blocks[i], ek[9]);
+                    let t = _mm_xor_si128(blocks[i], k[0]);
                     let t = sub_bytes(t, &P);
                     blocks[i] = transform(t, &DEC_TABLE);
                 }
@@ -275,7 +324,7 @@ impl BlockDecrypt for Kuznyechik {
                     unroll_par!{
                         j, {
                             let t = transform(blocks[j], &DEC_TABLE);
-                            blocks[j] = _mm_xor_si128(t, dk[i]);
+                            blocks[j] = _mm_xor_si128(t, k[i + 1]);
                         }
                     }
                 }
@@ -284,7 +333,7 @@ impl BlockDecrypt for Kuznyechik {
             unroll_par! {
                 i, {
                     let t = sub_bytes(blocks[i], &P_INV);
-                    let t2 = _mm_xor_si128(t, ek[0]);
+                    let t2 = _mm_xor_si128(t, k[9]);
                     _mm_storeu_si128(bptr.add(i), t2)
                 }
             }
@@ -1,5 +1,8 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use cipher::{generic_array::GenericArray, BlockEncrypt, BlockDecrypt, NewBlockCipher};
 use hex_literal::hex;
 
@@ -21,4 +24,53 @@ fn kuznyechik() {
 
     state.decrypt_block(&mut block);
     assert_eq!(&plaintext, block.as_slice());
+
+    let mut blocks = cipher::ParBlocks::<kuznyechik::Kuznyechik>::default();
+    for block in blocks.iter_mut() {
+        block.copy_from_slice(&plaintext);
+    }
+    state.encrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(&ciphertext, block.as_slice());
+    }
+
+    state.decrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(&plaintext, block.as_slice());
+    }
+}
+
+#[test]
+fn kuznyechik_enc_dec() {
+    let key = hex!("
+        8899AABBCCDDEEFF0011223344556677
+        FEDCBA98765432100123456789ABCDEF
+    ");
+    let plaintext = hex!("1122334455667700FFEEDDCCBBAA9988");
+    let ciphertext = hex!("7F679D90BEBC24305a468d42b9d4EDCD");
+
+    let enc = kuznyechik::KuznyechikEnc::new_from_slice(&key).unwrap();
+    let dec = kuznyechik::KuznyechikDec::new_from_slice(&key).unwrap();
+    assert_eq!(format!("{:?}", kuznyechik::KuznyechikDec::from(&enc)), "KuznyechikDec { ... }");
+
+    let mut block = GenericArray::clone_from_slice(&plaintext);
+    enc.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    dec.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+
+    let mut blocks = cipher::ParBlocks::<kuznyechik::KuznyechikEnc>::default();
+    for block in blocks.iter_mut() {
+        block.copy_from_slice(&plaintext);
+    }
+    enc.encrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(&ciphertext, block.as_slice());
+    }
+
+    dec.decrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(&plaintext, block.as_slice());
+    }
 }
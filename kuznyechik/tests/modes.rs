@@ -0,0 +1,70 @@
+#![cfg(all(feature = "ctr", feature = "ofb", feature = "cbc"))]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{NewCipher, StreamCipher};
+use gost_modes::BlockMode;
+use hex_literal::hex;
+use kuznyechik::{KuznyechikCbc, KuznyechikCtr, KuznyechikOfb};
+
+const KEY: [u8; 32] = hex!("
+    8899aabbccddeeff0011223344556677
+    fedcba98765432100123456789abcdef
+");
+const IV: [u8; 32] = hex!("
+    1234567890abcef0a1b2c3d4e5f00112
+    23344556677889901213141516171819
+");
+const PT: [u8; 64] = hex!("
+    1122334455667700ffeeddccbbaa9988
+    00112233445566778899aabbcceeff0a
+    112233445566778899aabbcceeff0a00
+    2233445566778899aabbcceeff0a0011
+");
+
+/// Example vectors from GOST R 34.13-2015
+#[test]
+fn kuznyechik_ctr() {
+    let ct = hex!("
+        f195d8bec10ed1dbd57b5fa240bda1b8
+        85eee733f6a13e5df33ce4b33c45dee4
+        a5eae88be6356ed3d5e877f13564a3a5
+        cb91fab1f20cbab6d1c6d15820bdba73
+    ");
+    let mut buf = PT;
+    KuznyechikCtr::new_from_slices(&KEY, &IV[..8]).unwrap().apply_keystream(&mut buf);
+    assert_eq!(buf[..], ct[..]);
+}
+
+#[test]
+fn kuznyechik_ofb() {
+    let ct = hex!("
+        81800a59b1842b24ff1f795e897abd95
+        ed5b47a7048cfab48fb521369d9326bf
+        66a257ac3ca0b8b1c80fe7fc10288a13
+        203ebbc066138660a0292243f6903150
+    ");
+    let mut buf = PT;
+    KuznyechikOfb::new_from_slices(&KEY, &IV).unwrap().apply_keystream(&mut buf);
+    assert_eq!(buf[..], ct[..]);
+}
+
+#[test]
+fn kuznyechik_cbc() {
+    let ct = hex!("
+        689972d4a085fa4d90e52e3d6d7dcc27
+        2826e661b478eca6af1e8e448d5ea5ac
+        fe7babf1e91999e85640e8b0f49d90d0
+        167688065a895c631a2d9a1560b63970
+    ");
+    // the padding procedure always appends a block to aligned messages
+    let mut buf = [0u8; 80];
+    buf[..64].copy_from_slice(&PT);
+    let res = KuznyechikCbc::new_from_slices(&KEY, &IV).unwrap().encrypt(&mut buf, 64).unwrap();
+    assert_eq!(res[..64], ct[..]);
+
+    let res = KuznyechikCbc::new_from_slices(&KEY, &IV).unwrap().decrypt(&mut buf).unwrap();
+    assert_eq!(res[..], PT[..]);
+}
@@ -0,0 +1,308 @@
+//! Generic implementation of the [Key Wrap][1] (KW, RFC 3394) and
+//! [Key Wrap with Padding][2] (KWP, RFC 5649) algorithms, also specified in
+//! NIST SP 800-38F.
+//!
+//! Key wrapping encrypts and authenticates key material under a key
+//! encryption key (KEK) without a nonce. With AES it is used e.g. by the
+//! `A128KW`/`A256KW` algorithms of JOSE and by many HSMs.
+//!
+//! KW requires the data to be a multiple of 8 bytes and at least 16 bytes
+//! long, KWP accepts data of any non-zero length. The wrapped data is 8
+//! bytes longer than the (padded) input.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use hex_literal::hex;
+//! use kw::Kek;
+//!
+//! let kek = Kek::new(Aes128::new(&hex!("000102030405060708090a0b0c0d0e0f").into()));
+//! let key = hex!("00112233445566778899aabbccddeeff");
+//!
+//! let mut wrapped = [0u8; 24];
+//! kek.wrap(&key, &mut wrapped).unwrap();
+//! assert_eq!(wrapped, hex!("1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5"));
+//!
+//! let mut unwrapped = [0u8; 16];
+//! kek.unwrap(&wrapped, &mut unwrapped).unwrap();
+//! assert_eq!(unwrapped, key);
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc3394
+//! [2]: https://tools.ietf.org/html/rfc5649
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use cipher;
+
+use cipher::{consts::U16, generic_array::GenericArray, BlockCipher, BlockDecrypt, BlockEncrypt};
+use core::{convert::TryInto, fmt};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Size of the semiblocks the data is processed in.
+pub const SEMIBLOCK_SIZE: usize = 8;
+
+/// Default initial value of KW, see RFC 3394 2.2.3.1.
+pub const IV: [u8; 8] = [0xa6; 8];
+
+/// Prefix of the alternative initial value of KWP, see RFC 5649 3.
+pub const KWP_IV_PREFIX: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+/// Key wrapping errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Input data has an invalid length.
+    InvalidDataSize,
+    /// Output buffer has an invalid length, `expected` is the required one.
+    InvalidOutputSize {
+        /// Required length of the output buffer.
+        expected: usize,
+    },
+    /// Integrity check of the unwrapped data failed.
+    IntegrityCheckFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Error::InvalidDataSize => f.write_str("invalid data size"),
+            Error::InvalidOutputSize { expected } => {
+                write!(f, "invalid output buffer size, expected {}", expected)
+            }
+            Error::IntegrityCheckFailed => f.write_str("integrity check failed"),
+        }
+    }
+}
+
+type Block = GenericArray<u8, U16>;
+
+/// Key encryption key, i.e. a 128-bit block cipher initialized with the
+/// KEK.
+#[derive(Clone)]
+pub struct Kek<C: BlockCipher<BlockSize = U16>> {
+    cipher: C,
+}
+
+impl<C> Kek<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Create KEK from the initialized block `cipher`.
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+
+    /// Get reference to the underlying block cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Wrap `data` (KW) into `out`, which must be 8 bytes longer than
+    /// `data`.
+    ///
+    /// `data` must be a multiple of 8 bytes and at least 16 bytes long.
+    pub fn wrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if data.len() < 2 * SEMIBLOCK_SIZE || data.len() % SEMIBLOCK_SIZE != 0 {
+            return Err(Error::InvalidDataSize);
+        }
+        check_out(out, data.len() + SEMIBLOCK_SIZE)?;
+        out[SEMIBLOCK_SIZE..].copy_from_slice(data);
+        self.wrap_in_place(IV, out);
+        Ok(())
+    }
+
+    /// Unwrap `data` (KW) into `out`, which must be 8 bytes shorter than
+    /// `data`.
+    pub fn unwrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if data.len() < 3 * SEMIBLOCK_SIZE || data.len() % SEMIBLOCK_SIZE != 0 {
+            return Err(Error::InvalidDataSize);
+        }
+        check_out(out, data.len() - SEMIBLOCK_SIZE)?;
+        let a = self.unwrap_into(data, out);
+        if a.ct_eq(&IV).into() {
+            Ok(())
+        } else {
+            out.iter_mut().for_each(|b| *b = 0);
+            Err(Error::IntegrityCheckFailed)
+        }
+    }
+
+    /// Wrap `data` with padding (KWP) into `out`, which must be
+    /// [`wrapped_len_with_padding`] bytes long.
+    pub fn wrap_with_padding(&self, data: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        if data.is_empty() || data.len() > u32::max_value() as usize {
+            return Err(Error::InvalidDataSize);
+        }
+        check_out(out, wrapped_len_with_padding(data.len()))?;
+
+        let mut aiv = [0u8; SEMIBLOCK_SIZE];
+        aiv[..4].copy_from_slice(&KWP_IV_PREFIX);
+        aiv[4..].copy_from_slice(&(data.len() as u32).to_be_bytes());
+
+        let (padded, pad) = out[SEMIBLOCK_SIZE..].split_at_mut(data.len());
+        padded.copy_from_slice(data);
+        pad.iter_mut().for_each(|b| *b = 0);
+
+        if out.len() == 2 * SEMIBLOCK_SIZE {
+            // a single semiblock is encrypted directly
+            out[..SEMIBLOCK_SIZE].copy_from_slice(&aiv);
+            self.cipher.encrypt_block(GenericArray::from_mut_slice(out));
+        } else {
+            self.wrap_in_place(aiv, out);
+        }
+        Ok(())
+    }
+
+    /// Unwrap `data` with padding (KWP) into `out`, which must be 8 bytes
+    /// shorter than `data`, and return the length of the unwrapped data.
+    ///
+    /// Bytes of `out` after the unwrapped data are set to zero.
+    pub fn unwrap_with_padding(&self, data: &[u8], out: &mut [u8]) -> Result<usize, Error> {
+        if data.len() < 2 * SEMIBLOCK_SIZE || data.len() % SEMIBLOCK_SIZE != 0 {
+            return Err(Error::InvalidDataSize);
+        }
+        check_out(out, data.len() - SEMIBLOCK_SIZE)?;
+
+        let aiv = if data.len() == 2 * SEMIBLOCK_SIZE {
+            let mut block = Block::clone_from_slice(data);
+            self.cipher.decrypt_block(&mut block);
+            out.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            block[..SEMIBLOCK_SIZE].try_into().unwrap()
+        } else {
+            self.unwrap_into(data, out)
+        };
+
+        // check the prefix, the length range and the zero padding without
+        // revealing which one failed
+        let mli = u32::from_be_bytes(aiv[4..].try_into().unwrap()) as usize;
+        let max = out.len();
+        let len_ok = mli <= max && mli + SEMIBLOCK_SIZE > max;
+        let mut ok = aiv[..4].ct_eq(&KWP_IV_PREFIX) & subtle::Choice::from(len_ok as u8);
+        if len_ok {
+            let zeros = [0u8; SEMIBLOCK_SIZE];
+            ok &= out[mli..].ct_eq(&zeros[..max - mli]);
+        }
+        if ok.into() {
+            Ok(mli)
+        } else {
+            out.iter_mut().for_each(|b| *b = 0);
+            Err(Error::IntegrityCheckFailed)
+        }
+    }
+
+    /// Wrap the semiblocks `buf[8..]` in place, writing the resulting
+    /// integrity check register to `buf[..8]`.
+    fn wrap_in_place(&self, iv: [u8; SEMIBLOCK_SIZE], buf: &mut [u8]) {
+        let n = buf.len() / SEMIBLOCK_SIZE - 1;
+        let mut a = u64::from_be_bytes(iv);
+        let mut block = Block::default();
+        for j in 0..6 {
+            for (i, r) in buf[SEMIBLOCK_SIZE..]
+                .chunks_exact_mut(SEMIBLOCK_SIZE)
+                .enumerate()
+            {
+                block[..SEMIBLOCK_SIZE].copy_from_slice(&a.to_be_bytes());
+                block[SEMIBLOCK_SIZE..].copy_from_slice(r);
+                self.cipher.encrypt_block(&mut block);
+                let t = (n * j + i + 1) as u64;
+                a = u64::from_be_bytes(block[..SEMIBLOCK_SIZE].try_into().unwrap()) ^ t;
+                r.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+        buf[..SEMIBLOCK_SIZE].copy_from_slice(&a.to_be_bytes());
+    }
+
+    /// Unwrap `data` into `out` and return the integrity check register.
+    fn unwrap_into(&self, data: &[u8], out: &mut [u8]) -> [u8; SEMIBLOCK_SIZE] {
+        let n = out.len() / SEMIBLOCK_SIZE;
+        out.copy_from_slice(&data[SEMIBLOCK_SIZE..]);
+        let mut a = u64::from_be_bytes(data[..SEMIBLOCK_SIZE].try_into().unwrap());
+        let mut block = Block::default();
+        for j in (0..6).rev() {
+            for (i, r) in out.chunks_exact_mut(SEMIBLOCK_SIZE).enumerate().rev() {
+                let t = (n * j + i + 1) as u64;
+                block[..SEMIBLOCK_SIZE].copy_from_slice(&(a ^ t).to_be_bytes());
+                block[SEMIBLOCK_SIZE..].copy_from_slice(r);
+                self.cipher.decrypt_block(&mut block);
+                a = u64::from_be_bytes(block[..SEMIBLOCK_SIZE].try_into().unwrap());
+                r.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+        a.to_be_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C> Kek<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Wrap `data` (KW) into a new vector.
+    pub fn wrap_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = alloc::vec![0; data.len() + SEMIBLOCK_SIZE];
+        self.wrap(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Unwrap `data` (KW) into a new vector.
+    pub fn unwrap_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let len = data
+            .len()
+            .checked_sub(SEMIBLOCK_SIZE)
+            .ok_or(Error::InvalidDataSize)?;
+        let mut out = alloc::vec![0; len];
+        self.unwrap(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Wrap `data` with padding (KWP) into a new vector.
+    pub fn wrap_with_padding_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = alloc::vec![0; wrapped_len_with_padding(data.len())];
+        self.wrap_with_padding(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Unwrap `data` with padding (KWP) into a new vector.
+    pub fn unwrap_with_padding_vec(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let len = data
+            .len()
+            .checked_sub(SEMIBLOCK_SIZE)
+            .ok_or(Error::InvalidDataSize)?;
+        let mut out = alloc::vec![0; len];
+        let n = self.unwrap_with_padding(data, &mut out)?;
+        out.truncate(n);
+        Ok(out)
+    }
+}
+
+impl<C: BlockCipher<BlockSize = U16>> fmt::Debug for Kek<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Kek { ... }")
+    }
+}
+
+/// Length of the data wrapped with padding (KWP) for `len` bytes of input.
+pub fn wrapped_len_with_padding(len: usize) -> usize {
+    let padded = (len + SEMIBLOCK_SIZE - 1) / SEMIBLOCK_SIZE * SEMIBLOCK_SIZE;
+    padded + SEMIBLOCK_SIZE
+}
+
+fn check_out(out: &[u8], expected: usize) -> Result<(), Error> {
+    if out.len() == expected {
+        Ok(())
+    } else {
+        Err(Error::InvalidOutputSize { expected })
+    }
+}
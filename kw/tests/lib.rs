@@ -0,0 +1,117 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, Aes192, Aes256, NewBlockCipher};
+use hex_literal::hex;
+use kw::{Error, Kek};
+
+/// Test vectors from RFC 3394, 4.1 to 4.6.
+#[test]
+fn rfc3394() {
+    let kek128 = Kek::new(Aes128::new(
+        &hex!("000102030405060708090a0b0c0d0e0f").into(),
+    ));
+    let kek192 = Kek::new(Aes192::new(
+        &hex!("000102030405060708090a0b0c0d0e0f1011121314151617").into(),
+    ));
+    let kek256 = Kek::new(Aes256::new(
+        &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f").into(),
+    ));
+    let key128 = hex!("00112233445566778899aabbccddeeff");
+    let key192 = hex!("00112233445566778899aabbccddeeff0001020304050607");
+    let key256 = hex!("00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f");
+
+    assert_eq!(
+        kek128.wrap_vec(&key128).unwrap(),
+        hex!("1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5")
+    );
+    assert_eq!(
+        kek192.wrap_vec(&key128).unwrap(),
+        hex!("96778b25ae6ca435f92b5b97c050aed2468ab8a17ad84e5d")
+    );
+    assert_eq!(
+        kek256.wrap_vec(&key128).unwrap(),
+        hex!("64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7")
+    );
+    assert_eq!(
+        kek192.wrap_vec(&key192).unwrap(),
+        hex!("031d33264e15d33268f24ec260743edce1c6c7ddee725a936ba814915c6762d2")
+    );
+    assert_eq!(
+        kek256.wrap_vec(&key192).unwrap(),
+        hex!("a8f9bc1612c68b3ff6e6f4fbe30e71e4769c8b80a32cb8958cd5d17d6b254da1")
+    );
+    let wrapped = kek256.wrap_vec(&key256).unwrap();
+    assert_eq!(
+        wrapped,
+        hex!("28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21")
+    );
+    assert_eq!(kek256.unwrap_vec(&wrapped).unwrap(), key256);
+}
+
+/// Test vectors from RFC 5649, 6.
+#[test]
+fn rfc5649() {
+    let kek = Kek::new(Aes192::new(
+        &hex!("5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8").into(),
+    ));
+
+    let key = hex!("c37b7e6492584340bed12207808941155068f738");
+    let wrapped = kek.wrap_with_padding_vec(&key).unwrap();
+    assert_eq!(
+        wrapped,
+        hex!("138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a")
+    );
+    assert_eq!(kek.unwrap_with_padding_vec(&wrapped).unwrap(), key);
+
+    let key = hex!("466f7250617369");
+    let wrapped = kek.wrap_with_padding_vec(&key).unwrap();
+    assert_eq!(wrapped, hex!("afbeb0f07dfbf5419200f2ccb50bb24f"));
+    assert_eq!(kek.unwrap_with_padding_vec(&wrapped).unwrap(), key);
+}
+
+#[test]
+fn kwp_roundtrip() {
+    let kek = Kek::new(Aes128::new(&[0x42; 16].into()));
+    for len in 1..=40 {
+        let key: Vec<u8> = (0..len as u8).collect();
+        let wrapped = kek.wrap_with_padding_vec(&key).unwrap();
+        assert_eq!(wrapped.len(), kw::wrapped_len_with_padding(len));
+        assert_eq!(kek.unwrap_with_padding_vec(&wrapped).unwrap(), key);
+    }
+}
+
+#[test]
+fn integrity_check() {
+    let kek = Kek::new(Aes128::new(&[0x42; 16].into()));
+    let mut wrapped = kek.wrap_vec(&[0x24; 32]).unwrap();
+    wrapped[10] ^= 1;
+    assert_eq!(kek.unwrap_vec(&wrapped), Err(Error::IntegrityCheckFailed));
+
+    let mut wrapped = kek.wrap_with_padding_vec(&[0x24; 5]).unwrap();
+    wrapped[3] ^= 1;
+    assert_eq!(
+        kek.unwrap_with_padding_vec(&wrapped),
+        Err(Error::IntegrityCheckFailed)
+    );
+
+    // KW and KWP are not interchangeable
+    let wrapped = kek.wrap_vec(&[0x24; 32]).unwrap();
+    assert_eq!(
+        kek.unwrap_with_padding_vec(&wrapped),
+        Err(Error::IntegrityCheckFailed)
+    );
+}
+
+#[test]
+fn invalid_sizes() {
+    let kek = Kek::new(Aes128::new(&[0x42; 16].into()));
+    assert_eq!(kek.wrap_vec(&[0; 8]), Err(Error::InvalidDataSize));
+    assert_eq!(kek.wrap_vec(&[0; 17]), Err(Error::InvalidDataSize));
+    assert_eq!(kek.unwrap_vec(&[0; 16]), Err(Error::InvalidDataSize));
+    assert_eq!(kek.wrap_with_padding_vec(&[]), Err(Error::InvalidDataSize));
+    assert_eq!(
+        kek.wrap(&[0; 16], &mut [0; 16]),
+        Err(Error::InvalidOutputSize { expected: 24 })
+    );
+}
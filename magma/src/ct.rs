@@ -0,0 +1,135 @@
+//! Bitsliced constant-time implementation processing up to 64 blocks at once.
+//!
+//! Bit `j` of all blocks is held in a single `u64`, so the key addition is
+//! computed with a ripple-carry adder, the S-boxes are evaluated as circuits
+//! built from the minterms of their input bits and the rotation is free.
+//! Neither memory accesses nor branches depend on secret data.
+
+use crate::{to_u32, Sbox};
+use cipher::{consts::U8, generic_array::GenericArray};
+
+type Block = GenericArray<u8, U8>;
+/// Bit planes of one half of the state.
+type Half = [u64; 32];
+
+/// Transpose a 64x64 bit matrix, i.e. afterwards bit `j` of `m[i]` is bit
+/// `i` of the former `m[j]`.
+fn transpose(m: &mut [u64; 64]) {
+    let mut mask = 0x0000_0000_ffff_ffffu64;
+    let mut j = 32;
+    while j != 0 {
+        let mut k = 0;
+        while k < 64 {
+            let t = ((m[k] >> j) ^ m[k + j]) & mask;
+            m[k] ^= t << j;
+            m[k + j] ^= t;
+            k = (k + j + 1) & !j;
+        }
+        j >>= 1;
+        mask ^= mask << j;
+    }
+}
+
+#[inline(always)]
+fn bit_mask(b: u32) -> u64 {
+    0u64.wrapping_sub(u64::from(b & 1))
+}
+
+/// Evaluate the 4-bit S-box `s` on the bit planes `x`.
+#[inline(always)]
+fn sbox(x: &[u64], s: &[u8; 16]) -> [u64; 4] {
+    let lo = [!x[0] & !x[1], x[0] & !x[1], !x[0] & x[1], x[0] & x[1]];
+    let hi = [!x[2] & !x[3], x[2] & !x[3], !x[2] & x[3], x[2] & x[3]];
+    let mut out = [0; 4];
+    for m in 0..16 {
+        // exactly one of the minterms is set for every block
+        let t = lo[m & 3] & hi[m >> 2];
+        for (j, o) in out.iter_mut().enumerate() {
+            *o |= t & bit_mask(u32::from(s[m]) >> j);
+        }
+    }
+    out
+}
+
+#[inline(always)]
+fn g<S: Sbox>(a: &Half, k: u32) -> Half {
+    let mut t = [0; 32];
+    let mut carry = 0;
+    for j in 0..32 {
+        let k = bit_mask(k >> j);
+        let s = a[j] ^ k;
+        t[j] = s ^ carry;
+        carry = (a[j] & k) | (carry & s);
+    }
+
+    let mut out = [0; 32];
+    for n in 0..8 {
+        let s = sbox(&t[4 * n..4 * n + 4], &S::SBOX[n]);
+        for (i, &v) in s.iter().enumerate() {
+            out[(4 * n + i + 11) % 32] = v;
+        }
+    }
+    out
+}
+
+#[inline(always)]
+fn round<S: Sbox>(v: &mut (Half, Half), k: u32) {
+    let t = g::<S>(&v.1, k);
+    for (a, t) in v.0.iter_mut().zip(t.iter()) {
+        *a ^= t;
+    }
+    core::mem::swap(&mut v.0, &mut v.1);
+}
+
+fn load(blocks: &[Block]) -> (Half, Half) {
+    let mut m = [0u64; 64];
+    for (r, block) in m.iter_mut().zip(blocks.iter()) {
+        *r = u64::from(to_u32(&block[0..4])) | (u64::from(to_u32(&block[4..8])) << 32);
+    }
+    transpose(&mut m);
+    let mut v = ([0; 32], [0; 32]);
+    v.0.copy_from_slice(&m[..32]);
+    v.1.copy_from_slice(&m[32..]);
+    v
+}
+
+fn store(blocks: &mut [Block], v: (Half, Half)) {
+    let mut m = [0u64; 64];
+    m[..32].copy_from_slice(&v.1);
+    m[32..].copy_from_slice(&v.0);
+    transpose(&mut m);
+    for (r, block) in m.iter().zip(blocks.iter_mut()) {
+        block[0..4].copy_from_slice(&(*r as u32).to_be_bytes());
+        block[4..8].copy_from_slice(&((*r >> 32) as u32).to_be_bytes());
+    }
+}
+
+/// Encrypt up to 64 blocks.
+pub(crate) fn encrypt<S: Sbox>(key: &[u32; 8], blocks: &mut [Block]) {
+    debug_assert!(blocks.len() <= 64);
+    let mut v = load(blocks);
+    for _ in 0..3 {
+        for &k in key.iter() {
+            round::<S>(&mut v, k);
+        }
+    }
+    for &k in key.iter().rev() {
+        round::<S>(&mut v, k);
+    }
+    store(blocks, v);
+}
+
+/// Decrypt up to 64 blocks.
+pub(crate) fn decrypt<S: Sbox>(key: &[u32; 8], blocks: &mut [Block]) {
+    debug_assert!(blocks.len() <= 64);
+    let mut v = load(blocks);
+    for &k in key.iter() {
+        round::<S>(&mut v, k);
+    }
+    for _ in 0..3 {
+        for &k in key.iter().rev() {
+            round::<S>(&mut v, k);
+        }
+    }
+    store(blocks, v);
+}
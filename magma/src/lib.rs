@@ -25,8 +25,29 @@
 //! assert_eq!(&plaintext, block.as_slice());
 //! ```
 //!
+//! When the S-box is only known at runtime, e.g. from the parameter set OID
+//! of a CMS structure, [`ParamSet`] maps the OID to the parameter set and
+//! [`DynGost89`] creates a cipher instance using it.
+//!
+//! Multi-block methods such as `encrypt_blocks` interleave the rounds of four
+//! blocks to exploit instruction-level parallelism. With the `simd` feature
+//! (nightly-only) eight blocks are processed in parallel using `core::simd`
+//! vectors instead.
+//!
+//! The round function is evaluated with table lookups by default and may
+//! therefore be vulnerable to cache timing attacks. The `constant-time`
+//! feature selects a bitsliced implementation instead, which does not
+//! perform any memory accesses or branches depending on secret data. It
+//! processes 64 blocks at once and takes precedence over the `simd` feature.
+//! As encrypting a single block costs as much as encrypting 64 blocks, use
+//! `encrypt_blocks` and `decrypt_blocks` wherever possible.
+//!
 //! [1]: https://en.wikipedia.org/wiki/GOST_(block_cipher)
 #![no_std]
+#![cfg_attr(
+    all(feature = "simd", not(feature = "constant-time")),
+    feature(portable_simd)
+)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
@@ -36,14 +57,24 @@
 
 pub use cipher::{self, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher};
 
+#[cfg(not(any(feature = "simd", feature = "constant-time")))]
+use cipher::consts::U4;
+#[cfg(feature = "constant-time")]
+use cipher::consts::U64;
 use cipher::{
-    consts::{U1, U32, U8},
+    consts::{U32, U8},
     generic_array::GenericArray,
 };
 use core::{convert::TryInto, marker::PhantomData};
 
+#[cfg(feature = "constant-time")]
+mod ct;
+mod param_set;
 mod sboxes;
+#[cfg(all(feature = "simd", not(feature = "constant-time")))]
+mod simd;
 
+pub use param_set::{DynGost89, ParamSet};
 pub use sboxes::Sbox;
 
 /// Block cipher defined in GOST 28147-89 generic over S-box
@@ -68,12 +99,49 @@ impl<S: Sbox> NewBlockCipher for Gost89<S> {
     }
 }
 
+#[cfg(not(any(feature = "simd", feature = "constant-time")))]
+impl<S: Sbox> Gost89<S> {
+    /// Apply round `i` to the states of several interleaved blocks, which
+    /// are independent and thus keep the execution units busy.
+    #[inline(always)]
+    fn round_par(&self, v: &mut [(u32, u32); 4], i: usize) {
+        for v in v.iter_mut() {
+            *v = (v.1, v.0 ^ S::g(v.1, self.key[i]));
+        }
+    }
+}
+
+#[cfg(not(any(feature = "simd", feature = "constant-time")))]
+#[inline(always)]
+fn load_par(blocks: &GenericArray<GenericArray<u8, U8>, U4>) -> [(u32, u32); 4] {
+    let mut v = [(0, 0); 4];
+    for (v, block) in v.iter_mut().zip(blocks.iter()) {
+        *v = (to_u32(&block[0..4]), to_u32(&block[4..8]));
+    }
+    v
+}
+
+#[cfg(not(any(feature = "simd", feature = "constant-time")))]
+#[inline(always)]
+fn store_par(blocks: &mut GenericArray<GenericArray<u8, U8>, U4>, v: [(u32, u32); 4]) {
+    for (v, block) in v.iter().zip(blocks.iter_mut()) {
+        block[0..4].copy_from_slice(&v.1.to_be_bytes());
+        block[4..8].copy_from_slice(&v.0.to_be_bytes());
+    }
+}
+
 impl<S: Sbox> BlockCipher for Gost89<S> {
     type BlockSize = U8;
-    type ParBlocks = U1;
+    #[cfg(not(any(feature = "simd", feature = "constant-time")))]
+    type ParBlocks = U4;
+    #[cfg(all(feature = "simd", not(feature = "constant-time")))]
+    type ParBlocks = U8;
+    #[cfg(feature = "constant-time")]
+    type ParBlocks = U64;
 }
 
 impl<S: Sbox> BlockEncrypt for Gost89<S> {
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn encrypt_block(&self, block: &mut GenericArray<u8, U8>) {
         let mut v = (to_u32(&block[0..4]), to_u32(&block[4..8]));
@@ -88,9 +156,51 @@ impl<S: Sbox> BlockEncrypt for Gost89<S> {
         block[0..4].copy_from_slice(&v.1.to_be_bytes());
         block[4..8].copy_from_slice(&v.0.to_be_bytes());
     }
+
+    #[cfg(not(any(feature = "simd", feature = "constant-time")))]
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U4>) {
+        let mut v = load_par(blocks);
+        for _ in 0..3 {
+            for i in 0..8 {
+                self.round_par(&mut v, i);
+            }
+        }
+        for i in (0..8).rev() {
+            self.round_par(&mut v, i);
+        }
+        store_par(blocks, v);
+    }
+
+    #[cfg(all(feature = "simd", not(feature = "constant-time")))]
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U8>) {
+        simd::encrypt::<S>(&self.key, blocks);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn encrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+        ct::encrypt::<S>(&self.key, core::slice::from_mut(block));
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U64>) {
+        ct::encrypt::<S>(&self.key, blocks);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [GenericArray<u8, U8>]) {
+        for chunk in blocks.chunks_mut(64) {
+            ct::encrypt::<S>(&self.key, chunk);
+        }
+    }
 }
 
 impl<S: Sbox> BlockDecrypt for Gost89<S> {
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn decrypt_block(&self, block: &mut GenericArray<u8, U8>) {
         let mut v = (to_u32(&block[0..4]), to_u32(&block[4..8]));
@@ -107,6 +217,47 @@ impl<S: Sbox> BlockDecrypt for Gost89<S> {
         block[0..4].copy_from_slice(&v.1.to_be_bytes());
         block[4..8].copy_from_slice(&v.0.to_be_bytes());
     }
+
+    #[cfg(not(any(feature = "simd", feature = "constant-time")))]
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U4>) {
+        let mut v = load_par(blocks);
+        for i in 0..8 {
+            self.round_par(&mut v, i);
+        }
+        for _ in 0..3 {
+            for i in (0..8).rev() {
+                self.round_par(&mut v, i);
+            }
+        }
+        store_par(blocks, v);
+    }
+
+    #[cfg(all(feature = "simd", not(feature = "constant-time")))]
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U8>) {
+        simd::decrypt::<S>(&self.key, blocks);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn decrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+        ct::decrypt::<S>(&self.key, core::slice::from_mut(block));
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut GenericArray<GenericArray<u8, U8>, U64>) {
+        ct::decrypt::<S>(&self.key, blocks);
+    }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [GenericArray<u8, U8>]) {
+        for chunk in blocks.chunks_mut(64) {
+            ct::decrypt::<S>(&self.key, chunk);
+        }
+    }
 }
 
 /// Block cipher defined in GOST R 34.12-2015 (Magma)
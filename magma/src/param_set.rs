@@ -0,0 +1,212 @@
+//! Selection of the S-box at runtime by parameter set OID.
+
+use crate::{
+    Gost89CryptoProA, Gost89CryptoProB, Gost89CryptoProC, Gost89CryptoProD, Gost89Test, Magma,
+};
+use cipher::{
+    consts::{U32, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher, ParBlocks,
+};
+
+type Block = GenericArray<u8, U8>;
+
+/// GOST 28147-89 S-box parameter sets with their object identifiers as
+/// assigned in RFC 4357 and by TC 26.
+///
+/// `id-Gost28147-89-CryptoPro-RIC-1-ParamSet` (1.2.643.2.2.31.7) and the
+/// EAC parameter set are not supported yet, [`ParamSet::from_oid`] returns
+/// `None` for them. Their S-boxes will be added together with known-answer
+/// tests; until then applications which need them can implement
+/// [`Sbox`](crate::Sbox) with the tables from RFC 4357.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ParamSet {
+    /// `id-Gost28147-89-TestParamSet` (1.2.643.2.2.31.0)
+    Test,
+    /// `id-Gost28147-89-CryptoPro-A-ParamSet` (1.2.643.2.2.31.1)
+    CryptoProA,
+    /// `id-Gost28147-89-CryptoPro-B-ParamSet` (1.2.643.2.2.31.2)
+    CryptoProB,
+    /// `id-Gost28147-89-CryptoPro-C-ParamSet` (1.2.643.2.2.31.3)
+    CryptoProC,
+    /// `id-Gost28147-89-CryptoPro-D-ParamSet` (1.2.643.2.2.31.4)
+    CryptoProD,
+    /// `id-tc26-gost-28147-param-Z` (1.2.643.7.1.2.5.1.1), used by Magma
+    Tc26Z,
+}
+
+/// Parameter sets with their OIDs in dotted notation and DER encoding.
+const PARAM_SETS: [(ParamSet, &str, &[u8]); 6] = [
+    (
+        ParamSet::Test,
+        "1.2.643.2.2.31.0",
+        &[0x2a, 0x85, 0x03, 0x02, 0x02, 0x1f, 0x00],
+    ),
+    (
+        ParamSet::CryptoProA,
+        "1.2.643.2.2.31.1",
+        &[0x2a, 0x85, 0x03, 0x02, 0x02, 0x1f, 0x01],
+    ),
+    (
+        ParamSet::CryptoProB,
+        "1.2.643.2.2.31.2",
+        &[0x2a, 0x85, 0x03, 0x02, 0x02, 0x1f, 0x02],
+    ),
+    (
+        ParamSet::CryptoProC,
+        "1.2.643.2.2.31.3",
+        &[0x2a, 0x85, 0x03, 0x02, 0x02, 0x1f, 0x03],
+    ),
+    (
+        ParamSet::CryptoProD,
+        "1.2.643.2.2.31.4",
+        &[0x2a, 0x85, 0x03, 0x02, 0x02, 0x1f, 0x04],
+    ),
+    (
+        ParamSet::Tc26Z,
+        "1.2.643.7.1.2.5.1.1",
+        &[0x2a, 0x85, 0x03, 0x07, 0x01, 0x02, 0x05, 0x01, 0x01],
+    ),
+];
+
+impl ParamSet {
+    /// Look up a parameter set by its OID in dotted notation, e.g.
+    /// `"1.2.643.2.2.31.1"`.
+    pub fn from_oid(oid: &str) -> Option<Self> {
+        PARAM_SETS
+            .iter()
+            .find(|(_, s, _)| *s == oid)
+            .map(|(p, _, _)| *p)
+    }
+
+    /// Look up a parameter set by the DER encoding of its OID, i.e. the
+    /// contents of an `OBJECT IDENTIFIER` without the tag and length bytes.
+    pub fn from_der_oid(oid: &[u8]) -> Option<Self> {
+        PARAM_SETS
+            .iter()
+            .find(|(_, _, der)| *der == oid)
+            .map(|(p, _, _)| *p)
+    }
+
+    /// OID of the parameter set in dotted notation.
+    pub fn oid(self) -> &'static str {
+        PARAM_SETS.iter().find(|(p, _, _)| *p == self).unwrap().1
+    }
+
+    /// DER encoding of the OID of the parameter set, without the tag and
+    /// length bytes.
+    pub fn der_oid(self) -> &'static [u8] {
+        PARAM_SETS.iter().find(|(p, _, _)| *p == self).unwrap().2
+    }
+}
+
+/// GOST 28147-89 block cipher with the S-box selected at runtime.
+///
+/// This is useful for protocols such as CMS, which specify the S-box
+/// parameter set by OID:
+///
+/// ```
+/// use magma::{BlockEncrypt, DynGost89, ParamSet};
+///
+/// let param_set = ParamSet::from_oid("1.2.643.2.2.31.1").unwrap();
+/// let cipher = DynGost89::new(param_set, &[0x42; 32].into());
+/// assert_eq!(cipher.param_set(), ParamSet::CryptoProA);
+///
+/// let mut block = Default::default();
+/// cipher.encrypt_block(&mut block);
+/// ```
+#[derive(Clone, Copy)]
+pub enum DynGost89 {
+    /// Test S-box
+    Test(Gost89Test),
+    /// CryptoPro S-box version A
+    CryptoProA(Gost89CryptoProA),
+    /// CryptoPro S-box version B
+    CryptoProB(Gost89CryptoProB),
+    /// CryptoPro S-box version C
+    CryptoProC(Gost89CryptoProC),
+    /// CryptoPro S-box version D
+    CryptoProD(Gost89CryptoProD),
+    /// TC 26 S-box (Magma)
+    Tc26Z(Magma),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $cipher:ident => $e:expr) => {
+        match $self {
+            DynGost89::Test($cipher) => $e,
+            DynGost89::CryptoProA($cipher) => $e,
+            DynGost89::CryptoProB($cipher) => $e,
+            DynGost89::CryptoProC($cipher) => $e,
+            DynGost89::CryptoProD($cipher) => $e,
+            DynGost89::Tc26Z($cipher) => $e,
+        }
+    };
+}
+
+impl DynGost89 {
+    /// Create a new cipher instance using the S-box of `param_set`.
+    pub fn new(param_set: ParamSet, key: &GenericArray<u8, U32>) -> Self {
+        match param_set {
+            ParamSet::Test => Self::Test(Gost89Test::new(key)),
+            ParamSet::CryptoProA => Self::CryptoProA(Gost89CryptoProA::new(key)),
+            ParamSet::CryptoProB => Self::CryptoProB(Gost89CryptoProB::new(key)),
+            ParamSet::CryptoProC => Self::CryptoProC(Gost89CryptoProC::new(key)),
+            ParamSet::CryptoProD => Self::CryptoProD(Gost89CryptoProD::new(key)),
+            ParamSet::Tc26Z => Self::Tc26Z(Magma::new(key)),
+        }
+    }
+
+    /// Parameter set of the S-box used by this instance.
+    pub fn param_set(&self) -> ParamSet {
+        match self {
+            Self::Test(_) => ParamSet::Test,
+            Self::CryptoProA(_) => ParamSet::CryptoProA,
+            Self::CryptoProB(_) => ParamSet::CryptoProB,
+            Self::CryptoProC(_) => ParamSet::CryptoProC,
+            Self::CryptoProD(_) => ParamSet::CryptoProD,
+            Self::Tc26Z(_) => ParamSet::Tc26Z,
+        }
+    }
+}
+
+impl BlockCipher for DynGost89 {
+    type BlockSize = U8;
+    type ParBlocks = <Magma as BlockCipher>::ParBlocks;
+}
+
+impl BlockEncrypt for DynGost89 {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block) {
+        dispatch!(self, cipher => cipher.encrypt_block(block))
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        dispatch!(self, cipher => cipher.encrypt_par_blocks(blocks))
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block]) {
+        dispatch!(self, cipher => cipher.encrypt_blocks(blocks))
+    }
+}
+
+impl BlockDecrypt for DynGost89 {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block) {
+        dispatch!(self, cipher => cipher.decrypt_block(block))
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        dispatch!(self, cipher => cipher.decrypt_par_blocks(blocks))
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block]) {
+        dispatch!(self, cipher => cipher.decrypt_blocks(blocks))
+    }
+}
+
+opaque_debug::implement!(DynGost89);
@@ -2,6 +2,44 @@
 
 type ExpSbox = [[u8; 256]; 4];
 type SmallSbox = [[u8; 16]; 8];
+pub(crate) type Table = [[u32; 256]; 4];
+
+/// Expand the byte S-boxes into tables of 32-bit words with the byte moved
+/// to its position in the word and the rotation by 11 bits applied.
+macro_rules! exp_table {
+    (@row $sbox:expr, $n:literal; $($i:literal)*) => {
+        [$(((($sbox)[$n][$i] as u32) << (8 * $n)).rotate_left(11)),*]
+    };
+    ($sbox:expr) => {
+        exp_table!(
+            $sbox;
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+            64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+            80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+            96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+            112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+            128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+            144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+            160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+            176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+            192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+            208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+            224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+            240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+        )
+    };
+    ($sbox:expr; $($i:literal)*) => {
+        [
+            exp_table!(@row $sbox, 0; $($i)*),
+            exp_table!(@row $sbox, 1; $($i)*),
+            exp_table!(@row $sbox, 2; $($i)*),
+            exp_table!(@row $sbox, 3; $($i)*),
+        ]
+    };
+}
 
 /// Trait implemented for the GOST 28147-89 cipher S-boxes
 pub trait Sbox {
@@ -11,6 +49,10 @@ pub trait Sbox {
     /// Unexpanded S-box
     const SBOX: SmallSbox;
 
+    /// Expanded S-box combined with the rotation of the round function, so
+    /// that it can be evaluated with four table lookups
+    const TABLE: Table = exp_table!(Self::EXP_SBOX);
+
     #[allow(clippy::needless_range_loop)]
     fn gen_exp_sbox() -> ExpSbox {
         let mut out = [[0u8; 256]; 4];
@@ -37,7 +79,13 @@ pub trait Sbox {
     }
 
     fn g(a: u32, k: u32) -> u32 {
-        Self::apply_sbox(a.wrapping_add(k)).rotate_left(11)
+        // borrowing promotes the table to a static instead of copying it
+        let table: &'static Table = &Self::TABLE;
+        let t = a.wrapping_add(k);
+        table[0][(t & 0xff) as usize]
+            ^ table[1][((t >> 8) & 0xff) as usize]
+            ^ table[2][((t >> 16) & 0xff) as usize]
+            ^ table[3][(t >> 24) as usize]
     }
 }
 
@@ -543,13 +591,12 @@ impl Sbox for CryptoProD {
 mod tests {
     use super::*;
 
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
     fn test_sbox<S: Sbox>() {
         let gen_sbox = S::gen_exp_sbox();
-        for i in 0..4 {
-            for j in 0..256 {
-                assert_eq!(gen_sbox[i][j], S::EXP_SBOX[i][j]);
-            }
-        }
+        assert_eq!(&gen_sbox[..], &S::EXP_SBOX[..]);
     }
 
     #[test]
@@ -0,0 +1,71 @@
+//! Portable SIMD implementation (nightly-only) processing eight blocks at
+//! once, one block per `u32` lane.
+
+#![allow(clippy::needless_range_loop)]
+
+use crate::{sboxes::Table, to_u32, Sbox};
+use cipher::{consts::U8, generic_array::GenericArray};
+use core::simd::{num::SimdUint, u32x8, Simd};
+
+type Block = GenericArray<u8, U8>;
+type ParBlocks = GenericArray<Block, U8>;
+
+#[inline(always)]
+fn g(table: &Table, a: u32x8, k: u32) -> u32x8 {
+    let t = a + u32x8::splat(k);
+    let mut v = u32x8::splat(0);
+    for i in 0..4 {
+        let idx = ((t >> (8 * i as u32)) & u32x8::splat(0xff)).cast::<usize>();
+        v ^= Simd::gather_or_default(&table[i], idx);
+    }
+    v
+}
+
+#[inline(always)]
+fn load(blocks: &ParBlocks) -> (u32x8, u32x8) {
+    let mut a = [0u32; 8];
+    let mut b = [0u32; 8];
+    for i in 0..8 {
+        a[i] = to_u32(&blocks[i][0..4]);
+        b[i] = to_u32(&blocks[i][4..8]);
+    }
+    (u32x8::from_array(a), u32x8::from_array(b))
+}
+
+#[inline(always)]
+fn store(blocks: &mut ParBlocks, v: (u32x8, u32x8)) {
+    let a = v.1.to_array();
+    let b = v.0.to_array();
+    for i in 0..8 {
+        blocks[i][0..4].copy_from_slice(&a[i].to_be_bytes());
+        blocks[i][4..8].copy_from_slice(&b[i].to_be_bytes());
+    }
+}
+
+pub(crate) fn encrypt<S: Sbox>(key: &[u32; 8], blocks: &mut ParBlocks) {
+    let table = &S::TABLE;
+    let mut v = load(blocks);
+    for _ in 0..3 {
+        for i in 0..8 {
+            v = (v.1, v.0 ^ g(table, v.1, key[i]));
+        }
+    }
+    for i in (0..8).rev() {
+        v = (v.1, v.0 ^ g(table, v.1, key[i]));
+    }
+    store(blocks, v);
+}
+
+pub(crate) fn decrypt<S: Sbox>(key: &[u32; 8], blocks: &mut ParBlocks) {
+    let table = &S::TABLE;
+    let mut v = load(blocks);
+    for i in 0..8 {
+        v = (v.1, v.0 ^ g(table, v.1, key[i]));
+    }
+    for _ in 0..3 {
+        for i in (0..8).rev() {
+            v = (v.1, v.0 ^ g(table, v.1, key[i]));
+        }
+    }
+    store(blocks, v);
+}
@@ -0,0 +1,109 @@
+//! Test vectors from GOST R 34.12-2015:
+//! https://tc26.ru/standard/gost/GOST_R_3412-2015.pdf
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+use magma::Magma;
+
+/// Example from GOST R 34.12-2015 A.2.4. Key and block bytes are loaded as
+/// big-endian words, so this also checks word handling on any host.
+#[test]
+fn magma_example() {
+    let key = hex!(
+        "
+        ffeeddccbbaa99887766554433221100
+        f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff
+    "
+    );
+    let pt = hex!("fedcba9876543210");
+    let ct = hex!("4ee901e5c2d8ca3d");
+
+    let cipher = Magma::new(&key.into());
+    let mut block = GenericArray::clone_from_slice(&pt);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(block[..], ct[..]);
+    cipher.decrypt_block(&mut block);
+    assert_eq!(block[..], pt[..]);
+
+    let mut blocks = cipher::ParBlocks::<Magma>::default();
+    for block in blocks.iter_mut() {
+        block.copy_from_slice(&pt);
+    }
+    cipher.encrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(block[..], ct[..]);
+    }
+    cipher.decrypt_par_blocks(&mut blocks);
+    for block in blocks.iter() {
+        assert_eq!(block[..], pt[..]);
+    }
+}
+
+/// Multi-block processing must match encrypting the blocks one by one, also
+/// for a number of blocks which is not a multiple of `ParBlocks`.
+#[test]
+fn magma_blocks() {
+    let cipher = Magma::new(&[0x42; 32].into());
+    let mut blocks = [GenericArray::default(); 75];
+    for (i, block) in blocks.iter_mut().enumerate() {
+        for (j, b) in block.iter_mut().enumerate() {
+            *b = (31 * i + 7 * j) as u8;
+        }
+    }
+    let pt = blocks;
+
+    cipher.encrypt_blocks(&mut blocks);
+    for (block, pt) in blocks.iter().zip(pt.iter()) {
+        let mut expected = *pt;
+        cipher.encrypt_block(&mut expected);
+        assert_eq!(block, &expected);
+    }
+    cipher.decrypt_blocks(&mut blocks);
+    assert_eq!(blocks[..], pt[..]);
+}
+
+#[test]
+fn param_set_oid() {
+    use magma::{DynGost89, ParamSet};
+
+    let sets = [
+        ParamSet::Test,
+        ParamSet::CryptoProA,
+        ParamSet::CryptoProB,
+        ParamSet::CryptoProC,
+        ParamSet::CryptoProD,
+        ParamSet::Tc26Z,
+    ];
+    for &p in sets.iter() {
+        assert_eq!(ParamSet::from_oid(p.oid()), Some(p));
+        assert_eq!(ParamSet::from_der_oid(p.der_oid()), Some(p));
+    }
+    assert_eq!(
+        ParamSet::from_oid("1.2.643.2.2.31.1"),
+        Some(ParamSet::CryptoProA)
+    );
+    assert_eq!(ParamSet::from_der_oid(&hex!("2a850307010205010101")), None);
+    // RIC-1 is not supported
+    assert_eq!(ParamSet::from_oid("1.2.643.2.2.31.7"), None);
+
+    let key = hex!(
+        "
+        ffeeddccbbaa99887766554433221100
+        f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff
+    "
+    );
+    let pt = hex!("fedcba9876543210");
+    let ct = hex!("4ee901e5c2d8ca3d");
+
+    let param_set = ParamSet::from_der_oid(&hex!("2a8503070102050101")).unwrap();
+    let cipher = DynGost89::new(param_set, &key.into());
+    assert_eq!(cipher.param_set(), ParamSet::Tc26Z);
+    let mut block = GenericArray::clone_from_slice(&pt);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(block[..], ct[..]);
+    cipher.decrypt_block(&mut block);
+    assert_eq!(block[..], pt[..]);
+}
@@ -0,0 +1,252 @@
+//! Generic implementation of the [Multilinear Galois Mode][1] (MGM) of
+//! authenticated encryption with associated data.
+//!
+//! MGM is defined in R 1323565.1.026-2019 and used by the GOST cipher
+//! suites of TLS 1.3 with the Kuznyechik (128-bit block) and Magma (64-bit
+//! block) ciphers. Both block sizes are supported by this implementation.
+//!
+//! The nonce is a single block with the most significant bit cleared, and
+//! the authentication tag is a full block, which may be truncated by the
+//! caller if the protocol requires it.
+//!
+//! # Examples
+//! ```
+//! use kuznyechik::{cipher::NewBlockCipher, Kuznyechik};
+//! use mgm::Mgm;
+//!
+//! let mgm = Mgm::new(Kuznyechik::new(&[0x42; 32].into()));
+//! let nonce = [0x24; 16].into();
+//!
+//! let mut buf = *b"my secret message";
+//! let tag = mgm.encrypt_in_place_detached(&nonce, b"header", &mut buf).unwrap();
+//! mgm.decrypt_in_place_detached(&nonce, b"header", &mut buf, &tag).unwrap();
+//! assert_eq!(&buf, b"my secret message");
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc9058
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockCipher, BlockEncrypt,
+};
+use core::fmt;
+use subtle::ConstantTimeEq;
+
+/// Block of the underlying cipher, used for nonces and tags.
+pub type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
+
+/// Error returned on invalid nonces, oversized messages and failed
+/// authentication.
+///
+/// The reason is deliberately not reported.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Error;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("MGM error")
+    }
+}
+
+/// Field parameters for a block size in bits.
+#[derive(Copy, Clone)]
+struct Field {
+    bits: u32,
+    /// Low terms of the irreducible polynomial.
+    reduction: u128,
+}
+
+impl Field {
+    fn new(block_size: usize) -> Option<Self> {
+        let reduction = match block_size {
+            // x^64 + x^4 + x^3 + x + 1
+            8 => 0x1b,
+            // x^128 + x^7 + x^2 + x + 1
+            16 => 0x87,
+            _ => return None,
+        };
+        Some(Self {
+            bits: 8 * block_size as u32,
+            reduction,
+        })
+    }
+
+    fn mask(self) -> u128 {
+        !0u128 >> (128 - self.bits)
+    }
+
+    fn half_mask(self) -> u128 {
+        !0u128 >> (128 - self.bits / 2)
+    }
+
+    /// Multiplication in `GF(2^n)` without secret-dependent branches.
+    fn mul(self, a: u128, b: u128) -> u128 {
+        let mut z = 0;
+        let mut v = a;
+        for i in 0..self.bits {
+            z ^= v & ((b >> i) & 1).wrapping_neg();
+            let carry = (v >> (self.bits - 1)) & 1;
+            v = ((v << 1) & self.mask()) ^ (self.reduction & carry.wrapping_neg());
+        }
+        z
+    }
+
+    /// Increment the right (least significant) half.
+    fn incr_r(self, x: u128) -> u128 {
+        let h = self.half_mask();
+        (x & !h) | (x.wrapping_add(1) & h)
+    }
+
+    /// Increment the left (most significant) half.
+    fn incr_l(self, x: u128) -> u128 {
+        let h = self.half_mask();
+        (x & h) | (x.wrapping_add(1 << (self.bits / 2)) & self.mask() & !h)
+    }
+}
+
+fn to_int(block: &[u8]) -> u128 {
+    block.iter().fold(0, |acc, &b| (acc << 8) | u128::from(b))
+}
+
+fn to_block<C: BlockCipher>(mut x: u128) -> Block<C> {
+    let mut block = Block::<C>::default();
+    for b in block.iter_mut().rev() {
+        *b = x as u8;
+        x >>= 8;
+    }
+    block
+}
+
+/// MGM instance.
+#[derive(Clone)]
+pub struct Mgm<C: BlockCipher + BlockEncrypt> {
+    cipher: C,
+    field: Field,
+}
+
+impl<C: BlockCipher + BlockEncrypt> Mgm<C> {
+    /// Create MGM instance from the initialized block `cipher`.
+    ///
+    /// # Panics
+    /// If the block size of `C` is neither 64 nor 128 bits.
+    pub fn new(cipher: C) -> Self {
+        let field = Field::new(C::BlockSize::to_usize()).expect("unsupported block size");
+        Self { cipher, field }
+    }
+
+    /// Get reference to the underlying block cipher.
+    pub fn cipher(&self) -> &C {
+        &self.cipher
+    }
+
+    /// Encrypt `buf` in place and return the authentication tag over `ad`
+    /// and the ciphertext.
+    pub fn encrypt_in_place_detached(
+        &self,
+        nonce: &Block<C>,
+        ad: &[u8],
+        buf: &mut [u8],
+    ) -> Result<Block<C>, Error> {
+        self.check(nonce, ad, buf)?;
+        self.apply_keystream(nonce, buf);
+        Ok(self.tag(nonce, ad, buf))
+    }
+
+    /// Verify `tag` and decrypt `buf` in place.
+    ///
+    /// On authentication failure `buf` is left unchanged.
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &Block<C>,
+        ad: &[u8],
+        buf: &mut [u8],
+        tag: &Block<C>,
+    ) -> Result<(), Error> {
+        self.check(nonce, ad, buf)?;
+        let expected = self.tag(nonce, ad, buf);
+        if expected.ct_eq(tag).into() {
+            self.apply_keystream(nonce, buf);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Check that the most significant nonce bit is cleared and that the
+    /// number of blocks and the bit lengths fit into half a block.
+    fn check(&self, nonce: &Block<C>, ad: &[u8], buf: &[u8]) -> Result<(), Error> {
+        let bs = C::BlockSize::to_usize() as u128;
+        let limit = self.field.half_mask();
+        let (ad_len, buf_len) = (ad.len() as u128, buf.len() as u128);
+        let blocks = (ad_len + bs - 1) / bs + (buf_len + bs - 1) / bs + 1;
+        if nonce[0] >> 7 != 0 || 8 * ad_len > limit || 8 * buf_len > limit || blocks > limit {
+            Err(Error)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn encrypt_int(&self, x: u128) -> u128 {
+        let mut block = to_block::<C>(x);
+        self.cipher.encrypt_block(&mut block);
+        to_int(&block)
+    }
+
+    /// Apply the keystream `E(Y_i)` with `Y_1 = E(0 || ICN)` and the right
+    /// half of `Y_i` incremented for every block.
+    fn apply_keystream(&self, nonce: &Block<C>, buf: &mut [u8]) {
+        let bs = C::BlockSize::to_usize();
+        let mut y = self.encrypt_int(to_int(nonce));
+        for chunk in buf.chunks_mut(bs) {
+            let mut ks = to_block::<C>(y);
+            self.cipher.encrypt_block(&mut ks);
+            for (b, k) in chunk.iter_mut().zip(ks.iter()) {
+                *b ^= k;
+            }
+            y = self.field.incr_r(y);
+        }
+    }
+
+    /// Compute `E(sum H_i * A_i)` over the zero-padded associated data,
+    /// the zero-padded ciphertext and the lengths block, where
+    /// `H_i = E(Z_i)` with `Z_1 = E(1 || ICN)` and the left half of `Z_i`
+    /// incremented for every block.
+    fn tag(&self, nonce: &Block<C>, ad: &[u8], ct: &[u8]) -> Block<C> {
+        let bs = C::BlockSize::to_usize();
+        let f = self.field;
+        let mut z = self.encrypt_int(to_int(nonce) | (1 << (f.bits - 1)));
+        let mut sum = 0;
+
+        let mut absorb = |a: u128| {
+            sum ^= f.mul(self.encrypt_int(z), a);
+            z = f.incr_l(z);
+        };
+        for data in [ad, ct].iter() {
+            for chunk in data.chunks(bs) {
+                let mut block = Block::<C>::default();
+                block[..chunk.len()].copy_from_slice(chunk);
+                absorb(to_int(&block));
+            }
+        }
+        let lens = ((8 * ad.len() as u128) << (f.bits / 2)) | (8 * ct.len() as u128);
+        absorb(lens);
+
+        to_block::<C>(self.encrypt_int(sum))
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> fmt::Debug for Mgm<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Mgm { ... }")
+    }
+}
@@ -0,0 +1,107 @@
+//! Test vectors from RFC 9058, Appendix A.
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use hex_literal::hex;
+use kuznyechik::Kuznyechik;
+use magma::Magma;
+use mgm::cipher::{BlockCipher, BlockEncrypt, NewBlockCipher};
+use mgm::{Block, Mgm};
+
+const KEY: [u8; 32] = hex!("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef");
+
+fn check<C: BlockCipher + BlockEncrypt + NewBlockCipher>(
+    key: &[u8],
+    nonce: &[u8],
+    ad: &[u8],
+    pt: &[u8],
+    ct: &[u8],
+    tag: &[u8],
+) {
+    let mgm = Mgm::new(C::new_from_slice(key).unwrap());
+    let nonce = Block::<C>::clone_from_slice(nonce);
+
+    let mut buf = pt.to_vec();
+    let t = mgm.encrypt_in_place_detached(&nonce, ad, &mut buf).unwrap();
+    assert_eq!(buf, ct);
+    assert_eq!(&t[..], tag);
+
+    mgm.decrypt_in_place_detached(&nonce, ad, &mut buf, &t)
+        .unwrap();
+    assert_eq!(buf, pt);
+
+    // any modification is detected and leaves the buffer unchanged
+    let mut buf = ct.to_vec();
+    buf[0] ^= 1;
+    assert!(mgm
+        .decrypt_in_place_detached(&nonce, ad, &mut buf, &t)
+        .is_err());
+    assert_eq!(buf[1..], ct[1..]);
+    let mut bad_tag = t.clone();
+    bad_tag[0] ^= 1;
+    assert!(mgm
+        .decrypt_in_place_detached(&nonce, ad, &mut ct.to_vec(), &bad_tag)
+        .is_err());
+    assert!(mgm
+        .decrypt_in_place_detached(&nonce, &ad[1..], &mut ct.to_vec(), &t)
+        .is_err());
+}
+
+#[test]
+fn kuznyechik_mgm() {
+    check::<Kuznyechik>(
+        &KEY,
+        &hex!("1122334455667700ffeeddccbbaa9988"),
+        &hex!("0202020202020202010101010101010104040404040404040303030303030303ea0505050505050505"),
+        &hex!("1122334455667700ffeeddccbbaa998800112233445566778899aabbcceeff0a112233445566778899aabbcceeff0a002233445566778899aabbcceeff0a0011aabbcc"),
+        &hex!("a9757b8147956e9055b8a33de89f42fc8075d2212bf9fd5bd3f7069aadc16b39497ab15915a6ba85936b5d0ea9f6851cc60c14d4d3f883d0ab94420695c76deb2c7552"),
+        &hex!("cf5d656f40c34f5c46e8bb0e29fcdb4c"),
+    );
+}
+
+#[test]
+fn magma_mgm() {
+    check::<Magma>(
+        &hex!("ffeeddccbbaa99887766554433221100f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+        &hex!("12def06b3c130a59"),
+        &hex!("01010101010101010202020202020202030303030303030304040404040404040505050505050505ea"),
+        &hex!("ffeeddccbbaa998811223344556677008899aabbcceeff0a001122334455667799aabbcceeff0a001122334455667788aabbcceeff0a00112233445566778899aabbcc"),
+        &hex!("c795066c5f9ea03b85113342459185ae1f2e00d6bf2b785d940470b8bb9c8e7d9a5dd3731f7ddc70ec27cb0ace6fa57670f65c646abb75d547aa37c3bcb5c34e03bb9c"),
+        &hex!("a7928069aa10fd10"),
+    );
+}
+
+/// The 64-bit variant shares all code with the 128-bit one except for the
+/// field, check it for consistency.
+#[test]
+fn magma_mgm_roundtrip() {
+    let mgm = Mgm::new(Magma::new(&KEY.into()));
+    let nonce = hex!("12def06b3c130a59").into();
+    let ad = [0x05; 41];
+    for len in 0..40 {
+        let pt: Vec<u8> = (0..len as u8).collect();
+        let mut buf = pt.clone();
+        let tag = mgm
+            .encrypt_in_place_detached(&nonce, &ad, &mut buf)
+            .unwrap();
+        if len != 0 {
+            assert_ne!(buf, pt);
+        }
+        let mut bad = buf.clone();
+        bad.push(0);
+        assert!(mgm
+            .decrypt_in_place_detached(&nonce, &ad, &mut bad, &tag)
+            .is_err());
+        mgm.decrypt_in_place_detached(&nonce, &ad, &mut buf, &tag)
+            .unwrap();
+        assert_eq!(buf, pt);
+    }
+}
+
+#[test]
+fn invalid_nonce() {
+    let mgm = Mgm::new(Kuznyechik::new(&KEY.into()));
+    let nonce = [0x80; 16].into();
+    assert!(mgm.encrypt_in_place_detached(&nonce, b"", &mut []).is_err());
+}
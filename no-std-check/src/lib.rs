@@ -0,0 +1,183 @@
+//! Link test for `no_std` targets.
+//!
+//! This crate depends on every crate in the workspace with default features
+//! disabled and exports a function using each of them, so building it as a
+//! static library for a bare-metal target fails if any of them pulls in `std`
+//! or `alloc` (the latter requires a global allocator, which is deliberately
+//! not provided here):
+//!
+//! ```text
+//! cargo build -p no-std-check --target thumbv6m-none-eabi
+//! ```
+
+#![cfg_attr(target_os = "none", no_std)]
+#![warn(rust_2018_idioms)]
+
+use block_modes::{block_padding::Pkcs7, BlockMode, Cbc};
+use ctr_flavors::Rfc3686Ctr;
+use gost_modes::{GostCtr128, GostCtr64, NewCipher, StreamCipher};
+use magma::cipher::{
+    generic_array::GenericArray, AsyncStreamCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use openpgp_cfb::OpenPgpCfb;
+
+#[cfg(target_os = "none")]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+macro_rules! impl_link_test {
+    ($name:ident, $cipher:ty, $key_size:expr, $block_size:expr) => {
+        #[no_mangle]
+        pub fn $name(key: &[u8; $key_size], block: &mut [u8; $block_size]) {
+            let cipher = <$cipher>::new(GenericArray::from_slice(key));
+            let block = GenericArray::from_mut_slice(block);
+            cipher.encrypt_block(block);
+            cipher.decrypt_block(block);
+        }
+    };
+}
+
+impl_link_test!(aes128, aes::Aes128, 16, 16);
+impl_link_test!(aes192, aes::Aes192, 24, 16);
+impl_link_test!(aes256, aes::Aes256, 32, 16);
+impl_link_test!(blowfish, blowfish::Blowfish, 56, 8);
+impl_link_test!(cast5, cast5::Cast5, 16, 8);
+impl_link_test!(cham64_128, cham::Cham64_128, 16, 8);
+impl_link_test!(cham128_128, cham::Cham128_128, 16, 16);
+impl_link_test!(des, des::Des, 8, 8);
+impl_link_test!(tdes_ede3, des::TdesEde3, 24, 8);
+impl_link_test!(idea, idea::Idea, 16, 8);
+impl_link_test!(katan64, katan::Katan64, 10, 8);
+impl_link_test!(ktantan64, katan::Ktantan64, 10, 8);
+impl_link_test!(kuznyechik, kuznyechik::Kuznyechik, 32, 16);
+impl_link_test!(magma, magma::Magma, 32, 8);
+impl_link_test!(prince, prince::Prince, 16, 8);
+impl_link_test!(rc2, rc2::Rc2, 32, 8);
+impl_link_test!(rc5, rc5::Rc5_32_12, 16, 8);
+impl_link_test!(rijndael256, rijndael::Rijndael256, 32, 32);
+impl_link_test!(serpent, serpent::Serpent, 16, 16);
+impl_link_test!(simeck64_128, simeck::Simeck64_128, 16, 8);
+impl_link_test!(sm4, sm4::Sm4, 16, 16);
+impl_link_test!(threefish256, threefish::Threefish256, 32, 32);
+impl_link_test!(twine128, twine::Twine128, 16, 8);
+impl_link_test!(twofish, twofish::Twofish, 32, 16);
+impl_link_test!(xxtea, xxtea::Xxtea128, 16, 16);
+
+/// Encrypt and decrypt `block` using Deoxys-BC-256 with `tweak`.
+#[no_mangle]
+pub fn deoxys_bc256(key: &[u8; 16], tweak: &[u8; 16], block: &mut [u8; 16]) {
+    let cipher = deoxys_bc::DeoxysBc256::new(GenericArray::from_slice(key));
+    let tweak = GenericArray::from_slice(tweak);
+    let block = GenericArray::from_mut_slice(block);
+    cipher.encrypt_block_with_tweak(block, tweak);
+    cipher.decrypt_block_with_tweak(block, tweak);
+}
+
+/// Encrypt `buf[..pos]` using AES-128-CBC with PKCS#7 padding.
+#[no_mangle]
+pub fn aes128_cbc(key: &[u8; 16], iv: &[u8; 16], buf: &mut [u8], pos: usize) -> usize {
+    let key = GenericArray::from_slice(key);
+    let iv = GenericArray::from_slice(iv);
+    Cbc::<aes::Aes128, Pkcs7>::new_fix(key, iv)
+        .encrypt(buf, pos)
+        .map(|ct| ct.len())
+        .unwrap_or(0)
+}
+
+/// Apply Kuznyechik-CTR keystream to `buf`.
+#[no_mangle]
+pub fn kuznyechik_ctr(key: &[u8; 32], nonce: &[u8; 8], buf: &mut [u8]) {
+    let key = GenericArray::from_slice(key);
+    let nonce = GenericArray::from_slice(nonce);
+    GostCtr128::<kuznyechik::Kuznyechik>::new(key, nonce).apply_keystream(buf);
+}
+
+/// Apply Magma-CTR keystream to `buf`.
+#[no_mangle]
+pub fn magma_ctr(key: &[u8; 32], nonce: &[u8; 4], buf: &mut [u8]) {
+    let key = GenericArray::from_slice(key);
+    let nonce = GenericArray::from_slice(nonce);
+    GostCtr64::<magma::Magma>::new(key, nonce).apply_keystream(buf);
+}
+
+/// Apply AES-128-CTR keystream as defined in RFC 3686 to `buf`.
+#[no_mangle]
+pub fn aes128_rfc3686_ctr(key: &[u8; 16], nonce: &[u8; 4], iv: &[u8; 8], buf: &mut [u8]) {
+    let cipher = aes::Aes128::new(GenericArray::from_slice(key));
+    Rfc3686Ctr::new_rfc3686(cipher, nonce, iv).apply_keystream(buf);
+}
+
+/// Encrypt and decrypt `buf` using AES-128-ECB.
+#[no_mangle]
+pub fn aes128_ecb(key: &[u8; 16], buf: &mut [u8]) -> bool {
+    let ecb = ecb::Ecb::new(aes::Aes128::new(GenericArray::from_slice(key)));
+    ecb.encrypt(buf).and_then(|()| ecb.decrypt(buf)).is_ok()
+}
+
+/// Encrypt and decrypt `buf` using AES-128-CBC-CS3.
+#[no_mangle]
+pub fn aes128_cbc_cts(key: &[u8; 16], iv: &[u8; 16], buf: &mut [u8]) -> bool {
+    type Cts = cts::CbcCts<aes::Aes128, cts::Cs3>;
+    let cipher = aes::Aes128::new(GenericArray::from_slice(key));
+    let iv = GenericArray::from_slice(iv);
+    Cts::new(cipher.clone(), iv)
+        .encrypt(buf)
+        .and_then(|()| Cts::new(cipher, iv).decrypt(buf))
+        .is_ok()
+}
+
+/// Encrypt and decrypt `buf` using AES-128-CMC with `tweak`.
+#[no_mangle]
+pub fn aes128_cmc(key1: &[u8; 16], key2: &[u8; 16], tweak: &[u8; 16], buf: &mut [u8]) -> bool {
+    let cmc = cmc::Cmc::new(
+        aes::Aes128::new(GenericArray::from_slice(key1)),
+        aes::Aes128::new(GenericArray::from_slice(key2)),
+    );
+    let tweak = GenericArray::from_slice(tweak);
+    cmc.encrypt(tweak, buf)
+        .and_then(|()| cmc.decrypt(tweak, buf))
+        .is_ok()
+}
+
+/// Encrypt and decrypt `buf` using EME2-AES-128 with `tweak`.
+#[no_mangle]
+pub fn aes128_eme2(key: &[u8; 48], tweak: &[u8], buf: &mut [u8]) -> bool {
+    let eme2 = eme2::Eme2::new(
+        aes::Aes128::new(GenericArray::from_slice(&key[..16])),
+        GenericArray::from_slice(&key[16..32]),
+        GenericArray::from_slice(&key[32..]),
+    );
+    eme2.encrypt(tweak, buf)
+        .and_then(|()| eme2.decrypt(tweak, buf))
+        .is_ok()
+}
+
+/// Wrap `data` into `out` using AES-128-KWP.
+#[no_mangle]
+pub fn aes128_kwp(kek: &[u8; 16], data: &[u8], out: &mut [u8]) -> bool {
+    let kek = kw::Kek::new(aes::Aes128::new(GenericArray::from_slice(kek)));
+    kek.wrap_with_padding(data, out).is_ok()
+}
+
+/// Encrypt `buf` in place using Kuznyechik-MGM and return the tag.
+#[no_mangle]
+pub fn kuznyechik_mgm(key: &[u8; 32], nonce: &[u8; 16], ad: &[u8], buf: &mut [u8]) -> [u8; 16] {
+    let mgm = mgm::Mgm::new(kuznyechik::Kuznyechik::new(GenericArray::from_slice(key)));
+    let mut tag = [0u8; 16];
+    if let Ok(t) = mgm.encrypt_in_place_detached(GenericArray::from_slice(nonce), ad, buf) {
+        tag.copy_from_slice(&t);
+    }
+    tag
+}
+
+/// Encrypt the `prefix` and `buf` using AES-128 in OpenPGP CFB mode.
+#[no_mangle]
+pub fn aes128_openpgp_cfb(key: &[u8; 16], prefix: &[u8; 16], out: &mut [u8; 18], buf: &mut [u8]) {
+    let cipher = aes::Aes128::new(GenericArray::from_slice(key));
+    let prefix = GenericArray::from_slice(prefix);
+    if let Ok(mut cfb) = OpenPgpCfb::encrypt_prefix(cipher, prefix, true, out) {
+        cfb.encrypt(buf);
+    }
+}
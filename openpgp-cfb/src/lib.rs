@@ -0,0 +1,190 @@
+//! Generic implementation of the [OpenPGP CFB mode][1] (RFC 4880).
+//!
+//! OpenPGP uses CFB with an all-zero IV and encrypts a block of random
+//! data, the prefix, in front of the message. The last two bytes of the
+//! prefix are repeated, which allows a quick check of the session key on
+//! decryption. For the Symmetrically Encrypted Data packet (tag 9) the CFB
+//! state is then resynchronized, i.e. the feedback register is loaded with
+//! the ciphertext bytes `2..BS + 2` and the message starts at a block
+//! boundary. The Symmetrically Encrypted Integrity Protected Data packet
+//! (tag 18) does not resynchronize.
+//!
+//! # Examples
+//! ```
+//! use aes::{Aes128, NewBlockCipher};
+//! use openpgp_cfb::{cipher::AsyncStreamCipher, OpenPgpCfb};
+//!
+//! let key = [0x42; 16];
+//! // must be random
+//! let prefix = [0x24; 16];
+//!
+//! let mut header = [0u8; 18];
+//! let mut buf = *b"my secret message";
+//! let cipher = Aes128::new(&key.into());
+//! let mut cfb = OpenPgpCfb::encrypt_prefix(cipher, &prefix.into(), true, &mut header).unwrap();
+//! cfb.encrypt(&mut buf);
+//!
+//! let mut cfb = OpenPgpCfb::decrypt_prefix(Aes128::new(&key.into()), &header, true).unwrap();
+//! cfb.decrypt(&mut buf);
+//! assert_eq!(&buf, b"my secret message");
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc4880#section-13.9
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    generic_array::{typenum::Unsigned, GenericArray},
+    AsyncStreamCipher, BlockCipher, BlockEncrypt,
+};
+use core::fmt;
+
+/// Block of the underlying cipher.
+pub type Block<C> = GenericArray<u8, <C as BlockCipher>::BlockSize>;
+
+/// OpenPGP CFB errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Encrypted prefix buffer is not two bytes longer than a block.
+    InvalidPrefixLength,
+    /// Repeated prefix bytes do not match, i.e. the session key is most
+    /// likely wrong.
+    QuickCheckFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Error::InvalidPrefixLength => f.write_str("invalid prefix length"),
+            Error::QuickCheckFailed => f.write_str("quick check failed"),
+        }
+    }
+}
+
+/// OpenPGP CFB mode instance.
+///
+/// Created by processing the encrypted prefix with
+/// [`encrypt_prefix`][OpenPgpCfb::encrypt_prefix] or
+/// [`decrypt_prefix`][OpenPgpCfb::decrypt_prefix], the message is then
+/// processed with the [`AsyncStreamCipher`] methods.
+#[derive(Clone)]
+pub struct OpenPgpCfb<C: BlockCipher + BlockEncrypt> {
+    cipher: C,
+    block: Block<C>,
+    pos: u8,
+}
+
+impl<C: BlockCipher + BlockEncrypt> OpenPgpCfb<C> {
+    fn zero_iv(cipher: C) -> Self {
+        let mut block = Block::<C>::default();
+        cipher.encrypt_block(&mut block);
+        Self {
+            cipher,
+            block,
+            pos: 0,
+        }
+    }
+
+    /// Load the feedback register with the ciphertext bytes `2..BS + 2` of
+    /// the encrypted prefix.
+    fn resync(&mut self, encrypted_prefix: &[u8]) {
+        self.block.copy_from_slice(&encrypted_prefix[2..]);
+        self.cipher.encrypt_block(&mut self.block);
+        self.pos = 0;
+    }
+
+    /// Encrypt the random `prefix` followed by its last two bytes into `out`,
+    /// which must be two bytes longer than a block.
+    ///
+    /// With `resync` the state is resynchronized afterwards, as required for
+    /// the Symmetrically Encrypted Data packet.
+    pub fn encrypt_prefix(
+        cipher: C,
+        prefix: &Block<C>,
+        resync: bool,
+        out: &mut [u8],
+    ) -> Result<Self, Error> {
+        let bs = C::BlockSize::USIZE;
+        if out.len() != bs + 2 {
+            return Err(Error::InvalidPrefixLength);
+        }
+        out[..bs].copy_from_slice(prefix);
+        out[bs..].copy_from_slice(&prefix[bs - 2..]);
+        let mut cfb = Self::zero_iv(cipher);
+        cfb.encrypt(out);
+        if resync {
+            cfb.resync(out);
+        }
+        Ok(cfb)
+    }
+
+    /// Decrypt the encrypted prefix `data`, which must be two bytes longer
+    /// than a block, and check the repeated bytes.
+    ///
+    /// `resync` must match the value used for encryption. Note that
+    /// reporting quick check failures to an attacker enables the chosen
+    /// ciphertext attack of Mister and Zuccherato, protocols should treat
+    /// them like any other decryption failure.
+    pub fn decrypt_prefix(cipher: C, data: &[u8], resync: bool) -> Result<Self, Error> {
+        let bs = C::BlockSize::USIZE;
+        if data.len() != bs + 2 {
+            return Err(Error::InvalidPrefixLength);
+        }
+        let mut cfb = Self::zero_iv(cipher);
+        let mut prefix = Block::<C>::clone_from_slice(&data[..bs]);
+        let mut check = [data[bs], data[bs + 1]];
+        cfb.decrypt(&mut prefix);
+        cfb.decrypt(&mut check);
+        if resync {
+            cfb.resync(data);
+        }
+        if prefix[bs - 2..] == check {
+            Ok(cfb)
+        } else {
+            Err(Error::QuickCheckFailed)
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> AsyncStreamCipher for OpenPgpCfb<C> {
+    fn encrypt(&mut self, data: &mut [u8]) {
+        let bs = C::BlockSize::USIZE;
+        for b in data {
+            *b ^= self.block[self.pos as usize];
+            self.block[self.pos as usize] = *b;
+            self.pos += 1;
+            if self.pos as usize == bs {
+                self.cipher.encrypt_block(&mut self.block);
+                self.pos = 0;
+            }
+        }
+    }
+
+    fn decrypt(&mut self, data: &mut [u8]) {
+        let bs = C::BlockSize::USIZE;
+        for b in data {
+            let c = *b;
+            *b ^= self.block[self.pos as usize];
+            self.block[self.pos as usize] = c;
+            self.pos += 1;
+            if self.pos as usize == bs {
+                self.cipher.encrypt_block(&mut self.block);
+                self.pos = 0;
+            }
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> fmt::Debug for OpenPgpCfb<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("OpenPgpCfb { ... }")
+    }
+}
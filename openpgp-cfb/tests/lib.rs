@@ -0,0 +1,65 @@
+//! Ciphertexts cross-checked against an implementation built on a generic
+//! CFB mode following RFC 4880, 13.9.
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use aes::{Aes128, NewBlockCipher};
+use hex_literal::hex;
+use openpgp_cfb::{cipher::AsyncStreamCipher, Error, OpenPgpCfb};
+
+const KEY: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+const PREFIX: [u8; 16] = hex!("a0a1a2a3a4a5a6a7a8a9aaabacadaeaf");
+const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+fn check(resync: bool, header: &[u8], ct: &[u8]) {
+    let cipher = || Aes128::new(&KEY.into());
+
+    let mut buf = [0u8; 18];
+    let mut cfb = OpenPgpCfb::encrypt_prefix(cipher(), &PREFIX.into(), resync, &mut buf).unwrap();
+    assert_eq!(buf, header);
+    for n in 1..MSG.len() {
+        let mut c = cfb.clone();
+        let mut buf = MSG.to_vec();
+        for chunk in buf.chunks_mut(n) {
+            c.encrypt(chunk);
+        }
+        assert_eq!(buf, ct);
+    }
+    let mut buf = MSG.to_vec();
+    cfb.encrypt(&mut buf);
+
+    let mut cfb = OpenPgpCfb::decrypt_prefix(cipher(), header, resync).unwrap();
+    cfb.decrypt(&mut buf);
+    assert_eq!(buf, MSG);
+}
+
+#[test]
+fn resync() {
+    check(
+        true,
+        &hex!("66009994232afd25c7e62bc90d6576d664fb"),
+        &hex!("9dec617f8acc18cecd96f9f7f211e54ab9e98baeb4e18fe8641e656ff95011214c9721e934a710109d1c2d"),
+    );
+}
+
+#[test]
+fn no_resync() {
+    check(
+        false,
+        &hex!("66009994232afd25c7e62bc90d6576d664fb"),
+        &hex!("ba6c3e4b62fcf5e17a780e02f963baeb0a5633d32a2fcffcd7b0e9cafbea4df37e664259035a345270bb95"),
+    );
+}
+
+#[test]
+fn quick_check() {
+    let cipher = Aes128::new(&[0x11; 16].into());
+    let header = hex!("66009994232afd25c7e62bc90d6576d664fb");
+    let res = OpenPgpCfb::decrypt_prefix(cipher.clone(), &header, true);
+    assert_eq!(res.unwrap_err(), Error::QuickCheckFailed);
+    let res = OpenPgpCfb::decrypt_prefix(cipher.clone(), &header[..17], true);
+    assert_eq!(res.unwrap_err(), Error::InvalidPrefixLength);
+    let res = OpenPgpCfb::encrypt_prefix(cipher, &PREFIX.into(), true, &mut [0u8; 16]);
+    assert_eq!(res.unwrap_err(), Error::InvalidPrefixLength);
+}
@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(prince::Prince, 16);
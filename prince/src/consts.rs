@@ -0,0 +1,20 @@
+/// Round constants, derived from the fraction part of π.
+///
+/// `RC[i] ^ RC[11 - i]` is `ALPHA` for all `i`.
+pub(crate) const RC: [u64; 12] = [
+    0x0000_0000_0000_0000,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+    0x4528_21e6_38d0_1377,
+    0xbe54_66cf_34e9_0c6c,
+    0x7ef8_4f78_fd95_5cb1,
+    0x8584_0851_f1ac_43aa,
+    0xc882_d32f_2532_3c54,
+    0x64a5_1195_e0e3_610d,
+    0xd3b5_a399_ca0c_2399,
+    0xc0ac_29b7_c97c_50dd,
+];
+
+/// The α constant of the α-reflection property.
+pub const ALPHA: u64 = 0xc0ac_29b7_c97c_50dd;
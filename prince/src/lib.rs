@@ -0,0 +1,236 @@
+//! An implementation of the [PRINCE][1] low-latency block cipher.
+//!
+//! PRINCE has 64-bit blocks and 128-bit keys and was designed for unrolled
+//! single-cycle hardware implementations, e.g. for memory encryption.
+//!
+//! The key `k0 || k1` is split into two 64-bit halves: `k0` is used for
+//! whitening while `k1` keys the 12-round `PRINCE_core`. The cipher has the
+//! α-reflection property: decryption is encryption with the whitening keys
+//! swapped and `k1` replaced by `k1 ^ α`. This implementation uses the same
+//! core for both directions, as a hardware implementation would.
+//!
+//! The S-box layer is computed with bitsliced boolean operations on all 16
+//! nibbles at once and the linear layer with shifts and masks, so the
+//! implementation is free of table lookups and runs in constant time.
+//!
+//! # Examples
+//! ```
+//! use prince::{
+//!     cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Prince,
+//! };
+//!
+//! let cipher = Prince::new(&GenericArray::from([0u8; 16]));
+//! let mut block = GenericArray::from([0u8; 8]);
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x81, 0x86, 0x65, 0xaa, 0x0d, 0x02, 0xdf, 0xda]);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block[..], [0u8; 8]);
+//!
+//! // the same using the 64-bit word interface
+//! let cipher = Prince::from_keys(0, 0);
+//! assert_eq!(cipher.encrypt_u64(0), 0x818665aa0d02dfda);
+//! ```
+//!
+//! [1]: https://eprint.iacr.org/2012/529
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U16, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::convert::TryInto;
+
+mod consts;
+
+pub use consts::ALPHA;
+use consts::RC;
+
+/// Lowest bit of every nibble.
+const NIBBLE_LSB: u64 = 0x1111_1111_1111_1111;
+/// Nibbles of the first row of the state, i.e. the top nibble of every
+/// 16-bit column.
+const ROW0: u64 = 0xf000_f000_f000_f000;
+/// Columns multiplied with `M̂0` by the `M'` layer.
+const M0_COLUMNS: u64 = 0xffff_0000_0000_ffff;
+
+/// PRINCE block cipher.
+#[derive(Clone)]
+pub struct Prince {
+    k0: u64,
+    k0_prime: u64,
+    k1: u64,
+}
+
+impl Prince {
+    /// Create cipher from the whitening key `k0` and the core key `k1`.
+    pub fn from_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            k0,
+            k0_prime: k0.rotate_right(1) ^ (k0 >> 63),
+            k1,
+        }
+    }
+
+    /// Encrypt a block given as a 64-bit word.
+    pub fn encrypt_u64(&self, block: u64) -> u64 {
+        core(block ^ self.k0, self.k1) ^ self.k0_prime
+    }
+
+    /// Decrypt a block given as a 64-bit word.
+    ///
+    /// Uses the α-reflection property, i.e. runs the encryption core with
+    /// `k1 ^ α` and swapped whitening keys.
+    pub fn decrypt_u64(&self, block: u64) -> u64 {
+        core(block ^ self.k0_prime, self.k1 ^ ALPHA) ^ self.k0
+    }
+}
+
+/// Bitsliced S-box layer, `inverse` selects `S^-1`.
+#[inline(always)]
+fn sub_nibbles(x: u64, inverse: bool) -> u64 {
+    // only the lowest bit of every nibble of the inputs is significant,
+    // the other bits are masked out at the end
+    let (x0, x1, x2, x3) = (x, x >> 1, x >> 2, x >> 3);
+    let (y0, y1, y2, y3) = if inverse {
+        (
+            !((x0 & x1) ^ (x1 & x2) ^ x3 ^ (x0 & x1 & x3) ^ (x2 & x3) ^ (x0 & x2 & x3)),
+            !((x0 & x2) ^ (x1 & x2) ^ (x0 & x1 & x2) ^ (x1 & x3) ^ (x2 & x3)),
+            x0 ^ (x0 & x1)
+                ^ x2
+                ^ (x0 & x2)
+                ^ (x1 & x2)
+                ^ (x0 & x1 & x2)
+                ^ (x1 & x3)
+                ^ (x0 & x1 & x3),
+            !(x0 ^ x1
+                ^ (x0 & x1)
+                ^ (x0 & x2)
+                ^ (x1 & x2)
+                ^ (x0 & x1 & x2)
+                ^ (x2 & x3)
+                ^ (x0 & x2 & x3)
+                ^ (x1 & x2 & x3)),
+        )
+    } else {
+        (
+            !((x0 & x1) ^ x2 ^ (x1 & x2) ^ (x0 & x1 & x2) ^ x3 ^ (x0 & x3) ^ (x2 & x3)),
+            !((x0 & x2) ^ (x1 & x2) ^ (x0 & x1 & x2) ^ (x1 & x3) ^ (x1 & x2 & x3)),
+            x0 ^ (x0 & x1) ^ x3 ^ (x0 & x3) ^ (x1 & x3) ^ (x0 & x1 & x3) ^ (x1 & x2 & x3),
+            !(x1 ^ (x1 & x2) ^ (x0 & x1 & x2) ^ x3 ^ (x0 & x1 & x3) ^ (x2 & x3) ^ (x0 & x2 & x3)),
+        )
+    };
+    (y0 & NIBBLE_LSB) | (y1 & NIBBLE_LSB) << 1 | (y2 & NIBBLE_LSB) << 2 | (y3 & NIBBLE_LSB) << 3
+}
+
+/// Rotate every 16-bit column of `x` left by `n` nibbles.
+#[inline(always)]
+fn rotate_columns(x: u64, n: u32) -> u64 {
+    if n == 0 {
+        return x;
+    }
+    let hi = 0x0001_0001_0001_0001 * ((0xffff << (4 * n)) & 0xffff);
+    ((x << (4 * n)) & hi) | ((x >> (16 - 4 * n)) & !hi)
+}
+
+/// Reverse the order of the nibbles in every 16-bit column of `x`.
+#[inline(always)]
+fn reverse_columns(x: u64) -> u64 {
+    let x = ((x & 0xff00_ff00_ff00_ff00) >> 8) | ((x & 0x00ff_00ff_00ff_00ff) << 8);
+    ((x & 0xf0f0_f0f0_f0f0_f0f0) >> 4) | ((x & 0x0f0f_0f0f_0f0f_0f0f) << 4)
+}
+
+/// The involutory `M'` layer.
+///
+/// Output bit `i` of nibble `r` in a column is the XOR of bit `i` of all
+/// nibbles except one, the excluded nibble being `i - r` (mod 4) for `M̂0`
+/// and `i - r - 1` (mod 4) for `M̂1`. With the nibbles of the column
+/// reversed this becomes a rotation per bit position.
+#[inline(always)]
+fn m_prime(x: u64) -> u64 {
+    let sum = x ^ rotate_columns(x, 1) ^ rotate_columns(x, 2) ^ rotate_columns(x, 3);
+    let rev = reverse_columns(x);
+    let (mut excl0, mut excl1) = (0, 0);
+    for i in 0..4 {
+        // bit `i` counted from the most significant bit of the nibble
+        let mask = 0x8888_8888_8888_8888 >> i;
+        excl0 |= rotate_columns(rev, 3 - i) & mask;
+        excl1 |= rotate_columns(rev, (4 - i) % 4) & mask;
+    }
+    sum ^ (excl0 & M0_COLUMNS) ^ (excl1 & !M0_COLUMNS)
+}
+
+/// Nibble-wise `ShiftRows`, row `r` is rotated left by `r` columns.
+#[inline(always)]
+fn shift_rows(x: u64, inverse: bool) -> u64 {
+    let mut res = x & ROW0;
+    for r in 1..4 {
+        let row = x & (ROW0 >> (4 * r));
+        res |= if inverse {
+            row.rotate_right(16 * r)
+        } else {
+            row.rotate_left(16 * r)
+        };
+    }
+    res
+}
+
+/// The 12-round `PRINCE_core` keyed with `k1`.
+fn core(mut x: u64, k1: u64) -> u64 {
+    x ^= k1 ^ RC[0];
+    for rc in &RC[1..6] {
+        x = sub_nibbles(x, false);
+        x = shift_rows(m_prime(x), false);
+        x ^= rc ^ k1;
+    }
+    x = sub_nibbles(x, false);
+    x = m_prime(x);
+    x = sub_nibbles(x, true);
+    for rc in &RC[6..11] {
+        x ^= rc ^ k1;
+        x = m_prime(shift_rows(x, true));
+        x = sub_nibbles(x, true);
+    }
+    x ^ RC[11] ^ k1
+}
+
+impl NewBlockCipher for Prince {
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        let k0 = u64::from_be_bytes(key[..8].try_into().unwrap());
+        let k1 = u64::from_be_bytes(key[8..].try_into().unwrap());
+        Self::from_keys(k0, k1)
+    }
+}
+
+impl BlockCipher for Prince {
+    type BlockSize = U8;
+    type ParBlocks = U1;
+}
+
+impl BlockEncrypt for Prince {
+    fn encrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+        let x = u64::from_be_bytes((*block).into());
+        block.copy_from_slice(&self.encrypt_u64(x).to_be_bytes());
+    }
+}
+
+impl BlockDecrypt for Prince {
+    fn decrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+        let x = u64::from_be_bytes((*block).into());
+        block.copy_from_slice(&self.decrypt_u64(x).to_be_bytes());
+    }
+}
+
+opaque_debug::implement!(Prince);
@@ -36,11 +36,11 @@ impl Rc2 {
     }
 
     fn expand_key(key: &[u8], t1: usize) -> [u16; 64] {
-        let key_len = key.len() as usize;
+        let key_len = key.len();
 
         let t8: usize = (t1 + 7) >> 3;
 
-        let tm: usize = (255 % ((2 as u32).pow((8 + t1 - 8 * t8) as u32))) as usize;
+        let tm: usize = (255 % (2u32.pow((8 + t1 - 8 * t8) as u32))) as usize;
 
         let mut key_buffer: [u8; 128] = [0; 128];
         key_buffer[..key_len].copy_from_slice(&key[..key_len]);
@@ -71,28 +71,28 @@ impl Rc2 {
             .wrapping_add(r[3] & r[2])
             .wrapping_add(!r[3] & r[1]);
         *j += 1;
-        r[0] = (r[0] << 1) | (r[0] >> 15);
+        r[0] = r[0].rotate_left(1);
 
         r[1] = r[1]
             .wrapping_add(self.exp_key[*j])
             .wrapping_add(r[0] & r[3])
             .wrapping_add(!r[0] & r[2]);
         *j += 1;
-        r[1] = (r[1] << 2) | (r[1] >> 14);
+        r[1] = r[1].rotate_left(2);
 
         r[2] = r[2]
             .wrapping_add(self.exp_key[*j])
             .wrapping_add(r[1] & r[0])
             .wrapping_add(!r[1] & r[3]);
         *j += 1;
-        r[2] = (r[2] << 3) | (r[2] >> 13);
+        r[2] = r[2].rotate_left(3);
 
         r[3] = r[3]
             .wrapping_add(self.exp_key[*j])
             .wrapping_add(r[2] & r[1])
             .wrapping_add(!r[2] & r[0]);
         *j += 1;
-        r[3] = (r[3] << 5) | (r[3] >> 11);
+        r[3] = r[3].rotate_left(5);
     }
 
     fn mash(&self, r: &mut [u16; 4]) {
@@ -103,28 +103,28 @@ impl Rc2 {
     }
 
     fn reverse_mix(&self, r: &mut [u16; 4], j: &mut usize) {
-        r[3] = (r[3] << 11) | (r[3] >> 5);
+        r[3] = r[3].rotate_right(5);
         r[3] = r[3]
             .wrapping_sub(self.exp_key[*j])
             .wrapping_sub(r[2] & r[1])
             .wrapping_sub(!r[2] & r[0]);
         *j -= 1;
 
-        r[2] = (r[2] << 13) | (r[2] >> 3);
+        r[2] = r[2].rotate_right(3);
         r[2] = r[2]
             .wrapping_sub(self.exp_key[*j])
             .wrapping_sub(r[1] & r[0])
             .wrapping_sub(!r[1] & r[3]);
         *j -= 1;
 
-        r[1] = (r[1] << 14) | (r[1] >> 2);
+        r[1] = r[1].rotate_right(2);
         r[1] = r[1]
             .wrapping_sub(self.exp_key[*j])
             .wrapping_sub(r[0] & r[3])
             .wrapping_sub(!r[0] & r[2]);
         *j -= 1;
 
-        r[0] = (r[0] << 15) | (r[0] >> 1);
+        r[0] = r[0].rotate_right(1);
         r[0] = r[0]
             .wrapping_sub(self.exp_key[*j])
             .wrapping_sub(r[3] & r[2])
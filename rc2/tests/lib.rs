@@ -1,3 +1,8 @@
+#![allow(clippy::needless_borrow)]
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use cipher::generic_array::GenericArray;
 use cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
 
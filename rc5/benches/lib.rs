@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(rc5::Rc5_32_12, 16);
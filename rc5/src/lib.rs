@@ -0,0 +1,230 @@
+//! An implementation of the [RC5][1] block cipher.
+//!
+//! RC5 is parameterized by the word size `w`, number of rounds `r` and key
+//! length `b`, usually written as RC5-w/r/b. Word size and number of rounds
+//! are selected with the type parameters of [`Rc5`], while the key length is
+//! determined by the slice passed to [`NewBlockCipher::new_from_slice`],
+//! which accepts keys of 0 to 255 bytes.
+//!
+//! # Examples
+//! ```
+//! use rc5::{
+//!     cipher::{consts::U16, generic_array::GenericArray, BlockEncrypt, NewBlockCipher},
+//!     Rc5, Rc5_32_12,
+//! };
+//!
+//! // RC5-32/12/16 as recommended by the original paper
+//! let cipher = Rc5_32_12::new_from_slice(&[0; 16]).unwrap();
+//! let mut block = GenericArray::default();
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x21, 0xa5, 0xdb, 0xee, 0x15, 0x4b, 0x8f, 0x6d]);
+//!
+//! // non-default parameterization: RC5-64/16/b
+//! type Rc5Custom = Rc5<u64, U16>;
+//! let cipher = Rc5Custom::new_from_slice(b"secret key").unwrap();
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/RC5
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U12, U16, U2, U20, U24},
+    errors::InvalidLength,
+    generic_array::{
+        typenum::{Prod, Sum, Unsigned},
+        ArrayLength, GenericArray,
+    },
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::{cmp, fmt, marker::PhantomData, ops::Add, ops::Mul};
+
+mod word;
+
+pub use word::Word;
+
+type KeyTableSize<R> = Sum<Prod<R, U2>, U2>;
+type Block<W> = GenericArray<u8, <W as Word>::BlockSize>;
+
+/// Maximum key length in bytes.
+const MAX_KEY_LEN: usize = 255;
+
+/// RC5 block cipher with word type `W` and `R` rounds.
+///
+/// Supported word types are `u16`, `u32` and `u64`, resulting in block
+/// sizes of 32, 64 and 128 bits respectively.
+pub struct Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    key_table: GenericArray<W, KeyTableSize<R>>,
+    _pd: PhantomData<R>,
+}
+
+/// RC5-16/16 with 32-bit blocks.
+#[allow(non_camel_case_types)]
+pub type Rc5_16_16 = Rc5<u16, U16>;
+/// RC5-32/12, the parameters recommended by the original paper.
+#[allow(non_camel_case_types)]
+pub type Rc5_32_12 = Rc5<u32, U12>;
+/// RC5-32/20 with 64-bit blocks.
+#[allow(non_camel_case_types)]
+pub type Rc5_32_20 = Rc5<u32, U20>;
+/// RC5-64/24 with 128-bit blocks.
+#[allow(non_camel_case_types)]
+pub type Rc5_64_24 = Rc5<u64, U24>;
+
+impl<W, R> Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    fn expand_key(key: &[u8]) -> GenericArray<W, KeyTableSize<R>> {
+        let u = W::Bytes::USIZE;
+        let c = cmp::max(1, (key.len() + u - 1) / u);
+
+        // key words in little-endian order, the last one zero padded
+        let mut l = [W::ZERO; MAX_KEY_LEN];
+        for (w, chunk) in l.iter_mut().zip(key.chunks(u)) {
+            *w = W::from_le_slice(chunk);
+        }
+        let l = &mut l[..c];
+
+        let mut s: GenericArray<W, KeyTableSize<R>> = Default::default();
+        s[0] = W::P;
+        for i in 1..s.len() {
+            s[i] = s[i - 1].wrapping_add(W::Q);
+        }
+
+        let t = s.len();
+        let (mut a, mut b) = (W::ZERO, W::ZERO);
+        let (mut i, mut j) = (0, 0);
+        for _ in 0..3 * cmp::max(t, c) {
+            a = s[i].wrapping_add(a).wrapping_add(b).rotl(W::THREE);
+            s[i] = a;
+            b = l[j].wrapping_add(a).wrapping_add(b).rotl(a.wrapping_add(b));
+            l[j] = b;
+            i = (i + 1) % t;
+            j = (j + 1) % c;
+        }
+
+        s
+    }
+}
+
+impl<W, R> NewBlockCipher for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        Self::new_from_slice(key).unwrap()
+    }
+
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        if key.len() > MAX_KEY_LEN {
+            return Err(InvalidLength);
+        }
+        Ok(Self {
+            key_table: Self::expand_key(key),
+            _pd: PhantomData,
+        })
+    }
+}
+
+impl<W, R> BlockCipher for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    type BlockSize = W::BlockSize;
+    type ParBlocks = U1;
+}
+
+impl<W, R> BlockEncrypt for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    fn encrypt_block(&self, block: &mut Block<W>) {
+        let s = &self.key_table;
+        let (l, r) = block.split_at_mut(W::Bytes::USIZE);
+        let mut a = W::from_le_slice(l).wrapping_add(s[0]);
+        let mut b = W::from_le_slice(r).wrapping_add(s[1]);
+        for k in s[2..].chunks_exact(2) {
+            a = (a ^ b).rotl(b).wrapping_add(k[0]);
+            b = (b ^ a).rotl(a).wrapping_add(k[1]);
+        }
+        a.write_le_slice(l);
+        b.write_le_slice(r);
+    }
+}
+
+impl<W, R> BlockDecrypt for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    fn decrypt_block(&self, block: &mut Block<W>) {
+        let s = &self.key_table;
+        let (l, r) = block.split_at_mut(W::Bytes::USIZE);
+        let mut a = W::from_le_slice(l);
+        let mut b = W::from_le_slice(r);
+        for k in s[2..].chunks_exact(2).rev() {
+            b = b.wrapping_sub(k[1]).rotr(a) ^ a;
+            a = a.wrapping_sub(k[0]).rotr(b) ^ b;
+        }
+        a.wrapping_sub(s[0]).write_le_slice(l);
+        b.wrapping_sub(s[1]).write_le_slice(r);
+    }
+}
+
+impl<W, R> Clone for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key_table: self.key_table.clone(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<W, R> fmt::Debug for Rc5<W, R>
+where
+    W: Word,
+    R: Unsigned + Mul<U2>,
+    Prod<R, U2>: Add<U2>,
+    KeyTableSize<R>: ArrayLength<W>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "Rc5<u{}, {}> {{ ... }}", 8 * W::Bytes::USIZE, R::USIZE)
+    }
+}
@@ -0,0 +1,97 @@
+use cipher::{
+    consts::{U16, U2, U4, U8},
+    generic_array::ArrayLength,
+};
+use core::ops::BitXor;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Machine word used by [`Rc5`][crate::Rc5].
+///
+/// This trait is sealed and implemented for `u16`, `u32` and `u64`.
+pub trait Word: Copy + Default + BitXor<Output = Self> + private::Sealed {
+    /// Word size in bytes.
+    type Bytes: ArrayLength<u8>;
+    /// Block size in bytes, i.e. two words.
+    type BlockSize: ArrayLength<u8>;
+
+    #[doc(hidden)]
+    const ZERO: Self;
+    #[doc(hidden)]
+    const THREE: Self;
+    /// Magic constant `P` used by the key schedule.
+    const P: Self;
+    /// Magic constant `Q` used by the key schedule.
+    const Q: Self;
+
+    #[doc(hidden)]
+    fn wrapping_add(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Rotate left by `n` modulo the word size.
+    #[doc(hidden)]
+    fn rotl(self, n: Self) -> Self;
+    /// Rotate right by `n` modulo the word size.
+    #[doc(hidden)]
+    fn rotr(self, n: Self) -> Self;
+    /// Read word from up to [`Word::Bytes`] little-endian bytes, missing
+    /// high bytes are set to zero.
+    #[doc(hidden)]
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    #[doc(hidden)]
+    fn write_le_slice(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_word {
+    ($t:ty, $bytes:ty, $block:ty, $p:expr, $q:expr) => {
+        impl private::Sealed for $t {}
+
+        impl Word for $t {
+            type Bytes = $bytes;
+            type BlockSize = $block;
+
+            const ZERO: Self = 0;
+            const THREE: Self = 3;
+            const P: Self = $p;
+            const Q: Self = $q;
+
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            #[inline(always)]
+            fn rotl(self, n: Self) -> Self {
+                self.rotate_left((n % (8 * core::mem::size_of::<$t>()) as $t) as u32)
+            }
+
+            #[inline(always)]
+            fn rotr(self, n: Self) -> Self {
+                self.rotate_right((n % (8 * core::mem::size_of::<$t>()) as $t) as u32)
+            }
+
+            #[inline(always)]
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut buf = [0u8; core::mem::size_of::<$t>()];
+                buf[..bytes.len()].copy_from_slice(bytes);
+                <$t>::from_le_bytes(buf)
+            }
+
+            #[inline(always)]
+            fn write_le_slice(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_word!(u16, U2, U4, 0xb7e1, 0x9e37);
+impl_word!(u32, U4, U8, 0xb7e1_5163, 0x9e37_79b9);
+impl_word!(u64, U8, U16, 0xb7e1_5162_8aed_2a6b, 0x9e37_79b9_7f4a_7c15);
@@ -0,0 +1,76 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use rc5::{Rc5_16_16, Rc5_32_12, Rc5_32_20, Rc5_64_24};
+
+fn check<C: NewBlockCipher + BlockEncrypt + BlockDecrypt>(key: &str, pt: &str, ct: &str) {
+    let key = hex(key);
+    let pt = hex(pt);
+    let ct = hex(ct);
+    let cipher = C::new_from_slice(&key).unwrap();
+
+    let mut buf = GenericArray::clone_from_slice(&pt);
+    cipher.encrypt_block(&mut buf);
+    assert_eq!(&buf[..], &ct[..]);
+    cipher.decrypt_block(&mut buf);
+    assert_eq!(&buf[..], &pt[..]);
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Test vectors from "The RC5 Encryption Algorithm" by R. Rivest
+#[test]
+fn rc5_32_12_16() {
+    let vectors = [
+        (
+            "00000000000000000000000000000000",
+            "0000000000000000",
+            "21a5dbee154b8f6d",
+        ),
+        (
+            "915f4619be41b2516355a50110a9ce91",
+            "21a5dbee154b8f6d",
+            "f7c013ac5b2b8952",
+        ),
+        (
+            "783348e75aeb0f2fd7b169bb8dc16787",
+            "f7c013ac5b2b8952",
+            "2f42b3b70369fc92",
+        ),
+        (
+            "dc49db1375a5584f6485b413b5f12baf",
+            "2f42b3b70369fc92",
+            "65c178b284d197cc",
+        ),
+        (
+            "5269f149d41ba0152497574d7f153125",
+            "65c178b284d197cc",
+            "eb44e415da319824",
+        ),
+    ];
+    for &(key, pt, ct) in vectors.iter() {
+        check::<Rc5_32_12>(key, pt, ct);
+    }
+}
+
+/// Test vectors from draft-krovetz-rc6-rc5-vectors-00
+#[test]
+fn rc5_other_parameters() {
+    check::<Rc5_16_16>("0001020304050607", "00010203", "23a8d72e");
+    check::<Rc5_32_20>(
+        "000102030405060708090a0b0c0d0e0f",
+        "0001020304050607",
+        "2a0edc0e9431ff73",
+    );
+    check::<Rc5_64_24>(
+        "000102030405060708090a0b0c0d0e0f1011121314151617",
+        "000102030405060708090a0b0c0d0e0f",
+        "a46772820edbce0235abea32ae7178da",
+    );
+}
@@ -0,0 +1,271 @@
+//! An implementation of the [Rijndael][1] block cipher with all block and
+//! key sizes of the original proposal.
+//!
+//! Block size `B` and key size `K` are given in bytes as type parameters of
+//! [`Rijndael`] and can be any of 16, 20, 24, 28 or 32. AES is the subset
+//! with 16-byte blocks; use the `aes` crate for it, which provides hardware
+//! accelerated and constant-time implementations.
+//!
+//! This implementation uses table lookups for the S-box and is therefore
+//! not constant-time. It is intended for interoperability with legacy
+//! systems only.
+//!
+//! # Examples
+//! ```
+//! use rijndael::{
+//!     cipher::{consts::U32, generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Rijndael256,
+//! };
+//!
+//! // Rijndael-256/256
+//! let cipher = Rijndael256::new(&GenericArray::from([0x42; 32]));
+//! let mut block = GenericArray::from([0u8; 32]);
+//! cipher.encrypt_block(&mut block);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block, GenericArray::from([0u8; 32]));
+//! ```
+//!
+//! [1]: https://csrc.nist.gov/csrc/media/projects/cryptographic-standards-and-guidelines/documents/aes-development/rijndael-ammended.pdf
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U16, U20, U24, U28, U32},
+    generic_array::{ArrayLength, GenericArray},
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::marker::PhantomData;
+
+mod consts;
+
+use consts::{INV_SBOX, SBOX};
+
+/// Maximum number of round key words, `Nb * (Nr + 1)` for `Nb = Nk = 8`.
+const MAX_WORDS: usize = 8 * 15;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Valid Rijndael block and key size in bytes.
+///
+/// This trait is sealed and implemented for `U16`, `U20`, `U24`, `U28` and
+/// `U32`.
+pub trait Size: ArrayLength<u8> + private::Sealed {}
+
+macro_rules! impl_size {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Size for $t {}
+        )*
+    };
+}
+
+impl_size!(U16, U20, U24, U28, U32);
+
+/// Rijndael block cipher with `B`-byte blocks and `K`-byte keys.
+pub struct Rijndael<B: Size, K: Size> {
+    round_keys: [u32; MAX_WORDS],
+    _pd: PhantomData<(B, K)>,
+}
+
+/// Rijndael with 160-bit blocks.
+pub type Rijndael160<K = U20> = Rijndael<U20, K>;
+/// Rijndael with 192-bit blocks.
+pub type Rijndael192<K = U24> = Rijndael<U24, K>;
+/// Rijndael with 224-bit blocks.
+pub type Rijndael224<K = U28> = Rijndael<U28, K>;
+/// Rijndael with 256-bit blocks.
+pub type Rijndael256<K = U32> = Rijndael<U32, K>;
+
+#[inline(always)]
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ (((x >> 7) & 1) * 0x1b)
+}
+
+#[inline(always)]
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut res = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            res ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    res
+}
+
+#[inline(always)]
+fn sub_word(w: u32) -> u32 {
+    let b = w.to_be_bytes();
+    u32::from_be_bytes([
+        SBOX[b[0] as usize],
+        SBOX[b[1] as usize],
+        SBOX[b[2] as usize],
+        SBOX[b[3] as usize],
+    ])
+}
+
+impl<B: Size, K: Size> Rijndael<B, K> {
+    const NB: usize = B::USIZE / 4;
+    const NK: usize = K::USIZE / 4;
+
+    /// Number of rounds, `max(Nb, Nk) + 6`.
+    fn rounds() -> usize {
+        core::cmp::max(Self::NB, Self::NK) + 6
+    }
+
+    /// Row shift offsets for rows 1 to 3.
+    fn shifts() -> [usize; 3] {
+        match Self::NB {
+            7 => [1, 2, 4],
+            8 => [1, 3, 4],
+            _ => [1, 2, 3],
+        }
+    }
+
+    fn add_round_key(&self, state: &mut [u8], round: usize) {
+        let keys = &self.round_keys[round * Self::NB..][..Self::NB];
+        for (col, k) in state.chunks_exact_mut(4).zip(keys) {
+            for (s, k) in col.iter_mut().zip(k.to_be_bytes().iter()) {
+                *s ^= k;
+            }
+        }
+    }
+
+    fn sub_bytes(state: &mut [u8], sbox: &[u8; 256]) {
+        for b in state.iter_mut() {
+            *b = sbox[*b as usize];
+        }
+    }
+
+    fn shift_rows(state: &mut [u8], inverse: bool) {
+        let nb = Self::NB;
+        let mut tmp = [0u8; 32];
+        tmp[..state.len()].copy_from_slice(state);
+        for (r, &shift) in (1..4).zip(Self::shifts().iter()) {
+            for c in 0..nb {
+                let src = if inverse {
+                    (c + nb - shift) % nb
+                } else {
+                    (c + shift) % nb
+                };
+                state[r + 4 * c] = tmp[r + 4 * src];
+            }
+        }
+    }
+
+    fn mix_columns(state: &mut [u8]) {
+        for col in state.chunks_exact_mut(4) {
+            let a = [col[0], col[1], col[2], col[3]];
+            let t = a[0] ^ a[1] ^ a[2] ^ a[3];
+            for i in 0..4 {
+                col[i] ^= t ^ xtime(a[i] ^ a[(i + 1) % 4]);
+            }
+        }
+    }
+
+    fn inv_mix_columns(state: &mut [u8]) {
+        for col in state.chunks_exact_mut(4) {
+            let a = [col[0], col[1], col[2], col[3]];
+            for i in 0..4 {
+                col[i] = mul(a[i], 14)
+                    ^ mul(a[(i + 1) % 4], 11)
+                    ^ mul(a[(i + 2) % 4], 13)
+                    ^ mul(a[(i + 3) % 4], 9);
+            }
+        }
+    }
+}
+
+impl<B: Size, K: Size> NewBlockCipher for Rijndael<B, K> {
+    type KeySize = K;
+
+    fn new(key: &GenericArray<u8, K>) -> Self {
+        let nk = Self::NK;
+        let total = Self::NB * (Self::rounds() + 1);
+        let mut w = [0u32; MAX_WORDS];
+        for (w, chunk) in w.iter_mut().zip(key.chunks_exact(4)) {
+            *w = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        let mut rcon = 1u8;
+        for i in nk..total {
+            let mut temp = w[i - 1];
+            if i % nk == 0 {
+                temp = sub_word(temp.rotate_left(8)) ^ (u32::from(rcon) << 24);
+                rcon = xtime(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                temp = sub_word(temp);
+            }
+            w[i] = w[i - nk] ^ temp;
+        }
+
+        Self {
+            round_keys: w,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<B: Size, K: Size> BlockCipher for Rijndael<B, K> {
+    type BlockSize = B;
+    type ParBlocks = U1;
+}
+
+impl<B: Size, K: Size> BlockEncrypt for Rijndael<B, K> {
+    fn encrypt_block(&self, block: &mut GenericArray<u8, B>) {
+        let state = &mut block[..];
+        self.add_round_key(state, 0);
+        for round in 1..Self::rounds() {
+            Self::sub_bytes(state, &SBOX);
+            Self::shift_rows(state, false);
+            Self::mix_columns(state);
+            self.add_round_key(state, round);
+        }
+        Self::sub_bytes(state, &SBOX);
+        Self::shift_rows(state, false);
+        self.add_round_key(state, Self::rounds());
+    }
+}
+
+impl<B: Size, K: Size> BlockDecrypt for Rijndael<B, K> {
+    fn decrypt_block(&self, block: &mut GenericArray<u8, B>) {
+        let state = &mut block[..];
+        self.add_round_key(state, Self::rounds());
+        for round in (1..Self::rounds()).rev() {
+            Self::shift_rows(state, true);
+            Self::sub_bytes(state, &INV_SBOX);
+            self.add_round_key(state, round);
+            Self::inv_mix_columns(state);
+        }
+        Self::shift_rows(state, true);
+        Self::sub_bytes(state, &INV_SBOX);
+        self.add_round_key(state, 0);
+    }
+}
+
+impl<B: Size, K: Size> Clone for Rijndael<B, K> {
+    fn clone(&self) -> Self {
+        Self {
+            round_keys: self.round_keys,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<B: Size, K: Size> core::fmt::Debug for Rijndael<B, K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "Rijndael<U{}, U{}> {{ ... }}", B::USIZE, K::USIZE)
+    }
+}
@@ -0,0 +1,290 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{
+    consts::{U16, U20, U24, U28, U32},
+    generic_array::{typenum::Unsigned, GenericArray},
+    BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use hex_literal::hex;
+use rijndael::Rijndael;
+
+// Test vectors from FIPS 197, appendices B and C
+
+#[test]
+fn fips197_b() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex!("3243f6a8885a308d313198a2e0370734");
+    let ciphertext = hex!("3925841d02dc09fbdc118597196a0b32");
+    let cipher = Rijndael::<U16, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn fips197_c1() {
+    let key = hex!("000102030405060708090a0b0c0d0e0f");
+    let plaintext = hex!("00112233445566778899aabbccddeeff");
+    let ciphertext = hex!("69c4e0d86a7b0430d8cdb78070b4c55a");
+    let cipher = Rijndael::<U16, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn fips197_c2() {
+    let key = hex!("000102030405060708090a0b0c0d0e0f1011121314151617");
+    let plaintext = hex!("00112233445566778899aabbccddeeff");
+    let ciphertext = hex!("dda97ca4864cdfe06eaf70a0ec0d7191");
+    let cipher = Rijndael::<U16, U24>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn fips197_c3() {
+    let key = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    let plaintext = hex!("00112233445566778899aabbccddeeff");
+    let ciphertext = hex!("8ea2b7ca516745bfeafc49904b496089");
+    let cipher = Rijndael::<U16, U32>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+fn roundtrip<C: NewBlockCipher + BlockEncrypt + BlockDecrypt>() {
+    let key: Vec<u8> = (0..32).collect();
+    let cipher = C::new_from_slice(&key[..C::KeySize::USIZE]).unwrap();
+    let mut block = GenericArray::default();
+    for (i, b) in block.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let pt = block.clone();
+    cipher.encrypt_block(&mut block);
+    assert_ne!(block, pt);
+    cipher.decrypt_block(&mut block);
+    assert_eq!(block, pt);
+}
+
+macro_rules! roundtrip_all {
+    ($($b:ty),*) => {
+        $(
+            roundtrip::<Rijndael<$b, U16>>();
+            roundtrip::<Rijndael<$b, U20>>();
+            roundtrip::<Rijndael<$b, U24>>();
+            roundtrip::<Rijndael<$b, U28>>();
+            roundtrip::<Rijndael<$b, U32>>();
+        )*
+    };
+}
+
+#[test]
+fn all_sizes_roundtrip() {
+    roundtrip_all!(U16, U20, U24, U28, U32);
+}
+
+// The FIPS 197 appendix B key and plaintext extended to larger sizes, with
+// ciphertexts cross-checked against the `simple-rijndael` crate, version
+// 0.3.2, which supports blocks and keys of 16, 24 and 32 bytes
+
+#[test]
+fn extended_b128_k192() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da5");
+    let plaintext = hex!("3243f6a8885a308d313198a2e0370734");
+    let ciphertext = hex!("f9fb29aefc384a250340d833b87ebc00");
+    let cipher = Rijndael::<U16, U24>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b128_k256() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfe");
+    let plaintext = hex!("3243f6a8885a308d313198a2e0370734");
+    let ciphertext = hex!("1a6e6c2c662e7da6501ffb62bc9e93f3");
+    let cipher = Rijndael::<U16, U32>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b192_k128() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a");
+    let ciphertext = hex!("794bc9ca83d6d2c87c068ec8f6647c126ea133efeea7abb1");
+    let cipher = Rijndael::<U24, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b192_k192() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da5");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a");
+    let ciphertext = hex!("e496a882c155a999e4c7eb775349205020f00853cd96a968");
+    let cipher = Rijndael::<U24, U24>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b192_k256() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfe");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a");
+    let ciphertext = hex!("e737693a4caa46f5cee6263390a882b646efc1ca07302da6");
+    let cipher = Rijndael::<U24, U32>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b256_k128() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a1c4a9d2c54a9d6a3");
+    let ciphertext = hex!("b61c990a529cbbce2604de3ea06ba0c9d6e60f0b85defa4c197ff28a17da6d0d");
+    let cipher = Rijndael::<U32, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b256_k192() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da5");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a1c4a9d2c54a9d6a3");
+    let ciphertext = hex!("452c1d4c91b3d2d61256c110876c35ed17b7abc317327df5d56cda05d3de53c4");
+    let cipher = Rijndael::<U32, U24>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn extended_b256_k256() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfe");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a1c4a9d2c54a9d6a3");
+    let ciphertext = hex!("39533985ba9e45c95a47bd5e2db95c865376b9ecf72fb00dcc03da415bb9a7f3");
+    let cipher = Rijndael::<U32, U32>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+// Sizes of 20 and 28 bytes are not supported by any implementation at hand,
+// so these only pin the output of this crate to catch regressions
+
+#[test]
+fn regression_b128_k160() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160");
+    let plaintext = hex!("3243f6a8885a308d313198a2e0370734");
+    let ciphertext = hex!("231d844639b31b412211cfe93712b880");
+    let cipher = Rijndael::<U16, U20>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn regression_b128_k224() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d90");
+    let plaintext = hex!("3243f6a8885a308d313198a2e0370734");
+    let ciphertext = hex!("8faa8fe4dee9eb17caa4797502fc9d3f");
+    let cipher = Rijndael::<U16, U28>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn regression_b160_k128() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2");
+    let ciphertext = hex!("e39c817ff239ca4be2c2fcf0aaa6dd9618dc8182");
+    let cipher = Rijndael::<U20, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn regression_b224_k128() {
+    let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex!("3243f6a8885a308d313198a2e03707344a9382e2a52e0c5a1c4a9d2c");
+    let ciphertext = hex!("ebb4b74687cf5cf76e9d99cefd4c568730fdc257dadd5f6289f4670d");
+    let cipher = Rijndael::<U28, U16>::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
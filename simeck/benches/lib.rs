@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(simeck::Simeck64_128, 16);
@@ -0,0 +1,185 @@
+//! An implementation of the [Simeck][1] family of lightweight block ciphers.
+//!
+//! Simeck combines the round function of SIMON with the key schedule design
+//! of SPECK. The following members of the family are provided:
+//!
+//! | Type             | Block size | Key size | Rounds |
+//! |------------------|------------|----------|--------|
+//! | [`Simeck32_64`]  | 32 bits    | 64 bits  | 32     |
+//! | [`Simeck48_96`]  | 48 bits    | 96 bits  | 36     |
+//! | [`Simeck64_128`] | 64 bits    | 128 bits | 44     |
+//!
+//! Blocks and keys are encoded as big-endian words in the order used by the
+//! test vectors of the paper, i.e. a block is `l || r` and a key is
+//! `t2 || t1 || t0 || k0`.
+//!
+//! # Examples
+//! ```
+//! use simeck::{
+//!     cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Simeck32_64,
+//! };
+//!
+//! let key = [0x19, 0x18, 0x11, 0x10, 0x09, 0x08, 0x01, 0x00];
+//! let cipher = Simeck32_64::new(&GenericArray::from(key));
+//! let mut block = GenericArray::from([0x65, 0x65, 0x68, 0x77]);
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x77, 0x0d, 0x2c, 0x76]);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block[..], [0x65, 0x65, 0x68, 0x77]);
+//! ```
+//!
+//! [1]: https://eprint.iacr.org/2015/612
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U12, U16, U4, U6, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+/// Bits of the m-sequence generated by `x^5 + x^2 + 1`, least significant
+/// bit first.
+const Z0: u64 = 0xf_9a42_bb1f;
+/// Bits of the m-sequence generated by `x^6 + x + 1`, least significant bit
+/// first.
+const Z1: u64 = 0x938_bca3_083f;
+
+/// Simeck round function on `n`-bit words, the key is added by the caller.
+#[inline(always)]
+fn f(x: u32, n: u32, mask: u32) -> u32 {
+    (x & rotl(x, 5, n, mask)) ^ rotl(x, 1, n, mask)
+}
+
+#[inline(always)]
+fn rotl(x: u32, r: u32, n: u32, mask: u32) -> u32 {
+    ((x << r) | (x >> (n - r))) & mask
+}
+
+/// Read big-endian word from `bytes`.
+#[inline(always)]
+fn read_word(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0, |acc, &b| (acc << 8) | u32::from(b))
+}
+
+/// Write big-endian word into `bytes`.
+#[inline(always)]
+fn write_word(bytes: &mut [u8], mut word: u32) {
+    for b in bytes.iter_mut().rev() {
+        *b = word as u8;
+        word >>= 8;
+    }
+}
+
+/// Expand `key` into `round_keys` for a word size of `n` bits using the
+/// round constant sequence `z`.
+fn expand_key(key: &[u8], round_keys: &mut [u32], n: u32, mask: u32, z: u64) {
+    let c = mask ^ 3;
+    let wb = n as usize / 8;
+    // the key is written as `t2 || t1 || t0 || k0`
+    let mut t = [
+        read_word(&key[2 * wb..3 * wb]),
+        read_word(&key[wb..2 * wb]),
+        read_word(&key[..wb]),
+    ];
+    let mut k = read_word(&key[3 * wb..]);
+    for (i, rk) in round_keys.iter_mut().enumerate() {
+        *rk = k;
+        let next = k ^ f(t[0], n, mask) ^ c ^ ((z >> i) & 1) as u32;
+        k = t[0];
+        t = [t[1], t[2], next];
+    }
+}
+
+macro_rules! define_simeck {
+    (
+        $name:ident, $doc:expr, $n:expr, $rounds:expr, $z:expr,
+        $block_size:ty, $key_size:ty
+    ) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone)]
+        pub struct $name {
+            round_keys: [u32; $rounds],
+        }
+
+        impl $name {
+            const MASK: u32 = ((1u64 << $n) - 1) as u32;
+            const WORD_BYTES: usize = $n / 8;
+        }
+
+        impl NewBlockCipher for $name {
+            type KeySize = $key_size;
+
+            fn new(key: &GenericArray<u8, $key_size>) -> Self {
+                let mut round_keys = [0; $rounds];
+                expand_key(key, &mut round_keys, $n, Self::MASK, $z);
+                Self { round_keys }
+            }
+        }
+
+        impl BlockCipher for $name {
+            type BlockSize = $block_size;
+            type ParBlocks = U1;
+        }
+
+        impl BlockEncrypt for $name {
+            fn encrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let (lb, rb) = block.split_at_mut(Self::WORD_BYTES);
+                let (mut l, mut r) = (read_word(lb), read_word(rb));
+                for &k in self.round_keys.iter() {
+                    let t = r ^ f(l, $n, Self::MASK) ^ k;
+                    r = l;
+                    l = t;
+                }
+                write_word(lb, l);
+                write_word(rb, r);
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            fn decrypt_block(&self, block: &mut GenericArray<u8, $block_size>) {
+                let (lb, rb) = block.split_at_mut(Self::WORD_BYTES);
+                let (mut l, mut r) = (read_word(lb), read_word(rb));
+                for &k in self.round_keys.iter().rev() {
+                    let t = l ^ f(r, $n, Self::MASK) ^ k;
+                    l = r;
+                    r = t;
+                }
+                write_word(lb, l);
+                write_word(rb, r);
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+define_simeck!(Simeck32_64, "Simeck32/64 block cipher.", 16, 32, Z0, U4, U8);
+define_simeck!(
+    Simeck48_96,
+    "Simeck48/96 block cipher.",
+    24,
+    36,
+    Z0,
+    U6,
+    U12
+);
+define_simeck!(
+    Simeck64_128,
+    "Simeck64/128 block cipher.",
+    32,
+    44,
+    Z1,
+    U8,
+    U16
+);
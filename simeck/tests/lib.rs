@@ -0,0 +1,53 @@
+//! Test vectors are from "The Simeck Family of Lightweight Block Ciphers"
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+use simeck::{Simeck32_64, Simeck48_96, Simeck64_128};
+
+#[test]
+fn simeck32_64() {
+    let key = hex!("1918111009080100");
+    let plaintext = hex!("65656877");
+    let ciphertext = hex!("770d2c76");
+    let cipher = Simeck32_64::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn simeck48_96() {
+    let key = hex!("1a19181211100a0908020100");
+    let plaintext = hex!("72696320646e");
+    let ciphertext = hex!("f3cf25e33b36");
+    let cipher = Simeck48_96::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn simeck64_128() {
+    let key = hex!("1b1a1918131211100b0a090803020100");
+    let plaintext = hex!("656b696c20646e75");
+    let ciphertext = hex!("45ce69025f7ab7ed");
+    let cipher = Simeck64_128::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
@@ -1,5 +1,10 @@
 //! Test vectors are from GM/T 0002-2012
 
+#![allow(clippy::clone_on_copy)]
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
 use cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
 use hex_literal::hex;
 use sm4::Sm4;
@@ -0,0 +1,18 @@
+/// TWINE S-box.
+pub(crate) const SBOX: [u8; 16] = [
+    0xc, 0x0, 0xf, 0xa, 0x2, 0xb, 0x9, 0x5, 0x8, 0x3, 0xd, 0x7, 0x1, 0xe, 0x6, 0x4,
+];
+
+/// Block shuffle, nibble `h` is moved to position `PI[h]`.
+pub(crate) const PI: [usize; 16] = [5, 0, 1, 4, 7, 12, 3, 8, 13, 6, 9, 2, 15, 10, 11, 14];
+
+/// Inverse block shuffle.
+pub(crate) const PI_INV: [usize; 16] = [1, 2, 11, 6, 3, 0, 9, 4, 7, 10, 13, 14, 5, 8, 15, 12];
+
+/// Key schedule round constants, `x^(i - 1)` in `GF(2^6)` defined by
+/// `x^6 + x + 1`.
+pub(crate) const CON: [u8; 35] = [
+    0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x03, 0x06, 0x0c, 0x18, 0x30, 0x23, 0x05, 0x0a, 0x14, 0x28,
+    0x13, 0x26, 0x0f, 0x1e, 0x3c, 0x3b, 0x35, 0x29, 0x11, 0x22, 0x07, 0x0e, 0x1c, 0x38, 0x33, 0x25,
+    0x09, 0x12, 0x24,
+];
@@ -0,0 +1,193 @@
+//! An implementation of the [TWINE][1] lightweight block cipher.
+//!
+//! TWINE has 64-bit blocks and 80-bit ([`Twine80`]) or 128-bit
+//! ([`Twine128`]) keys. It is a Type-2 generalized Feistel network with 16
+//! nibble branches and 36 rounds, using an improved block shuffle instead
+//! of the usual cyclic shift of the branches.
+//!
+//! This implementation uses table lookups for the S-box and is therefore
+//! not constant-time.
+//!
+//! # Examples
+//! ```
+//! use twine::{
+//!     cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher},
+//!     Twine80,
+//! };
+//!
+//! let key = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99];
+//! let cipher = Twine80::new(&GenericArray::from(key));
+//! let mut block = GenericArray::from([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+//! cipher.encrypt_block(&mut block);
+//! assert_eq!(block[..], [0x7c, 0x1f, 0x0f, 0x80, 0xb1, 0xdf, 0x9c, 0x28]);
+//! cipher.decrypt_block(&mut block);
+//! assert_eq!(block[..], [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+//! ```
+//!
+//! [1]: https://www.nec.com/en/global/rd/tg/code/symenc/twine.html
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U1, U10, U16, U8},
+    generic_array::GenericArray,
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+
+mod consts;
+
+use consts::{CON, PI, PI_INV, SBOX};
+
+const ROUNDS: usize = 36;
+
+/// Round keys, eight nibbles per round.
+type RoundKeys = [[u8; 8]; ROUNDS];
+
+/// Split `bytes` into nibbles, most significant nibble first.
+fn to_nibbles(bytes: &[u8], nibbles: &mut [u8]) {
+    for (n, b) in nibbles.chunks_exact_mut(2).zip(bytes) {
+        n[0] = b >> 4;
+        n[1] = b & 0xf;
+    }
+}
+
+/// Join nibbles into `bytes`, most significant nibble first.
+fn from_nibbles(nibbles: &[u8], bytes: &mut [u8]) {
+    for (b, n) in bytes.iter_mut().zip(nibbles.chunks_exact(2)) {
+        *b = (n[0] << 4) | n[1];
+    }
+}
+
+/// Key schedule on the nibbles `wk` of the 80-bit or 128-bit key.
+///
+/// `sel` are the positions of the round key nibbles and `sub` pairs of
+/// positions `(dst, src)` updated with `wk[dst] ^= S(wk[src])` every round.
+fn expand_key(wk: &mut [u8], sel: &[usize; 8], sub: &[(usize, usize)]) -> RoundKeys {
+    let mut rks = [[0u8; 8]; ROUNDS];
+    for (i, rk) in rks.iter_mut().enumerate() {
+        for (k, &j) in rk.iter_mut().zip(sel.iter()) {
+            *k = wk[j];
+        }
+        if i == ROUNDS - 1 {
+            break;
+        }
+        for &(dst, src) in sub {
+            wk[dst] ^= SBOX[wk[src] as usize];
+        }
+        wk[7] ^= CON[i] >> 3;
+        wk[19] ^= CON[i] & 7;
+        wk[..4].rotate_left(1);
+        wk.rotate_left(4);
+    }
+    rks
+}
+
+#[inline(always)]
+fn feistel(x: &mut [u8; 16], rk: &[u8; 8]) {
+    for (j, k) in rk.iter().enumerate() {
+        x[2 * j + 1] ^= SBOX[(x[2 * j] ^ k) as usize];
+    }
+}
+
+#[inline(always)]
+fn shuffle(x: &[u8; 16], pi: &[usize; 16]) -> [u8; 16] {
+    let mut res = [0u8; 16];
+    for (&v, &p) in x.iter().zip(pi.iter()) {
+        res[p] = v;
+    }
+    res
+}
+
+fn encrypt(rks: &RoundKeys, block: &mut GenericArray<u8, U8>) {
+    let mut x = [0u8; 16];
+    to_nibbles(block, &mut x);
+    for rk in &rks[..ROUNDS - 1] {
+        feistel(&mut x, rk);
+        x = shuffle(&x, &PI);
+    }
+    feistel(&mut x, &rks[ROUNDS - 1]);
+    from_nibbles(&x, block);
+}
+
+fn decrypt(rks: &RoundKeys, block: &mut GenericArray<u8, U8>) {
+    let mut x = [0u8; 16];
+    to_nibbles(block, &mut x);
+    feistel(&mut x, &rks[ROUNDS - 1]);
+    for rk in rks[..ROUNDS - 1].iter().rev() {
+        x = shuffle(&x, &PI_INV);
+        feistel(&mut x, rk);
+    }
+    from_nibbles(&x, block);
+}
+
+/// TWINE block cipher with 80-bit key.
+#[derive(Clone)]
+pub struct Twine80 {
+    rks: RoundKeys,
+}
+
+/// TWINE block cipher with 128-bit key.
+#[derive(Clone)]
+pub struct Twine128 {
+    rks: RoundKeys,
+}
+
+impl NewBlockCipher for Twine80 {
+    type KeySize = U10;
+
+    fn new(key: &GenericArray<u8, U10>) -> Self {
+        let mut wk = [0u8; 20];
+        to_nibbles(key, &mut wk);
+        let sel = [1, 3, 4, 6, 13, 14, 15, 16];
+        Self {
+            rks: expand_key(&mut wk, &sel, &[(1, 0), (4, 16)]),
+        }
+    }
+}
+
+impl NewBlockCipher for Twine128 {
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        let mut wk = [0u8; 32];
+        to_nibbles(key, &mut wk);
+        let sel = [2, 3, 12, 15, 17, 18, 28, 31];
+        Self {
+            rks: expand_key(&mut wk, &sel, &[(1, 0), (4, 16), (23, 30)]),
+        }
+    }
+}
+
+macro_rules! impl_block_cipher {
+    ($name:ident) => {
+        impl BlockCipher for $name {
+            type BlockSize = U8;
+            type ParBlocks = U1;
+        }
+
+        impl BlockEncrypt for $name {
+            fn encrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+                encrypt(&self.rks, block);
+            }
+        }
+
+        impl BlockDecrypt for $name {
+            fn decrypt_block(&self, block: &mut GenericArray<u8, U8>) {
+                decrypt(&self.rks, block);
+            }
+        }
+
+        opaque_debug::implement!($name);
+    };
+}
+
+impl_block_cipher!(Twine80);
+impl_block_cipher!(Twine128);
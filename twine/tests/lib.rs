@@ -0,0 +1,39 @@
+//! Test vectors are from "TWINE: A Lightweight Block Cipher for Multiple
+//! Platforms"
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+use twine::{Twine128, Twine80};
+
+#[test]
+fn twine80() {
+    let key = hex!("00112233445566778899");
+    let plaintext = hex!("0123456789abcdef");
+    let ciphertext = hex!("7c1f0f80b1df9c28");
+    let cipher = Twine80::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
+
+#[test]
+fn twine128() {
+    let key = hex!("00112233445566778899aabbccddeeff");
+    let plaintext = hex!("0123456789abcdef");
+    let ciphertext = hex!("979ff9b379b5a9b8");
+    let cipher = Twine128::new(&key.into());
+
+    let mut block = plaintext.into();
+    cipher.encrypt_block(&mut block);
+    assert_eq!(&ciphertext, block.as_slice());
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(&plaintext, block.as_slice());
+}
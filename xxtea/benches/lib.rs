@@ -0,0 +1,3 @@
+#![feature(test)]
+
+cipher::block_cipher_bench!(xxtea::Xxtea128, 16);
@@ -0,0 +1,281 @@
+//! An implementation of the [XXTEA][1] (Corrected Block TEA) cipher.
+//!
+//! Unlike most block ciphers XXTEA operates on messages of variable length,
+//! any sequence of at least two 32-bit words forms a single block. The
+//! [`Xxtea`] type exposes this directly with methods working on word and
+//! byte slices, while [`XxteaBlock`] adapts it to the fixed block size
+//! traits of the [`cipher`] crate, so it can be used with generic modes of
+//! operation.
+//!
+//! Words are encoded in little-endian byte order, following the reference
+//! implementation.
+//!
+//! # Examples
+//! ```
+//! use xxtea::Xxtea;
+//!
+//! let cipher = Xxtea::new(&[0x42; 16].into());
+//! let mut msg = *b"variable length message!";
+//! cipher.encrypt_bytes(&mut msg).unwrap();
+//! cipher.decrypt_bytes(&mut msg).unwrap();
+//! assert_eq!(&msg, b"variable length message!");
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/XXTEA
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use cipher;
+
+use cipher::{
+    consts::{U0, U1, U16, U4, U8},
+    errors::InvalidLength,
+    generic_array::{
+        typenum::{IsGreaterOrEqual, True},
+        ArrayLength, GenericArray,
+    },
+    BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+};
+use core::{convert::TryInto, marker::PhantomData, ops::Rem};
+
+const DELTA: u32 = 0x9e37_79b9;
+
+/// Mutable sequence of 32-bit words processed by the cipher.
+trait Words {
+    fn len(&self) -> usize;
+    fn get(&self, i: usize) -> u32;
+    fn set(&mut self, i: usize, val: u32);
+}
+
+impl Words for [u32] {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[u32]>::len(self)
+    }
+
+    #[inline(always)]
+    fn get(&self, i: usize) -> u32 {
+        self[i]
+    }
+
+    #[inline(always)]
+    fn set(&mut self, i: usize, val: u32) {
+        self[i] = val;
+    }
+}
+
+/// Byte slice viewed as little-endian words.
+struct LeBytes<'a>(&'a mut [u8]);
+
+impl Words for LeBytes<'_> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len() / 4
+    }
+
+    #[inline(always)]
+    fn get(&self, i: usize) -> u32 {
+        u32::from_le_bytes(self.0[4 * i..4 * i + 4].try_into().unwrap())
+    }
+
+    #[inline(always)]
+    fn set(&mut self, i: usize, val: u32) {
+        self.0[4 * i..4 * i + 4].copy_from_slice(&val.to_le_bytes());
+    }
+}
+
+/// XXTEA cipher operating on variable-length messages.
+#[derive(Clone)]
+pub struct Xxtea {
+    key: [u32; 4],
+}
+
+impl Xxtea {
+    /// Initialize cipher with 128-bit key.
+    pub fn new(key: &GenericArray<u8, U16>) -> Self {
+        let mut k = [0u32; 4];
+        for (k, chunk) in k.iter_mut().zip(key.chunks_exact(4)) {
+            *k = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self { key: k }
+    }
+
+    /// Encrypt `data` in place as a single block.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than two words.
+    pub fn encrypt_in_place(&self, data: &mut [u32]) -> Result<(), InvalidLength> {
+        check_len(data.len())?;
+        self.encrypt_words(data);
+        Ok(())
+    }
+
+    /// Decrypt `data` in place as a single block.
+    ///
+    /// Returns [`InvalidLength`] if `data` is shorter than two words.
+    pub fn decrypt_in_place(&self, data: &mut [u32]) -> Result<(), InvalidLength> {
+        check_len(data.len())?;
+        self.decrypt_words(data);
+        Ok(())
+    }
+
+    /// Encrypt `data` in place as a single block of little-endian words.
+    ///
+    /// Returns [`InvalidLength`] if the length of `data` is not a multiple
+    /// of 4 or shorter than 8 bytes.
+    pub fn encrypt_bytes(&self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        check_byte_len(data.len())?;
+        self.encrypt_words(&mut LeBytes(data));
+        Ok(())
+    }
+
+    /// Decrypt `data` in place as a single block of little-endian words.
+    ///
+    /// Returns [`InvalidLength`] if the length of `data` is not a multiple
+    /// of 4 or shorter than 8 bytes.
+    pub fn decrypt_bytes(&self, data: &mut [u8]) -> Result<(), InvalidLength> {
+        check_byte_len(data.len())?;
+        self.decrypt_words(&mut LeBytes(data));
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn mx(&self, sum: u32, y: u32, z: u32, p: usize, e: usize) -> u32 {
+        let a = (z >> 5 ^ y << 2).wrapping_add(y >> 3 ^ z << 4);
+        let b = (sum ^ y).wrapping_add(self.key[(p & 3) ^ e] ^ z);
+        a ^ b
+    }
+
+    fn encrypt_words<W: Words + ?Sized>(&self, v: &mut W) {
+        let n = v.len();
+        let rounds = 6 + 52 / n;
+        let mut sum = 0u32;
+        let mut z = v.get(n - 1);
+        for _ in 0..rounds {
+            sum = sum.wrapping_add(DELTA);
+            let e = (sum >> 2 & 3) as usize;
+            for p in 0..n {
+                let y = v.get((p + 1) % n);
+                z = v.get(p).wrapping_add(self.mx(sum, y, z, p, e));
+                v.set(p, z);
+            }
+        }
+    }
+
+    fn decrypt_words<W: Words + ?Sized>(&self, v: &mut W) {
+        let n = v.len();
+        let rounds = 6 + 52 / n;
+        let mut sum = (rounds as u32).wrapping_mul(DELTA);
+        let mut y = v.get(0);
+        for _ in 0..rounds {
+            let e = (sum >> 2 & 3) as usize;
+            for p in (0..n).rev() {
+                let z = v.get((p + n - 1) % n);
+                y = v.get(p).wrapping_sub(self.mx(sum, y, z, p, e));
+                v.set(p, y);
+            }
+            sum = sum.wrapping_sub(DELTA);
+        }
+    }
+}
+
+fn check_len(words: usize) -> Result<(), InvalidLength> {
+    if words < 2 {
+        Err(InvalidLength)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_byte_len(bytes: usize) -> Result<(), InvalidLength> {
+    if bytes % 4 != 0 {
+        return Err(InvalidLength);
+    }
+    check_len(bytes / 4)
+}
+
+opaque_debug::implement!(Xxtea);
+
+/// XXTEA with a fixed block size of `N` bytes.
+///
+/// `N` has to be a multiple of 4 and at least 8.
+pub struct XxteaBlock<N> {
+    cipher: Xxtea,
+    _pd: PhantomData<N>,
+}
+
+/// XXTEA with 64-bit blocks.
+pub type Xxtea64 = XxteaBlock<U8>;
+
+/// XXTEA with 128-bit blocks.
+pub type Xxtea128 = XxteaBlock<U16>;
+
+impl<N> XxteaBlock<N> {
+    /// Get reference to the variable-length cipher.
+    pub fn get_ref(&self) -> &Xxtea {
+        &self.cipher
+    }
+}
+
+impl<N> Clone for XxteaBlock<N> {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<N> From<Xxtea> for XxteaBlock<N> {
+    fn from(cipher: Xxtea) -> Self {
+        Self {
+            cipher,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<N> NewBlockCipher for XxteaBlock<N> {
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        Xxtea::new(key).into()
+    }
+}
+
+impl<N> BlockCipher for XxteaBlock<N>
+where
+    N: ArrayLength<u8> + Rem<U4, Output = U0> + IsGreaterOrEqual<U8, Output = True>,
+{
+    type BlockSize = N;
+    type ParBlocks = U1;
+}
+
+impl<N> BlockEncrypt for XxteaBlock<N>
+where
+    N: ArrayLength<u8> + Rem<U4, Output = U0> + IsGreaterOrEqual<U8, Output = True>,
+{
+    fn encrypt_block(&self, block: &mut GenericArray<u8, N>) {
+        self.cipher.encrypt_words(&mut LeBytes(block));
+    }
+}
+
+impl<N> BlockDecrypt for XxteaBlock<N>
+where
+    N: ArrayLength<u8> + Rem<U4, Output = U0> + IsGreaterOrEqual<U8, Output = True>,
+{
+    fn decrypt_block(&self, block: &mut GenericArray<u8, N>) {
+        self.cipher.decrypt_words(&mut LeBytes(block));
+    }
+}
+
+impl<N> core::fmt::Debug for XxteaBlock<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("XxteaBlock { ... }")
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+use cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, NewBlockCipher};
+use hex_literal::hex;
+use xxtea::{Xxtea, Xxtea128, Xxtea64, XxteaBlock};
+
+/// Vectors computed with the reference implementation by Wheeler and
+/// Needham, words serialized in little-endian order.
+const VECTORS: &[(&[u8], &[u8], &[u8])] = &[
+    (
+        &hex!("00000000000000000000000000000000"),
+        &hex!("0000000000000000"),
+        &hex!("ab043705808c5d57"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f"),
+        &hex!("000102030405060708090a0b0c0d0e0f"),
+        &hex!("557024c2068744014b13416b0a583175"),
+    ),
+    (
+        &hex!("000102030405060708090a0b0c0d0e0f"),
+        &hex!("101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f30313233"),
+        &hex!("2e5e333b88484c93bdf9c837b30790006118d0cc67d962f57adabfc23fa4be08d300cbc9"),
+    ),
+];
+
+#[test]
+fn variable_length() {
+    for &(key, pt, ct) in VECTORS {
+        let cipher = Xxtea::new(GenericArray::from_slice(key));
+        let mut buf = pt.to_vec();
+        cipher.encrypt_bytes(&mut buf).unwrap();
+        assert_eq!(buf, ct);
+        cipher.decrypt_bytes(&mut buf).unwrap();
+        assert_eq!(buf, pt);
+
+        let mut words: Vec<u32> = pt
+            .chunks(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+        cipher.encrypt_in_place(&mut words).unwrap();
+        let bytes: Vec<u8> = words
+            .iter()
+            .flat_map(|w| w.to_le_bytes().to_vec())
+            .collect();
+        assert_eq!(bytes, ct);
+        cipher.decrypt_in_place(&mut words).unwrap();
+    }
+}
+
+#[test]
+fn invalid_lengths() {
+    let cipher = Xxtea::new(&Default::default());
+    assert!(cipher.encrypt_in_place(&mut [0]).is_err());
+    assert!(cipher.decrypt_in_place(&mut []).is_err());
+    assert!(cipher.encrypt_bytes(&mut [0; 4]).is_err());
+    assert!(cipher.decrypt_bytes(&mut [0; 9]).is_err());
+}
+
+#[test]
+fn fixed_block() {
+    let (key, pt, ct) = VECTORS[0];
+    let cipher = Xxtea64::new_from_slice(key).unwrap();
+    let mut block = GenericArray::clone_from_slice(pt);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(ct, block.as_slice());
+    cipher.decrypt_block(&mut block);
+    assert_eq!(pt, block.as_slice());
+
+    let (key, pt, ct) = VECTORS[1];
+    let cipher = Xxtea128::new_from_slice(key).unwrap();
+    let mut block = GenericArray::clone_from_slice(pt);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(ct, block.as_slice());
+    cipher.decrypt_block(&mut block);
+    assert_eq!(pt, block.as_slice());
+
+    let (key, pt, ct) = VECTORS[2];
+    let cipher = XxteaBlock::<cipher::consts::U36>::new_from_slice(key).unwrap();
+    let mut block = GenericArray::clone_from_slice(pt);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(ct, block.as_slice());
+    cipher.decrypt_block(&mut block);
+    assert_eq!(pt, block.as_slice());
+}